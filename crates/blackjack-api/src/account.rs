@@ -0,0 +1,322 @@
+//! Account self-service handlers
+//!
+//! Routes under `/api/v1/users/me` let players manage their own account. They
+//! all act on the account behind the JWT, so a user can never reach someone
+//! else's data through them.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/users/me` - Show the account, including the email
+//! - `PATCH /api/v1/users/me` - Change the display name or email
+//! - `DELETE /api/v1/users/me` - Delete the account after a grace period
+//! - `GET /api/v1/users/me/export` - Download everything stored about the account
+//!
+//! Deleting an account deactivates it and signs it out everywhere. Once
+//! `[account] deletion_grace_days` have passed, [`purge_deleted_accounts`]
+//! removes it: its seats in past games move to a tombstone ID named
+//! `deleted-xxxxxxxx` and its invitations are dropped. Until then an
+//! administrator can reactivate the account, which cancels the deletion.
+
+use crate::auth::Claims;
+use crate::error::ApiError;
+use crate::handlers::{claims_actor, issue_token, record_audit, send_verification_email};
+use crate::middleware::RequestId;
+use axum::extract::State;
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use blackjack_service::audit::actions;
+use blackjack_service::{
+    GameError, GameHistoryEntry, InvitationInfo, PlayerAchievements, PlayerGameEntry, UserInfo,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// ID of the account behind `claims`
+fn account_id(claims: &Claims) -> Result<Uuid, ApiError> {
+    Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })
+}
+
+/// Shows the authenticated account
+///
+/// # Endpoint
+///
+/// `GET /api/v1/users/me`
+///
+/// # Response
+///
+/// **Success (200 OK)**: the account, including email, role and statistics
+/// (never the password hash).
+#[tracing::instrument(skip(state, claims))]
+pub async fn get_account(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<UserInfo>, ApiError> {
+    let user = state.user_service.get_user(account_id(&claims)?)?;
+    Ok(Json(UserInfo::from(&user)))
+}
+
+/// Changes to the authenticated account; omitted fields stay as they are
+#[derive(Debug, Deserialize)]
+pub struct UpdateAccountRequest {
+    /// New public name
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// New email address, which must be verified again
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Current password, required to change the email
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// Account after an update
+#[derive(Debug, Serialize)]
+pub struct UpdateAccountResponse {
+    pub user: UserInfo,
+    /// Replacement token when the email changed, since earlier tokens are revoked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// Changes the display name and/or email of the authenticated account
+///
+/// # Endpoint
+///
+/// `PATCH /api/v1/users/me`
+///
+/// # Request Body (JSON)
+///
+/// ```json
+/// {
+///   "display_name": "lucky_ace",
+///   "email": "new@example.com",
+///   "password": "CurrentP@ss1"
+/// }
+/// ```
+///
+/// Changing the email needs the current password. The new address is
+/// unverified until the owner follows the link mailed to it, and every earlier
+/// token is revoked; the response carries a replacement `token`.
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Nothing to change, malformed name or email, or
+///   missing password
+/// - **401 Unauthorized** - Wrong password
+/// - **409 Conflict** - `DISPLAY_NAME_TAKEN` or `USER_EXISTS`
+#[tracing::instrument(skip(state, claims, payload))]
+pub async fn update_account(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Json(payload): Json<UpdateAccountRequest>,
+) -> Result<Json<UpdateAccountResponse>, ApiError> {
+    let user_id = account_id(&claims)?;
+    if payload.display_name.is_none() && payload.email.is_none() {
+        return Err(GameError::ValidationError(
+            "Provide a display_name or email to change".to_string(),
+        )
+        .into());
+    }
+
+    let result = (|| {
+        if let Some(display_name) = &payload.display_name {
+            state.user_service.set_display_name(user_id, display_name)?;
+        }
+        match (&payload.email, &payload.password) {
+            (Some(email), Some(password)) => state
+                .user_service
+                .update_email(user_id, email, password)
+                .map(Some),
+            (Some(_), None) => Err(GameError::ValidationError(
+                "password is required to change the email".to_string(),
+            )),
+            (None, _) => Ok(None),
+        }
+    })()
+    .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::ACCOUNT_UPDATE,
+        format!("users/{}", user_id),
+        &request_id,
+        &result,
+    );
+
+    let token = match result? {
+        Some(user) if user.email != claims.email => {
+            if !send_verification_email(&state, user.id, &user.email).await {
+                tracing::warn!(user_id = %user.id, "Verification email for new address not sent");
+            }
+            Some(issue_token(&state, &user)?)
+        }
+        _ => None,
+    };
+
+    let user = state.user_service.get_user(user_id)?;
+    tracing::info!(user_id = %user_id, "Account updated");
+
+    Ok(Json(UpdateAccountResponse {
+        user: UserInfo::from(&user),
+        token,
+    }))
+}
+
+/// Password confirmation for deleting an account
+#[derive(Debug, Deserialize)]
+pub struct DeleteAccountRequest {
+    pub password: String,
+}
+
+/// Schedules the authenticated account for deletion
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/users/me`
+///
+/// # Request Body (JSON)
+///
+/// ```json
+/// {
+///   "password": "CurrentP@ss1"
+/// }
+/// ```
+///
+/// # Response
+///
+/// **Accepted (202)**: the account is deactivated and signed out at once and
+/// anonymised after `[account] deletion_grace_days` (30 by default).
+///
+/// ```json
+/// {
+///   "message": "Account deactivated and scheduled for deletion",
+///   "delete_after": "2026-02-01T12:00:00+00:00"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - Wrong password
+#[tracing::instrument(skip(state, claims, payload))]
+pub async fn delete_account(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Json(payload): Json<DeleteAccountRequest>,
+) -> Result<(StatusCode, Json<serde_json::Value>), ApiError> {
+    let user_id = account_id(&claims)?;
+
+    let result = state
+        .user_service
+        .request_deletion(user_id, &payload.password)
+        .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::ACCOUNT_DELETION,
+        format!("users/{}", user_id),
+        &request_id,
+        &result,
+    );
+    result?;
+
+    let delete_after =
+        chrono::Utc::now() + chrono::Duration::days(state.config.account.deletion_grace_days);
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({
+            "message": "Account deactivated and scheduled for deletion",
+            "delete_after": delete_after.to_rfc3339(),
+        })),
+    ))
+}
+
+/// Everything stored about an account
+#[derive(Debug, Serialize)]
+pub struct AccountExport {
+    pub exported_at: String,
+    /// Account details and statistics
+    pub profile: UserInfo,
+    pub achievements: PlayerAchievements,
+    /// Finished games the account played, from the archive, newest first
+    pub games: Vec<PlayerGameEntry>,
+    /// Games joined that haven't finished yet, newest first
+    pub current_games: Vec<GameHistoryEntry>,
+    pub invitations_sent: Vec<InvitationInfo>,
+    pub invitations_received: Vec<InvitationInfo>,
+}
+
+/// Downloads everything stored about the authenticated account
+///
+/// # Endpoint
+///
+/// `GET /api/v1/users/me/export`
+///
+/// # Response
+///
+/// **Success (200 OK)**: an [`AccountExport`] as a `blackjack-account.json`
+/// attachment.
+#[tracing::instrument(skip(state, claims))]
+pub async fn export_account(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user = state.user_service.get_user(account_id(&claims)?)?;
+
+    let export = AccountExport {
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        profile: UserInfo::from(&user),
+        achievements: state.user_service.achievements(user.id)?,
+        games: state.game_service.all_player_games(user.id),
+        current_games: state
+            .game_service
+            .game_history(user.id)
+            .into_iter()
+            .filter(|game| !game.finished)
+            .collect(),
+        invitations_sent: state
+            .invitation_service
+            .get_sent_by_user(user.id, &state.user_service),
+        invitations_received: state
+            .invitation_service
+            .get_received_by_email(&user.email, &state.user_service),
+    };
+
+    tracing::info!(user_id = %user.id, "Account data exported");
+
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"blackjack-account.json\"",
+        )],
+        Json(export),
+    ))
+}
+
+/// Anonymises the accounts whose deletion grace period is over
+///
+/// Run periodically by the server. Returns how many accounts were removed.
+pub fn purge_deleted_accounts(state: &crate::AppState) -> usize {
+    let grace = chrono::Duration::days(state.config.account.deletion_grace_days);
+    let deleted = state.game_service.purge_deleted_accounts(grace);
+    for account in &deleted {
+        state
+            .invitation_service
+            .forget_user(account.user_id, &account.email);
+        state.tournament_service.anonymize_player(
+            account.user_id,
+            account.tombstone_id,
+            &account.tombstone_name(),
+        );
+    }
+    deleted.len()
+}
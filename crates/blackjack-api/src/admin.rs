@@ -0,0 +1,736 @@
+//! Server administration handlers
+//!
+//! Routes under `/api/v1/admin` let operators manage accounts, games and
+//! invitations without touching code. They are only reachable through
+//! [`admin_middleware`](crate::middleware::admin_middleware), which admits
+//! moderators and administrators and writes an audit record for every call.
+//! The same audit trail also holds security events from the public API, such
+//! as logins and kicks.
+//!
+//! # Endpoints
+//!
+//! Moderators and administrators:
+//!
+//! - `GET /api/v1/admin/users` - List accounts
+//! - `GET /api/v1/admin/users/:user_id` - Show an account
+//! - `POST /api/v1/admin/users/:user_id/activate` - Reactivate an account
+//! - `POST /api/v1/admin/users/:user_id/deactivate` - Suspend an account
+//! - `GET /api/v1/admin/games` - List games in any phase
+//! - `GET /api/v1/admin/games/:game_id` - Full internal game state
+//! - `POST /api/v1/admin/games/:game_id/abort` - Force-finish a stuck game
+//! - `GET /api/v1/admin/invitations` - List invitations (`?status=pending`)
+//! - `POST /api/v1/admin/invitations/cleanup` - Expire overdue invitations
+//! - `GET /api/v1/admin/stats` - Server statistics
+//!
+//! Administrators only:
+//!
+//! - `POST /api/v1/admin/users/:user_id/unlock` - Unlock after failed logins
+//! - `POST /api/v1/admin/users/:user_id/reset-password` - Set a new password
+//! - `PUT /api/v1/admin/users/:user_id/role` - Change a platform role
+//! - `GET /api/v1/admin/config` - Running configuration, secrets masked
+//! - `GET /api/v1/admin/audit` - Query the security audit trail
+//! - `GET /api/v1/admin/audit/export` - Export the audit trail as JSON Lines
+//!
+//! Moderators can only suspend or reactivate regular `user` accounts.
+
+use crate::auth::Claims;
+use crate::config::AppConfig;
+use crate::error::ApiError;
+use axum::body::Body;
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use blackjack_core::{Game, InvitationStatus, PlatformRole};
+use blackjack_service::{
+    AuditFilter, AuditOutcome, AuditRecord, GameError, GameOverview, InvitationInfo,
+    MAX_AUDIT_RECORDS, ServiceConfig, UserInfo,
+};
+use chrono::{DateTime, Utc};
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use uuid::Uuid;
+
+/// Rejects callers below `role`
+///
+/// `claims.role` has been refreshed from the user store by the admin middleware.
+fn require_role(claims: &Claims, role: PlatformRole) -> Result<(), ApiError> {
+    if claims.role < role {
+        return Err(GameError::InsufficientPermissions.into());
+    }
+    Ok(())
+}
+
+/// Rejects moderators acting on other moderators or administrators
+fn require_authority_over(
+    state: &crate::AppState,
+    claims: &Claims,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let target = state.user_service.get_user(user_id)?;
+    if claims.role < PlatformRole::Admin && target.role > PlatformRole::User {
+        return Err(GameError::InsufficientPermissions.into());
+    }
+    Ok(())
+}
+
+/// List of accounts
+#[derive(Debug, Serialize)]
+pub struct UsersResponse {
+    pub users: Vec<UserInfo>,
+    pub count: usize,
+}
+
+/// List every account, ordered by email
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/users`
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "users": [
+///     {
+///       "id": "650e8400-e29b-41d4-a716-446655440001",
+///       "email": "player@example.com",
+///       "is_active": true,
+///       "is_locked": false,
+///       "failed_login_attempts": 0,
+///       "created_at": "2025-01-02T12:00:00Z",
+///       "last_login": null,
+///       "stats": { "games_played": 3, "games_won": 1, ... }
+///     }
+///   ],
+///   "count": 1
+/// }
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn list_users(State(state): State<crate::AppState>) -> Json<UsersResponse> {
+    let users = state.user_service.list_users();
+    Json(UsersResponse {
+        count: users.len(),
+        users,
+    })
+}
+
+/// Show one account
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/users/:user_id`
+///
+/// # Errors
+///
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state))]
+pub async fn get_user(
+    State(state): State<crate::AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<UserInfo>, ApiError> {
+    let user = state.user_service.get_user(user_id)?;
+    Ok(Json(UserInfo::from(&user)))
+}
+
+/// Reactivate a suspended account
+///
+/// # Endpoint
+///
+/// `POST /api/v1/admin/users/:user_id/activate`
+///
+/// # Response (200 OK)
+///
+/// The updated account, as returned by `GET /api/v1/admin/users/:user_id`.
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Moderator acting on a moderator or administrator
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn activate_user(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<UserInfo>, ApiError> {
+    require_authority_over(&state, &claims, user_id)?;
+    state.user_service.activate_account(user_id)?;
+    tracing::info!(admin = claims.email, user_id = %user_id, "Admin activated account");
+    get_user(State(state), Path(user_id)).await
+}
+
+/// Suspend an account, preventing login
+///
+/// Tokens that were already issued are revoked right away.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/admin/users/:user_id/deactivate`
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Administrators can't deactivate themselves
+/// - **403 Forbidden** - Moderator acting on a moderator or administrator
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn deactivate_user(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<UserInfo>, ApiError> {
+    if claims.user_id == user_id.to_string() {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "VALIDATION_ERROR",
+            "Administrators can't deactivate their own account",
+        ));
+    }
+    require_authority_over(&state, &claims, user_id)?;
+
+    state.user_service.deactivate_account(user_id)?;
+    tracing::info!(admin = claims.email, user_id = %user_id, "Admin deactivated account");
+    get_user(State(state), Path(user_id)).await
+}
+
+/// Unlock an account locked by too many failed logins
+///
+/// # Endpoint
+///
+/// `POST /api/v1/admin/users/:user_id/unlock`
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Not an administrator
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn unlock_user(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<UserInfo>, ApiError> {
+    require_role(&claims, PlatformRole::Admin)?;
+    state.user_service.unlock_account(user_id)?;
+    tracing::info!(admin = claims.email, user_id = %user_id, "Admin unlocked account");
+    get_user(State(state), Path(user_id)).await
+}
+
+/// Request body for an administrator password reset
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordRequest {
+    /// New password; must meet the usual complexity rules
+    pub new_password: String,
+}
+
+/// Set a new password for an account (also unlocks it)
+///
+/// # Endpoint
+///
+/// `POST /api/v1/admin/users/:user_id/reset-password`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "new_password": "N3w!Passw0rd"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Password doesn't meet requirements
+/// - **403 Forbidden** - Not an administrator
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state, claims, payload))]
+pub async fn reset_user_password(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<ResetPasswordRequest>,
+) -> Result<Json<UserInfo>, ApiError> {
+    require_role(&claims, PlatformRole::Admin)?;
+    state
+        .user_service
+        .reset_password(user_id, &payload.new_password)?;
+    tracing::info!(admin = claims.email, user_id = %user_id, "Admin reset password");
+    get_user(State(state), Path(user_id)).await
+}
+
+/// Request body for changing a platform role
+#[derive(Debug, Deserialize)]
+pub struct SetRoleRequest {
+    /// `user`, `moderator` or `admin`
+    pub role: PlatformRole,
+}
+
+/// Change an account's platform role
+///
+/// The new role applies to the account's next admin request; its token
+/// carries the new role after the next login.
+///
+/// # Endpoint
+///
+/// `PUT /api/v1/admin/users/:user_id/role`
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "role": "moderator"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Administrators can't change their own role
+/// - **403 Forbidden** - Not an administrator
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn set_user_role(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<SetRoleRequest>,
+) -> Result<Json<UserInfo>, ApiError> {
+    require_role(&claims, PlatformRole::Admin)?;
+    if claims.user_id == user_id.to_string() {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "VALIDATION_ERROR",
+            "Administrators can't change their own role",
+        ));
+    }
+
+    state.user_service.set_role(user_id, payload.role)?;
+    tracing::info!(admin = claims.email, user_id = %user_id, role = ?payload.role, "Admin changed role");
+    get_user(State(state), Path(user_id)).await
+}
+
+/// List of games
+#[derive(Debug, Serialize)]
+pub struct GamesResponse {
+    pub games: Vec<GameOverview>,
+    pub count: usize,
+}
+
+/// List every game in any phase, newest first
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/games`
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "games": [
+///     {
+///       "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///       "creator_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "players": ["player@example.com"],
+///       "bots": [],
+///       "enrollment_closed": true,
+///       "finished": false,
+///       "active": true,
+///       "current_turn_player": "player@example.com",
+///       "cards_in_deck": 50,
+///       "created_at": "2025-01-02T12:00:00Z",
+///       "visibility": "public"
+///     }
+///   ],
+///   "count": 1
+/// }
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn list_games(State(state): State<crate::AppState>) -> Json<GamesResponse> {
+    let games = state.game_service.list_games();
+    Json(GamesResponse {
+        count: games.len(),
+        games,
+    })
+}
+
+/// Full internal state of a game, remaining deck included
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/games/:game_id`
+///
+/// # Errors
+///
+/// - **404 Not Found** - Game not found
+#[tracing::instrument(skip(state))]
+pub async fn inspect_game(
+    State(state): State<crate::AppState>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<Game>, ApiError> {
+    Ok(Json(state.game_service.inspect_game(game_id)?))
+}
+
+/// Force-finish a stuck game without playing the dealer
+///
+/// Aborted games are not counted in player statistics.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/admin/games/:game_id/abort`
+///
+/// # Response (200 OK)
+///
+/// The game's summary, with `finished: true` and `active: false`.
+///
+/// # Errors
+///
+/// - **404 Not Found** - Game not found
+/// - **409 Conflict** - Game already finished
+#[tracing::instrument(skip(state, claims))]
+pub async fn abort_game(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<GameOverview>, ApiError> {
+    let overview = state.game_service.abort_game(game_id)?;
+    tracing::info!(admin = claims.email, game_id = %game_id, "Admin aborted game");
+    Ok(Json(overview))
+}
+
+/// Query parameters for listing invitations
+#[derive(Debug, Default, Deserialize)]
+pub struct InvitationsParams {
+    /// Only invitations with this status (`pending`, `accepted`, `declined`,
+    /// `expired` or `revoked`)
+    pub status: Option<InvitationStatus>,
+}
+
+/// List of invitations
+#[derive(Debug, Serialize)]
+pub struct InvitationsResponse {
+    pub invitations: Vec<InvitationInfo>,
+    pub count: usize,
+}
+
+/// List every invitation, newest first
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/invitations?status=pending`
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Unknown status
+#[tracing::instrument(skip(state))]
+pub async fn list_invitations(
+    State(state): State<crate::AppState>,
+    Query(params): Query<InvitationsParams>,
+) -> Json<InvitationsResponse> {
+    let invitations = state
+        .invitation_service
+        .list_all(params.status, &state.user_service);
+    Json(InvitationsResponse {
+        count: invitations.len(),
+        invitations,
+    })
+}
+
+/// Result of an invitation cleanup
+#[derive(Debug, Serialize)]
+pub struct CleanupInvitationsResponse {
+    /// Pending invitations that were past their deadline and are now expired
+    pub expired: usize,
+}
+
+/// Mark every overdue pending invitation as expired
+///
+/// # Endpoint
+///
+/// `POST /api/v1/admin/invitations/cleanup`
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// { "expired": 3 }
+/// ```
+#[tracing::instrument(skip(state, claims))]
+pub async fn cleanup_invitations(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Json<CleanupInvitationsResponse> {
+    let expired = state.invitation_service.cleanup_expired();
+    tracing::info!(
+        admin = claims.email,
+        expired,
+        "Admin cleaned up invitations"
+    );
+    Json(CleanupInvitationsResponse { expired })
+}
+
+/// Account counts
+#[derive(Debug, Serialize)]
+pub struct UserCounts {
+    pub total: usize,
+    pub active: usize,
+    pub locked: usize,
+    /// Accounts per platform role
+    pub by_role: BTreeMap<PlatformRole, usize>,
+}
+
+/// Game counts by phase
+#[derive(Debug, Serialize)]
+pub struct GameCounts {
+    pub total: usize,
+    /// Still accepting players
+    pub enrolling: usize,
+    pub in_progress: usize,
+    pub finished: usize,
+    /// Finished by an administrator (also counted as finished)
+    pub aborted: usize,
+}
+
+/// Server statistics
+#[derive(Debug, Serialize)]
+pub struct ServerStatsResponse {
+    pub users: UserCounts,
+    pub games: GameCounts,
+    /// Invitations per status
+    pub invitations: BTreeMap<String, usize>,
+    /// Records currently kept in the audit trail
+    pub audit_records: usize,
+}
+
+/// Server statistics: accounts, games and invitations
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/stats`
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "users": { "total": 12, "active": 11, "locked": 1, "by_role": { "user": 10, "moderator": 1, "admin": 1 } },
+///   "games": { "total": 5, "enrolling": 1, "in_progress": 2, "finished": 2, "aborted": 1 },
+///   "invitations": { "accepted": 3, "pending": 2 },
+///   "audit_records": 42
+/// }
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn server_stats(State(state): State<crate::AppState>) -> Json<ServerStatsResponse> {
+    let users = state.user_service.list_users();
+    let mut by_role = BTreeMap::new();
+    for user in &users {
+        *by_role.entry(user.role).or_insert(0) += 1;
+    }
+
+    let games = state.game_service.list_games();
+    let mut invitations = BTreeMap::new();
+    for invitation in state.invitation_service.list_all(None, &state.user_service) {
+        *invitations.entry(invitation.status).or_insert(0) += 1;
+    }
+
+    Json(ServerStatsResponse {
+        users: UserCounts {
+            total: users.len(),
+            active: users.iter().filter(|u| u.is_active).count(),
+            locked: users.iter().filter(|u| u.is_locked).count(),
+            by_role,
+        },
+        games: GameCounts {
+            total: games.len(),
+            enrolling: games.iter().filter(|g| !g.enrollment_closed).count(),
+            in_progress: games
+                .iter()
+                .filter(|g| g.enrollment_closed && !g.finished)
+                .count(),
+            finished: games.iter().filter(|g| g.finished).count(),
+            aborted: games.iter().filter(|g| !g.active).count(),
+        },
+        invitations,
+        audit_records: state.audit_log.len(),
+    })
+}
+
+/// Running configuration
+#[derive(Debug, Serialize)]
+pub struct ConfigResponse {
+    /// API configuration with the JWT secret masked
+    pub api: AppConfig,
+    /// Table limits of the game service
+    pub game_service: ServiceConfig,
+}
+
+/// Inspect the running configuration, secrets masked
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/config`
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Not an administrator
+#[tracing::instrument(skip(state, claims))]
+pub async fn get_config(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<ConfigResponse>, ApiError> {
+    require_role(&claims, PlatformRole::Admin)?;
+    Ok(Json(ConfigResponse {
+        api: state.config.redacted(),
+        game_service: state.game_service.config().clone(),
+    }))
+}
+
+/// Query parameters for the audit trail
+///
+/// Every filter is optional and they combine with AND.
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditParams {
+    /// Actor ID or email
+    pub actor: Option<String>,
+    /// Action prefix, e.g. `auth.` or `POST /api/v1/admin/users`
+    pub action: Option<String>,
+    /// Substring of the target
+    pub target: Option<String>,
+    /// `success`, `denied` or `failed`
+    pub outcome: Option<AuditOutcome>,
+    pub request_id: Option<String>,
+    /// RFC 3339 timestamp, inclusive
+    pub since: Option<DateTime<Utc>>,
+    /// RFC 3339 timestamp, exclusive
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum records to return (default 100, ignored by the export)
+    pub limit: Option<usize>,
+}
+
+impl AuditParams {
+    fn filter(self) -> AuditFilter {
+        AuditFilter {
+            actor: self.actor,
+            action: self.action,
+            target: self.target,
+            outcome: self.outcome,
+            request_id: self.request_id,
+            since: self.since,
+            until: self.until,
+        }
+    }
+}
+
+/// Audit records, newest first
+#[derive(Debug, Serialize)]
+pub struct AuditResponse {
+    pub records: Vec<AuditRecord>,
+    pub count: usize,
+}
+
+/// Query the audit trail, newest first
+///
+/// Covers security events (`auth.login`, `auth.password_change`,
+/// `game.close_enrollment`, `game.kick_player`) and every admin request.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/audit?actor=&action=&target=&outcome=&request_id=&since=&until=&limit=100`
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "records": [
+///     {
+///       "id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+///       "timestamp": "2025-01-02T12:00:00Z",
+///       "request_id": "0d4c8f4e-57a4-4b8e-9a7a-2f1f0c6b1c55",
+///       "actor_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "actor_email": "player@example.com",
+///       "actor_role": "user",
+///       "action": "game.kick_player",
+///       "target": "games/550e8400-e29b-41d4-a716-446655440000/players/750e8400-e29b-41d4-a716-446655440002",
+///       "outcome": "denied",
+///       "status": 403
+///     }
+///   ],
+///   "count": 1
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Unknown outcome or malformed timestamp
+/// - **403 Forbidden** - Not an administrator
+#[tracing::instrument(skip(state, claims))]
+pub async fn list_audit_records(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(params): Query<AuditParams>,
+) -> Result<Json<AuditResponse>, ApiError> {
+    require_role(&claims, PlatformRole::Admin)?;
+    let limit = params.limit.unwrap_or(100).min(MAX_AUDIT_RECORDS);
+    let records = state.audit_log.query(&params.filter(), limit);
+    Ok(Json(AuditResponse {
+        count: records.len(),
+        records,
+    }))
+}
+
+/// Export the audit trail as JSON Lines, oldest first
+///
+/// Takes the same filters as [`list_audit_records`] and returns one record
+/// per line as `application/x-ndjson`, ready for log shippers and `jq`.
+/// With `[audit] file` set the response is streamed from the file and covers
+/// the whole trail; otherwise only the records kept in memory (the newest
+/// [`MAX_AUDIT_RECORDS`]) are exported.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/admin/audit/export?since=2025-01-01T00:00:00Z`
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Unknown outcome or malformed timestamp
+/// - **403 Forbidden** - Not an administrator
+/// - **500 Internal Server Error** - The audit file can't be read
+#[tracing::instrument(skip(state, claims))]
+pub async fn export_audit_records(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(params): Query<AuditParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_role(&claims, PlatformRole::Admin)?;
+    let lines = state
+        .audit_log
+        .export_lines(params.filter())
+        .map_err(|err| {
+            tracing::error!(error = %err, "Failed to open audit log for export");
+            ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "AUDIT_EXPORT_FAILED",
+                "The audit log could not be read",
+            )
+        })?;
+
+    // Read the file on a blocking thread and hand the lines over as they come
+    let (sender, receiver) = tokio::sync::mpsc::channel::<String>(256);
+    tokio::task::spawn_blocking(move || {
+        for line in lines {
+            if sender.blocking_send(line).is_err() {
+                break; // the client went away
+            }
+        }
+    });
+    let body = Body::from_stream(stream::unfold(receiver, |mut receiver| async move {
+        let line = receiver.recv().await?;
+        Some((Ok::<_, Infallible>(line), receiver))
+    }));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-ndjson"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"audit.jsonl\"",
+            ),
+        ],
+        body,
+    ))
+}
@@ -1,3 +1,4 @@
+use blackjack_core::PlatformRole;
 use serde::{Deserialize, Serialize};
 
 /// JWT (JSON Web Token) claims structure
@@ -25,10 +26,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// ```
 /// use blackjack_api::auth::Claims;
+/// use blackjack_core::PlatformRole;
 ///
 /// let claims = Claims {
 ///     user_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
 ///     email: "user@example.com".to_string(),
+///     role: PlatformRole::User,
+///     session_version: 0,
 ///     exp: 1704672000, // Unix timestamp
 /// };
 /// ```
@@ -47,6 +51,21 @@ pub struct Claims {
     /// The email associated with the user account for reference.
     pub email: String,
 
+    /// Platform role when the token was issued
+    ///
+    /// Lets clients show or hide administration features. The admin
+    /// middleware re-checks the stored role, so demoting a user takes effect
+    /// immediately. Tokens issued before roles existed decode as `user`.
+    #[serde(default)]
+    pub role: PlatformRole,
+
+    /// Account session version when the token was issued
+    ///
+    /// The auth middleware rejects tokens whose version no longer matches the
+    /// account, which is how a password reset signs the user out everywhere.
+    #[serde(default)]
+    pub session_version: u32,
+
     /// Token expiration time as Unix timestamp (seconds since epoch)
     ///
     /// The JWT library automatically validates this field. Once the current time
@@ -56,3 +75,33 @@ pub struct Claims {
     /// Example: 1704672000 represents January 8, 2024, 00:00:00 UTC
     pub exp: usize,
 }
+
+/// Claims of a signed game join token (shareable invite link)
+///
+/// Join tokens are signed with the same secret as authentication tokens but
+/// carry a different payload, so neither can be used in place of the other.
+/// The token only references the server-side invite link; revocation and
+/// use limits are enforced by `InvitationService` when it is redeemed.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_api::auth::JoinTokenClaims;
+///
+/// let claims = JoinTokenClaims {
+///     link_id: "7c9e6679-7425-40de-944b-e07fc1f90ae7".to_string(),
+///     game_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+///     exp: 1704672000,
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JoinTokenClaims {
+    /// ID of the invite link this token was issued for
+    pub link_id: String,
+
+    /// Game the token grants access to
+    pub game_id: String,
+
+    /// Expiration as Unix timestamp, matching the game's enrollment deadline
+    pub exp: usize,
+}
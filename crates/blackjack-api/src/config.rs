@@ -1,21 +1,45 @@
 use config::{Config, ConfigError, File};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Server configuration settings
 ///
 /// Controls the HTTP server binding address and port.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
     /// The host address to bind to (e.g., "127.0.0.1" or "0.0.0.0")
     pub host: String,
     /// The port number to listen on (typically 8080 for development)
     pub port: u16,
+    /// Public base URL used in links sent by email, e.g. `https://blackjack.example.com`
+    ///
+    /// Defaults to `http://{host}:{port}`.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+impl ServerConfig {
+    /// Base URL clients reach the server at, without a trailing slash
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use blackjack_api::config::ServerConfig;
+    ///
+    /// let server = ServerConfig { host: "127.0.0.1".to_string(), port: 8080, url: None };
+    /// assert_eq!(server.public_url(), "http://127.0.0.1:8080");
+    /// ```
+    pub fn public_url(&self) -> String {
+        match &self.url {
+            Some(url) => url.trim_end_matches('/').to_string(),
+            None => format!("http://{}:{}", self.host, self.port),
+        }
+    }
 }
 
 /// CORS (Cross-Origin Resource Sharing) configuration
 ///
 /// Defines which origins are allowed to make requests to the API.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CorsConfig {
     /// List of allowed origin URLs (e.g., ["http://localhost:3000"])
     pub allowed_origins: Vec<String>,
@@ -24,7 +48,7 @@ pub struct CorsConfig {
 /// JWT (JSON Web Token) authentication configuration
 ///
 /// Controls token generation and validation settings.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JwtConfig {
     /// Secret key used for signing and verifying JWT tokens
     ///
@@ -38,7 +62,7 @@ pub struct JwtConfig {
 /// Rate limiting configuration
 ///
 /// Controls the maximum number of requests allowed per player per minute.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitConfig {
     /// Maximum number of requests allowed per minute per player
     ///
@@ -49,7 +73,7 @@ pub struct RateLimitConfig {
 /// API versioning configuration
 ///
 /// Controls deprecation policies for API versions.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiConfig {
     /// Number of months before an API version is sunset after deprecation
     ///
@@ -57,6 +81,193 @@ pub struct ApiConfig {
     pub version_deprecation_months: u64,
 }
 
+/// Server administration configuration
+///
+/// Lists the accounts promoted to the `admin` platform role when they log in
+/// with a verified address, so a fresh server has someone who can grant
+/// roles to others.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdminConfig {
+    /// Emails of bootstrap administrator accounts
+    ///
+    /// Set from the environment as a comma-separated list:
+    /// `BLACKJACK_ADMIN_EMAILS=ops@example.com,lead@example.com`
+    #[serde(default)]
+    pub emails: Vec<String>,
+}
+
+impl AdminConfig {
+    /// Checks whether `email` belongs to an administrator
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use blackjack_api::config::AdminConfig;
+    ///
+    /// let admin = AdminConfig { emails: vec!["ops@example.com".to_string()] };
+    /// assert!(admin.is_admin("ops@example.com"));
+    /// assert!(!admin.is_admin("player@example.com"));
+    /// ```
+    pub fn is_admin(&self, email: &str) -> bool {
+        self.emails.iter().any(|admin| admin == email)
+    }
+}
+
+/// Security audit log configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditConfig {
+    /// JSON Lines file the audit log is appended to and replayed from
+    ///
+    /// When unset nothing is persisted and only the newest `MAX_AUDIT_RECORDS`
+    /// are kept; when set, exports are streamed from the whole file. Set from
+    /// the environment with `BLACKJACK_AUDIT_FILE=/var/lib/blackjack/audit.jsonl`.
+    #[serde(default)]
+    pub file: Option<String>,
+}
+
+/// Finished game archive configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchiveConfig {
+    /// JSON Lines file finished games are appended to and replayed from
+    ///
+    /// Games are kept in memory only when unset, so players' game history is
+    /// lost on restart. Set from the environment with
+    /// `BLACKJACK_ARCHIVE_FILE=/var/lib/blackjack/games.jsonl`.
+    #[serde(default)]
+    pub file: Option<String>,
+}
+
+/// How outgoing mail is delivered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailTransport {
+    /// Keep messages in memory, or write them to `[mail] outbox` (development)
+    #[default]
+    Outbox,
+    /// Send messages through `[mail.smtp]`
+    Smtp,
+}
+
+/// SMTP relay settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    /// Leave empty to send without authentication
+    pub username: String,
+    pub password: String,
+    /// Upgrade the connection with STARTTLS
+    pub starttls: bool,
+}
+
+impl Default for SmtpConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 587,
+            username: String::new(),
+            password: String::new(),
+            starttls: true,
+        }
+    }
+}
+
+/// Outgoing mail configuration
+///
+/// Keys are single words so every one can be set from the environment, e.g.
+/// `BLACKJACK_MAIL_TRANSPORT=smtp` and `BLACKJACK_MAIL_SMTP_HOST=mail.example.com`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MailConfig {
+    pub transport: MailTransport,
+    /// Sender mailbox, e.g. `Blackjack <noreply@example.com>`
+    pub from: String,
+    /// Directory the outbox transport writes `.eml` files to (memory only when unset)
+    pub outbox: Option<String>,
+    pub smtp: SmtpConfig,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            transport: MailTransport::Outbox,
+            from: "Blackjack <noreply@localhost>".to_string(),
+            outbox: None,
+            smtp: SmtpConfig::default(),
+        }
+    }
+}
+
+/// Password reset configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasswordResetConfig {
+    /// How long a reset token stays valid
+    pub token_ttl_minutes: i64,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_minutes: blackjack_service::PASSWORD_RESET_TTL_MINUTES,
+        }
+    }
+}
+
+/// Email verification configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailVerificationConfig {
+    /// Block game creation and joining until the account's email is verified
+    ///
+    /// Set from the environment with `BLACKJACK_VERIFICATION_REQUIRED=true`.
+    pub required: bool,
+    /// How long a verification link stays valid
+    pub token_ttl_hours: i64,
+}
+
+impl Default for EmailVerificationConfig {
+    fn default() -> Self {
+        Self {
+            required: false,
+            token_ttl_hours: blackjack_service::EMAIL_VERIFICATION_TTL_HOURS,
+        }
+    }
+}
+
+/// Account self-service configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountConfig {
+    /// Days a deleted account stays deactivated before it is anonymised
+    pub deletion_grace_days: i64,
+}
+
+impl Default for AccountConfig {
+    fn default() -> Self {
+        Self {
+            deletion_grace_days: blackjack_service::ACCOUNT_DELETION_GRACE_DAYS,
+        }
+    }
+}
+
+/// Table chat configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatConfig {
+    /// Chat messages a user can post per minute, across all games
+    pub messages_per_minute: u32,
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            messages_per_minute: 20,
+        }
+    }
+}
+
 /// Main application configuration
 ///
 /// Aggregates all configuration sections and provides methods for loading
@@ -82,7 +293,7 @@ pub struct ApiConfig {
 /// let config = AppConfig::from_file().expect("Failed to load configuration");
 /// println!("Server will listen on {}:{}", config.server.host, config.server.port);
 /// ```
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     /// Server configuration (host, port)
     pub server: ServerConfig,
@@ -94,9 +305,52 @@ pub struct AppConfig {
     pub rate_limit: RateLimitConfig,
     /// API versioning configuration (deprecation period)
     pub api: ApiConfig,
+    /// Administrator accounts (empty by default)
+    #[serde(default)]
+    pub admin: AdminConfig,
+    /// Security audit log (memory only by default)
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Finished game archive (memory only by default)
+    #[serde(default)]
+    pub archive: ArchiveConfig,
+    /// Outgoing mail (local outbox by default)
+    #[serde(default)]
+    pub mail: MailConfig,
+    /// Password reset tokens
+    #[serde(default)]
+    pub password_reset: PasswordResetConfig,
+    /// Email verification (optional by default)
+    #[serde(default)]
+    pub verification: EmailVerificationConfig,
+    /// Account deletion grace period
+    #[serde(default)]
+    pub account: AccountConfig,
+    /// Table chat rate limit
+    #[serde(default)]
+    pub chat: ChatConfig,
 }
 
 impl AppConfig {
+    /// Copy of the configuration that is safe to show, with secrets masked
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use blackjack_api::config::AppConfig;
+    ///
+    /// let config = AppConfig::from_file().unwrap().redacted();
+    /// assert_eq!(config.jwt.secret, "********");
+    /// ```
+    pub fn redacted(&self) -> Self {
+        let mut config = self.clone();
+        config.jwt.secret = "********".to_string();
+        if !config.mail.smtp.password.is_empty() {
+            config.mail.smtp.password = "********".to_string();
+        }
+        config
+    }
+
     /// Loads configuration from file and environment variables
     ///
     /// # Configuration File Locations
@@ -159,7 +413,9 @@ impl AppConfig {
         builder = builder.add_source(
             config::Environment::with_prefix("BLACKJACK")
                 .separator("_")
-                .try_parsing(true),
+                .try_parsing(true)
+                .list_separator(",")
+                .with_list_parse_key("admin.emails"),
         );
 
         let config = builder.build()?;
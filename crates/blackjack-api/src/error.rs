@@ -274,7 +274,7 @@ impl From<blackjack_service::GameError> for ApiError {
             GameError::GameFull => Self::new(
                 StatusCode::BAD_REQUEST,
                 "GAME_FULL",
-                "Game is at maximum capacity (10 players)",
+                "Game is at maximum capacity",
             ),
             GameError::EnrollmentClosed => Self::new(
                 StatusCode::GONE,
@@ -300,6 +300,11 @@ impl From<blackjack_service::GameError> for ApiError {
             GameError::UserAlreadyExists => {
                 Self::new(StatusCode::CONFLICT, "USER_EXISTS", "User already exists")
             }
+            GameError::DisplayNameTaken => Self::new(
+                StatusCode::CONFLICT,
+                "DISPLAY_NAME_TAKEN",
+                "Display name is already taken",
+            ),
             GameError::InvalidCredentials => Self::unauthorized(),
             GameError::InvitationNotFound => Self::new(
                 StatusCode::NOT_FOUND,
@@ -311,6 +316,76 @@ impl From<blackjack_service::GameError> for ApiError {
                 "INVITATION_EXPIRED",
                 "Invitation has expired",
             ),
+            GameError::InvitationAlreadyExists => Self::new(
+                StatusCode::CONFLICT,
+                "INVITATION_ALREADY_EXISTS",
+                "A pending invitation already exists for this user and game",
+            ),
+            GameError::InvitationNotPending => Self::new(
+                StatusCode::CONFLICT,
+                "INVITATION_NOT_PENDING",
+                "Invitation has already been answered, revoked or expired",
+            ),
+            GameError::InviteLinkNotFound => Self::new(
+                StatusCode::NOT_FOUND,
+                "INVITE_LINK_NOT_FOUND",
+                "Invite link not found",
+            ),
+            GameError::InviteLinkRevoked => Self::new(
+                StatusCode::GONE,
+                "INVITE_LINK_REVOKED",
+                "Invite link has been revoked",
+            ),
+            GameError::InviteLinkExhausted => Self::new(
+                StatusCode::GONE,
+                "INVITE_LINK_EXHAUSTED",
+                "Invite link has no uses left",
+            ),
+            GameError::GameIsPrivate => Self::new(
+                StatusCode::FORBIDDEN,
+                "GAME_PRIVATE",
+                "This game is private and can only be joined by invitation",
+            ),
+            GameError::InvalidGamePassword => Self::new(
+                StatusCode::FORBIDDEN,
+                "INVALID_GAME_PASSWORD",
+                "Invalid or missing game password",
+            ),
+            GameError::AlreadyQueued => Self::new(
+                StatusCode::CONFLICT,
+                "ALREADY_QUEUED",
+                "You are already waiting in the matchmaking queue",
+            ),
+            GameError::NotQueued => Self::new(
+                StatusCode::NOT_FOUND,
+                "NOT_QUEUED",
+                "You are not waiting in the matchmaking queue",
+            ),
+            GameError::ChatMuted => Self::new(
+                StatusCode::FORBIDDEN,
+                "CHAT_MUTED",
+                "You are muted in this game's chat",
+            ),
+            GameError::ChatMessageNotFound => Self::new(
+                StatusCode::NOT_FOUND,
+                "CHAT_MESSAGE_NOT_FOUND",
+                "The requested chat message does not exist",
+            ),
+            GameError::TournamentNotFound => Self::new(
+                StatusCode::NOT_FOUND,
+                "TOURNAMENT_NOT_FOUND",
+                "The requested tournament does not exist",
+            ),
+            GameError::TournamentAlreadyStarted => Self::new(
+                StatusCode::CONFLICT,
+                "TOURNAMENT_ALREADY_STARTED",
+                "Registration for this tournament has closed",
+            ),
+            GameError::AlreadyRegistered => Self::new(
+                StatusCode::CONFLICT,
+                "ALREADY_REGISTERED",
+                "You are already registered for this tournament",
+            ),
             GameError::InvalidTimeout { max } => Self::new(
                 StatusCode::BAD_REQUEST,
                 "INVALID_TIMEOUT",
@@ -362,6 +437,21 @@ impl From<blackjack_service::GameError> for ApiError {
                 "ACCOUNT_LOCKED",
                 "Account is locked due to too many failed login attempts",
             ),
+            GameError::InvalidResetToken => Self::new(
+                StatusCode::BAD_REQUEST,
+                "INVALID_RESET_TOKEN",
+                "Password reset token is invalid or expired",
+            ),
+            GameError::InvalidVerificationToken => Self::new(
+                StatusCode::BAD_REQUEST,
+                "INVALID_VERIFICATION_TOKEN",
+                "Email verification token is invalid or expired",
+            ),
+            GameError::EmailNotVerified => Self::new(
+                StatusCode::FORBIDDEN,
+                "EMAIL_NOT_VERIFIED",
+                "Verify your email address before creating or joining games",
+            ),
             GameError::ValidationError(msg) => {
                 Self::new(StatusCode::BAD_REQUEST, "VALIDATION_ERROR", msg)
             }
@@ -30,18 +30,140 @@
 //! }
 //! ```
 
-use crate::auth::Claims;
+use crate::auth::{Claims, JoinTokenClaims};
 use crate::error::ApiError;
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use crate::middleware::RequestId;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::{Extension, Json};
-use blackjack_core::GameResult;
-use blackjack_service::{DrawCardResponse, GameStateResponse, PlayerStateResponse};
-use jsonwebtoken::{EncodingKey, Header, encode};
+use blackjack_core::events::GameEvent;
+use blackjack_core::rating::RatingBand;
+use blackjack_core::strategy::BotStrategy;
+use blackjack_core::{GameResult, GameVisibility, PlatformRole, PlayerOutcome, TableRules, User};
+use blackjack_service::audit::actions;
+use blackjack_service::{
+    AuditActor, AuditEvent, AuditOutcome, ChatHistory, ChatMessage, CreateGameOptions,
+    DrawCardResponse, EmailMessage, GameInfo, GameStateResponse, HintResponse, InvitationInfo,
+    InviteLinkInfo, Leaderboard, LeaderboardQuery, Notification, OpenGamesQuery, OpenGamesSort,
+    PlayerAchievements, PlayerGameEntry, PlayerGamesQuery, PlayerProfile, PlayerStateResponse,
+    QueueStatus, QueueTicket, SortOrder, TournamentInfo, TournamentRound, TournamentSettings,
+    TournamentStanding, TournamentStatus,
+};
+use chrono::{DateTime, Utc};
+use futures_util::stream;
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use uuid::Uuid;
 
+/// Appends the outcome of a security-relevant handler to the audit log
+pub(crate) fn record_audit<T>(
+    state: &crate::AppState,
+    actor: AuditActor,
+    action: &str,
+    target: String,
+    request_id: &RequestId,
+    result: &Result<T, ApiError>,
+) {
+    let status = match result {
+        Ok(_) => StatusCode::OK.as_u16(),
+        Err(err) => err.status,
+    };
+    state.audit_log.record(AuditEvent {
+        actor,
+        action: action.to_string(),
+        target,
+        outcome: AuditOutcome::from_status(status),
+        status,
+        request_id: Some(request_id.0.clone()),
+    });
+}
+
+/// Audit actor for the account behind `claims`
+pub(crate) fn claims_actor(state: &crate::AppState, claims: &Claims) -> AuditActor {
+    Uuid::parse_str(&claims.user_id)
+        .ok()
+        .and_then(|user_id| state.user_service.get_user(user_id).ok())
+        .map_or_else(
+            || AuditActor::anonymous(&claims.email),
+            |user| (&user).into(),
+        )
+}
+
+/// Sends `message` off the async workers, since SMTP delivery blocks
+///
+/// Failures are logged; returns whether the mail was accepted.
+async fn deliver_mail(state: &crate::AppState, message: EmailMessage) -> bool {
+    let mailer = state.mailer.clone();
+    match tokio::task::spawn_blocking(move || mailer.send(&message)).await {
+        Ok(Ok(())) => true,
+        Ok(Err(err)) => {
+            tracing::error!(error = %err, "Failed to send email");
+            false
+        }
+        Err(err) => {
+            tracing::error!(error = %err, "Mail task failed");
+            false
+        }
+    }
+}
+
+/// Rejects accounts without a verified email when
+/// `[verification] required` is set
+fn require_verified_email(state: &crate::AppState, claims: &Claims) -> Result<(), ApiError> {
+    if !state.config.verification.required {
+        return Ok(());
+    }
+    let verified = Uuid::parse_str(&claims.user_id)
+        .ok()
+        .and_then(|user_id| state.user_service.get_user(user_id).ok())
+        .is_some_and(|user| user.email_verified);
+    if verified {
+        Ok(())
+    } else {
+        Err(blackjack_service::GameError::EmailNotVerified.into())
+    }
+}
+
+/// Mails a verification link to a not yet verified account
+///
+/// Returns whether a link was sent.
+pub(crate) async fn send_verification_email(
+    state: &crate::AppState,
+    user_id: Uuid,
+    email: &str,
+) -> bool {
+    let ttl_hours = state.config.verification.token_ttl_hours;
+    let token = match state
+        .user_service
+        .request_email_verification(user_id, chrono::Duration::hours(ttl_hours))
+    {
+        Ok(Some(token)) => token,
+        Ok(None) => return false,
+        Err(err) => {
+            tracing::error!(user_id = %user_id, error = %err, "Failed to issue verification token");
+            return false;
+        }
+    };
+
+    let message = EmailMessage {
+        to: email.to_string(),
+        subject: "Verify your Blackjack email address".to_string(),
+        body: format!(
+            "Welcome to Blackjack! Open this link to verify your email address \
+             (valid for {} hours):\n\n    {}/api/v1/auth/verify/{}\n\n\
+             Or run: blackjack-cli client verify-email <token>\n\n\
+             If you didn't create an account, ignore this email.",
+            ttl_hours,
+            state.config.server.public_url(),
+            token
+        ),
+    };
+    deliver_mail(state, message).await
+}
+
 /// Request payload for user authentication
 ///
 /// Used by the `POST /api/v1/auth/login` endpoint to authenticate
@@ -80,7 +202,8 @@ pub struct LoginRequest {
 /// ```json
 /// {
 ///   "token": "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...",
-///   "expires_in": 86400
+///   "expires_in": 86400,
+///   "display_name": "lucky_ace"
 /// }
 /// ```
 #[derive(Debug, Serialize)]
@@ -96,6 +219,9 @@ pub struct LoginResponse {
     /// Calculated as `expiration_hours * 3600`
     /// Default: 86400 (24 hours)
     pub expires_in: u64,
+
+    /// Public name the user appears under in games
+    pub display_name: String,
 }
 
 /// Authenticates a player for a game session
@@ -127,7 +253,8 @@ pub struct LoginResponse {
 /// ```json
 /// {
 ///   "token": "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJlbWFpbCI6InBsYXllckBleGFtcGxlLmNvbSIsImdhbWVfaWQiOiI1NTBlODQwMC1lMjliLTQxZDQtYTcxNi00NDY2NTU0NDAwMDAiLCJleHAiOjE3MDQ3NTg0MDB9.signature",
-///   "expires_in": 86400
+///   "expires_in": 86400,
+///   "display_name": "lucky_ace"
 /// }
 /// ```
 ///
@@ -175,6 +302,7 @@ pub struct LoginResponse {
 /// - Token includes expiration timestamp (validated automatically)
 /// - Each token is bound to a specific game and player
 /// - Failed authentication attempts are logged with warning level
+/// - Every attempt is recorded in the audit log as `auth.login`
 ///
 /// # Logging
 ///
@@ -192,16 +320,62 @@ pub struct LoginResponse {
 ///     "password": "SecurePassword123!"
 ///   }'
 /// ```
-#[tracing::instrument(skip(state))]
+#[tracing::instrument(skip(state, payload))]
 pub async fn login(
     State(state): State<crate::AppState>,
+    request_id: RequestId,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Json<LoginResponse>, ApiError> {
     // Authenticate user with UserService
-    let user = state
+    let result = state
         .user_service
-        .login(&payload.email, &payload.password)?;
+        .login(&payload.email, &payload.password)
+        .map_err(ApiError::from);
+    let actor = match &result {
+        Ok(user) => AuditActor::from(user),
+        Err(_) => state
+            .user_service
+            .get_user_by_email(&payload.email)
+            .map_or_else(
+                |_| AuditActor::anonymous(&payload.email),
+                |user| (&user).into(),
+            ),
+    };
+    let target = actor
+        .user_id
+        .map_or_else(|| payload.email.clone(), |id| format!("users/{}", id));
+    record_audit(&state, actor, actions::LOGIN, target, &request_id, &result);
+    let mut user = result?;
+
+    // Accounts listed in [admin] are promoted on login, which bootstraps the
+    // first administrators of a fresh server. The address must be verified,
+    // otherwise anyone could sign up with it before its owner does.
+    if state.config.admin.is_admin(&user.email)
+        && user.email_verified
+        && user.role != PlatformRole::Admin
+    {
+        state.user_service.set_role(user.id, PlatformRole::Admin)?;
+        user.role = PlatformRole::Admin;
+        tracing::warn!(user_id = %user.id, email = %user.email, "Promoted configured administrator");
+    }
+
+    let token = issue_token(&state, &user)?;
+
+    tracing::info!(
+        user_id = %user.id,
+        email = %user.email,
+        "User authenticated successfully"
+    );
+
+    Ok(Json(LoginResponse {
+        token,
+        expires_in: state.config.jwt.expiration_hours * 3600,
+        display_name: user.display_name,
+    }))
+}
 
+/// Signs an authentication token for the account's current session
+pub(crate) fn issue_token(state: &crate::AppState, user: &User) -> Result<String, ApiError> {
     // Calculate expiration time
     let expiration =
         chrono::Utc::now() + chrono::Duration::hours(state.config.jwt.expiration_hours as i64);
@@ -210,11 +384,13 @@ pub async fn login(
     let claims = Claims {
         user_id: user.id.to_string(),
         email: user.email.clone(),
+        role: user.role,
+        session_version: user.session_version,
         exp: expiration.timestamp() as usize,
     };
 
     // Generate JWT token
-    let token = encode(
+    encode(
         &Header::default(),
         &claims,
         &EncodingKey::from_secret(state.config.jwt.secret.as_bytes()),
@@ -226,18 +402,7 @@ pub async fn login(
             "TOKEN_GENERATION_FAILED",
             "Failed to generate authentication token",
         )
-    })?;
-
-    tracing::info!(
-        user_id = %user.id,
-        email = %user.email,
-        "User authenticated successfully"
-    );
-
-    Ok(Json(LoginResponse {
-        token,
-        expires_in: state.config.jwt.expiration_hours * 3600,
-    }))
+    })
 }
 
 /// Change password request payload
@@ -295,6 +460,7 @@ pub struct ChangePasswordRequest {
 pub async fn change_password(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
+    request_id: RequestId,
     Json(payload): Json<ChangePasswordRequest>,
 ) -> Result<Json<serde_json::Value>, ApiError> {
     let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
@@ -305,9 +471,19 @@ pub async fn change_password(
         )
     })?;
 
-    state
+    let result = state
         .user_service
-        .change_password(user_id, &payload.old_password, &payload.new_password)?;
+        .change_password(user_id, &payload.old_password, &payload.new_password)
+        .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::PASSWORD_CHANGE,
+        format!("users/{}", user_id),
+        &request_id,
+        &result,
+    );
+    result?;
 
     tracing::info!(
         user_id = %user_id,
@@ -319,6 +495,182 @@ pub async fn change_password(
     })))
 }
 
+/// Forgot password request payload
+#[derive(Debug, Deserialize)]
+pub struct ForgotPasswordRequest {
+    pub email: String,
+}
+
+/// Reset password request payload
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordRequest {
+    /// Token from the password reset email
+    pub token: String,
+    pub new_password: String,
+}
+
+/// Same answer whether or not the account exists, so the endpoint can't be
+/// used to find out which emails are registered
+const FORGOT_PASSWORD_MESSAGE: &str =
+    "If an active account exists for this email, a password reset token has been sent";
+
+/// Request a password reset token by email
+///
+/// Mails a single-use token to the account, valid for
+/// `[password_reset] token_ttl_minutes` (30 by default). Requesting again
+/// replaces the earlier token.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/forgot-password`
+///
+/// # Request Body (JSON)
+///
+/// ```json
+/// {
+///   "email": "user@example.com"
+/// }
+/// ```
+///
+/// # Response (202 Accepted)
+///
+/// The response is identical for unknown emails, inactive accounts and mail
+/// delivery failures; those are only logged and audited.
+///
+/// ```json
+/// {
+///   "message": "If an active account exists for this email, a password reset token has been sent"
+/// }
+/// ```
+#[tracing::instrument(skip(state, payload))]
+pub async fn forgot_password(
+    State(state): State<crate::AppState>,
+    request_id: RequestId,
+    Json(payload): Json<ForgotPasswordRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let ttl_minutes = state.config.password_reset.token_ttl_minutes;
+    let token = state
+        .user_service
+        .request_password_reset(&payload.email, chrono::Duration::minutes(ttl_minutes));
+    let actor = state
+        .user_service
+        .get_user_by_email(&payload.email)
+        .map_or_else(
+            |_| AuditActor::anonymous(&payload.email),
+            |user| (&user).into(),
+        );
+
+    let sent = match token {
+        Some(token) => {
+            let message = EmailMessage {
+                to: payload.email.clone(),
+                subject: "Reset your Blackjack password".to_string(),
+                body: format!(
+                    "Someone asked to reset the password of your Blackjack account.\n\n\
+                     Your reset token (valid for {} minutes, single use):\n\n    {}\n\n\
+                     Send it with your new password to POST /api/v1/auth/reset-password, or run\n\
+                     blackjack-cli client reset-password --token <token>\n\n\
+                     If this wasn't you, ignore this email; your password stays unchanged.",
+                    ttl_minutes, token
+                ),
+            };
+            deliver_mail(&state, message).await
+        }
+        None => false,
+    };
+
+    let target = actor
+        .user_id
+        .map_or_else(|| payload.email.clone(), |id| format!("users/{}", id));
+    state.audit_log.record(AuditEvent {
+        actor,
+        action: actions::PASSWORD_RESET_REQUEST.to_string(),
+        target,
+        outcome: if sent {
+            AuditOutcome::Success
+        } else {
+            AuditOutcome::Failed
+        },
+        status: StatusCode::ACCEPTED.as_u16(),
+        request_id: Some(request_id.0),
+    });
+
+    (
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({ "message": FORGOT_PASSWORD_MESSAGE })),
+    )
+}
+
+/// Set a new password with a reset token
+///
+/// The token is single-use. The new password must meet the usual complexity
+/// rules; a rejected password leaves the token valid. On success the account
+/// is unlocked and every existing session is revoked, so all devices have to
+/// log in again.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/reset-password`
+///
+/// # Request Body (JSON)
+///
+/// ```json
+/// {
+///   "token": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+///   "new_password": "NewP@ssw0rd"
+/// }
+/// ```
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "message": "Password reset successfully, please log in again"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - `INVALID_RESET_TOKEN` for unknown, used or expired
+///   tokens; `WEAK_PASSWORD` when the password doesn't meet requirements
+#[tracing::instrument(skip(state, payload))]
+pub async fn reset_password(
+    State(state): State<crate::AppState>,
+    request_id: RequestId,
+    Json(payload): Json<ResetPasswordRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let result = state
+        .user_service
+        .reset_password_with_token(&payload.token, &payload.new_password)
+        .map_err(ApiError::from);
+
+    let (actor, target) = match &result {
+        Ok(user_id) => (
+            state
+                .user_service
+                .get_user(*user_id)
+                .map_or_else(|_| AuditActor::anonymous(""), |user| (&user).into()),
+            format!("users/{}", user_id),
+        ),
+        Err(_) => (AuditActor::anonymous(""), "reset-token".to_string()),
+    };
+    record_audit(
+        &state,
+        actor,
+        actions::PASSWORD_RESET,
+        target,
+        &request_id,
+        &result,
+    );
+    let user_id = result?;
+
+    tracing::info!(user_id = %user_id, "Password reset, sessions revoked");
+
+    Ok(Json(serde_json::json!({
+        "message": "Password reset successfully, please log in again"
+    })))
+}
+
 // ============================================================================
 // Player Statistics Endpoints
 // ============================================================================
@@ -328,6 +680,7 @@ pub async fn change_password(
 pub struct PlayerStatsResponse {
     pub user_id: String,
     pub email: String,
+    pub display_name: String,
     pub games_played: u32,
     pub games_won: u32,
     pub games_lost: u32,
@@ -336,6 +689,11 @@ pub struct PlayerStatsResponse {
     pub average_points: f32,
     pub highest_score: u8,
     pub times_busted: u32,
+    /// Elo skill rating (1500 for new players)
+    pub rating: u32,
+    /// Wins in a row up to the last game
+    pub current_win_streak: u32,
+    pub longest_win_streak: u32,
 }
 
 /// Get player statistics
@@ -358,6 +716,7 @@ pub struct PlayerStatsResponse {
 /// {
 ///   "user_id": "550e8400-e29b-41d4-a716-446655440000",
 ///   "email": "player@example.com",
+///   "display_name": "lucky_ace",
 ///   "games_played": 42,
 ///   "games_won": 25,
 ///   "games_lost": 15,
@@ -365,7 +724,10 @@ pub struct PlayerStatsResponse {
 ///   "win_rate": 59.52,
 ///   "average_points": 18.5,
 ///   "highest_score": 21,
-///   "times_busted": 8
+///   "times_busted": 8,
+///   "rating": 1562,
+///   "current_win_streak": 2,
+///   "longest_win_streak": 5
 /// }
 /// ```
 ///
@@ -413,6 +775,7 @@ pub async fn get_player_stats(
     Ok(Json(PlayerStatsResponse {
         user_id: user.id.to_string(),
         email: user.email,
+        display_name: user.display_name,
         games_played: stats.games_played,
         games_won: stats.games_won,
         games_lost: stats.games_lost,
@@ -421,104 +784,404 @@ pub async fn get_player_stats(
         average_points: stats.average_points(),
         highest_score: stats.highest_score,
         times_busted: stats.times_busted,
+        rating: stats.rating_points(),
+        current_win_streak: stats.current_win_streak,
+        longest_win_streak: stats.longest_win_streak,
     }))
 }
 
-// ============================================================================
-// Health Check Endpoints
-// ============================================================================
-
-/// Health check response
+/// Get the authenticated player's achievements
 ///
-/// Provides basic server health information including uptime and version.
-#[derive(Debug, Serialize)]
-pub struct HealthResponse {
-    /// Server health status
-    pub status: String,
+/// Lists every achievement with the time it was unlocked (`null` while still
+/// locked), along with the player's streaks. Achievements are unlocked when a
+/// game's results are recorded.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/players/me/achievements`
+///
+/// # Authentication
+///
+/// **Required** - User ID extracted from JWT token.
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "unlocked": 1,
+///   "total": 5,
+///   "current_win_streak": 2,
+///   "longest_win_streak": 3,
+///   "dealer_bust_streak": 0,
+///   "achievements": [
+///     {
+///       "id": "natural_blackjack",
+///       "name": "Natural",
+///       "description": "Get a blackjack: 21 with your first two cards",
+///       "unlocked_at": "2026-10-18T12:00:00+00:00"
+///     },
+///     {
+///       "id": "hot_streak",
+///       "name": "Hot Streak",
+///       "description": "Win five games in a row",
+///       "unlocked_at": null
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - Invalid or missing JWT token
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state))]
+pub async fn get_player_achievements(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<PlayerAchievements>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            "INVALID_CLAIMS",
+            "Invalid user ID in JWT token",
+        )
+    })?;
 
-    /// Server uptime in seconds since startup
-    pub uptime_seconds: u64,
+    Ok(Json(state.user_service.achievements(user_id)?))
+}
 
-    /// API version
-    pub version: String,
+/// Query parameters for a player's game history
+#[derive(Debug, Deserialize)]
+pub struct PlayerGamesParams {
+    /// `won`, `lost`, `push` or `busted`
+    pub outcome: Option<PlayerOutcome>,
+
+    /// RFC 3339 timestamp, inclusive
+    pub since: Option<DateTime<Utc>>,
+
+    /// RFC 3339 timestamp, exclusive
+    pub until: Option<DateTime<Utc>>,
+
+    /// Cursor from the previous page's `next_cursor`
+    pub cursor: Option<String>,
+
+    /// Page size (default 20, max 100)
+    pub limit: Option<usize>,
 }
 
-/// Readiness check response
-///
-/// Provides detailed readiness information for all system components.
+impl From<PlayerGamesParams> for PlayerGamesQuery {
+    fn from(params: PlayerGamesParams) -> Self {
+        Self {
+            outcome: params.outcome,
+            since: params.since,
+            until: params.until,
+            cursor: params.cursor,
+            limit: params.limit,
+        }
+    }
+}
+
+/// Response for a player's game history
 #[derive(Debug, Serialize)]
-pub struct ReadyResponse {
-    /// Overall readiness status
-    pub ready: bool,
+pub struct PlayerGamesResponse {
+    /// Games on this page, newest first
+    pub games: Vec<PlayerGameEntry>,
 
-    /// Individual component health checks
-    pub checks: HashMap<String, String>,
+    /// Number of games on this page
+    pub count: usize,
+
+    /// Number of games matching the filters across all pages
+    pub total: usize,
+
+    /// Cursor for the next page, `null` on the last page
+    pub next_cursor: Option<String>,
 }
 
-/// Basic health check endpoint
+/// Get the authenticated player's finished games
 ///
-/// Returns the current health status of the server. This endpoint is useful
-/// for load balancers and monitoring systems to verify the server is running.
+/// Served from the game archive, so games stay listed once they are gone
+/// from memory, and across restarts when `[archive] file` is set.
 ///
 /// # Endpoint
 ///
-/// `GET /health`
+/// `GET /api/v1/players/me/games`
 ///
 /// # Authentication
 ///
-/// No authentication required (public endpoint).
+/// **Required** - User ID extracted from JWT token.
+///
+/// # Query Parameters
+///
+/// - `outcome` - `won`, `lost`, `push` or `busted`
+/// - `since`, `until` - RFC 3339 range of the finish time (`until` exclusive)
+/// - `limit` - page size (default 20, max 100)
+/// - `cursor` - `next_cursor` from the previous page
 ///
 /// # Response
 ///
-/// **Success (200 OK)**:
+/// **Success (200 OK)**: net chips count a bet of the table minimum, with
+/// blackjack paying 3:2.
 /// ```json
 /// {
-///   "status": "healthy",
-///   "uptime_seconds": 3600,
-///   "version": "1.0.0"
+///   "games": [
+///     {
+///       "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///       "played_at": "2026-10-18T12:00:00Z",
+///       "rules": { "max_players": 4, "min_bet": 10, "max_bet": 500 },
+///       "hands": [
+///         {
+///           "cards": [
+///             { "id": "...", "name": "K", "value": 10, "suit": "Spades" },
+///             { "id": "...", "name": "9", "value": 9, "suit": "Hearts" }
+///           ],
+///           "points": 19,
+///           "busted": false
+///         }
+///       ],
+///       "dealer_points": 18,
+///       "dealer_busted": false,
+///       "outcome": "won",
+///       "net_chips": 10,
+///       "opponents": [
+///         { "user_id": null, "display_name": "bot-1", "outcome": "busted" }
+///       ]
+///     }
+///   ],
+///   "count": 1,
+///   "total": 1,
+///   "next_cursor": null
 /// }
 /// ```
 ///
-/// # Example
+/// # Errors
 ///
-/// ```bash
-/// curl http://localhost:8080/health
-/// ```
-#[tracing::instrument]
-pub async fn health_check() -> Json<HealthResponse> {
-    // Calculate uptime from process start
-    // In production, this would use a global start time variable
-    static START_TIME: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
-    let start = START_TIME.get_or_init(std::time::Instant::now);
-    let uptime_seconds = start.elapsed().as_secs();
+/// - **400 Bad Request** - Malformed query parameters or cursor
+/// - **401 Unauthorized** - Invalid or missing JWT token
+#[tracing::instrument(skip(state))]
+pub async fn get_player_games(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(params): Query<PlayerGamesParams>,
+) -> Result<Json<PlayerGamesResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            "INVALID_CLAIMS",
+            "Invalid user ID in JWT token",
+        )
+    })?;
 
-    Json(HealthResponse {
-        status: "healthy".to_string(),
-        uptime_seconds,
-        version: env!("CARGO_PKG_VERSION").to_string(),
-    })
+    let page = state.game_service.player_games(user_id, &params.into())?;
+    let count = page.games.len();
+
+    Ok(Json(PlayerGamesResponse {
+        games: page.games,
+        count,
+        total: page.total,
+        next_cursor: page.next_cursor,
+    }))
 }
 
-/// Readiness check endpoint
+/// Get a player's public profile
 ///
-/// Returns detailed readiness information for all system components.
-/// This endpoint can be used by orchestration systems (like Kubernetes)
-/// to determine if the service is ready to accept traffic.
+/// Any authenticated user may look up another player. The profile shows the
+/// display name, avatar seed and headline statistics, never the email.
 ///
 /// # Endpoint
 ///
-/// `GET /health/ready`
+/// `GET /api/v1/players/:user_id`
 ///
 /// # Authentication
 ///
-/// No authentication required (public endpoint).
+/// **Required** - JWT token must be provided
 ///
 /// # Response
 ///
 /// **Success (200 OK)**:
 /// ```json
 /// {
-///   "ready": true,
+///   "user_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "display_name": "lucky_ace",
+///   "avatar_seed": "9f86d081884c7d65",
+///   "member_since": "2026-01-02T12:00:00Z",
+///   "games_played": 42,
+///   "games_won": 25,
+///   "win_rate": 59.52,
+///   "rating": 1562
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - Invalid or missing JWT token
+/// - **404 Not Found** - User not found
+#[tracing::instrument(skip(state))]
+pub async fn get_player_profile(
+    State(state): State<crate::AppState>,
+    Extension(_claims): Extension<Claims>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<PlayerProfile>, ApiError> {
+    let user = state.user_service.get_user(user_id)?;
+
+    Ok(Json(PlayerProfile::from(&user)))
+}
+
+/// Get a leaderboard
+///
+/// Boards are kept up to date as game results are recorded, so a request only
+/// sorts the players of the chosen period.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/leaderboards`
+///
+/// # Authentication
+///
+/// This is a public endpoint - no JWT required.
+///
+/// # Query Parameters
+///
+/// - `board` - `win_rate` (default), `net_chips`, `win_streak` or `blackjacks`
+/// - `window` - `daily`, `weekly` or `all_time` (default)
+/// - `min_games` - Games needed for the win rate board (default 5)
+/// - `limit` - Entries to return (default 10, max 100)
+///
+/// Net chips count a flat bet of the table's minimum per hand, with blackjack
+/// paying 3:2.
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "board": "win_rate",
+///   "window": "weekly",
+///   "period": "2026-W42",
+///   "min_games": 5,
+///   "entries": [
+///     {
+///       "rank": 1,
+///       "user_id": "550e8400-e29b-41d4-a716-446655440000",
+///       "display_name": "lucky_ace",
+///       "avatar_seed": "9f86d081884c7d65",
+///       "games_played": 12,
+///       "games_won": 8,
+///       "win_rate": 66.67,
+///       "net_chips": 95,
+///       "longest_win_streak": 4,
+///       "blackjacks": 2
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Unknown board or window
+#[tracing::instrument(skip(state))]
+pub async fn get_leaderboards(
+    State(state): State<crate::AppState>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Json<Leaderboard> {
+    Json(state.user_service.leaderboard(&query))
+}
+
+// ============================================================================
+// Health Check Endpoints
+// ============================================================================
+
+/// Health check response
+///
+/// Provides basic server health information including uptime and version.
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    /// Server health status
+    pub status: String,
+
+    /// Server uptime in seconds since startup
+    pub uptime_seconds: u64,
+
+    /// API version
+    pub version: String,
+}
+
+/// Readiness check response
+///
+/// Provides detailed readiness information for all system components.
+#[derive(Debug, Serialize)]
+pub struct ReadyResponse {
+    /// Overall readiness status
+    pub ready: bool,
+
+    /// Individual component health checks
+    pub checks: HashMap<String, String>,
+}
+
+/// Basic health check endpoint
+///
+/// Returns the current health status of the server. This endpoint is useful
+/// for load balancers and monitoring systems to verify the server is running.
+///
+/// # Endpoint
+///
+/// `GET /health`
+///
+/// # Authentication
+///
+/// No authentication required (public endpoint).
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "status": "healthy",
+///   "uptime_seconds": 3600,
+///   "version": "1.0.0"
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```bash
+/// curl http://localhost:8080/health
+/// ```
+#[tracing::instrument]
+pub async fn health_check() -> Json<HealthResponse> {
+    // Calculate uptime from process start
+    // In production, this would use a global start time variable
+    static START_TIME: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    let start = START_TIME.get_or_init(std::time::Instant::now);
+    let uptime_seconds = start.elapsed().as_secs();
+
+    Json(HealthResponse {
+        status: "healthy".to_string(),
+        uptime_seconds,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    })
+}
+
+/// Readiness check endpoint
+///
+/// Returns detailed readiness information for all system components.
+/// This endpoint can be used by orchestration systems (like Kubernetes)
+/// to determine if the service is ready to accept traffic.
+///
+/// # Endpoint
+///
+/// `GET /health/ready`
+///
+/// # Authentication
+///
+/// No authentication required (public endpoint).
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "ready": true,
 ///   "checks": {
 ///     "memory": "ok",
 ///     "config": "loaded",
@@ -564,10 +1227,22 @@ pub async fn ready_check() -> Json<ReadyResponse> {
 ///
 /// - Must contain 1-10 unique email addresses
 /// - Email addresses must not be empty
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct CreateGameRequest {
     /// Optional enrollment timeout in seconds (defaults to 300)
     pub enrollment_timeout_seconds: Option<u64>,
+
+    /// Optional visibility: `public` (default), `unlisted` or `private`
+    #[serde(default)]
+    pub visibility: Option<GameVisibility>,
+
+    /// Optional password players must supply to enroll directly
+    #[serde(default)]
+    pub password: Option<String>,
+
+    /// Optional table size and stakes (defaults: 10 seats, bets 10-500)
+    #[serde(default)]
+    pub rules: Option<TableRules>,
 }
 
 /// Response for game creation
@@ -593,6 +1268,15 @@ pub struct CreateGameResponse {
 
     /// Time remaining for enrollment in seconds
     pub time_remaining_seconds: i64,
+
+    /// Who can discover and join the game
+    pub visibility: GameVisibility,
+
+    /// Whether direct enrollment requires a password
+    pub password_protected: bool,
+
+    /// Table size and stakes
+    pub rules: TableRules,
 }
 
 /// Creates a new game in enrollment mode
@@ -663,14 +1347,20 @@ pub struct CreateGameResponse {
 ///   -H "Authorization: Bearer YOUR_JWT_TOKEN" \
 ///   -H "Content-Type: application/json" \
 ///   -d '{
-///     "enrollment_timeout_seconds": 600
+///     "enrollment_timeout_seconds": 600,
+///     "visibility": "unlisted",
+///     "password": "table-secret",
+///     "rules": { "max_players": 6, "min_bet": 25, "max_bet": 1000 }
 ///   }'
 /// ```
 ///
 /// # Notes
 ///
 /// - Creator is automatically enrolled as the first player
-/// - Maximum 10 players per game
+/// - `public` games are listed in `/games/open`; `unlisted` games can be joined
+///   by ID; `private` games only accept invitations and invite links
+/// - The join password is hashed with Argon2id and never returned
+/// - Tables seat up to 10 players (`rules.max_players`)
 /// - Enrollment phase lasts `enrollment_timeout_seconds` (default: 300s)
 /// - Creator can close enrollment early via `/close-enrollment` endpoint
 #[tracing::instrument(skip(state, claims))]
@@ -697,12 +1387,19 @@ pub async fn create_game(
             "User from token does not exist. Token may be invalid or user was deleted.",
         )
     })?;
+    require_verified_email(&state, &claims)?;
 
     let enrollment_timeout = payload.enrollment_timeout_seconds;
     // Creator email is automatically retrieved from user database by the service
-    let game_id = state
-        .game_service
-        .create_game(creator_id, enrollment_timeout)?;
+    let game_id = state.game_service.create_game_with_options(
+        creator_id,
+        CreateGameOptions {
+            enrollment_timeout_seconds: enrollment_timeout,
+            visibility: payload.visibility.unwrap_or_default(),
+            join_password: payload.password,
+            rules: payload.rules.unwrap_or_default(),
+        },
+    )?;
 
     // Get game to retrieve enrollment info
     let games = state.game_service.games.lock().unwrap();
@@ -717,6 +1414,9 @@ pub async fn create_game(
     let enrollment_closes_at = game.get_enrollment_expires_at();
     let time_remaining = game.get_enrollment_time_remaining();
     let timeout_seconds = game.enrollment_timeout_seconds;
+    let visibility = game.visibility;
+    let password_protected = game.is_password_protected();
+    let rules = game.rules.clone();
     drop(games); // Release lock
 
     tracing::info!(
@@ -735,6 +1435,9 @@ pub async fn create_game(
         enrollment_timeout_seconds: timeout_seconds,
         enrollment_closes_at,
         time_remaining_seconds: time_remaining,
+        visibility,
+        password_protected,
+        rules,
     }))
 }
 
@@ -807,6 +1510,120 @@ pub async fn get_game_state(
     Ok(Json(state_response))
 }
 
+/// How often an open event stream checks its game for new events
+const GAME_EVENTS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Stream the game's events as Server-Sent Events
+///
+/// An alternative to polling the game state for clients that can't keep a
+/// WebSocket open. Each event carries its per-game sequence number as the SSE
+/// `id`, its type as the SSE `event` name and the event itself as JSON
+/// `data`. After a reconnect, the `Last-Event-ID` header (sent automatically
+/// by `EventSource`) resumes the stream after that event; without it the
+/// stream starts with the first event of the game. The stream ends after the
+/// `game_finished` event.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games/:game_id/events`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Event Types
+///
+/// - `enrollment_closed` - Play started, with `turn_order` and `dealer_up_card`
+/// - `card_drawn` - A player drew a `card`, with their new `points` and `busted`
+/// - `turn_changed` - It is now `player_name`'s turn
+/// - `dealer_played` - The dealer's final `cards`, `points` and `busted`
+/// - `game_finished` - The game is over (`aborted` when ended by an administrator)
+///
+/// # Response (200 OK)
+///
+/// ```text
+/// id: 2
+/// event: card_drawn
+/// data: {"seq":2,"type":"card_drawn","player_id":"650e8400-e29b-41d4-a716-446655440001","player_name":"lucky_ace","card":{"id":"...","name":"K","value":10,"suit":"Hearts"},"points":10,"busted":false,"created_at":"2026-01-14T12:01:00+00:00"}
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - `Last-Event-ID` is not a sequence number
+/// - **404 Not Found** - Game does not exist
+///
+/// # Example
+///
+/// ```bash
+/// curl -N http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/events \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN" \
+///   -H "Last-Event-ID: 2"
+/// ```
+#[tracing::instrument(skip(state, claims, headers))]
+pub async fn stream_game_events(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ApiError> {
+    let last_event_id = match headers.get("last-event-id") {
+        Some(value) => value
+            .to_str()
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .ok_or_else(|| {
+                ApiError::new(
+                    StatusCode::BAD_REQUEST,
+                    "INVALID_LAST_EVENT_ID",
+                    "Last-Event-ID must be the sequence number of a game event",
+                )
+            })?,
+        None => 0,
+    };
+
+    // Unknown games fail here, before the stream is opened
+    let first = state.game_service.game_events(game_id, last_event_id)?;
+
+    tracing::info!(
+        game_id = %game_id,
+        user_id = %claims.user_id,
+        last_event_id,
+        "Game event stream opened"
+    );
+
+    let pending: VecDeque<GameEvent> = first.events.into();
+    let stream = stream::unfold(
+        (state, pending, last_event_id, first.finished),
+        move |(state, mut pending, mut last_seq, mut finished)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    last_seq = event.seq;
+                    let sse_event = Event::default()
+                        .id(event.seq.to_string())
+                        .event(event.kind.name())
+                        .json_data(&event);
+                    return Some((sse_event, (state, pending, last_seq, finished)));
+                }
+                if finished {
+                    return None;
+                }
+
+                tokio::time::sleep(GAME_EVENTS_POLL_INTERVAL).await;
+                // The game is gone once it has been cleaned up
+                let batch = state.game_service.game_events(game_id, last_seq).ok()?;
+                pending.extend(batch.events);
+                finished = batch.finished;
+            }
+        },
+    );
+
+    Ok((
+        // Keep buffering proxies (nginx) from holding events back
+        [("X-Accel-Buffering", "no")],
+        Sse::new(stream).keep_alive(KeepAlive::default()),
+    ))
+}
+
 /// Request to draw a card
 ///
 /// Players use this endpoint to draw cards from the deck during their turn.
@@ -916,16 +1733,6 @@ pub async fn draw_card(
         ));
     }
 
-    if let Some(current_player) = game_state.current_turn_player
-        && current_player != claims.email
-    {
-        return Err(ApiError::new(
-            StatusCode::CONFLICT,
-            "NOT_YOUR_TURN",
-            "It's not your turn",
-        ));
-    }
-
     // Parse user_id from JWT claims
     let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
         ApiError::new(
@@ -935,6 +1742,17 @@ pub async fn draw_card(
         )
     })?;
 
+    let display_name = state.user_service.get_user(user_id)?.display_name;
+    if let Some(current_player) = game_state.current_turn_player
+        && current_player != display_name
+    {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "NOT_YOUR_TURN",
+            "It's not your turn",
+        ));
+    }
+
     let response = state.game_service.draw_card(game_id, user_id)?;
 
     Ok(Json(response))
@@ -1029,6 +1847,69 @@ pub async fn set_ace_value(
     Ok(Json(response))
 }
 
+/// Suggests the basic strategy play for the current player
+///
+/// Looks at the caller's hand, the dealer's up-card and the table rules
+/// and recommends hit, stand, double, split or surrender. Only the player
+/// whose turn it is can ask. Each hint is counted and reported as
+/// `hints_used` in the game results.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games/:game_id/hint`
+///
+/// # Authentication
+///
+/// **Required** - Player email extracted from JWT token.
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "action": "stand",
+///   "hand_total": 15,
+///   "soft": false,
+///   "pair": false,
+///   "dealer_value": 6,
+///   "dealer_up_card": { "id": "...", "name": "6", "value": 6, "suit": "Clubs" },
+///   "hints_used": 1
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - Missing or invalid JWT token
+/// - **403 Forbidden** - Not the caller's turn
+/// - **404 Not Found** - Game does not exist
+/// - **409 Conflict** - Enrollment is still open, or the game is already finished
+///
+/// # Example
+///
+/// ```bash
+/// curl http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/hint \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
+/// ```
+#[tracing::instrument(skip(state), fields(player_email = %claims.email))]
+pub async fn get_hint(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<HintResponse>, ApiError> {
+    // Parse user_id from JWT claims
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let response = state.game_service.get_hint(game_id, user_id)?;
+
+    Ok(Json(response))
+}
+
 /// Finishes a game and calculates results
 ///
 /// Marks the game as finished and determines the winner based on
@@ -1189,6 +2070,10 @@ pub struct RegisterRequest {
 
     /// User's password (will be hashed)
     pub password: String,
+
+    /// Public name shown to other players; generated when omitted
+    #[serde(default)]
+    pub display_name: Option<String>,
 }
 
 /// Response for successful user registration
@@ -1200,6 +2085,9 @@ pub struct RegisterResponse {
     /// User's email
     pub email: String,
 
+    /// Public name shown to other players
+    pub display_name: String,
+
     /// Success message
     pub message: String,
 }
@@ -1208,7 +2096,8 @@ pub struct RegisterResponse {
 ///
 /// Creates a new user account with email and password.
 /// Password is hashed before storage (currently using placeholder,
-/// will be upgraded to Argon2 in M8).
+/// will be upgraded to Argon2 in M8). A verification link is mailed to the
+/// address; see [`verify_email`].
 ///
 /// # Endpoint
 ///
@@ -1223,10 +2112,14 @@ pub struct RegisterResponse {
 /// ```json
 /// {
 ///   "email": "user@example.com",
-///   "password": "SecurePassword123!"
+///   "password": "SecurePassword123!",
+///   "display_name": "lucky_ace"
 /// }
 /// ```
 ///
+/// `display_name` is optional; without it the account gets a `player-xxxxxxxx`
+/// name that can be changed later.
+///
 /// # Response
 ///
 /// **Success (200 OK)**:
@@ -1234,22 +2127,27 @@ pub struct RegisterResponse {
 /// {
 ///   "user_id": "550e8400-e29b-41d4-a716-446655440000",
 ///   "email": "user@example.com",
-///   "message": "User registered successfully"
+///   "display_name": "lucky_ace",
+///   "message": "User registered successfully, check your email to verify your address"
 /// }
 /// ```
 ///
 /// # Errors
 ///
 /// - **400 Bad Request** - Invalid email or password
-/// - **409 Conflict** - User already exists
+/// - **400 Bad Request** - Invalid display name
+/// - **409 Conflict** - User already exists, or `DISPLAY_NAME_TAKEN`
 #[tracing::instrument(skip(state))]
 pub async fn register_user(
     State(state): State<crate::AppState>,
     Json(payload): Json<RegisterRequest>,
 ) -> Result<Json<RegisterResponse>, ApiError> {
-    let user_id = state
-        .user_service
-        .register(payload.email.clone(), payload.password)?;
+    let user_id = state.user_service.register_with_display_name(
+        payload.email.clone(),
+        payload.password,
+        payload.display_name,
+    )?;
+    let display_name = state.user_service.get_user(user_id)?.display_name;
 
     tracing::info!(
         user_id = %user_id,
@@ -1257,13 +2155,138 @@ pub async fn register_user(
         "User registered successfully"
     );
 
+    // Registration succeeds even if the mail can't be delivered; the user
+    // can ask for another link with POST /api/v1/auth/resend-verification
+    send_verification_email(&state, user_id, &payload.email).await;
+
     Ok(Json(RegisterResponse {
         user_id,
         email: payload.email,
-        message: "User registered successfully".to_string(),
+        display_name,
+        message: "User registered successfully, check your email to verify your address"
+            .to_string(),
     }))
 }
 
+/// Verify an email address with the link mailed on registration
+///
+/// The token is single-use and valid for `[verification] token_ttl_hours`
+/// (48 by default).
+///
+/// # Endpoint
+///
+/// `GET /api/v1/auth/verify/:token`
+///
+/// # Authentication
+///
+/// No authentication required (public endpoint), so the link works from any
+/// mail client.
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "user_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "email": "user@example.com",
+///   "message": "Email address verified"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - `INVALID_VERIFICATION_TOKEN` for unknown, used or
+///   expired tokens
+#[tracing::instrument(skip(state, token))]
+pub async fn verify_email(
+    State(state): State<crate::AppState>,
+    request_id: RequestId,
+    Path(token): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let result = state
+        .user_service
+        .verify_email(&token)
+        .map_err(ApiError::from);
+
+    let (actor, target) = match &result {
+        Ok(user) => (user.into(), format!("users/{}", user.id)),
+        Err(_) => (AuditActor::anonymous(""), "verification-token".to_string()),
+    };
+    record_audit(
+        &state,
+        actor,
+        actions::EMAIL_VERIFICATION,
+        target,
+        &request_id,
+        &result,
+    );
+    let user = result?;
+
+    Ok(Json(serde_json::json!({
+        "user_id": user.id,
+        "email": user.email,
+        "message": "Email address verified"
+    })))
+}
+
+/// Mail a new verification link to the logged-in user
+///
+/// Replaces any earlier link.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/resend-verification`
+///
+/// # Authentication
+///
+/// **Required** - JWT token
+///
+/// # Response (202 Accepted)
+///
+/// ```json
+/// {
+///   "message": "Verification email sent"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **409 Conflict** - `EMAIL_ALREADY_VERIFIED`
+/// - **503 Service Unavailable** - `MAIL_DELIVERY_FAILED`
+#[tracing::instrument(skip(state, claims))]
+pub async fn resend_verification(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<(StatusCode, Json<serde_json::Value>), ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+    let user = state.user_service.get_user(user_id)?;
+    if user.email_verified {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "EMAIL_ALREADY_VERIFIED",
+            "Email address is already verified",
+        ));
+    }
+
+    if !send_verification_email(&state, user.id, &user.email).await {
+        return Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "MAIL_DELIVERY_FAILED",
+            "Verification email could not be sent, try again later",
+        ));
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({ "message": "Verification email sent" })),
+    ))
+}
+
 // ============================================================================
 // Invitation Management Endpoints
 // ============================================================================
@@ -1333,8 +2356,6 @@ pub async fn create_invitation(
     // Get game to check enrollment status and get expires_at
     let game_state = state.game_service.get_game_state(game_id)?;
 
-    // Check if game is still in enrollment phase
-    // For now, we'll assume any game that's not finished is open (need to add enrollment_open flag to response)
     if game_state.finished {
         return Err(ApiError::new(
             StatusCode::BAD_REQUEST,
@@ -1343,9 +2364,13 @@ pub async fn create_invitation(
         ));
     }
 
-    // Get the game enrollment expires_at time
-    // This is a workaround - we should expose this in GameStateResponse
-    let game_enrollment_expires_at = "2099-01-01T00:00:00Z".to_string(); // Placeholder - need to get from game
+    // Invitations are only useful while the game accepts new players
+    if game_state.enrollment_closed || game_state.time_remaining_seconds <= 0 {
+        return Err(blackjack_service::GameError::EnrollmentClosed.into());
+    }
+
+    // Invitations expire together with the game's enrollment window
+    let game_enrollment_expires_at = game_state.enrollment_closes_at;
 
     let invitation_id = state.invitation_service.create(
         game_id,
@@ -1379,57 +2404,124 @@ pub struct PendingInvitationsResponse {
     pub invitations: Vec<InvitationInfo>,
 }
 
-/// Information about a single invitation
-#[derive(Debug, Serialize)]
-pub struct InvitationInfo {
-    /// Invitation ID
-    pub id: Uuid,
+/// Gets pending invitations for authenticated user
+///
+/// Returns all non-expired invitations for the current user, newest first.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/invitations/pending`
+///
+/// # Authentication
+///
+/// **Required** - User must be authenticated.
+#[tracing::instrument(skip(state))]
+pub async fn get_pending_invitations(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<PendingInvitationsResponse>, ApiError> {
+    let invitations = state
+        .invitation_service
+        .get_pending_for_user(&claims.email, &state.user_service);
 
-    /// Game ID
-    pub game_id: Uuid,
+    Ok(Json(PendingInvitationsResponse { invitations }))
+}
 
-    /// Inviter user ID
-    pub inviter_id: Uuid,
+/// Response for sent invitations list
+#[derive(Debug, Serialize)]
+pub struct SentInvitationsResponse {
+    /// Invitations created by the caller, in any status
+    pub invitations: Vec<InvitationInfo>,
 
-    /// Expiration timestamp
-    pub expires_at: String,
+    /// Number of invitations returned
+    pub count: usize,
 }
 
-/// Gets pending invitations for authenticated user
+/// Gets invitations sent by the authenticated user
 ///
-/// Returns all non-expired invitations for the current user.
+/// Returns every invitation the caller created, newest first, including
+/// accepted, declined, revoked and expired ones.
 ///
 /// # Endpoint
 ///
-/// `GET /api/v1/invitations/pending`
+/// `GET /api/v1/invitations/sent`
 ///
 /// # Authentication
 ///
 /// **Required** - User must be authenticated.
 #[tracing::instrument(skip(state))]
-pub async fn get_pending_invitations(
+pub async fn get_sent_invitations(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
-) -> Result<Json<PendingInvitationsResponse>, ApiError> {
-    let invitations = state.invitation_service.get_pending_for_user(&claims.email);
+) -> Result<Json<SentInvitationsResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
 
-    // Service já retorna Vec<InvitationInfo>, mas precisamos converter para nosso tipo local
-    let invitation_infos: Vec<InvitationInfo> = invitations
-        .into_iter()
-        .map(|inv| {
-            // Parse inviter_email to get inviter_id (simplified for now)
-            let inviter_id = Uuid::new_v4(); // TODO: lookup real user_id
-            InvitationInfo {
-                id: inv.id,
-                game_id: inv.game_id,
-                inviter_id,
-                expires_at: inv.expires_at,
-            }
-        })
-        .collect();
+    let invitations = state
+        .invitation_service
+        .get_sent_by_user(user_id, &state.user_service);
+    let count = invitations.len();
+
+    Ok(Json(SentInvitationsResponse { invitations, count }))
+}
+
+/// Response for invitation revocation
+#[derive(Debug, Serialize)]
+pub struct RevokeInvitationResponse {
+    /// Revoked invitation ID
+    pub invitation_id: Uuid,
+
+    /// Success message
+    pub message: String,
+}
+
+/// Revokes a pending game invitation
+///
+/// The inviter withdraws an invitation that has not been answered yet.
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/invitations/:id`
+///
+/// # Authentication
+///
+/// **Required** - User must be the inviter.
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Caller did not send this invitation
+/// - **404 Not Found** - Invitation does not exist
+/// - **409 Conflict** - Invitation is no longer pending
+#[tracing::instrument(skip(state))]
+pub async fn revoke_invitation(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(invitation_id): Path<Uuid>,
+) -> Result<Json<RevokeInvitationResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    state.invitation_service.revoke(invitation_id, user_id)?;
+
+    tracing::info!(
+        invitation_id = %invitation_id,
+        user_id = %user_id,
+        "Invitation revoked"
+    );
 
-    Ok(Json(PendingInvitationsResponse {
-        invitations: invitation_infos,
+    Ok(Json(RevokeInvitationResponse {
+        invitation_id,
+        message: "Invitation revoked".to_string(),
     }))
 }
 
@@ -1460,8 +2552,7 @@ pub async fn accept_invitation(
     Extension(claims): Extension<Claims>,
     Path(invitation_id): Path<Uuid>,
 ) -> Result<Json<AcceptInvitationResponse>, ApiError> {
-    // Accept the invitation
-    let invitation = state.invitation_service.accept(invitation_id)?;
+    let invitation = state.invitation_service.get_invitation(invitation_id)?;
 
     // Verify the invitee email matches
     if invitation.invitee_email != claims.email {
@@ -1471,6 +2562,7 @@ pub async fn accept_invitation(
             "This invitation is not for you",
         ));
     }
+    require_verified_email(&state, &claims)?;
 
     // Parse user_id from JWT claims
     let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
@@ -1481,13 +2573,16 @@ pub async fn accept_invitation(
         )
     })?;
 
-    // Add player to game
-    state
-        .game_service
-        .add_player_to_game(invitation.game_id, user_id)?;
-
-    // Mark invitation as accepted
+    // Accept first so a concurrent accept or revoke can't use the invitation
+    // while we enroll; a full game or closed enrollment hands it back
     state.invitation_service.accept(invitation_id)?;
+    if let Err(error) = state
+        .game_service
+        .add_player_to_game(invitation.game_id, user_id)
+    {
+        state.invitation_service.reopen(invitation_id)?;
+        return Err(error.into());
+    }
 
     tracing::info!(
         invitation_id = %invitation_id,
@@ -1550,56 +2645,83 @@ pub async fn decline_invitation(
 }
 
 // ============================================================================
-// M7: Turn-Based Gameplay Endpoints
+// Shareable Invite Link Endpoints
 // ============================================================================
 
-/// Response for stand action
-#[derive(Debug, Serialize)]
-pub struct StandResponse {
-    /// Current player points
-    pub points: u32,
+/// Request to create a shareable invite link
+#[derive(Debug, Deserialize)]
+pub struct CreateInviteLinkRequest {
+    /// Optional maximum number of redemptions (unlimited if omitted)
+    pub max_uses: Option<u32>,
+}
 
-    /// Whether player is busted
-    pub busted: bool,
+/// Response for a created invite link
+#[derive(Debug, Serialize)]
+pub struct CreateInviteLinkResponse {
+    /// Link details (uses, expiration, ...)
+    pub link: InviteLinkInfo,
 
-    /// Success message
-    pub message: String,
+    /// Signed join token to share; redeem with `POST /api/v1/games/join/:token`
+    pub token: String,
 
-    /// Game automatically finished?
-    pub game_finished: bool,
+    /// Relative URL for redeeming the token
+    pub join_path: String,
 }
 
-/// Player stands (stops drawing cards)
+/// Creates a shareable, signed join token for a game
 ///
-/// Marks the player as standing and advances to next turn.
-/// If all players have stood or busted, game finishes automatically.
+/// Anyone holding the token can join the game while enrollment is open,
+/// without needing a personal invitation. The token expires together with
+/// the game's enrollment window and can be revoked at any time.
 ///
 /// # Endpoint
 ///
-/// `POST /api/v1/games/:game_id/stand`
+/// `POST /api/v1/games/:game_id/invite-links`
 ///
 /// # Authentication
 ///
-/// **Required** - Must be player's turn.
-#[tracing::instrument(skip(state), fields(player_email = %claims.email))]
-pub async fn stand(
+/// **Required** - Must be the game creator.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "max_uses": 5
+/// }
+/// ```
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "link": {
+///     "id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+///     "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///     "max_uses": 5,
+///     "uses": 0,
+///     "remaining_uses": 5,
+///     "revoked": false,
+///     "expires_at": "2026-01-14T12:05:00+00:00"
+///   },
+///   "token": "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...",
+///   "join_path": "/api/v1/games/join/eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9..."
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - `max_uses` is zero
+/// - **403 Forbidden** - Caller is not the game creator
+/// - **404 Not Found** - Game does not exist
+/// - **410 Gone** - Enrollment is closed
+#[tracing::instrument(skip(state, claims))]
+pub async fn create_invite_link(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
     Path(game_id): Path<Uuid>,
-) -> Result<Json<StandResponse>, ApiError> {
-    // Validate it's the player's turn
-    let current_game_state = state.game_service.get_game_state(game_id)?;
-    if let Some(current_player) = current_game_state.current_turn_player
-        && current_player != claims.email
-    {
-        return Err(ApiError::new(
-            StatusCode::CONFLICT,
-            "NOT_YOUR_TURN",
-            "It's not your turn",
-        ));
-    }
-
-    // Parse user_id from JWT claims
+    Json(payload): Json<CreateInviteLinkRequest>,
+) -> Result<Json<CreateInviteLinkResponse>, ApiError> {
     let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
         ApiError::new(
             StatusCode::BAD_REQUEST,
@@ -1608,189 +2730,147 @@ pub async fn stand(
         )
     })?;
 
-    let game_state = state.game_service.stand(game_id, user_id)?;
+    let link = state.invitation_service.create_link(
+        game_id,
+        user_id,
+        payload.max_uses,
+        &state.game_service.games,
+    )?;
 
-    // Get player info from response
-    let player_info = game_state.players.get(&claims.email).ok_or_else(|| {
+    let exp = chrono::DateTime::parse_from_rfc3339(&link.expires_at)
+        .map(|dt| dt.timestamp() as usize)
+        .map_err(|_| {
+            ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "TOKEN_GENERATION_FAILED",
+                "Invite link has an invalid expiration time",
+            )
+        })?;
+
+    let join_claims = JoinTokenClaims {
+        link_id: link.id.to_string(),
+        game_id: game_id.to_string(),
+        exp,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &join_claims,
+        &EncodingKey::from_secret(state.config.jwt.secret.as_bytes()),
+    )
+    .map_err(|err| {
+        tracing::error!(error = ?err, "Failed to sign join token");
         ApiError::new(
-            StatusCode::NOT_FOUND,
-            "PLAYER_NOT_FOUND",
-            "Player not found in game",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "TOKEN_GENERATION_FAILED",
+            "Failed to generate join token",
         )
     })?;
 
     tracing::info!(
+        link_id = %link.id,
         game_id = %game_id,
-        email = %claims.email,
-        points = player_info.points,
-        game_finished = game_state.finished,
-        "Player stood"
+        creator_id = %user_id,
+        "Invite link created"
     );
 
-    Ok(Json(StandResponse {
-        points: player_info.points as u32,
-        busted: player_info.busted,
-        message: "Player stood successfully".to_string(),
-        game_finished: game_state.finished,
+    Ok(Json(CreateInviteLinkResponse {
+        join_path: format!("/api/v1/games/join/{}", token),
+        link,
+        token,
     }))
 }
 
-// ============================================================================
-// M7: Game Enrollment Endpoints
-// ============================================================================
-
-/// Response for getting open games
+/// Response listing a game's invite links
 #[derive(Debug, Serialize)]
-pub struct GetOpenGamesResponse {
-    /// List of games in enrollment phase
-    pub games: Vec<OpenGameInfo>,
-
-    /// Total number of open games
-    pub count: usize,
+pub struct InviteLinksResponse {
+    pub game_id: Uuid,
+    pub links: Vec<InviteLinkInfo>,
 }
 
-/// Information about an open game
-#[derive(Debug, Serialize)]
-pub struct OpenGameInfo {
-    /// Game ID
-    pub game_id: Uuid,
-
-    /// Creator's user ID
-    pub creator_id: Uuid,
-
-    /// Number of enrolled players
-    pub enrolled_count: u64,
-
-    /// Maximum number of players allowed
-    pub max_players: u64,
-
-    /// Enrollment timeout in seconds
-    pub enrollment_timeout_seconds: u64,
+/// Lists the invite links of a game
+///
+/// Tokens are only returned when a link is created; this endpoint reports
+/// usage and status so the creator can decide what to revoke.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games/:game_id/invite-links`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+#[tracing::instrument(skip(state, claims))]
+pub async fn get_invite_links(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<InviteLinksResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
 
-    /// Time remaining until enrollment closes (seconds)
-    pub time_remaining_seconds: i64,
+    let links =
+        state
+            .invitation_service
+            .get_links_for_game(game_id, user_id, &state.game_service.games)?;
 
-    /// When enrollment closes (RFC3339 format)
-    pub enrollment_closes_at: String,
+    Ok(Json(InviteLinksResponse { game_id, links }))
 }
 
-/// Gets all games currently in enrollment phase
+/// Revokes an invite link
 ///
-/// Returns a list of games that are accepting player enrollments.
-/// Games must not be finished and must still be within their enrollment timeout.
+/// Tokens issued for the link stop working immediately.
 ///
 /// # Endpoint
 ///
-/// `GET /api/v1/games/open`
+/// `DELETE /api/v1/games/:game_id/invite-links/:link_id`
 ///
 /// # Authentication
 ///
-/// **Required** - Must include valid JWT token in Authorization header.
-///
-/// # Response
-///
-/// **Success (200 OK)**:
-/// ```json
-/// {
-///   "games": [
-///     {
-///       "game_id": "550e8400-e29b-41d4-a716-446655440000",
-///       "creator_id": "660e8400-e29b-41d4-a716-446655440000",
-///       "enrolled_count": 2,
-///       "max_players": 10,
-///       "enrollment_timeout_seconds": 300,
-///       "time_remaining_seconds": 250,
-///       "enrollment_closes_at": "2026-01-10T01:30:00Z"
-///     }
-///   ],
-///   "count": 1
-/// }
-/// ```
+/// **Required** - Must be the link creator.
 ///
 /// # Errors
 ///
-/// - **401 Unauthorized** - Missing or invalid JWT token
-///
-/// # Example
-///
-/// ```bash
-/// curl http://localhost:8080/api/v1/games/open \
-///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
-/// ```
-#[tracing::instrument(skip(state, _claims))]
-pub async fn get_open_games(
+/// - **403 Forbidden** - Caller did not create the link
+/// - **404 Not Found** - Link does not exist for this game
+#[tracing::instrument(skip(state, claims))]
+pub async fn revoke_invite_link(
     State(state): State<crate::AppState>,
-    Extension(_claims): Extension<Claims>,
-) -> Result<Json<GetOpenGamesResponse>, ApiError> {
-    let game_infos = state.game_service.get_open_games(None)?;
-
-    let games = game_infos
-        .into_iter()
-        .map(|info| OpenGameInfo {
-            game_id: info.game_id,
-            creator_id: info.creator_id,
-            enrolled_count: info.enrolled_count,
-            max_players: info.max_players,
-            enrollment_timeout_seconds: info.enrollment_timeout_seconds,
-            time_remaining_seconds: info.time_remaining_seconds,
-            enrollment_closes_at: info.enrollment_closes_at,
-        })
-        .collect::<Vec<_>>();
-
-    let count = games.len();
-
-    tracing::info!(count = count, "Retrieved open games list");
-
-    Ok(Json(GetOpenGamesResponse { games, count }))
-}
-
-/// Request to enroll a player in a game
-#[derive(Debug, Deserialize)]
-pub struct EnrollPlayerRequest {
-    /// Player's email address
-    pub email: String,
-}
-
-/// Response for player enrollment
-#[derive(Debug, Serialize)]
-pub struct EnrollPlayerResponse {
-    /// Game ID
-    pub game_id: Uuid,
-
-    /// Player's email
-    pub email: String,
+    Extension(claims): Extension<Claims>,
+    Path((game_id, link_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<InviteLinkInfo>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
 
-    /// Success message
-    pub message: String,
+    let link = state
+        .invitation_service
+        .revoke_link(link_id, game_id, user_id)?;
 
-    /// Updated enrolled player count
-    pub enrolled_count: u64,
+    Ok(Json(link))
 }
 
-/// Enrolls a player in a game during enrollment phase
+/// Joins a game using a shared join token
 ///
-/// Adds a player to a game that is currently accepting enrollments.
-/// The game must not be finished and enrollment must still be open.
+/// Verifies the token signature and expiration, then enrolls the caller
+/// in the linked game.
 ///
 /// # Endpoint
 ///
-/// `POST /api/v1/games/:game_id/enroll`
+/// `POST /api/v1/games/join/:token`
 ///
 /// # Authentication
 ///
-/// **Required** - Must include valid JWT token in Authorization header.
-///
-/// # Path Parameters
-///
-/// - `game_id` - UUID of the game
-///
-/// # Request Body
-///
-/// ```json
-/// {
-///   "email": "player@example.com"
-/// }
-/// ```
+/// **Required** - The authenticated user is enrolled.
 ///
 /// # Response
 ///
@@ -1799,48 +2879,23 @@ pub struct EnrollPlayerResponse {
 /// {
 ///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
 ///   "email": "player@example.com",
-///   "message": "Player enrolled successfully",
+///   "message": "Joined game via invite link",
 ///   "enrolled_count": 3
 /// }
 /// ```
 ///
 /// # Errors
 ///
-/// - **401 Unauthorized** - Missing or invalid JWT token
-/// - **404 Not Found** - Game does not exist
-/// - **409 Conflict** - Game is full
-///   ```json
-///   {
-///     "message": "Game is full",
-///     "code": "GAME_FULL",
-///     "status": 409
-///   }
-///   ```
-/// - **410 Gone** - Enrollment period has closed
-///   ```json
-///   {
-///     "message": "Enrollment is closed for this game",
-///     "code": "ENROLLMENT_CLOSED",
-///     "status": 410
-///   }
-///   ```
-///
-/// # Example
-///
-/// ```bash
-/// curl -X POST http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/enroll \
-///   -H "Authorization: Bearer YOUR_JWT_TOKEN" \
-///   -H "Content-Type: application/json" \
-///   -d '{"email": "player@example.com"}'
-/// ```
-#[tracing::instrument(skip(state, claims), fields(game_id, user_id = %claims.user_id))]
-pub async fn enroll_player(
+/// - **400 Bad Request** - Token is malformed, tampered with or expired
+/// - **404 Not Found** - Link no longer exists
+/// - **409 Conflict** - Already enrolled
+/// - **410 Gone** - Link revoked, exhausted, or enrollment closed
+#[tracing::instrument(skip(state, claims, token))]
+pub async fn join_with_token(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
-    Path(game_id): Path<Uuid>,
-    Json(_payload): Json<EnrollPlayerRequest>,
+    Path(token): Path<String>,
 ) -> Result<Json<EnrollPlayerResponse>, ApiError> {
-    // Parse user_id from JWT claims
     let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
         ApiError::new(
             StatusCode::BAD_REQUEST,
@@ -1849,308 +2904,1902 @@ pub async fn enroll_player(
         )
     })?;
 
-    // Enroll the player using user_id
-    state.game_service.enroll_player(game_id, user_id)?;
+    require_verified_email(&state, &claims)?;
 
-    // Get updated game state to return enrolled count
-    let game_state = state.game_service.get_game_state(game_id)?;
-    let enrolled_count = game_state.players.len() as u64;
+    let invalid_token = || {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_JOIN_TOKEN",
+            "Join token is invalid or has expired",
+        )
+    };
+
+    let join_claims = decode::<JoinTokenClaims>(
+        &token,
+        &DecodingKey::from_secret(state.config.jwt.secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map_err(|err| {
+        tracing::debug!(error = ?err, "Join token validation failed");
+        invalid_token()
+    })?
+    .claims;
+
+    let link_id = Uuid::parse_str(&join_claims.link_id).map_err(|_| invalid_token())?;
+    let game_id = Uuid::parse_str(&join_claims.game_id).map_err(|_| invalid_token())?;
+
+    state
+        .invitation_service
+        .redeem_link(link_id, game_id, user_id, &state.game_service)?;
+
+    let enrolled_count = state.game_service.get_game_state(game_id)?.players.len() as u64;
 
     tracing::info!(
         game_id = %game_id,
+        link_id = %link_id,
         user_id = %user_id,
-        email = %claims.email,
-        enrolled_count = enrolled_count,
-        "Player enrolled successfully"
+        "Player joined game via invite link"
     );
 
     Ok(Json(EnrollPlayerResponse {
         game_id,
         email: claims.email,
-        message: "Player enrolled successfully".to_string(),
+        message: "Joined game via invite link".to_string(),
         enrolled_count,
     }))
 }
 
-/// Request to close enrollment for a game
-#[derive(Debug, Deserialize)]
-pub struct CloseEnrollmentRequest {
-    // No body needed - creator_id comes from JWT token
-}
+// ============================================================================
+// M7: Turn-Based Gameplay Endpoints
+// ============================================================================
 
-/// Response for closing enrollment
+/// Response for stand action
 #[derive(Debug, Serialize)]
-pub struct CloseEnrollmentResponse {
-    /// Game ID
-    pub game_id: Uuid,
+pub struct StandResponse {
+    /// Current player points
+    pub points: u32,
+
+    /// Whether player is busted
+    pub busted: bool,
 
     /// Success message
     pub message: String,
 
-    /// Order in which players will take turns
-    pub turn_order: Vec<String>,
-
-    /// Total enrolled players
-    pub player_count: usize,
+    /// Game automatically finished?
+    pub game_finished: bool,
 }
 
-/// Closes enrollment for a game and initializes turn order
+/// Player stands (stops drawing cards)
 ///
-/// Only the game creator can close enrollment. Once closed, no more
-/// players can join. The turn order is randomized among enrolled players.
+/// Marks the player as standing and advances to next turn.
+/// If all players have stood or busted, game finishes automatically.
 ///
 /// # Endpoint
 ///
-/// `POST /api/v1/games/:game_id/close-enrollment`
+/// `POST /api/v1/games/:game_id/stand`
 ///
 /// # Authentication
 ///
-/// **Required** - Player email extracted from JWT token.
-/// Must be the game creator.
-///
-/// # Path Parameters
-///
-/// - `game_id` - UUID of the game
-///
-/// # Response
-///
-/// **Success (200 OK)**:
-/// ```json
-/// {
-///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
-///   "message": "Enrollment closed successfully",
-///   "turn_order": [
-///     "player1@example.com",
-///     "player3@example.com",
-///     "player2@example.com"
-///   ],
-///   "player_count": 3
-/// }
-/// ```
-///
-/// # Errors
-///
-/// - **401 Unauthorized** - Missing or invalid JWT token
-/// - **403 Forbidden** - User is not the game creator
-///   ```json
-///   {
-///     "message": "Only the game creator can close enrollment",
-///     "code": "NOT_GAME_CREATOR",
-///     "status": 403
-///   }
-///   ```
-/// - **404 Not Found** - Game does not exist
-///
-/// # Example
-///
-/// ```bash
-/// curl -X POST http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/close-enrollment \
-///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
-/// ```
-#[tracing::instrument(skip(state, claims), fields(game_id))]
-pub async fn close_enrollment(
+/// **Required** - Must be player's turn.
+#[tracing::instrument(skip(state), fields(player_email = %claims.email))]
+pub async fn stand(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
     Path(game_id): Path<Uuid>,
-    Json(_payload): Json<CloseEnrollmentRequest>,
-) -> Result<Json<CloseEnrollmentResponse>, ApiError> {
-    // Parse user_id from claims (for now using email, but should be user_id)
-    // TODO: M7 - Update Claims to include user_id
-    let user_id = match uuid::Uuid::parse_str(&claims.user_id) {
-        Ok(id) => id,
-        Err(_) => {
-            // Fallback: generate a deterministic ID from email hash if needed
-            return Err(ApiError::new(
-                StatusCode::UNAUTHORIZED,
-                "INVALID_CLAIMS",
-                "Invalid user ID in JWT token",
-            ));
-        }
-    };
+) -> Result<Json<StandResponse>, ApiError> {
+    // Validate it's the player's turn
+    let current_game_state = state.game_service.get_game_state(game_id)?;
+    // Parse user_id from JWT claims
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
 
-    // Close enrollment (only creator can do this)
-    let turn_order = state.game_service.close_enrollment(game_id, user_id)?;
-    let player_count = turn_order.len();
+    let display_name = state.user_service.get_user(user_id)?.display_name;
+    if let Some(current_player) = current_game_state.current_turn_player
+        && current_player != display_name
+    {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "NOT_YOUR_TURN",
+            "It's not your turn",
+        ));
+    }
+
+    let game_state = state.game_service.stand(game_id, user_id)?;
+
+    // Get player info from response
+    let player_info = game_state.players.get(&display_name).ok_or_else(|| {
+        ApiError::new(
+            StatusCode::NOT_FOUND,
+            "PLAYER_NOT_FOUND",
+            "Player not found in game",
+        )
+    })?;
 
     tracing::info!(
         game_id = %game_id,
-        creator_id = %user_id,
-        player_count = player_count,
-        turn_order = ?turn_order,
-        "Enrollment closed successfully"
+        email = %claims.email,
+        points = player_info.points,
+        game_finished = game_state.finished,
+        "Player stood"
     );
 
-    Ok(Json(CloseEnrollmentResponse {
-        game_id,
-        message: "Enrollment closed successfully".to_string(),
-        turn_order,
-        player_count,
+    Ok(Json(StandResponse {
+        points: player_info.points as u32,
+        busted: player_info.busted,
+        message: "Player stood successfully".to_string(),
+        game_finished: game_state.finished,
     }))
 }
 
-/// Kick player response
+// ============================================================================
+// M7: Game Enrollment Endpoints
+// ============================================================================
+
+/// Default page size for the open games lobby
+const DEFAULT_OPEN_GAMES_LIMIT: usize = 20;
+
+/// Query parameters for the open games lobby
+#[derive(Debug, Default, Deserialize)]
+pub struct OpenGamesParams {
+    /// Only games with at least this many free seats
+    pub min_free_seats: Option<u64>,
+
+    /// Only tables with exactly this many seats
+    pub table_size: Option<u8>,
+
+    /// Only tables whose betting range accepts this bet
+    pub stake: Option<u32>,
+
+    /// Only games created by this user
+    pub creator_id: Option<Uuid>,
+
+    /// Only games with at least this many seconds of enrollment left
+    pub min_time_remaining: Option<i64>,
+
+    /// Only games with at most this many seconds of enrollment left
+    pub max_time_remaining: Option<i64>,
+
+    /// Only tables whose average player rating is at least this
+    pub min_rating: Option<u32>,
+
+    /// Only tables whose average player rating is at most this
+    pub max_rating: Option<u32>,
+
+    /// `time_remaining` (default), `created_at`, `free_seats` or `min_bet`
+    #[serde(default)]
+    pub sort: OpenGamesSort,
+
+    /// `asc` (default) or `desc`
+    #[serde(default)]
+    pub order: SortOrder,
+
+    /// Cursor from the previous page's `next_cursor`
+    pub cursor: Option<String>,
+
+    /// Page size (default 20, max 100)
+    pub limit: Option<usize>,
+}
+
+impl From<OpenGamesParams> for OpenGamesQuery {
+    fn from(params: OpenGamesParams) -> Self {
+        Self {
+            min_free_seats: params.min_free_seats,
+            table_size: params.table_size,
+            stake: params.stake,
+            creator_id: params.creator_id,
+            min_time_remaining: params.min_time_remaining,
+            max_time_remaining: params.max_time_remaining,
+            rating: RatingBand {
+                min: params.min_rating,
+                max: params.max_rating,
+            },
+            sort: params.sort,
+            order: params.order,
+            cursor: params.cursor,
+            limit: Some(params.limit.unwrap_or(DEFAULT_OPEN_GAMES_LIMIT)),
+        }
+    }
+}
+
+/// Response for getting open games
 #[derive(Debug, Serialize)]
-pub struct KickPlayerResponse {
+pub struct GetOpenGamesResponse {
+    /// Games on this page
+    pub games: Vec<OpenGameInfo>,
+
+    /// Number of games on this page
+    pub count: usize,
+
+    /// Number of games matching the filters across all pages
+    pub total: usize,
+
+    /// Cursor for the next page, `null` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Information about an open game
+#[derive(Debug, Serialize)]
+pub struct OpenGameInfo {
+    /// Game ID
     pub game_id: Uuid,
-    pub player_email: String,
-    pub message: String,
+
+    /// Creator's user ID
+    pub creator_id: Uuid,
+
+    /// Number of enrolled players
+    pub enrolled_count: u64,
+
+    /// Maximum number of players allowed (table size)
+    pub max_players: u64,
+
+    /// Seats still available
+    pub free_seats: u64,
+
+    /// Table size and stakes
+    pub rules: TableRules,
+
+    /// Enrollment timeout in seconds
+    pub enrollment_timeout_seconds: u64,
+
+    /// Time remaining until enrollment closes (seconds)
+    pub time_remaining_seconds: i64,
+
+    /// When the game was created (RFC3339 format)
+    pub created_at: String,
+
+    /// When enrollment closes (RFC3339 format)
+    pub enrollment_closes_at: String,
+
+    /// Game visibility (always `public` in the lobby)
+    pub visibility: GameVisibility,
+
+    /// Whether enrolling requires a password
+    pub password_protected: bool,
+
+    /// Average rating of the players enrolled so far
+    pub table_rating: u32,
 }
 
-/// Kick a player from the game (only creator can do this)
+impl From<GameInfo> for OpenGameInfo {
+    fn from(info: GameInfo) -> Self {
+        Self {
+            game_id: info.game_id,
+            creator_id: info.creator_id,
+            enrolled_count: info.enrolled_count,
+            max_players: info.max_players,
+            free_seats: info.free_seats,
+            rules: info.rules,
+            enrollment_timeout_seconds: info.enrollment_timeout_seconds,
+            time_remaining_seconds: info.time_remaining_seconds,
+            created_at: info.created_at,
+            enrollment_closes_at: info.enrollment_closes_at,
+            visibility: info.visibility,
+            password_protected: info.password_protected,
+            table_rating: info.table_rating,
+        }
+    }
+}
+
+/// Gets games currently in enrollment phase
+///
+/// Returns a page of public games that are accepting player enrollments.
+/// Games must not be finished and must still be within their enrollment timeout.
+/// Unlisted and private games, and games the caller already joined, are omitted.
 ///
 /// # Endpoint
 ///
-/// `DELETE /api/v1/games/:game_id/players/:player_id`
+/// `GET /api/v1/games/open`
 ///
 /// # Authentication
 ///
-/// **Required** - Must be the game creator.
+/// **Required** - Must include valid JWT token in Authorization header.
 ///
-/// # Path Parameters
+/// # Query Parameters
 ///
-/// - `game_id` - The game ID
-/// - `player_id` - The player's user ID to kick
+/// - `min_free_seats`, `table_size`, `stake`, `creator_id`,
+///   `min_time_remaining`, `max_time_remaining` - optional filters
+/// - `min_rating`, `max_rating` - band for the table's average player rating
+/// - `sort` - `time_remaining` (default), `created_at`, `free_seats`, `min_bet`
+/// - `order` - `asc` (default) or `desc`
+/// - `limit` - page size (default 20, max 100)
+/// - `cursor` - `next_cursor` from the previous page
 ///
-/// # Response (200 OK)
+/// # Response
 ///
+/// **Success (200 OK)**:
 /// ```json
 /// {
-///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
-///   "player_email": "player@example.com",
-///   "message": "Player kicked successfully"
+///   "games": [
+///     {
+///       "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///       "creator_id": "660e8400-e29b-41d4-a716-446655440000",
+///       "enrolled_count": 2,
+///       "max_players": 6,
+///       "free_seats": 4,
+///       "rules": { "max_players": 6, "min_bet": 10, "max_bet": 500 },
+///       "enrollment_timeout_seconds": 300,
+///       "time_remaining_seconds": 250,
+///       "created_at": "2026-01-10T01:25:00Z",
+///       "enrollment_closes_at": "2026-01-10T01:30:00Z",
+///       "visibility": "public",
+///       "password_protected": false,
+///       "table_rating": 1540
+///     }
+///   ],
+///   "count": 1,
+///   "total": 1,
+///   "next_cursor": null
 /// }
 /// ```
 ///
 /// # Errors
 ///
-/// - **403 Forbidden** - Not the game creator or trying to kick creator
-/// - **404 Not Found** - Game or player not found
-/// - **410 Gone** - Enrollment already closed
+/// - **400 Bad Request** - Malformed query parameters or cursor, or
+///   `min_rating` above `max_rating`
+/// - **401 Unauthorized** - Missing or invalid JWT token
 ///
 /// # Example
 ///
 /// ```bash
-/// curl -X DELETE http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/players/650e8400-e29b-41d4-a716-446655440001 \
+/// curl "http://localhost:8080/api/v1/games/open?min_free_seats=2&sort=created_at&order=desc&limit=10" \
 ///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
 /// ```
 #[tracing::instrument(skip(state, claims))]
-pub async fn kick_player(
+pub async fn get_open_games(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
-    Path((game_id, player_id)): Path<(Uuid, Uuid)>,
-) -> Result<Json<KickPlayerResponse>, ApiError> {
-    let kicker_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
-        ApiError::new(
-            StatusCode::BAD_REQUEST,
-            "INVALID_USER_ID",
-            "Invalid user ID format",
-        )
-    })?;
-
-    let player_email = state
+    Query(params): Query<OpenGamesParams>,
+) -> Result<Json<GetOpenGamesResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).ok();
+    let page = state
         .game_service
-        .kick_player(game_id, kicker_id, player_id)?;
+        .list_open_games(user_id, &params.into())?;
+
+    let games = page
+        .games
+        .into_iter()
+        .map(OpenGameInfo::from)
+        .collect::<Vec<_>>();
+    let count = games.len();
 
     tracing::info!(
-        game_id = %game_id,
-        kicker_id = %kicker_id,
-        player_id = %player_id,
-        player_email = %player_email,
-        "Player kicked successfully"
+        count = count,
+        total = page.total,
+        "Retrieved open games list"
     );
 
-    Ok(Json(KickPlayerResponse {
-        game_id,
-        player_email: player_email.clone(),
-        message: format!("Player {} kicked successfully", player_email),
+    Ok(Json(GetOpenGamesResponse {
+        games,
+        count,
+        total: page.total,
+        next_cursor: page.next_cursor,
     }))
 }
 
-/// Participant info response
-#[derive(Debug, Serialize)]
-pub struct ParticipantInfo {
-    pub user_id: String,
+/// Request to enroll a player in a game
+#[derive(Debug, Deserialize)]
+pub struct EnrollPlayerRequest {
+    /// Player's email address
     pub email: String,
-    pub role: String,
-    pub joined_at: String,
+
+    /// Join password, required when the game is password protected
+    #[serde(default)]
+    pub password: Option<String>,
 }
 
-/// Get participants response
+/// Response for player enrollment
 #[derive(Debug, Serialize)]
-pub struct GetParticipantsResponse {
+pub struct EnrollPlayerResponse {
+    /// Game ID
     pub game_id: Uuid,
-    pub participants: Vec<ParticipantInfo>,
+
+    /// Player's email
+    pub email: String,
+
+    /// Success message
+    pub message: String,
+
+    /// Updated enrolled player count
+    pub enrolled_count: u64,
 }
 
-/// Get all participants in a game with their roles
+/// Enrolls a player in a game during enrollment phase
+///
+/// Adds a player to a game that is currently accepting enrollments.
+/// The game must not be finished and enrollment must still be open.
 ///
 /// # Endpoint
 ///
-/// `GET /api/v1/games/:game_id/participants`
+/// `POST /api/v1/games/:game_id/enroll`
 ///
 /// # Authentication
 ///
-/// **Required** - Must be a participant in the game.
+/// **Required** - Must include valid JWT token in Authorization header.
 ///
 /// # Path Parameters
 ///
-/// - `game_id` - The game ID
+/// - `game_id` - UUID of the game
 ///
-/// # Response (200 OK)
+/// # Request Body
 ///
 /// ```json
 /// {
-///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
-///   "participants": [
-///     {
-///       "user_id": "650e8400-e29b-41d4-a716-446655440001",
-///       "email": "creator@example.com",
-///       "role": "Creator",
-///       "joined_at": "2025-01-02T12:00:00Z"
-///     },
-///     {
-///       "user_id": "650e8400-e29b-41d4-a716-446655440002",
-///       "email": "player@example.com",
-///       "role": "Player",
-///       "joined_at": "2025-01-02T12:05:00Z"
-///     }
-///   ]
+///   "email": "player@example.com",
+///   "password": "table-secret"
 /// }
 /// ```
 ///
-/// # Errors
-///
-/// - **404 Not Found** - Game not found
+/// `password` is only needed for password-protected games.
 ///
-/// # Example
+/// # Response
 ///
-/// ```bash
-/// curl -X GET http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/participants \
-///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "email": "player@example.com",
+///   "message": "Player enrolled successfully",
+///   "enrolled_count": 3
+/// }
 /// ```
-#[tracing::instrument(skip(state))]
-pub async fn get_participants(
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - Missing or invalid JWT token
+/// - **403 Forbidden** - Game is private (`GAME_PRIVATE`) or the password is
+///   missing or wrong (`INVALID_GAME_PASSWORD`)
+/// - **404 Not Found** - Game does not exist
+/// - **409 Conflict** - Game is full
+///   ```json
+///   {
+///     "message": "Game is full",
+///     "code": "GAME_FULL",
+///     "status": 409
+///   }
+///   ```
+/// - **410 Gone** - Enrollment period has closed
+///   ```json
+///   {
+///     "message": "Enrollment is closed for this game",
+///     "code": "ENROLLMENT_CLOSED",
+///     "status": 410
+///   }
+///   ```
+///
+/// # Example
+///
+/// ```bash
+/// curl -X POST http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/enroll \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN" \
+///   -H "Content-Type: application/json" \
+///   -d '{"email": "player@example.com"}'
+/// ```
+#[tracing::instrument(skip(state, claims), fields(game_id, user_id = %claims.user_id))]
+pub async fn enroll_player(
     State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
     Path(game_id): Path<Uuid>,
-) -> Result<Json<GetParticipantsResponse>, ApiError> {
-    // Get game to access participants
-    let games = state.game_service.games.lock().unwrap();
-    let game = games.get(&game_id).ok_or_else(ApiError::game_not_found)?;
+    Json(payload): Json<EnrollPlayerRequest>,
+) -> Result<Json<EnrollPlayerResponse>, ApiError> {
+    // Parse user_id from JWT claims
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
 
-    // Convert participants to response format
-    let participants: Vec<ParticipantInfo> = game
-        .participants
-        .values()
-        .map(|p| ParticipantInfo {
-            user_id: p.user_id.to_string(),
-            email: p.email.clone(),
-            role: format!("{:?}", p.role),
-            joined_at: p.joined_at.clone(),
-        })
-        .collect();
+    // Enroll the player using user_id
+    state.game_service.enroll_player_with_password(
+        game_id,
+        user_id,
+        payload.password.as_deref(),
+    )?;
 
-    Ok(Json(GetParticipantsResponse {
+    // Get updated game state to return enrolled count
+    let game_state = state.game_service.get_game_state(game_id)?;
+    let enrolled_count = game_state.players.len() as u64;
+
+    tracing::info!(
+        game_id = %game_id,
+        user_id = %user_id,
+        email = %claims.email,
+        enrolled_count = enrolled_count,
+        "Player enrolled successfully"
+    );
+
+    Ok(Json(EnrollPlayerResponse {
         game_id,
-        participants,
+        email: claims.email,
+        message: "Player enrolled successfully".to_string(),
+        enrolled_count,
+    }))
+}
+
+/// Request to close enrollment for a game
+#[derive(Debug, Deserialize)]
+pub struct CloseEnrollmentRequest {
+    // No body needed - creator_id comes from JWT token
+}
+
+/// Response for closing enrollment
+#[derive(Debug, Serialize)]
+pub struct CloseEnrollmentResponse {
+    /// Game ID
+    pub game_id: Uuid,
+
+    /// Success message
+    pub message: String,
+
+    /// Order in which players will take turns
+    pub turn_order: Vec<String>,
+
+    /// Total enrolled players
+    pub player_count: usize,
+}
+
+/// Closes enrollment for a game and initializes turn order
+///
+/// Only the game creator can close enrollment. Once closed, no more
+/// players can join. The turn order is randomized among enrolled players.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/close-enrollment`
+///
+/// # Authentication
+///
+/// **Required** - Player email extracted from JWT token.
+/// Must be the game creator.
+///
+/// # Path Parameters
+///
+/// - `game_id` - UUID of the game
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "message": "Enrollment closed successfully",
+///   "turn_order": [
+///     "player1@example.com",
+///     "player3@example.com",
+///     "player2@example.com"
+///   ],
+///   "player_count": 3
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - Missing or invalid JWT token
+/// - **403 Forbidden** - User is not the game creator
+///   ```json
+///   {
+///     "message": "Only the game creator can close enrollment",
+///     "code": "NOT_GAME_CREATOR",
+///     "status": 403
+///   }
+///   ```
+/// - **404 Not Found** - Game does not exist
+///
+/// # Example
+///
+/// ```bash
+/// curl -X POST http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/close-enrollment \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
+/// ```
+#[tracing::instrument(skip(state, claims), fields(game_id))]
+pub async fn close_enrollment(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Path(game_id): Path<Uuid>,
+    Json(_payload): Json<CloseEnrollmentRequest>,
+) -> Result<Json<CloseEnrollmentResponse>, ApiError> {
+    // Parse user_id from claims (for now using email, but should be user_id)
+    // TODO: M7 - Update Claims to include user_id
+    let user_id = match uuid::Uuid::parse_str(&claims.user_id) {
+        Ok(id) => id,
+        Err(_) => {
+            // Fallback: generate a deterministic ID from email hash if needed
+            return Err(ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "INVALID_CLAIMS",
+                "Invalid user ID in JWT token",
+            ));
+        }
+    };
+
+    // Close enrollment (only creator can do this)
+    let result = state
+        .game_service
+        .close_enrollment(game_id, user_id)
+        .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::CLOSE_ENROLLMENT,
+        format!("games/{}", game_id),
+        &request_id,
+        &result,
+    );
+    let turn_order = result?;
+    let player_count = turn_order.len();
+
+    tracing::info!(
+        game_id = %game_id,
+        creator_id = %user_id,
+        player_count = player_count,
+        turn_order = ?turn_order,
+        "Enrollment closed successfully"
+    );
+
+    Ok(Json(CloseEnrollmentResponse {
+        game_id,
+        message: "Enrollment closed successfully".to_string(),
+        turn_order,
+        player_count,
     }))
 }
+
+/// Kick player response
+#[derive(Debug, Serialize)]
+pub struct KickPlayerResponse {
+    pub game_id: Uuid,
+    pub display_name: String,
+    pub message: String,
+}
+
+/// Kick a player from the game (only creator can do this)
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/games/:game_id/players/:player_id`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+///
+/// # Path Parameters
+///
+/// - `game_id` - The game ID
+/// - `player_id` - The player's user ID to kick
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "display_name": "lucky_ace",
+///   "message": "Player lucky_ace kicked successfully"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Not the game creator or trying to kick creator
+/// - **404 Not Found** - Game or player not found
+/// - **410 Gone** - Enrollment already closed
+///
+/// # Example
+///
+/// ```bash
+/// curl -X DELETE http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/players/650e8400-e29b-41d4-a716-446655440001 \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
+/// ```
+#[tracing::instrument(skip(state, claims))]
+pub async fn kick_player(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Path((game_id, player_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<KickPlayerResponse>, ApiError> {
+    let kicker_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format",
+        )
+    })?;
+
+    let result = state
+        .game_service
+        .kick_player(game_id, kicker_id, player_id)
+        .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::KICK_PLAYER,
+        format!("games/{}/players/{}", game_id, player_id),
+        &request_id,
+        &result,
+    );
+    let display_name = result?;
+
+    tracing::info!(
+        game_id = %game_id,
+        kicker_id = %kicker_id,
+        player_id = %player_id,
+        display_name = %display_name,
+        "Player kicked successfully"
+    );
+
+    Ok(Json(KickPlayerResponse {
+        game_id,
+        message: format!("Player {} kicked successfully", display_name),
+        display_name,
+    }))
+}
+
+/// Request to seat a bot player
+#[derive(Debug, Default, Deserialize)]
+pub struct AddBotRequest {
+    /// `basic_strategy` (default), `mimic_dealer` or `random`
+    #[serde(default)]
+    pub strategy: BotStrategy,
+}
+
+/// Response for a seated bot
+#[derive(Debug, Serialize)]
+pub struct AddBotResponse {
+    pub game_id: Uuid,
+    /// Player name of the bot (`bot-1`, `bot-2`, ...)
+    pub bot_name: String,
+    pub strategy: BotStrategy,
+    /// Updated enrolled player count (bots included)
+    pub enrolled_count: u64,
+}
+
+/// Seat a bot player in the game (only creator can do this)
+///
+/// Bots fill a seat during enrollment and play automatically on their turn.
+/// They show up in game state and results (`is_bot: true`) but have no user
+/// account, so they never affect player statistics.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/bots`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "strategy": "mimic_dealer"
+/// }
+/// ```
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "bot_name": "bot-1",
+///   "strategy": "mimic_dealer",
+///   "enrolled_count": 2
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Game is full
+/// - **403 Forbidden** - Not the game creator
+/// - **404 Not Found** - Game not found
+/// - **410 Gone** - Enrollment already closed
+#[tracing::instrument(skip(state, claims))]
+pub async fn add_bot(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+    Json(payload): Json<AddBotRequest>,
+) -> Result<Json<AddBotResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format",
+        )
+    })?;
+
+    let bot_name = state
+        .game_service
+        .add_bot(game_id, user_id, payload.strategy)?;
+    let enrolled_count = state.game_service.get_game_state(game_id)?.players.len() as u64;
+
+    Ok(Json(AddBotResponse {
+        game_id,
+        bot_name,
+        strategy: payload.strategy,
+        enrolled_count,
+    }))
+}
+
+/// Participant info response
+#[derive(Debug, Serialize)]
+pub struct ParticipantInfo {
+    pub user_id: String,
+    pub display_name: String,
+    pub role: String,
+    pub joined_at: String,
+}
+
+/// Get participants response
+#[derive(Debug, Serialize)]
+pub struct GetParticipantsResponse {
+    pub game_id: Uuid,
+    pub participants: Vec<ParticipantInfo>,
+}
+
+/// Get all participants in a game with their roles
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games/:game_id/participants`
+///
+/// # Authentication
+///
+/// **Required** - Must be a participant in the game.
+///
+/// # Path Parameters
+///
+/// - `game_id` - The game ID
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "participants": [
+///     {
+///       "user_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "display_name": "ace_of_spades",
+///       "role": "Creator",
+///       "joined_at": "2025-01-02T12:00:00Z"
+///     },
+///     {
+///       "user_id": "650e8400-e29b-41d4-a716-446655440002",
+///       "display_name": "lucky_seven",
+///       "role": "Player",
+///       "joined_at": "2025-01-02T12:05:00Z"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **404 Not Found** - Game not found
+///
+/// # Example
+///
+/// ```bash
+/// curl -X GET http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/participants \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN"
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn get_participants(
+    State(state): State<crate::AppState>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<GetParticipantsResponse>, ApiError> {
+    // Get game to access participants
+    let games = state.game_service.games.lock().unwrap();
+    let game = games.get(&game_id).ok_or_else(ApiError::game_not_found)?;
+
+    // Convert participants to response format
+    let participants: Vec<ParticipantInfo> = game
+        .participants
+        .values()
+        .map(|p| ParticipantInfo {
+            user_id: p.user_id.to_string(),
+            display_name: p.display_name.clone(),
+            role: format!("{:?}", p.role),
+            joined_at: p.joined_at.clone(),
+        })
+        .collect();
+
+    Ok(Json(GetParticipantsResponse {
+        game_id,
+        participants,
+    }))
+}
+
+// ============================================================================
+// Table Chat Endpoints
+// ============================================================================
+
+/// Request to post a chat message
+#[derive(Debug, Deserialize)]
+pub struct PostChatMessageRequest {
+    /// Message text, 1 to 500 characters after trimming
+    pub text: String,
+}
+
+/// Query parameters for the chat history
+#[derive(Debug, Default, Deserialize)]
+pub struct ChatHistoryParams {
+    /// Latest messages to return (default 50, at most 200)
+    pub limit: Option<usize>,
+}
+
+/// Response for a muted or unmuted participant
+#[derive(Debug, Serialize)]
+pub struct ChatMuteResponse {
+    pub game_id: Uuid,
+    pub user_id: Uuid,
+    pub muted: bool,
+}
+
+/// Post a message to the game's table chat
+///
+/// Every other participant receives a `chat_message` notification with the
+/// `message_id`; the text is read from the chat history. Each user can post
+/// `chat.messages_per_minute` messages per minute across all games.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/chat`
+///
+/// # Authentication
+///
+/// **Required** - Must be a participant in the game.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "text": "Good luck everyone!"
+/// }
+/// ```
+///
+/// # Response (201 Created)
+///
+/// ```json
+/// {
+///   "id": "8f14e45f-ceea-467f-a0e6-7b1e5c0d3a21",
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "author_id": "650e8400-e29b-41d4-a716-446655440001",
+///   "author_name": "ace_of_spades",
+///   "text": "Good luck everyone!",
+///   "sent_at": "2026-01-14T12:01:00+00:00"
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Empty or too long message
+/// - **403 Forbidden** - Not a participant, or muted in this game
+/// - **404 Not Found** - Game not found
+/// - **429 Too Many Requests** - Chat rate limit exceeded
+///
+/// # Example
+///
+/// ```bash
+/// curl -X POST http://localhost:8080/api/v1/games/550e8400-e29b-41d4-a716-446655440000/chat \
+///   -H "Authorization: Bearer YOUR_JWT_TOKEN" \
+///   -H "Content-Type: application/json" \
+///   -d '{"text": "Good luck everyone!"}'
+/// ```
+#[tracing::instrument(skip(state, claims, payload))]
+pub async fn post_chat_message(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+    Json(payload): Json<PostChatMessageRequest>,
+) -> Result<(StatusCode, Json<ChatMessage>), ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format",
+        )
+    })?;
+
+    state
+        .chat_rate_limiter
+        .check_rate_limit(&user_id.to_string())?;
+
+    let message = state.game_service.post_chat_message(
+        game_id,
+        user_id,
+        &payload.text,
+        &state.notification_service,
+    )?;
+
+    Ok((StatusCode::CREATED, Json(message)))
+}
+
+/// Get the latest messages of the game's table chat
+///
+/// Messages are returned oldest first, along with the participants who are
+/// currently muted. Only the latest 200 messages of a game are kept.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games/:game_id/chat?limit=50`
+///
+/// # Authentication
+///
+/// **Required** - Must be a participant in the game.
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "messages": [
+///     {
+///       "id": "8f14e45f-ceea-467f-a0e6-7b1e5c0d3a21",
+///       "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///       "author_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "author_name": "ace_of_spades",
+///       "text": "Good luck everyone!",
+///       "sent_at": "2026-01-14T12:01:00+00:00"
+///     }
+///   ],
+///   "muted": []
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Not a participant
+/// - **404 Not Found** - Game not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn get_chat_history(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+    Query(params): Query<ChatHistoryParams>,
+) -> Result<Json<ChatHistory>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format",
+        )
+    })?;
+
+    let history = state
+        .game_service
+        .chat_history(game_id, user_id, params.limit)?;
+
+    Ok(Json(history))
+}
+
+/// Delete a message from the game's table chat (only creator can do this)
+///
+/// The other participants receive a `chat_message_deleted` notification.
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/games/:game_id/chat/:message_id`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+///
+/// # Response
+///
+/// **204 No Content**
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Not the game creator
+/// - **404 Not Found** - Game or message not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn delete_chat_message(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Path((game_id, message_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format",
+        )
+    })?;
+
+    let result = state
+        .game_service
+        .delete_chat_message(game_id, user_id, message_id, &state.notification_service)
+        .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::CHAT_DELETE_MESSAGE,
+        format!("games/{}/chat/{}", game_id, message_id),
+        &request_id,
+        &result,
+    );
+    result?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Mute a participant in the game's table chat (only creator can do this)
+///
+/// Muted participants can still read the chat but can't post until they are
+/// unmuted. They receive a `chat_muted` notification.
+///
+/// # Endpoint
+///
+/// `PUT /api/v1/games/:game_id/chat/mutes/:user_id`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+///
+/// # Response (200 OK)
+///
+/// ```json
+/// {
+///   "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "user_id": "650e8400-e29b-41d4-a716-446655440002",
+///   "muted": true
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Trying to mute the creator
+/// - **403 Forbidden** - Not the game creator, or the user is not a participant
+/// - **404 Not Found** - Game not found
+#[tracing::instrument(skip(state, claims))]
+pub async fn mute_chat_participant(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Path((game_id, target_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ChatMuteResponse>, ApiError> {
+    set_chat_muted(state, claims, request_id, game_id, target_id, true)
+}
+
+/// Unmute a participant in the game's table chat (only creator can do this)
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/games/:game_id/chat/mutes/:user_id`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+///
+/// # Errors
+///
+/// Same as [`mute_chat_participant`].
+#[tracing::instrument(skip(state, claims))]
+pub async fn unmute_chat_participant(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    request_id: RequestId,
+    Path((game_id, target_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ChatMuteResponse>, ApiError> {
+    set_chat_muted(state, claims, request_id, game_id, target_id, false)
+}
+
+fn set_chat_muted(
+    state: crate::AppState,
+    claims: Claims,
+    request_id: RequestId,
+    game_id: Uuid,
+    target_id: Uuid,
+    muted: bool,
+) -> Result<Json<ChatMuteResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format",
+        )
+    })?;
+
+    let result = state
+        .game_service
+        .set_chat_muted(
+            game_id,
+            user_id,
+            target_id,
+            muted,
+            &state.notification_service,
+        )
+        .map_err(ApiError::from);
+    record_audit(
+        &state,
+        claims_actor(&state, &claims),
+        actions::CHAT_MUTE,
+        format!("games/{}/chat/mutes/{}", game_id, target_id),
+        &request_id,
+        &result,
+    );
+    result?;
+
+    Ok(Json(ChatMuteResponse {
+        game_id,
+        user_id: target_id,
+        muted,
+    }))
+}
+
+// ============================================================================
+// Matchmaking Endpoints
+// ============================================================================
+
+/// Request to join the matchmaking queue
+#[derive(Debug, Default, Deserialize)]
+pub struct JoinQueueRequest {
+    /// Wanted table size and stakes (defaults: 10 seats, bets 10-500)
+    #[serde(default)]
+    pub rules: TableRules,
+
+    /// How long to wait for a table before timing out (server default if omitted)
+    pub wait_seconds: Option<u64>,
+
+    /// Ratings accepted at the table (any rating if omitted)
+    #[serde(default)]
+    pub rating_band: RatingBand,
+}
+
+/// Joins the matchmaking queue
+///
+/// Players wanting the same table size with overlapping stakes are grouped
+/// together, as long as each one's rating lies in every other player's
+/// `rating_band`. As soon as enough of them are waiting to fill a table, or
+/// at least the configured minimum once the longest-waiting one has waited
+/// long enough, a private game is created, everyone is seated, enrollment is
+/// closed and each player receives a `match_found` notification. The table
+/// plays at the stakes everyone accepts and the longest-waiting player
+/// becomes the game creator.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/matchmaking/queue`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "rules": { "max_players": 4, "min_bet": 10, "max_bet": 500 },
+///   "wait_seconds": 120,
+///   "rating_band": { "min": 1400, "max": 1700 }
+/// }
+/// ```
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "ticket": {
+///     "id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+///     "user_id": "650e8400-e29b-41d4-a716-446655440001",
+///     "rules": { "max_players": 4, "min_bet": 10, "max_bet": 500 },
+///     "rating": 1530,
+///     "rating_band": { "min": 1400, "max": 1700 },
+///     "status": "waiting",
+///     "game_id": null,
+///     "enqueued_at": "2026-01-14T12:00:00+00:00",
+///     "expires_at": "2026-01-14T12:02:00+00:00"
+///   },
+///   "players_waiting": 2
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Invalid rules, wait time or rating band
+/// - **403 Forbidden** - Email not verified while `[verification] required` is set
+/// - **409 Conflict** - Already waiting in the queue (`ALREADY_QUEUED`)
+#[tracing::instrument(skip(state, claims))]
+pub async fn join_matchmaking_queue(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(payload): Json<JoinQueueRequest>,
+) -> Result<Json<QueueStatus>, ApiError> {
+    require_verified_email(&state, &claims)?;
+
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let status = state.matchmaking_service.enqueue_with_rating_band(
+        user_id,
+        payload.rules,
+        payload.wait_seconds,
+        payload.rating_band,
+        &state.game_service,
+        &state.notification_service,
+    )?;
+
+    Ok(Json(status))
+}
+
+/// Gets the caller's latest matchmaking ticket
+///
+/// Poll this (or the notifications inbox) to find out when a table forms;
+/// once matched, `ticket.status` is `matched` and `ticket.game_id` is set.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/matchmaking/queue`
+///
+/// # Errors
+///
+/// - **404 Not Found** - The caller never queued (`NOT_QUEUED`)
+#[tracing::instrument(skip(state, claims))]
+pub async fn get_matchmaking_status(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<QueueStatus>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let status = state
+        .matchmaking_service
+        .status(user_id, &state.notification_service)?;
+
+    Ok(Json(status))
+}
+
+/// Leaves the matchmaking queue
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/matchmaking/queue`
+///
+/// # Errors
+///
+/// - **404 Not Found** - The caller has no waiting ticket (`NOT_QUEUED`)
+#[tracing::instrument(skip(state, claims))]
+pub async fn leave_matchmaking_queue(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<QueueTicket>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let ticket = state
+        .matchmaking_service
+        .cancel(user_id, &state.notification_service)?;
+
+    Ok(Json(ticket))
+}
+
+// ============================================================================
+// Tournament Endpoints
+// ============================================================================
+
+/// Request to create a tournament
+#[derive(Debug, Deserialize)]
+pub struct CreateTournamentRequest {
+    /// Shown in listings and notifications (1-64 characters)
+    pub name: String,
+
+    /// Rounds, stacks, table size, players advancing per table and bet
+    /// (defaults: 3 rounds, 1000 chips, 4 seats, 2 advancing, 100 per hand)
+    #[serde(default)]
+    pub settings: TournamentSettings,
+}
+
+/// Query parameters for listing tournaments
+#[derive(Debug, Default, Deserialize)]
+pub struct TournamentsParams {
+    /// `registering`, `running` or `finished`
+    pub status: Option<TournamentStatus>,
+}
+
+/// Response for listing tournaments
+#[derive(Debug, Serialize)]
+pub struct TournamentsResponse {
+    /// Tournaments, newest first
+    pub tournaments: Vec<TournamentInfo>,
+
+    /// Number of tournaments returned
+    pub count: usize,
+}
+
+/// Response for a tournament's standings
+#[derive(Debug, Serialize)]
+pub struct TournamentStandingsResponse {
+    pub tournament_id: Uuid,
+    pub status: TournamentStatus,
+
+    /// Round being played, 0 before the tournament starts
+    pub current_round: u8,
+
+    pub champion_id: Option<Uuid>,
+
+    /// Every registered player, best first
+    pub standings: Vec<TournamentStanding>,
+}
+
+/// Response for a tournament's bracket
+#[derive(Debug, Serialize)]
+pub struct TournamentBracketResponse {
+    pub tournament_id: Uuid,
+    pub status: TournamentStatus,
+
+    /// Rounds seated so far, each with its tables
+    pub rounds: Vec<TournamentRound>,
+}
+
+/// Creates a tournament open for registration
+///
+/// The caller becomes the organiser, who starts the tournament; organisers
+/// register like anyone else if they want to play.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/tournaments`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "name": "Friday Night Blackjack",
+///   "settings": {
+///     "rounds": 3,
+///     "starting_chips": 1000,
+///     "table_size": 4,
+///     "advancing_per_table": 2,
+///     "bet": 100
+///   }
+/// }
+/// ```
+///
+/// # Response
+///
+/// **Success (200 OK)**: the tournament summary, with `status` `registering`.
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Invalid name or settings
+/// - **403 Forbidden** - Email not verified (when verification is required)
+#[tracing::instrument(skip(state, claims))]
+pub async fn create_tournament(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(payload): Json<CreateTournamentRequest>,
+) -> Result<Json<TournamentInfo>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+    require_verified_email(&state, &claims)?;
+
+    let tournament = state
+        .tournament_service
+        .create(user_id, &payload.name, payload.settings)?;
+
+    Ok(Json((&tournament).into()))
+}
+
+/// Lists tournaments, newest first
+///
+/// # Endpoint
+///
+/// `GET /api/v1/tournaments?status=registering`
+///
+/// # Authentication
+///
+/// **Public endpoint**
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "tournaments": [
+///     {
+///       "id": "3f1c2b9e-5d4a-4c8b-9f0e-1a2b3c4d5e6f",
+///       "name": "Friday Night Blackjack",
+///       "creator_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "settings": { "rounds": 3, "starting_chips": 1000, "table_size": 4, "advancing_per_table": 2, "bet": 100 },
+///       "status": "running",
+///       "registered_players": 12,
+///       "current_round": 1,
+///       "champion_id": null,
+///       "created_at": "2026-10-16T18:00:00+00:00"
+///     }
+///   ],
+///   "count": 1
+/// }
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn list_tournaments(
+    State(state): State<crate::AppState>,
+    Query(params): Query<TournamentsParams>,
+) -> Json<TournamentsResponse> {
+    state
+        .tournament_service
+        .advance(&state.game_service, &state.notification_service);
+    let tournaments = state.tournament_service.list(params.status);
+    let count = tournaments.len();
+
+    Json(TournamentsResponse { tournaments, count })
+}
+
+/// Gets a tournament's summary
+///
+/// # Endpoint
+///
+/// `GET /api/v1/tournaments/:tournament_id`
+///
+/// # Authentication
+///
+/// **Public endpoint**
+///
+/// # Errors
+///
+/// - **404 Not Found** - Tournament doesn't exist (`TOURNAMENT_NOT_FOUND`)
+#[tracing::instrument(skip(state))]
+pub async fn get_tournament(
+    State(state): State<crate::AppState>,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Json<TournamentInfo>, ApiError> {
+    state
+        .tournament_service
+        .advance(&state.game_service, &state.notification_service);
+    let tournament = state.tournament_service.get(tournament_id)?;
+
+    Ok(Json((&tournament).into()))
+}
+
+/// Registers the caller for a tournament
+///
+/// Players start with the tournament's `starting_chips`.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/tournaments/:tournament_id/register`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Email not verified (when verification is required)
+/// - **404 Not Found** - Tournament doesn't exist (`TOURNAMENT_NOT_FOUND`)
+/// - **409 Conflict** - Registration has closed (`TOURNAMENT_ALREADY_STARTED`)
+///   or the caller is registered already (`ALREADY_REGISTERED`)
+#[tracing::instrument(skip(state, claims))]
+pub async fn register_for_tournament(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Json<TournamentInfo>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+    require_verified_email(&state, &claims)?;
+
+    let tournament =
+        state
+            .tournament_service
+            .register(tournament_id, user_id, &state.user_service)?;
+
+    Ok(Json((&tournament).into()))
+}
+
+/// Starts a tournament (organiser only)
+///
+/// Registration closes and the first round is seated: players are spread
+/// over private games of at most `table_size` seats with enrollment already
+/// closed, and each receives a `tournament_table_ready` notification with
+/// the game to play. When every table of a round has finished, the top
+/// `advancing_per_table` stacks of each table are seated for the next
+/// round; after the last round, or once one player remains, the biggest
+/// stack wins.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/tournaments/:tournament_id/start`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Errors
+///
+/// - **400 Bad Request** - Fewer than 2 players registered
+/// - **403 Forbidden** - Caller is not the organiser
+/// - **404 Not Found** - Tournament doesn't exist (`TOURNAMENT_NOT_FOUND`)
+/// - **409 Conflict** - Already started (`TOURNAMENT_ALREADY_STARTED`)
+#[tracing::instrument(skip(state, claims))]
+pub async fn start_tournament(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Json<TournamentInfo>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let tournament = state.tournament_service.start(
+        tournament_id,
+        user_id,
+        &state.game_service,
+        &state.notification_service,
+    )?;
+
+    Ok(Json((&tournament).into()))
+}
+
+/// Gets a tournament's standings
+///
+/// The champion comes first, then players still in by chips, then
+/// eliminated players, those who lasted longest first.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/tournaments/:tournament_id/standings`
+///
+/// # Authentication
+///
+/// **Public endpoint**
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "tournament_id": "3f1c2b9e-5d4a-4c8b-9f0e-1a2b3c4d5e6f",
+///   "status": "running",
+///   "current_round": 2,
+///   "champion_id": null,
+///   "standings": [
+///     {
+///       "rank": 1,
+///       "user_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "display_name": "alice",
+///       "chips": 1150,
+///       "status": "playing",
+///       "eliminated_in_round": null
+///     },
+///     {
+///       "rank": 2,
+///       "user_id": "750e8400-e29b-41d4-a716-446655440002",
+///       "display_name": "bob",
+///       "chips": 900,
+///       "status": "eliminated",
+///       "eliminated_in_round": 1
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **404 Not Found** - Tournament doesn't exist (`TOURNAMENT_NOT_FOUND`)
+#[tracing::instrument(skip(state))]
+pub async fn get_tournament_standings(
+    State(state): State<crate::AppState>,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Json<TournamentStandingsResponse>, ApiError> {
+    state
+        .tournament_service
+        .advance(&state.game_service, &state.notification_service);
+    let tournament = state.tournament_service.get(tournament_id)?;
+
+    Ok(Json(TournamentStandingsResponse {
+        tournament_id,
+        status: tournament.status,
+        current_round: tournament.current_round(),
+        champion_id: tournament.champion_id,
+        standings: tournament.standings(),
+    }))
+}
+
+/// Gets a tournament's bracket: every round with its tables
+///
+/// A table's seats show each player's stack before the round and, once
+/// the table's game has finished, the hand's outcome, the new stack and
+/// whether the player advanced.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/tournaments/:tournament_id/bracket`
+///
+/// # Authentication
+///
+/// **Public endpoint**
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "tournament_id": "3f1c2b9e-5d4a-4c8b-9f0e-1a2b3c4d5e6f",
+///   "status": "running",
+///   "rounds": [
+///     {
+///       "number": 1,
+///       "tables": [
+///         {
+///           "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///           "seats": [
+///             {
+///               "user_id": "650e8400-e29b-41d4-a716-446655440001",
+///               "display_name": "alice",
+///               "starting_chips": 1000,
+///               "chips": 1100,
+///               "outcome": "won",
+///               "advanced": true
+///             }
+///           ],
+///           "settled": true
+///         }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - **404 Not Found** - Tournament doesn't exist (`TOURNAMENT_NOT_FOUND`)
+#[tracing::instrument(skip(state))]
+pub async fn get_tournament_bracket(
+    State(state): State<crate::AppState>,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Json<TournamentBracketResponse>, ApiError> {
+    state
+        .tournament_service
+        .advance(&state.game_service, &state.notification_service);
+    let tournament = state.tournament_service.get(tournament_id)?;
+
+    Ok(Json(TournamentBracketResponse {
+        tournament_id,
+        status: tournament.status,
+        rounds: tournament.rounds,
+    }))
+}
+
+// ============================================================================
+// Notification Endpoints
+// ============================================================================
+
+/// Query parameters for listing notifications
+#[derive(Debug, Default, Deserialize)]
+pub struct NotificationsParams {
+    /// Only return notifications not yet marked as read
+    #[serde(default)]
+    pub unread_only: bool,
+}
+
+/// Response for listing notifications
+#[derive(Debug, Serialize)]
+pub struct NotificationsResponse {
+    /// Notifications, newest first
+    pub notifications: Vec<Notification>,
+
+    /// Number of notifications returned
+    pub count: usize,
+}
+
+/// Response for marking notifications as read
+#[derive(Debug, Serialize)]
+pub struct MarkNotificationsReadResponse {
+    /// Number of notifications that were unread
+    pub marked: usize,
+}
+
+/// Lists the caller's notifications
+///
+/// # Endpoint
+///
+/// `GET /api/v1/notifications?unread_only=true`
+///
+/// # Response
+///
+/// **Success (200 OK)**:
+/// ```json
+/// {
+///   "notifications": [
+///     {
+///       "id": "9a8b7c6d-5e4f-3a2b-1c0d-9e8f7a6b5c4d",
+///       "user_id": "650e8400-e29b-41d4-a716-446655440001",
+///       "type": "match_found",
+///       "ticket_id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+///       "game_id": "550e8400-e29b-41d4-a716-446655440000",
+///       "message": "Matched with 3 player(s), your game is ready",
+///       "created_at": "2026-01-14T12:01:00+00:00",
+///       "read": false
+///     }
+///   ],
+///   "count": 1
+/// }
+/// ```
+#[tracing::instrument(skip(state, claims))]
+pub async fn get_notifications(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(params): Query<NotificationsParams>,
+) -> Result<Json<NotificationsResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let notifications = state
+        .notification_service
+        .get_for_user(user_id, params.unread_only);
+    let count = notifications.len();
+
+    Ok(Json(NotificationsResponse {
+        notifications,
+        count,
+    }))
+}
+
+/// Marks all of the caller's notifications as read
+///
+/// # Endpoint
+///
+/// `POST /api/v1/notifications/read`
+#[tracing::instrument(skip(state, claims))]
+pub async fn mark_notifications_read(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<MarkNotificationsReadResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "INVALID_USER_ID",
+            "Invalid user ID format in token",
+        )
+    })?;
+
+    let marked = state.notification_service.mark_all_read(user_id);
+
+    Ok(Json(MarkNotificationsReadResponse { marked }))
+}
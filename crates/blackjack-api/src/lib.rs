@@ -26,7 +26,11 @@
 //!
 //! ```no_run
 //! use blackjack_api::{AppState, config::AppConfig};
-//! use blackjack_service::{GameService, ServiceConfig, UserService, InvitationService, InvitationConfig};
+//! use blackjack_service::{
+//!     AuditLog, GameService, InvitationConfig, InvitationService, MatchmakingConfig,
+//!     MatchmakingService, NotificationService, OutboxMailer, ServiceConfig, TournamentService,
+//!     UserService,
+//! };
 //! use blackjack_api::rate_limiter::RateLimiter;
 //! use std::sync::Arc;
 //!
@@ -39,15 +43,27 @@
 //!     let user_service = Arc::new(UserService::new());
 //!     let game_service = Arc::new(GameService::new(ServiceConfig::from_env(), user_service.clone()));
 //!     let invitation_service = Arc::new(InvitationService::new(InvitationConfig::default()));
+//!     let matchmaking_service = Arc::new(MatchmakingService::new(MatchmakingConfig::from_env()));
+//!     let notification_service = Arc::new(NotificationService::new());
+//!     let tournament_service = Arc::new(TournamentService::new());
+//!     let audit_log = Arc::new(AuditLog::new());
+//!     let mailer = Arc::new(OutboxMailer::new());
 //!     let rate_limiter = RateLimiter::new(config.rate_limit.requests_per_minute);
+//!     let chat_rate_limiter = RateLimiter::new(config.chat.messages_per_minute);
 //!     
 //!     // Create shared state
 //!     let state = AppState {
 //!         game_service,
 //!         user_service,
 //!         invitation_service,
+//!         matchmaking_service,
+//!         notification_service,
+//!         tournament_service,
+//!         audit_log,
+//!         mailer,
 //!         config,
 //!         rate_limiter,
+//!         chat_rate_limiter,
 //!     };
 //!     
 //!     // Build and run server (see main.rs for full example)
@@ -92,8 +108,19 @@
 //! - `POST /api/v1/games/:id/finish` - Finish the game
 //! - `GET /api/v1/games/:id/results` - Get game results
 //!
+//! ## Account Endpoints (require JWT)
+//!
+//! - `/api/v1/users/me` and `/api/v1/users/me/export` - see [`account`]
+//!
+//! ## Admin Endpoints (require an administrator account)
+//!
+//! - `/api/v1/admin/users`, `/api/v1/admin/games`, `/api/v1/admin/invitations` -
+//!   see [`admin`]
+//!
 //! # Modules
 //!
+//! - [`account`] - Account self-service handlers
+//! - [`admin`] - Server administration handlers
 //! - [`auth`] - JWT claims and authentication types
 //! - [`config`] - Application configuration structures
 //! - [`error`] - Standardized API error responses
@@ -101,6 +128,8 @@
 //! - [`middleware`] - Authentication, rate limiting, and deprecation middleware
 //! - [`rate_limiter`] - Request rate limiting implementation
 
+pub mod account;
+pub mod admin;
 pub mod auth;
 pub mod config;
 pub mod error;
@@ -109,7 +138,10 @@ pub mod middleware;
 pub mod rate_limiter;
 pub mod websocket;
 
-use blackjack_service::{GameService, InvitationService, UserService};
+use blackjack_service::{
+    AuditLog, GameService, InvitationService, Mailer, MatchmakingService, NotificationService,
+    TournamentService, UserService,
+};
 use config::AppConfig;
 use rate_limiter::RateLimiter;
 use std::sync::Arc;
@@ -126,6 +158,9 @@ use std::sync::Arc;
 /// - `GameService` uses `Arc<Mutex<HashMap>>` internally
 /// - `UserService` uses `Arc<Mutex<HashMap>>` internally
 /// - `InvitationService` uses `Arc<Mutex<HashMap>>` internally
+/// - `MatchmakingService`, `NotificationService` and `TournamentService` use
+///   `Arc<Mutex<HashMap>>` internally
+/// - `AuditLog` uses `Arc<Mutex<VecDeque>>` internally
 /// - `RateLimiter` uses `Arc<Mutex<HashMap>>` internally  
 /// - `AppConfig` is immutable after initialization
 ///
@@ -134,6 +169,8 @@ use std::sync::Arc;
 /// ```ignore
 /// use blackjack_api::{AppState, config::AppConfig};
 /// use blackjack_service::{GameService, UserService, InvitationService, ServiceConfig, InvitationConfig};
+/// use blackjack_service::{AuditLog, MatchmakingService, NotificationService, OutboxMailer};
+/// use blackjack_service::TournamentService;
 /// use blackjack_api::rate_limiter::RateLimiter;
 /// use std::sync::Arc;
 ///
@@ -141,14 +178,26 @@ use std::sync::Arc;
 /// let game_service = Arc::new(GameService::new(ServiceConfig::default()));
 /// let user_service = Arc::new(UserService::new());
 /// let invitation_service = Arc::new(InvitationService::new(InvitationConfig::default()));
+/// let matchmaking_service = Arc::new(MatchmakingService::default());
+/// let notification_service = Arc::new(NotificationService::new());
+/// let tournament_service = Arc::new(TournamentService::new());
+/// let audit_log = Arc::new(AuditLog::new());
+/// let mailer = Arc::new(OutboxMailer::new());
 /// let rate_limiter = RateLimiter::new(10);
+/// let chat_rate_limiter = RateLimiter::new(20);
 ///
 /// let state = AppState {
 ///     game_service,
 ///     user_service,
 ///     invitation_service,
+///     matchmaking_service,
+///     notification_service,
+///     tournament_service,
+///     audit_log,
+///     mailer,
 ///     config: config.clone(),
 ///     rate_limiter,
+///     chat_rate_limiter,
 /// };
 ///
 /// // State can be cloned efficiently for each request
@@ -174,6 +223,21 @@ pub struct AppState {
     /// Thread-safe for concurrent access.
     pub invitation_service: Arc<InvitationService>,
 
+    /// Matchmaking queue that groups compatible players into new games
+    pub matchmaking_service: Arc<MatchmakingService>,
+
+    /// Per-user notification inbox (match found, queue timed out, ...)
+    pub notification_service: Arc<NotificationService>,
+
+    /// Elimination tournaments seated across several games
+    pub tournament_service: Arc<TournamentService>,
+
+    /// Security audit log (logins, kicks, admin requests, ...)
+    pub audit_log: Arc<AuditLog>,
+
+    /// Outgoing mail (password reset links, ...)
+    pub mailer: Arc<dyn Mailer>,
+
     /// Application configuration
     ///
     /// Contains all runtime configuration including server settings, JWT secrets,
@@ -185,4 +249,10 @@ pub struct AppState {
     /// Enforces per-user request limits using a sliding window algorithm.
     /// Tracks requests by `user_id` key.
     pub rate_limiter: RateLimiter,
+
+    /// Rate limiter for table chat messages
+    ///
+    /// Tracks messages by `user_id` key across all games, separately from
+    /// the request limit.
+    pub chat_rate_limiter: RateLimiter,
 }
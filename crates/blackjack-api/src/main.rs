@@ -29,20 +29,33 @@
 use axum::Router;
 use axum::routing::{delete, get, post, put};
 use blackjack_api::AppState;
+use blackjack_api::account;
+use blackjack_api::admin;
 use blackjack_api::config::AppConfig;
+use blackjack_api::config::MailTransport;
 use blackjack_api::handlers::{
-    accept_invitation, change_password, close_enrollment, create_game, create_invitation,
-    decline_invitation, draw_card, enroll_player, finish_game, get_game_results, get_game_state,
-    get_open_games, get_participants, get_pending_invitations, get_player_stats, health_check,
-    kick_player, login, ready_check, register_user, set_ace_value, stand,
+    accept_invitation, add_bot, change_password, close_enrollment, create_game, create_invitation,
+    create_invite_link, create_tournament, decline_invitation, delete_chat_message, draw_card,
+    enroll_player, finish_game, forgot_password, get_chat_history, get_game_results,
+    get_game_state, get_hint, get_invite_links, get_leaderboards, get_matchmaking_status,
+    get_notifications, get_open_games, get_participants, get_pending_invitations,
+    get_player_achievements, get_player_games, get_player_profile, get_player_stats,
+    get_sent_invitations, get_tournament, get_tournament_bracket, get_tournament_standings,
+    health_check, join_matchmaking_queue, join_with_token, kick_player, leave_matchmaking_queue,
+    list_tournaments, login, mark_notifications_read, mute_chat_participant, post_chat_message,
+    ready_check, register_for_tournament, register_user, resend_verification, reset_password,
+    revoke_invitation, revoke_invite_link, set_ace_value, stand, start_tournament,
+    stream_game_events, unmute_chat_participant, verify_email,
 };
 use blackjack_api::middleware::{
-    auth_middleware, rate_limit_middleware, security_headers_middleware,
-    version_deprecation_middleware,
+    admin_middleware, auth_middleware, rate_limit_middleware, request_id_middleware,
+    security_headers_middleware, version_deprecation_middleware,
 };
 use blackjack_api::rate_limiter::RateLimiter;
 use blackjack_service::{
-    GameService, InvitationConfig, InvitationService, ServiceConfig, UserService,
+    AuditLog, GameArchive, GameService, InvitationConfig, InvitationService, Mailer,
+    MatchmakingConfig, MatchmakingService, NotificationService, OutboxMailer, ServiceConfig,
+    SmtpMailer, SmtpSettings, TournamentService, UserService,
 };
 use std::sync::Arc;
 use tower::ServiceBuilder;
@@ -82,16 +95,93 @@ async fn main() {
     // Create game service with configuration from environment variables
     // Service manages all active games with thread-safe concurrent access
     // Requires user service to look up creator emails when creating games
+    // Finished games are archived to [archive] file when set
     let service_config = ServiceConfig::from_env();
-    let game_service = Arc::new(GameService::new(service_config, user_service.clone()));
+    let archive = match &app_config.archive.file {
+        Some(path) => GameArchive::open(path).expect("Failed to open game archive file"),
+        None => GameArchive::new(),
+    };
+    let game_service = Arc::new(GameService::with_archive(
+        service_config,
+        user_service.clone(),
+        archive,
+    ));
 
     // Create invitation service with configuration
     let invitation_config = InvitationConfig::from_env();
     let invitation_service = Arc::new(InvitationService::new(invitation_config));
 
+    // Create matchmaking queue and the notification inbox it reports matches to
+    let matchmaking_service = Arc::new(MatchmakingService::new(MatchmakingConfig::from_env()));
+    let notification_service = Arc::new(NotificationService::new());
+
+    // Time out stale matchmaking tickets and form short-handed tables in the
+    // background so players are notified even when nobody else joins the queue
+    {
+        let matchmaking_service = matchmaking_service.clone();
+        let game_service = game_service.clone();
+        let notification_service = notification_service.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(5));
+            loop {
+                interval.tick().await;
+                matchmaking_service.form_waiting_tables(&game_service, &notification_service);
+            }
+        });
+    }
+
+    // Settle tournament tables whose games finished and seat the next round
+    let tournament_service = Arc::new(TournamentService::new());
+    {
+        let tournament_service = tournament_service.clone();
+        let game_service = game_service.clone();
+        let notification_service = notification_service.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(5));
+            loop {
+                interval.tick().await;
+                tournament_service.advance(&game_service, &notification_service);
+            }
+        });
+    }
+
+    // Open the security audit log, file-backed when [audit] file is set
+    let audit_log = match &app_config.audit.file {
+        Some(path) => AuditLog::open(path).expect("Failed to open audit log file"),
+        None => {
+            tracing::warn!(
+                "Audit log is memory only: set [audit] file to keep it across restarts and export more than the newest records"
+            );
+            AuditLog::new()
+        }
+    };
+
+    // Outgoing mail: a local outbox unless SMTP is configured
+    let mailer: Arc<dyn Mailer> = match app_config.mail.transport {
+        MailTransport::Outbox => Arc::new(match &app_config.mail.outbox {
+            Some(dir) => OutboxMailer::with_dir(dir).expect("Failed to create mail outbox"),
+            None => OutboxMailer::new(),
+        }),
+        MailTransport::Smtp => {
+            let smtp = &app_config.mail.smtp;
+            let settings = SmtpSettings {
+                host: smtp.host.clone(),
+                port: smtp.port,
+                username: smtp.username.clone(),
+                password: smtp.password.clone(),
+                starttls: smtp.starttls,
+            };
+            Arc::new(
+                SmtpMailer::new(&settings, &app_config.mail.from)
+                    .expect("Invalid SMTP configuration"),
+            )
+        }
+    };
+
     // Create rate limiter with configured requests per minute
     // Uses sliding window algorithm to track requests per user
     let rate_limiter = RateLimiter::new(app_config.rate_limit.requests_per_minute);
+    let chat_rate_limiter = RateLimiter::new(app_config.chat.messages_per_minute);
 
     // Build shared application state
     // This state is cloned for each request and provides access to services
@@ -99,10 +189,28 @@ async fn main() {
         game_service,
         user_service,
         invitation_service,
+        matchmaking_service,
+        notification_service,
+        tournament_service,
+        audit_log: Arc::new(audit_log),
+        mailer,
         config: app_config.clone(),
         rate_limiter,
+        chat_rate_limiter,
     };
 
+    // Anonymise accounts whose deletion grace period is over
+    {
+        let state = state.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+            loop {
+                interval.tick().await;
+                account::purge_deleted_accounts(&state);
+            }
+        });
+    }
+
     // Configure CORS (Cross-Origin Resource Sharing)
     // Uses allowed origins from configuration
     let cors = CorsLayer::new()
@@ -122,6 +230,32 @@ async fn main() {
         "CORS configured"
     );
 
+    // Administration routes, for moderators and administrators only (audited)
+    let admin_routes = Router::new()
+        .route("/users", get(admin::list_users))
+        .route("/users/:user_id", get(admin::get_user))
+        .route("/users/:user_id/activate", post(admin::activate_user))
+        .route("/users/:user_id/deactivate", post(admin::deactivate_user))
+        .route("/users/:user_id/unlock", post(admin::unlock_user))
+        .route("/users/:user_id/role", put(admin::set_user_role))
+        .route(
+            "/users/:user_id/reset-password",
+            post(admin::reset_user_password),
+        )
+        .route("/games", get(admin::list_games))
+        .route("/games/:game_id", get(admin::inspect_game))
+        .route("/games/:game_id/abort", post(admin::abort_game))
+        .route("/invitations", get(admin::list_invitations))
+        .route("/invitations/cleanup", post(admin::cleanup_invitations))
+        .route("/stats", get(admin::server_stats))
+        .route("/config", get(admin::get_config))
+        .route("/audit", get(admin::list_audit_records))
+        .route("/audit/export", get(admin::export_audit_records))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_middleware,
+        ));
+
     // Build the application router with all routes and middleware
     let app = Router::new()
         // Health check endpoints (public, no authentication)
@@ -130,8 +264,26 @@ async fn main() {
         // M7: User authentication endpoints
         .route("/api/v1/auth/register", post(register_user))
         .route("/api/v1/auth/login", post(login))
+        .route("/api/v1/auth/forgot-password", post(forgot_password))
+        .route("/api/v1/auth/reset-password", post(reset_password))
+        .route("/api/v1/auth/verify/:token", get(verify_email))
         // Player statistics endpoints
         .route("/api/v1/players/me/stats", get(get_player_stats))
+        .route(
+            "/api/v1/players/me/achievements",
+            get(get_player_achievements),
+        )
+        .route("/api/v1/players/me/games", get(get_player_games))
+        .route("/api/v1/players/:user_id", get(get_player_profile))
+        .route("/api/v1/leaderboards", get(get_leaderboards))
+        // Account self-service
+        .route(
+            "/api/v1/users/me",
+            get(account::get_account)
+                .patch(account::update_account)
+                .delete(account::delete_account),
+        )
+        .route("/api/v1/users/me/export", get(account::export_account))
         // M7: Game enrollment endpoints
         .route("/api/v1/games", post(create_game))
         .route("/api/v1/games/open", get(get_open_games))
@@ -142,9 +294,11 @@ async fn main() {
         )
         // Protected game endpoints (require JWT authentication)
         .route("/api/v1/games/:game_id", get(get_game_state))
+        .route("/api/v1/games/:game_id/events", get(stream_game_events))
         .route("/api/v1/games/:game_id/draw", post(draw_card))
         .route("/api/v1/games/:game_id/ace", put(set_ace_value))
         .route("/api/v1/games/:game_id/stand", post(stand))
+        .route("/api/v1/games/:game_id/hint", get(get_hint))
         .route("/api/v1/games/:game_id/finish", post(finish_game))
         .route("/api/v1/games/:game_id/results", get(get_game_results))
         // M8: Game management endpoints
@@ -153,20 +307,85 @@ async fn main() {
             delete(kick_player),
         )
         .route("/api/v1/games/:game_id/participants", get(get_participants))
+        .route("/api/v1/games/:game_id/bots", post(add_bot))
+        // Table chat
+        .route(
+            "/api/v1/games/:game_id/chat",
+            post(post_chat_message).get(get_chat_history),
+        )
+        .route(
+            "/api/v1/games/:game_id/chat/:message_id",
+            delete(delete_chat_message),
+        )
+        .route(
+            "/api/v1/games/:game_id/chat/mutes/:user_id",
+            put(mute_chat_participant).delete(unmute_chat_participant),
+        )
         // M7: Invitation endpoints
         .route(
             "/api/v1/games/:game_id/invitations",
             post(create_invitation),
         )
+        // Shareable invite links (signed join tokens)
+        .route(
+            "/api/v1/games/:game_id/invite-links",
+            post(create_invite_link).get(get_invite_links),
+        )
+        .route(
+            "/api/v1/games/:game_id/invite-links/:link_id",
+            delete(revoke_invite_link),
+        )
+        .route("/api/v1/games/join/:token", post(join_with_token))
         .route("/api/v1/invitations/pending", get(get_pending_invitations))
+        .route("/api/v1/invitations/sent", get(get_sent_invitations))
+        .route("/api/v1/invitations/:id", delete(revoke_invitation))
         .route("/api/v1/invitations/:id/accept", post(accept_invitation))
         .route("/api/v1/invitations/:id/decline", post(decline_invitation))
         // M8: Auth endpoints
         .route("/api/v1/auth/change-password", post(change_password))
+        .route(
+            "/api/v1/auth/resend-verification",
+            post(resend_verification),
+        )
+        // Tournaments
+        .route(
+            "/api/v1/tournaments",
+            post(create_tournament).get(list_tournaments),
+        )
+        .route("/api/v1/tournaments/:tournament_id", get(get_tournament))
+        .route(
+            "/api/v1/tournaments/:tournament_id/register",
+            post(register_for_tournament),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/start",
+            post(start_tournament),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/standings",
+            get(get_tournament_standings),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/bracket",
+            get(get_tournament_bracket),
+        )
+        // Matchmaking queue and notification inbox
+        .route(
+            "/api/v1/matchmaking/queue",
+            post(join_matchmaking_queue)
+                .get(get_matchmaking_status)
+                .delete(leave_matchmaking_queue),
+        )
+        .route("/api/v1/notifications", get(get_notifications))
+        .route("/api/v1/notifications/read", post(mark_notifications_read))
+        // Server administration
+        .nest("/api/v1/admin", admin_routes)
         // Apply middleware layers in order (executed bottom-to-top)
         .layer(
             ServiceBuilder::new()
-                // Rate limiting (applied first, checks all requests)
+                // Request IDs (outermost, so every response carries X-Request-Id)
+                .layer(axum::middleware::from_fn(request_id_middleware))
+                // Rate limiting (checks all requests)
                 .layer(axum::middleware::from_fn_with_state(
                     state.clone(),
                     rate_limit_middleware,
@@ -1,11 +1,67 @@
 use crate::auth::Claims;
 use crate::error::ApiError;
-use axum::extract::{Request, State};
-use axum::http::HeaderValue;
+use axum::extract::{FromRequestParts, MatchedPath, OriginalUri, Request, State};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue};
 use axum::middleware::Next;
 use axum::response::Response;
+use blackjack_core::PlatformRole;
+use blackjack_service::{AuditActor, AuditEvent, AuditOutcome, GameError};
 use jsonwebtoken::{DecodingKey, Validation, decode};
 
+/// Header carrying the request ID
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// ID that ties a request to its log lines and audit records
+///
+/// Taken from the client's `X-Request-Id` header when it is a short,
+/// printable value, otherwise a new UUID. Handlers extract it like any other
+/// parameter; outside [`request_id_middleware`] a fresh ID is made up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| {
+                !id.is_empty() && id.len() <= 128 && id.bytes().all(|b| b.is_ascii_graphic())
+            })
+            .map(|id| Self(id.to_string()))
+            .unwrap_or_else(|| Self(uuid::Uuid::new_v4().to_string()))
+    }
+}
+
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for RequestId {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<RequestId>()
+            .cloned()
+            .unwrap_or_else(|| Self::from_headers(&parts.headers)))
+    }
+}
+
+/// Request ID middleware
+///
+/// Assigns every request a [`RequestId`], stores it in the request
+/// extensions and echoes it in the `X-Request-Id` response header so clients
+/// can quote it when reporting problems.
+pub async fn request_id_middleware(mut request: Request, next: Next) -> Response {
+    let request_id = RequestId::from_headers(request.headers());
+    request.extensions_mut().insert(request_id.clone());
+
+    let mut response = next.run(request).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id.0) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
 /// JWT authentication middleware
 ///
 /// Validates JWT tokens from the Authorization header and injects the decoded
@@ -21,8 +77,9 @@ use jsonwebtoken::{DecodingKey, Validation, decode};
 /// 3. If present, verifies it starts with "Bearer " prefix
 /// 4. Decodes and validates the JWT using the configured secret
 /// 5. Checks token expiration automatically via `exp` claim
-/// 6. Injects validated `Claims` into request extensions
-/// 7. Passes request to next middleware/handler
+/// 6. Rejects tokens whose sessions were revoked (e.g. by a password reset)
+/// 7. Injects validated `Claims` into request extensions
+/// 8. Passes request to next middleware/handler
 ///
 /// # Headers Required (for protected routes)
 ///
@@ -37,6 +94,7 @@ use jsonwebtoken::{DecodingKey, Validation, decode};
 /// - Token is malformed or invalid
 /// - Token signature verification fails
 /// - Token has expired
+/// - Token was issued before the account's sessions were revoked
 ///
 /// # Protected Routes
 ///
@@ -101,6 +159,19 @@ pub async fn auth_middleware(
         ApiError::unauthorized()
     })?;
 
+    // Tokens of accounts that no longer exist count as revoked
+    let revoked = uuid::Uuid::parse_str(&token_data.claims.user_id)
+        .ok()
+        .and_then(|user_id| state.user_service.get_user(user_id).ok())
+        .is_none_or(|user| user.session_version != token_data.claims.session_version);
+    if revoked {
+        tracing::debug!(
+            user_id = token_data.claims.user_id,
+            "Token of revoked session"
+        );
+        return Err(ApiError::unauthorized());
+    }
+
     tracing::debug!(
         email = token_data.claims.email,
         user_id = token_data.claims.user_id,
@@ -168,6 +239,107 @@ pub async fn rate_limit_middleware(
     Ok(next.run(request).await)
 }
 
+/// Platform role guard and audit trail for the `/api/v1/admin` routes
+///
+/// Runs after `auth_middleware` as a route layer of the admin router, so the
+/// request already carries validated `Claims`. The caller's role is read from
+/// the user store rather than the token, so promotions and demotions apply
+/// immediately; handlers see the stored role in `claims.role`.
+///
+/// Moderators and administrators pass; individual handlers require
+/// `admin` for the more sensitive actions.
+///
+/// # Audit
+///
+/// Every request from an authenticated account is recorded in the
+/// `AuditLog` with its route, concrete path and outcome (`success`,
+/// `denied` or `failed`), including requests this guard turns away.
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - No valid token, or the account no longer exists
+/// - **403 Forbidden** - `INSUFFICIENT_PERMISSIONS` for regular users,
+///   `ACCOUNT_INACTIVE` for suspended accounts
+///
+/// # Usage in Routes
+///
+/// ```ignore
+/// use axum::{Router, routing::get, middleware};
+/// use blackjack_api::middleware::admin_middleware;
+///
+/// let admin = Router::new()
+///     .route("/users", get(list_users))
+///     .route_layer(middleware::from_fn_with_state(state.clone(), admin_middleware));
+/// let app = Router::new().nest("/api/v1/admin", admin);
+/// ```
+pub async fn admin_middleware(
+    State(state): State<crate::AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let claims = request
+        .extensions()
+        .get::<Claims>()
+        .cloned()
+        .ok_or_else(ApiError::unauthorized)?;
+
+    let user = uuid::Uuid::parse_str(&claims.user_id)
+        .ok()
+        .and_then(|user_id| state.user_service.get_user(user_id).ok())
+        .ok_or_else(ApiError::unauthorized)?;
+
+    // Nested routers see the path without the `/api/v1/admin` prefix
+    let target = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map_or(request.uri().path(), |uri| uri.path())
+        .to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(target.as_str(), |path| path.as_str());
+    let action = format!("{} {}", request.method(), route);
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.clone());
+    let mut event = AuditEvent {
+        actor: AuditActor::from(&user),
+        action,
+        target,
+        outcome: AuditOutcome::Denied,
+        status: 0,
+        request_id,
+    };
+
+    let denied = if !user.is_account_active() {
+        Some(GameError::AccountInactive)
+    } else if user.role < PlatformRole::Moderator {
+        Some(GameError::InsufficientPermissions)
+    } else {
+        None
+    };
+    if let Some(error) = denied {
+        let error = ApiError::from(error);
+        tracing::warn!(user_id = %user.id, action = %event.action, "Admin route denied");
+        event.status = error.status;
+        state.audit_log.record(event);
+        return Err(error);
+    }
+
+    if let Some(claims) = request.extensions_mut().get_mut::<Claims>() {
+        claims.role = user.role;
+    }
+
+    let response = next.run(request).await;
+
+    event.status = response.status().as_u16();
+    event.outcome = AuditOutcome::from_status(event.status);
+    state.audit_log.record(event);
+
+    Ok(response)
+}
+
 /// API version deprecation middleware
 ///
 /// Adds deprecation headers to responses to inform clients about API lifecycle.
@@ -27,7 +27,7 @@
 //! ```json
 //! {
 //!   "event_type": "draw_card",
-//!   "player_email": "player@example.com",
+//!   "player": "lucky_ace",
 //!   "game_id": "550e8400-e29b-41d4-a716-446655440000",
 //!   "data": {
 //!     "card": {
@@ -48,7 +48,7 @@
 //! ```json
 //! {
 //!   "event_type": "ace_changed",
-//!   "player_email": "player@example.com",
+//!   "player": "lucky_ace",
 //!   "game_id": "550e8400-e29b-41d4-a716-446655440000",
 //!   "data": {
 //!     "card_id": "card-uuid",
@@ -64,14 +64,14 @@
 //! ```json
 //! {
 //!   "event_type": "game_finished",
-//!   "player_email": "all",
+//!   "player": "all",
 //!   "game_id": "550e8400-e29b-41d4-a716-446655440000",
 //!   "data": {
-//!     "winner": "player@example.com",
+//!     "winner": "lucky_ace",
 //!     "tied_players": [],
 //!     "highest_score": 21,
 //!     "all_players": {
-//!       "player@example.com": {
+//!       "lucky_ace": {
 //!         "points": 21,
 //!         "cards_count": 2,
 //!         "busted": false
@@ -99,9 +99,9 @@
  *     /// Type of event: "draw_card", "ace_changed", "game_finished"
  *     pub event_type: String,
  *
- *     /// Email of the player who triggered the event
- *     /// Use "all" for game-wide events like game_finished
- *     pub player_email: String,
+ *     /// Display name of the player who triggered the event; emails are
+ *     /// never broadcast. Use "all" for game-wide events like game_finished
+ *     pub player: String,
  *
  *     /// Game UUID as a string
  *     pub game_id: String,
@@ -270,14 +270,14 @@
  *
  * ```rust
  * // In draw_card handler
- * let response = state.game_service.draw_card(game_id, claims.email.clone())?;
+ * let response = state.game_service.draw_card(game_id, user_id)?;
  *
  * // Broadcast notification
  * state.connection_manager.broadcast(
  *     &game_id.to_string(),
  *     GameNotification {
  *         event_type: "draw_card".to_string(),
- *         player_email: claims.email.clone(),
+ *         player: display_name.clone(),
  *         game_id: game_id.to_string(),
  *         data: serde_json::to_value(&response).unwrap(),
  *     }
@@ -24,8 +24,11 @@
 //! ```
 
 use blackjack_api::AppState;
+use blackjack_core::PlatformRole;
 use blackjack_service::{
-    GameService, InvitationConfig, InvitationService, ServiceConfig, UserService,
+    AuditLog, AuditOutcome, GameService, InvitationConfig, InvitationService, MatchmakingService,
+    NotificationKind, NotificationService, OutboxMailer, ServiceConfig, TournamentService,
+    UserService,
 };
 use std::sync::Arc;
 
@@ -60,8 +63,14 @@ fn test_app_state_creation() {
         game_service,
         user_service,
         invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
         config,
         rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
     };
 }
 
@@ -439,8 +448,14 @@ async fn test_create_game_requires_authentication() {
         game_service,
         user_service,
         invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
         config,
         rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
     };
 
     // Test 1: Without Authorization header - should extract Claims fail
@@ -454,11 +469,14 @@ async fn test_create_game_requires_authentication() {
     let claims = Claims {
         user_id: user_id.to_string(),
         email: test_email.to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
         exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
     };
 
     let request = CreateGameRequest {
         enrollment_timeout_seconds: Some(300),
+        ..Default::default()
     };
 
     let result = create_game(AxumState(state), Extension(claims), Json(request)).await;
@@ -519,14 +537,22 @@ async fn test_create_game_creator_id_from_jwt() {
         game_service,
         user_service,
         invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
         config,
         rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
     };
 
     // User 1 creates a game
     let claims1 = Claims {
         user_id: user1_id.to_string(),
         email: user1_email.to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
         exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
     };
 
@@ -535,6 +561,7 @@ async fn test_create_game_creator_id_from_jwt() {
         Extension(claims1),
         Json(CreateGameRequest {
             enrollment_timeout_seconds: None,
+            ..Default::default()
         }),
     )
     .await;
@@ -547,6 +574,8 @@ async fn test_create_game_creator_id_from_jwt() {
     let claims2 = Claims {
         user_id: user2_id.to_string(),
         email: user2_email.to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
         exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
     };
 
@@ -555,6 +584,7 @@ async fn test_create_game_creator_id_from_jwt() {
         Extension(claims2),
         Json(CreateGameRequest {
             enrollment_timeout_seconds: Some(600),
+            ..Default::default()
         }),
     )
     .await;
@@ -594,8 +624,14 @@ async fn test_create_game_rejects_nonexistent_user() {
         game_service,
         user_service,
         invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
         config,
         rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
     };
 
     // Create claims with non-existent user_id
@@ -603,6 +639,8 @@ async fn test_create_game_rejects_nonexistent_user() {
     let claims = Claims {
         user_id: fake_user_id.to_string(),
         email: "nonexistent@example.com".to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
         exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
     };
 
@@ -611,6 +649,7 @@ async fn test_create_game_rejects_nonexistent_user() {
         Extension(claims),
         Json(CreateGameRequest {
             enrollment_timeout_seconds: None,
+            ..Default::default()
         }),
     )
     .await;
@@ -672,8 +711,14 @@ async fn test_stand_not_your_turn() {
         game_service: game_service.clone(),
         user_service: user_service.clone(),
         invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
         config,
         rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
     };
 
     // Create two users
@@ -703,6 +748,8 @@ async fn test_stand_not_your_turn() {
     let claims = Claims {
         user_id: user2_id.to_string(),
         email: "player2@example.com".to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
         exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
     };
 
@@ -754,8 +801,14 @@ async fn test_draw_card_game_already_finished() {
         game_service: game_service.clone(),
         user_service: user_service.clone(),
         invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
         config,
         rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
     };
 
     // Create user and game
@@ -777,6 +830,8 @@ async fn test_draw_card_game_already_finished() {
     let claims = Claims {
         user_id: user_id.to_string(),
         email: "player1@example.com".to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
         exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
     };
 
@@ -798,3 +853,2295 @@ async fn test_draw_card_game_already_finished() {
         "Should return appropriate message"
     );
 }
+
+/// Tests the shareable invite link flow
+///
+/// Validates:
+/// - Creator can create a signed join token with max uses
+/// - Another user can join the game with the token
+/// - Token stops working once its uses are exhausted
+/// - Tampered tokens are rejected with INVALID_JOIN_TOKEN
+#[tokio::test]
+async fn test_join_game_with_invite_link() {
+    use axum::Json;
+    use axum::extract::{Extension, Path, State as AxumState};
+    use blackjack_api::auth::Claims;
+    use blackjack_api::handlers::{
+        CreateInviteLinkRequest, create_invite_link, join_with_token, revoke_invite_link,
+    };
+
+    let user_service = Arc::new(UserService::new());
+    let config = Arc::new(blackjack_api::config::AppConfig::from_file().unwrap());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let invitation_service = Arc::new(InvitationService::new(InvitationConfig::default()));
+    let rate_limiter = blackjack_api::rate_limiter::RateLimiter::new(10);
+
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service,
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config,
+        rate_limiter,
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(20),
+    };
+
+    let claims_for = |user_id: uuid::Uuid, email: &str| Claims {
+        user_id: user_id.to_string(),
+        email: email.to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
+        exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
+    };
+
+    let creator_id = user_service
+        .register(
+            "creator@example.com".to_string(),
+            "TestP@ssw0rd".to_string(),
+        )
+        .unwrap();
+    let guest1_id = user_service
+        .register("guest1@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let guest2_id = user_service
+        .register("guest2@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let game_id = game_service.create_game(creator_id, None).unwrap();
+
+    let created = create_invite_link(
+        AxumState(state.clone()),
+        Extension(claims_for(creator_id, "creator@example.com")),
+        Path(game_id),
+        Json(CreateInviteLinkRequest { max_uses: Some(1) }),
+    )
+    .await
+    .expect("creator should be able to create a link")
+    .0;
+    assert_eq!(created.link.remaining_uses, Some(1));
+
+    // Revoking through another game's path fails and leaves the link active
+    let other_game_id = game_service.create_game(creator_id, None).unwrap();
+    let wrong_game = revoke_invite_link(
+        AxumState(state.clone()),
+        Extension(claims_for(creator_id, "creator@example.com")),
+        Path((other_game_id, created.link.id)),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(wrong_game.code, "INVITE_LINK_NOT_FOUND");
+    let links = state
+        .invitation_service
+        .get_links_for_game(game_id, creator_id, &game_service.games)
+        .unwrap();
+    assert!(!links[0].revoked);
+
+    let joined = join_with_token(
+        AxumState(state.clone()),
+        Extension(claims_for(guest1_id, "guest1@example.com")),
+        Path(created.token.clone()),
+    )
+    .await
+    .expect("first guest should join")
+    .0;
+    assert_eq!(joined.game_id, game_id);
+    assert_eq!(joined.enrolled_count, 2);
+
+    let exhausted = join_with_token(
+        AxumState(state.clone()),
+        Extension(claims_for(guest2_id, "guest2@example.com")),
+        Path(created.token.clone()),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(exhausted.code, "INVITE_LINK_EXHAUSTED");
+
+    let tampered = join_with_token(
+        AxumState(state),
+        Extension(claims_for(guest2_id, "guest2@example.com")),
+        Path(format!("{}x", created.token)),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(tampered.code, "INVALID_JOIN_TOKEN");
+}
+
+/// Tests that admin routes are limited to configured administrators
+///
+/// Validates:
+/// - Requests without a token are rejected with 401
+/// - Regular users get 403 INSUFFICIENT_PERMISSIONS
+/// - Administrators can list users and abort games
+/// - Administrators can't deactivate their own account
+#[tokio::test]
+async fn test_admin_routes_require_administrator() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::admin;
+    use blackjack_api::auth::Claims;
+    use blackjack_api::middleware::{admin_middleware, auth_middleware};
+    use jsonwebtoken::{EncodingKey, Header, encode};
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let config = Arc::new(blackjack_api::config::AppConfig::from_file().unwrap());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let audit_log = Arc::new(AuditLog::new());
+
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: audit_log.clone(),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: config.clone(),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+
+    let admin_id = user_service
+        .register("admin@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let moderator_id = user_service
+        .register("mod@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let player_id = user_service
+        .register("player@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    user_service
+        .set_role(admin_id, PlatformRole::Admin)
+        .unwrap();
+    user_service
+        .set_role(moderator_id, PlatformRole::Moderator)
+        .unwrap();
+    let game_id = game_service.create_game(player_id, None).unwrap();
+
+    let admin_routes = Router::new()
+        .route("/users", get(admin::list_users))
+        .route("/users/:user_id/deactivate", post(admin::deactivate_user))
+        .route("/games/:game_id/abort", post(admin::abort_game))
+        .route("/audit", get(admin::list_audit_records))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_middleware,
+        ));
+    let app = Router::new()
+        .nest("/api/v1/admin", admin_routes)
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let token = |user_id: uuid::Uuid, email: &str| {
+        let claims = Claims {
+            user_id: user_id.to_string(),
+            email: email.to_string(),
+            role: PlatformRole::User,
+            session_version: 0,
+            exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config.jwt.secret.as_bytes()),
+        )
+        .unwrap()
+    };
+    let send = |method: &str, uri: String, token: Option<String>| {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+        app.clone().oneshot(request.body(Body::empty()).unwrap())
+    };
+    let admin_token = token(admin_id, "admin@example.com");
+
+    let response = send("GET", "/api/v1/admin/users".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = send(
+        "GET",
+        "/api/v1/admin/users".to_string(),
+        Some(token(player_id, "player@example.com")),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send(
+        "GET",
+        "/api/v1/admin/users".to_string(),
+        Some(admin_token.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["count"], 3);
+    assert!(body["users"][0].get("password_hash").is_none());
+
+    let response = send(
+        "POST",
+        format!("/api/v1/admin/users/{}/deactivate", admin_id),
+        Some(admin_token.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send(
+        "POST",
+        format!("/api/v1/admin/games/{}/abort", game_id),
+        Some(admin_token),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(game_service.get_game_state(game_id).unwrap().finished);
+
+    // Moderators can read but not touch administrators or the audit trail
+    let moderator_token = token(moderator_id, "mod@example.com");
+    let response = send(
+        "GET",
+        "/api/v1/admin/users".to_string(),
+        Some(moderator_token.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        "POST",
+        format!("/api/v1/admin/users/{}/deactivate", admin_id),
+        Some(moderator_token.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send(
+        "GET",
+        "/api/v1/admin/audit".to_string(),
+        Some(moderator_token),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Every authenticated admin request was audited, denied ones included
+    let records = audit_log.recent(100);
+    assert_eq!(records.len(), 7);
+    assert_eq!(records[0].actor_id, Some(moderator_id));
+    assert_eq!(records[0].outcome, AuditOutcome::Denied);
+    assert_eq!(records[0].action, "GET /api/v1/admin/audit");
+    let abort = records
+        .iter()
+        .find(|r| r.action == "POST /api/v1/admin/games/:game_id/abort")
+        .unwrap();
+    assert_eq!(abort.outcome, AuditOutcome::Success);
+    assert_eq!(abort.actor_role, PlatformRole::Admin);
+    assert_eq!(
+        abort.target,
+        format!("/api/v1/admin/games/{}/abort", game_id)
+    );
+}
+
+/// Tests that configured administrators are only promoted once verified
+///
+/// Validates:
+/// - Logging in with an unverified address listed in `[admin]` keeps the user role
+/// - The same account is promoted after verifying its address
+#[tokio::test]
+async fn test_configured_admin_requires_verified_email() {
+    use axum::Json;
+    use axum::extract::State as AxumState;
+    use blackjack_api::handlers::{self, LoginRequest};
+    use blackjack_api::middleware::RequestId;
+
+    let mut config = blackjack_api::config::AppConfig::from_file().unwrap();
+    config.admin.emails = vec!["ops@example.com".to_string()];
+
+    let user_service = Arc::new(UserService::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(config),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    let login = || {
+        handlers::login(
+            AxumState(state.clone()),
+            RequestId("req-login".to_string()),
+            Json(LoginRequest {
+                email: "ops@example.com".to_string(),
+                password: "TestP@ssw0rd".to_string(),
+            }),
+        )
+    };
+
+    // Someone registering the address before its owner doesn't become admin
+    let user_id = user_service
+        .register("ops@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    assert!(login().await.is_ok());
+    assert_eq!(
+        user_service.get_user(user_id).unwrap().role,
+        PlatformRole::User
+    );
+
+    let token = user_service
+        .request_email_verification(user_id, chrono::Duration::hours(1))
+        .unwrap()
+        .unwrap();
+    user_service.verify_email(&token).unwrap();
+    assert!(login().await.is_ok());
+    assert_eq!(
+        user_service.get_user(user_id).unwrap().role,
+        PlatformRole::Admin
+    );
+}
+
+/// Tests that accepting an invitation the game can't honour keeps it pending
+///
+/// Validates:
+/// - Accepting after enrollment closed fails with `ENROLLMENT_CLOSED`
+/// - The invitation is still pending afterwards, not used up
+#[tokio::test]
+async fn test_failed_invitation_acceptance_keeps_invitation_pending() {
+    use axum::Extension;
+    use axum::extract::{Path, State as AxumState};
+    use blackjack_api::auth::Claims;
+    use blackjack_api::handlers;
+    use blackjack_core::InvitationStatus;
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let invitation_service = Arc::new(InvitationService::new(InvitationConfig::default()));
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: invitation_service.clone(),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+
+    let creator_id = user_service
+        .register("host@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let guest_id = user_service
+        .register("guest@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let game_id = game_service.create_game(creator_id, None).unwrap();
+    let expires_at = game_service
+        .get_game_state(game_id)
+        .unwrap()
+        .enrollment_closes_at;
+    let invitation_id = invitation_service
+        .create(
+            game_id,
+            creator_id,
+            "guest@example.com".to_string(),
+            expires_at,
+            &game_service.games,
+        )
+        .unwrap();
+    game_service.close_enrollment(game_id, creator_id).unwrap();
+
+    let claims = Claims {
+        user_id: guest_id.to_string(),
+        email: "guest@example.com".to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
+        exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
+    };
+    let error =
+        handlers::accept_invitation(AxumState(state), Extension(claims), Path(invitation_id))
+            .await
+            .unwrap_err();
+    assert_eq!(error.code, "ENROLLMENT_CLOSED");
+    assert_eq!(
+        invitation_service
+            .get_invitation(invitation_id)
+            .unwrap()
+            .status,
+        InvitationStatus::Pending
+    );
+}
+
+/// Tests that security events land in the audit log with their request IDs
+/// and can be exported as JSON Lines
+#[tokio::test]
+async fn test_security_events_are_audited() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{delete, get, post};
+    use blackjack_api::middleware::{admin_middleware, auth_middleware, request_id_middleware};
+    use blackjack_api::{admin, handlers};
+    use blackjack_service::AuditFilter;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let audit_log = Arc::new(AuditLog::new());
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: audit_log.clone(),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+
+    let admin_id = user_service
+        .register("admin@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    user_service
+        .set_role(admin_id, PlatformRole::Admin)
+        .unwrap();
+    let creator_id = user_service
+        .register(
+            "creator@example.com".to_string(),
+            "TestP@ssw0rd".to_string(),
+        )
+        .unwrap();
+    let player_id = user_service
+        .register("player@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let game_id = game_service.create_game(creator_id, None).unwrap();
+    game_service.enroll_player(game_id, player_id).unwrap();
+
+    let admin_routes = Router::new()
+        .route("/audit/export", get(admin::export_audit_records))
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            admin_middleware,
+        ));
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/games/:game_id/players/:player_id",
+            delete(handlers::kick_player),
+        )
+        .nest("/api/v1/admin", admin_routes)
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .layer(axum::middleware::from_fn(request_id_middleware))
+        .with_state(state);
+
+    let login = |email: &str, password: &str, request_id: &str| {
+        let body = serde_json::json!({ "email": email, "password": password }).to_string();
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/auth/login")
+                .header("Content-Type", "application/json")
+                .header("X-Request-Id", request_id)
+                .body(Body::from(body))
+                .unwrap(),
+        )
+    };
+    let token_of = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        body["token"].as_str().unwrap().to_string()
+    };
+
+    let response = login("ghost@example.com", "TestP@ssw0rd", "req-ghost")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()["x-request-id"], "req-ghost");
+
+    let response = login("player@example.com", "TestP@ssw0rd", "req-player")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let player_token = token_of(response).await;
+
+    // A player trying to kick the creator is denied and audited
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/v1/games/{}/players/{}", game_id, creator_id))
+                .header("Authorization", format!("Bearer {}", player_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let kick_request_id = response.headers()["x-request-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let filter = AuditFilter {
+        action: Some("auth.login".to_string()),
+        ..Default::default()
+    };
+    let logins = audit_log.query(&filter, 10);
+    assert_eq!(logins.len(), 2);
+    assert_eq!(logins[0].actor_id, Some(player_id));
+    assert_eq!(logins[0].outcome, AuditOutcome::Success);
+    assert_eq!(logins[1].actor_id, None);
+    assert_eq!(logins[1].outcome, AuditOutcome::Failed);
+    assert_eq!(logins[1].request_id.as_deref(), Some("req-ghost"));
+
+    let kick = &audit_log.recent(1)[0];
+    assert_eq!(kick.action, "game.kick_player");
+    assert_eq!(kick.outcome, AuditOutcome::Denied);
+    assert_eq!(kick.status, 403);
+    assert_eq!(kick.request_id.as_deref(), Some(kick_request_id.as_str()));
+
+    // Administrators can export the trail as JSON Lines, filtered by actor
+    let response = login("admin@example.com", "TestP@ssw0rd", "req-admin")
+        .await
+        .unwrap();
+    let admin_token = token_of(response).await;
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/audit/export?actor=player@example.com")
+                .header("Authorization", format!("Bearer {}", admin_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/x-ndjson");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let lines: Vec<serde_json::Value> = String::from_utf8(body.to_vec())
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["action"], "auth.login");
+    assert_eq!(lines[1]["action"], "game.kick_player");
+}
+
+/// Tests the forgot/reset password flow through the outbox mailer, including
+/// revocation of tokens issued before the reset
+#[tokio::test]
+async fn test_password_reset_flow_revokes_sessions() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let outbox = Arc::new(OutboxMailer::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: outbox.clone(),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    user_service
+        .register("user@example.com".to_string(), "OldP@ssw0rd".to_string())
+        .unwrap();
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/auth/forgot-password",
+            post(handlers::forgot_password),
+        )
+        .route(
+            "/api/v1/auth/reset-password",
+            post(handlers::reset_password),
+        )
+        .route("/api/v1/players/me/stats", get(handlers::get_player_stats))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let post_json = |uri: &str, body: serde_json::Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+    };
+    let stats = |token: &str| {
+        app.clone().oneshot(
+            Request::builder()
+                .uri("/api/v1/players/me/stats")
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+    let login = |password: &str| {
+        post_json(
+            "/api/v1/auth/login",
+            serde_json::json!({ "email": "user@example.com", "password": password }),
+        )
+    };
+    let json_body = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+
+    let old_token = json_body(login("OldP@ssw0rd").await.unwrap()).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(stats(&old_token).await.unwrap().status(), StatusCode::OK);
+
+    // Unknown emails get the same answer and no mail
+    let response = post_json(
+        "/api/v1/auth/forgot-password",
+        serde_json::json!({ "email": "nobody@example.com" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    assert!(outbox.messages().is_empty());
+
+    let response = post_json(
+        "/api/v1/auth/forgot-password",
+        serde_json::json!({ "email": "user@example.com" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let mail = outbox.last_to("user@example.com").unwrap();
+    let reset_token = mail
+        .body
+        .lines()
+        .find_map(|line| line.strip_prefix("    "))
+        .unwrap()
+        .to_string();
+
+    let response = post_json(
+        "/api/v1/auth/reset-password",
+        serde_json::json!({ "token": reset_token, "new_password": "weak" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = post_json(
+        "/api/v1/auth/reset-password",
+        serde_json::json!({ "token": reset_token, "new_password": "NewP@ssw0rd" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = post_json(
+        "/api/v1/auth/reset-password",
+        serde_json::json!({ "token": reset_token, "new_password": "OtherP@ssw0rd" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(json_body(response).await["code"], "INVALID_RESET_TOKEN");
+
+    // Tokens issued before the reset are revoked
+    assert_eq!(
+        stats(&old_token).await.unwrap().status(),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        login("OldP@ssw0rd").await.unwrap().status(),
+        StatusCode::UNAUTHORIZED
+    );
+    let new_token = json_body(login("NewP@ssw0rd").await.unwrap()).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(stats(&new_token).await.unwrap().status(), StatusCode::OK);
+}
+
+/// Tests that tokens of accounts that no longer exist are rejected
+#[tokio::test]
+async fn test_token_of_missing_account_is_rejected() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use blackjack_api::auth::Claims;
+    use blackjack_api::middleware::auth_middleware;
+    use jsonwebtoken::{EncodingKey, Header, encode};
+    use tower::ServiceExt;
+
+    let config = Arc::new(blackjack_api::config::AppConfig::from_file().unwrap());
+    let user_service = Arc::new(UserService::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: config.clone(),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    let app = Router::new()
+        .route("/protected", get(|| async { "OK" }))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let request = |user_id: uuid::Uuid| {
+        let claims = Claims {
+            user_id: user_id.to_string(),
+            email: "user@example.com".to_string(),
+            role: PlatformRole::User,
+            session_version: 0,
+            exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config.jwt.secret.as_bytes()),
+        )
+        .unwrap();
+        app.clone().oneshot(
+            Request::builder()
+                .uri("/protected")
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+
+    let user_id = user_service
+        .register("user@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    assert_eq!(request(user_id).await.unwrap().status(), StatusCode::OK);
+    assert_eq!(
+        request(uuid::Uuid::new_v4()).await.unwrap().status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+/// Tests that `[verification] required` blocks game creation and matchmaking
+/// until the address is verified through the mailed link
+#[tokio::test]
+async fn test_email_verification_required_for_game_creation() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let mut config = blackjack_api::config::AppConfig::from_file().unwrap();
+    config.verification.required = true;
+    config.server.url = Some("https://blackjack.example.com/".to_string());
+
+    let user_service = Arc::new(UserService::new());
+    let outbox = Arc::new(OutboxMailer::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: outbox.clone(),
+        config: Arc::new(config),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+
+    let app = Router::new()
+        .route("/api/v1/auth/register", post(handlers::register_user))
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route("/api/v1/auth/verify/:token", get(handlers::verify_email))
+        .route("/api/v1/games", post(handlers::create_game))
+        .route(
+            "/api/v1/matchmaking/queue",
+            post(handlers::join_matchmaking_queue),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let post_json = |uri: &str, token: Option<&str>, body: serde_json::Value| {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("Content-Type", "application/json");
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+        app.clone()
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+    };
+    let credentials = serde_json::json!({ "email": "user@example.com", "password": "P@ssw0rd1" });
+
+    let response = post_json("/api/v1/auth/register", None, credentials.clone())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = post_json("/api/v1/auth/login", None, credentials)
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let login: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let token = login["token"].as_str().unwrap().to_string();
+
+    let response = post_json("/api/v1/games", Some(&token), serde_json::json!({}))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = post_json(
+        "/api/v1/matchmaking/queue",
+        Some(&token),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let mail = outbox.last_to("user@example.com").unwrap();
+    let link = mail
+        .body
+        .lines()
+        .find_map(|line| line.strip_prefix("    https://blackjack.example.com"))
+        .unwrap()
+        .to_string();
+
+    let verify = |uri: String| {
+        app.clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+    };
+    assert_eq!(verify(link.clone()).await.unwrap().status(), StatusCode::OK);
+    assert_eq!(
+        verify(link).await.unwrap().status(),
+        StatusCode::BAD_REQUEST
+    );
+
+    let response = post_json("/api/v1/games", Some(&token), serde_json::json!({}))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = post_json(
+        "/api/v1/matchmaking/queue",
+        Some(&token),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Tests that other players only ever see display names
+///
+/// Validates:
+/// - Registration accepts a display name and rejects a taken one
+/// - Game state, results and participants are keyed by display name
+/// - Public profiles show the display name and avatar seed
+/// - No response leaks the players' email addresses
+#[tokio::test]
+async fn test_game_responses_show_display_names_not_emails() {
+    use axum::Extension;
+    use axum::Json;
+    use axum::extract::{Path, State as AxumState};
+    use blackjack_api::auth::Claims;
+    use blackjack_api::handlers::{
+        RegisterRequest, get_game_results, get_game_state, get_participants, get_player_profile,
+        register_user,
+    };
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+
+    let register = |email: &str, display_name: &str| {
+        register_user(
+            AxumState(state.clone()),
+            Json(RegisterRequest {
+                email: email.to_string(),
+                password: "TestP@ssw0rd".to_string(),
+                display_name: Some(display_name.to_string()),
+            }),
+        )
+    };
+    let alice = register("alice@example.com", "alice").await.unwrap().0;
+    assert_eq!(alice.display_name, "alice");
+    let bob = register("bob@example.com", "Bob_21").await.unwrap().0;
+    let taken = register("carol@example.com", "ALICE").await.unwrap_err();
+    assert_eq!(taken.status, 409);
+    assert_eq!(taken.code, "DISPLAY_NAME_TAKEN");
+
+    let game_id = game_service.create_game(alice.user_id, None).unwrap();
+    game_service.enroll_player(game_id, bob.user_id).unwrap();
+    game_service
+        .close_enrollment(game_id, alice.user_id)
+        .unwrap();
+
+    let bob_claims = Claims {
+        user_id: bob.user_id.to_string(),
+        email: "bob@example.com".to_string(),
+        role: PlatformRole::User,
+        session_version: 0,
+        exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
+    };
+    let game_state = get_game_state(
+        AxumState(state.clone()),
+        Extension(bob_claims.clone()),
+        Path(game_id),
+    )
+    .await
+    .unwrap()
+    .0;
+    assert_eq!(game_state.turn_order, vec!["alice", "Bob_21"]);
+    assert_eq!(game_state.current_turn_player.as_deref(), Some("alice"));
+    assert!(game_state.players.contains_key("Bob_21"));
+
+    game_service.stand(game_id, alice.user_id).unwrap();
+    game_service.stand(game_id, bob.user_id).unwrap();
+    let results = get_game_results(
+        AxumState(state.clone()),
+        Extension(bob_claims.clone()),
+        Path(game_id),
+    )
+    .await
+    .unwrap()
+    .0;
+    assert_eq!(results.player_results["alice"].player_id, alice.user_id);
+
+    let participants = get_participants(AxumState(state.clone()), Path(game_id))
+        .await
+        .unwrap()
+        .0;
+
+    let profile = get_player_profile(AxumState(state), Extension(bob_claims), Path(alice.user_id))
+        .await
+        .unwrap()
+        .0;
+    assert_eq!(profile.display_name, "alice");
+    assert_eq!(profile.games_played, 1);
+    assert!(!profile.avatar_seed.is_empty());
+
+    for response in [
+        serde_json::to_string(&game_state).unwrap(),
+        serde_json::to_string(&results).unwrap(),
+        serde_json::to_string(&participants).unwrap(),
+        serde_json::to_string(&profile).unwrap(),
+    ] {
+        assert!(
+            !response.contains("@example.com"),
+            "leaked email: {}",
+            response
+        );
+    }
+}
+
+/// Tests account self-service: viewing, changing the email, exporting and
+/// deleting the account
+#[tokio::test]
+async fn test_account_self_service() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::middleware::{auth_middleware, request_id_middleware};
+    use blackjack_api::{account, handlers};
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let outbox = Arc::new(OutboxMailer::new());
+    let mut config = blackjack_api::config::AppConfig::from_file().unwrap();
+    config.account.deletion_grace_days = 0;
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: outbox.clone(),
+        config: Arc::new(config),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    let user_id = user_service
+        .register("user@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let game_id = game_service.create_game(user_id, None).unwrap();
+    let finished_id = game_service.create_game(user_id, None).unwrap();
+    game_service.close_enrollment(finished_id, user_id).unwrap();
+    game_service.finish_game(finished_id, user_id).unwrap();
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/users/me",
+            get(account::get_account)
+                .patch(account::update_account)
+                .delete(account::delete_account),
+        )
+        .route("/api/v1/users/me/export", get(account::export_account))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .layer(axum::middleware::from_fn(request_id_middleware))
+        .with_state(state.clone());
+
+    let send = |method: &str, uri: &str, token: Option<&str>, body: serde_json::Value| {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json");
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+        app.clone()
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+    };
+    let json_body = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+    let login = |email: &'static str| {
+        send(
+            "POST",
+            "/api/v1/auth/login",
+            None,
+            serde_json::json!({ "email": email, "password": "TestP@ssw0rd" }),
+        )
+    };
+
+    let token = json_body(login("user@example.com").await.unwrap()).await["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let me = json_body(
+        send(
+            "GET",
+            "/api/v1/users/me",
+            Some(&token),
+            serde_json::json!({}),
+        )
+        .await
+        .unwrap(),
+    )
+    .await;
+    assert_eq!(me["email"], "user@example.com");
+    assert!(me.get("password_hash").is_none());
+
+    // Changing the email needs the password and replaces the token
+    let response = send(
+        "PATCH",
+        "/api/v1/users/me",
+        Some(&token),
+        serde_json::json!({ "email": "new@example.com" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = send(
+        "PATCH",
+        "/api/v1/users/me",
+        Some(&token),
+        serde_json::json!({
+            "display_name": "lucky_ace",
+            "email": "new@example.com",
+            "password": "TestP@ssw0rd",
+        }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = json_body(response).await;
+    assert_eq!(updated["user"]["display_name"], "lucky_ace");
+    assert_eq!(updated["user"]["email_verified"], false);
+    assert!(outbox.last_to("new@example.com").is_some());
+    let old_token = token;
+    let token = updated["token"].as_str().unwrap().to_string();
+    assert_eq!(
+        send(
+            "GET",
+            "/api/v1/users/me",
+            Some(&old_token),
+            serde_json::json!({})
+        )
+        .await
+        .unwrap()
+        .status(),
+        StatusCode::UNAUTHORIZED
+    );
+
+    let response = send(
+        "GET",
+        "/api/v1/users/me/export",
+        Some(&token),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response
+            .headers()
+            .get("content-disposition")
+            .is_some_and(|value| value.to_str().unwrap().starts_with("attachment"))
+    );
+    let export = json_body(response).await;
+    assert_eq!(export["profile"]["email"], "new@example.com");
+    assert_eq!(export["games"].as_array().unwrap().len(), 1);
+    assert_eq!(export["games"][0]["game_id"], finished_id.to_string());
+    assert_eq!(export["current_games"][0]["game_id"], game_id.to_string());
+    assert_eq!(export["current_games"][0]["role"], "creator");
+
+    // Deleting signs the account out, then it is anonymised
+    let response = send(
+        "DELETE",
+        "/api/v1/users/me",
+        Some(&token),
+        serde_json::json!({ "password": "TestP@ssw0rd" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    assert_eq!(
+        send(
+            "GET",
+            "/api/v1/users/me",
+            Some(&token),
+            serde_json::json!({})
+        )
+        .await
+        .unwrap()
+        .status(),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        login("new@example.com").await.unwrap().status(),
+        StatusCode::FORBIDDEN
+    );
+
+    assert_eq!(account::purge_deleted_accounts(&state), 1);
+    assert!(user_service.get_user(user_id).is_err());
+    let game = game_service.inspect_game(game_id).unwrap();
+    assert_ne!(game.creator_id, user_id);
+    assert!(game.players[&game.creator_id].name.starts_with("deleted-"));
+}
+
+/// Tests that a changed email must be verified again before it counts
+///
+/// Validates:
+/// - Switching to an address listed in `[admin]` doesn't promote on login
+/// - With verification required, the new address can't create games until verified
+#[tokio::test]
+async fn test_email_change_needs_verification_again() {
+    use axum::Json;
+    use axum::extract::{Extension, State as AxumState};
+    use blackjack_api::account::{self, UpdateAccountRequest};
+    use blackjack_api::auth::Claims;
+    use blackjack_api::handlers::{self, CreateGameRequest, LoginRequest};
+    use blackjack_api::middleware::RequestId;
+
+    let mut config = blackjack_api::config::AppConfig::from_file().unwrap();
+    config.verification.required = true;
+    config.admin.emails = vec!["ops@example.com".to_string()];
+
+    let user_service = Arc::new(UserService::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(config),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+
+    let user_id = user_service
+        .register("user@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let token = user_service
+        .request_email_verification(user_id, chrono::Duration::hours(1))
+        .unwrap()
+        .unwrap();
+    user_service.verify_email(&token).unwrap();
+
+    let claims_for = |email: &str| Claims {
+        user_id: user_id.to_string(),
+        email: email.to_string(),
+        role: PlatformRole::User,
+        session_version: user_service.get_user(user_id).unwrap().session_version,
+        exp: (chrono::Utc::now() + chrono::Duration::hours(1)).timestamp() as usize,
+    };
+    let create_game = |email: &str| {
+        handlers::create_game(
+            AxumState(state.clone()),
+            Extension(claims_for(email)),
+            Json(CreateGameRequest::default()),
+        )
+    };
+    assert!(create_game("user@example.com").await.is_ok());
+
+    let updated = account::update_account(
+        AxumState(state.clone()),
+        Extension(claims_for("user@example.com")),
+        RequestId("req-update".to_string()),
+        Json(UpdateAccountRequest {
+            display_name: None,
+            email: Some("ops@example.com".to_string()),
+            password: Some("TestP@ssw0rd".to_string()),
+        }),
+    )
+    .await
+    .unwrap();
+    assert!(updated.0.token.is_some());
+    assert!(!updated.0.user.email_verified);
+
+    let error = create_game("ops@example.com").await.unwrap_err();
+    assert_eq!(error.code, "EMAIL_NOT_VERIFIED");
+
+    let login = handlers::login(
+        AxumState(state.clone()),
+        RequestId("req-login".to_string()),
+        Json(LoginRequest {
+            email: "ops@example.com".to_string(),
+            password: "TestP@ssw0rd".to_string(),
+        }),
+    )
+    .await;
+    assert!(login.is_ok());
+    let user = user_service.get_user(user_id).unwrap();
+    assert!(!user.email_verified);
+    assert_eq!(user.role, PlatformRole::User);
+}
+
+/// Tests that finished games show up on the public leaderboards
+#[tokio::test]
+async fn test_leaderboards_are_public_and_updated_by_results() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use blackjack_api::handlers;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    let user_id = user_service
+        .register_with_display_name(
+            "player@example.com".to_string(),
+            "TestP@ssw0rd".to_string(),
+            Some("high_roller".to_string()),
+        )
+        .unwrap();
+    let game_id = game_service.create_game(user_id, None).unwrap();
+    game_service.close_enrollment(game_id, user_id).unwrap();
+    game_service.stand(game_id, user_id).unwrap();
+
+    let app = Router::new()
+        .route("/api/v1/leaderboards", get(handlers::get_leaderboards))
+        .with_state(state);
+    let get_board = |uri: &'static str| {
+        app.clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+    };
+
+    let response = get_board("/api/v1/leaderboards?board=net_chips&window=daily")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let board: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(board["board"], "net_chips");
+    assert_eq!(board["window"], "daily");
+    assert_eq!(board["entries"][0]["rank"], 1);
+    assert_eq!(board["entries"][0]["display_name"], "high_roller");
+    assert_eq!(board["entries"][0]["games_played"], 1);
+    assert!(board["entries"][0].get("email").is_none());
+
+    // One game is not enough for the win rate board by default
+    let response = get_board("/api/v1/leaderboards").await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let board: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(board["min_games"], 5);
+    assert_eq!(board["entries"].as_array().unwrap().len(), 0);
+
+    let response = get_board("/api/v1/leaderboards?board=luckiest")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Tests that players can list their achievements, locked ones included
+#[tokio::test]
+async fn test_player_achievements_endpoint() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    user_service
+        .register(
+            "achiever@example.com".to_string(),
+            "TestP@ssw0rd".to_string(),
+        )
+        .unwrap();
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/players/me/achievements",
+            get(handlers::get_player_achievements),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let login = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/auth/login")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({
+                        "email": "achiever@example.com",
+                        "password": "TestP@ssw0rd"
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(login.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let token = serde_json::from_slice::<serde_json::Value>(&body).unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/players/me/achievements")
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let progress: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(progress["unlocked"], 0);
+    assert_eq!(
+        progress["achievements"].as_array().unwrap().len(),
+        progress["total"].as_u64().unwrap() as usize
+    );
+    assert_eq!(progress["achievements"][0]["id"], "natural_blackjack");
+    assert!(progress["achievements"][0]["unlocked_at"].is_null());
+}
+
+#[tokio::test]
+async fn test_player_games_endpoint() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    let user_id = user_service
+        .register(
+            "history@example.com".to_string(),
+            "TestP@ssw0rd".to_string(),
+        )
+        .unwrap();
+    for _ in 0..3 {
+        let game_id = state.game_service.create_game(user_id, None).unwrap();
+        state
+            .game_service
+            .close_enrollment(game_id, user_id)
+            .unwrap();
+        state.game_service.stand(game_id, user_id).unwrap();
+    }
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route("/api/v1/players/me/games", get(handlers::get_player_games))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let login = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/auth/login")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({
+                        "email": "history@example.com",
+                        "password": "TestP@ssw0rd"
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(login.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let token = serde_json::from_slice::<serde_json::Value>(&body).unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let get_games = |uri: &str| {
+        Request::builder()
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(get_games("/api/v1/players/me/games?limit=2"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let page: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(page["count"], 2);
+    assert_eq!(page["total"], 3);
+    assert_eq!(page["games"][0]["rules"]["min_bet"], 10);
+    assert!(page["games"][0]["opponents"].as_array().unwrap().is_empty());
+    let cursor = page["next_cursor"].as_str().unwrap().to_string();
+
+    let response = app
+        .clone()
+        .oneshot(get_games(&format!(
+            "/api/v1/players/me/games?limit=2&cursor={}",
+            cursor
+        )))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let page: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(page["count"], 1);
+    assert!(page["next_cursor"].is_null());
+
+    let response = app
+        .clone()
+        .oneshot(get_games(
+            "/api/v1/players/me/games?since=2999-01-01T00:00:00Z",
+        ))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let page: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(page["total"], 0);
+
+    let response = app
+        .oneshot(get_games("/api/v1/players/me/games?cursor=bogus"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_tournament_endpoints() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let state = AppState {
+        game_service: Arc::new(GameService::new(
+            ServiceConfig::default(),
+            user_service.clone(),
+        )),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    for email in ["organiser@example.com", "entrant@example.com"] {
+        user_service
+            .register(email.to_string(), "TestP@ssw0rd".to_string())
+            .unwrap();
+    }
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/tournaments",
+            post(handlers::create_tournament).get(handlers::list_tournaments),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id",
+            get(handlers::get_tournament),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/register",
+            post(handlers::register_for_tournament),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/start",
+            post(handlers::start_tournament),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/standings",
+            get(handlers::get_tournament_standings),
+        )
+        .route(
+            "/api/v1/tournaments/:tournament_id/bracket",
+            get(handlers::get_tournament_bracket),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let mut tokens = Vec::new();
+    for email in ["organiser@example.com", "entrant@example.com"] {
+        let login = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/auth/login")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "email": email, "password": "TestP@ssw0rd" })
+                            .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(login.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        tokens.push(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()["token"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    let post_as = |token: &str, uri: &str, body: serde_json::Value| {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+    let get_public = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(post_as(
+            &tokens[0],
+            "/api/v1/tournaments",
+            serde_json::json!({
+                "name": "Friday Night",
+                "settings": { "rounds": 2, "table_size": 2, "advancing_per_table": 1 }
+            }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let tournament: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(tournament["status"], "registering");
+    assert_eq!(tournament["settings"]["starting_chips"], 1000);
+    let id = tournament["id"].as_str().unwrap().to_string();
+
+    for token in &tokens {
+        let response = app
+            .clone()
+            .oneshot(post_as(
+                token,
+                &format!("/api/v1/tournaments/{}/register", id),
+                serde_json::json!({}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let response = app
+        .clone()
+        .oneshot(post_as(
+            &tokens[1],
+            &format!("/api/v1/tournaments/{}/register", id),
+            serde_json::json!({}),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Only the organiser starts the tournament
+    let response = app
+        .clone()
+        .oneshot(post_as(
+            &tokens[1],
+            &format!("/api/v1/tournaments/{}/start", id),
+            serde_json::json!({}),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = app
+        .clone()
+        .oneshot(post_as(
+            &tokens[0],
+            &format!("/api/v1/tournaments/{}/start", id),
+            serde_json::json!({}),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(get_public(&format!("/api/v1/tournaments/{}/standings", id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let standings: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(standings["status"], "running");
+    assert_eq!(standings["current_round"], 1);
+    assert_eq!(standings["standings"].as_array().unwrap().len(), 2);
+    assert_eq!(standings["standings"][0]["status"], "playing");
+
+    let response = app
+        .clone()
+        .oneshot(get_public(&format!("/api/v1/tournaments/{}/bracket", id)))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let bracket: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let table = &bracket["rounds"][0]["tables"][0];
+    assert!(table["game_id"].is_string());
+    assert_eq!(table["seats"].as_array().unwrap().len(), 2);
+    assert_eq!(table["settled"], false);
+
+    let response = app
+        .clone()
+        .oneshot(get_public("/api/v1/tournaments?status=running"))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let list: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(list["count"], 1);
+    assert_eq!(list["tournaments"][0]["registered_players"], 2);
+
+    let response = app
+        .oneshot(get_public(&format!(
+            "/api/v1/tournaments/{}",
+            uuid::Uuid::new_v4()
+        )))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_table_chat_endpoints() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{delete, post, put};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(2),
+    };
+    let creator_id = user_service
+        .register("host@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let player_id = user_service
+        .register("guest@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let game_id = game_service.create_game(creator_id, None).unwrap();
+    game_service.enroll_player(game_id, player_id).unwrap();
+    let other_game_id = game_service.create_game(creator_id, None).unwrap();
+    game_service
+        .enroll_player(other_game_id, player_id)
+        .unwrap();
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/games/:game_id/chat",
+            post(handlers::post_chat_message).get(handlers::get_chat_history),
+        )
+        .route(
+            "/api/v1/games/:game_id/chat/:message_id",
+            delete(handlers::delete_chat_message),
+        )
+        .route(
+            "/api/v1/games/:game_id/chat/mutes/:user_id",
+            put(handlers::mute_chat_participant).delete(handlers::unmute_chat_participant),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state.clone());
+
+    let mut tokens = Vec::new();
+    for email in ["host@example.com", "guest@example.com"] {
+        let login = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/auth/login")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "email": email, "password": "TestP@ssw0rd" })
+                            .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(login.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        tokens.push(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()["token"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    let request_as = |method: &str, token: &str, uri: String, body: Option<serde_json::Value>| {
+        let builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token));
+        match body {
+            Some(body) => builder
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+            None => builder.body(Body::empty()).unwrap(),
+        }
+    };
+    let chat_uri = format!("/api/v1/games/{}/chat", game_id);
+
+    // The guest posts; the host is notified
+    let response = app
+        .clone()
+        .oneshot(request_as(
+            "POST",
+            &tokens[1],
+            chat_uri.clone(),
+            Some(serde_json::json!({ "text": "hello table" })),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let message: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(message["text"], "hello table");
+    let message_id = message["id"].as_str().unwrap().to_string();
+    let inbox = state.notification_service.get_for_user(creator_id, true);
+    assert_eq!(inbox.len(), 1);
+    assert!(!inbox[0].message.contains("hello table"));
+    assert!(matches!(
+        inbox[0].kind,
+        NotificationKind::ChatMessage { message_id: id, .. } if id.to_string() == message_id
+    ));
+
+    // The chat rate limit is per user, across games
+    let response = app
+        .clone()
+        .oneshot(request_as(
+            "POST",
+            &tokens[1],
+            chat_uri.clone(),
+            Some(serde_json::json!({ "text": "second" })),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let response = app
+        .clone()
+        .oneshot(request_as(
+            "POST",
+            &tokens[1],
+            chat_uri.clone(),
+            Some(serde_json::json!({ "text": "third" })),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let response = app
+        .clone()
+        .oneshot(request_as(
+            "POST",
+            &tokens[1],
+            format!("/api/v1/games/{}/chat", other_game_id),
+            Some(serde_json::json!({ "text": "elsewhere" })),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // Only the host can mute
+    let mute_uri = format!("/api/v1/games/{}/chat/mutes/{}", game_id, player_id);
+    let response = app
+        .clone()
+        .oneshot(request_as("PUT", &tokens[1], mute_uri.clone(), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = app
+        .clone()
+        .oneshot(request_as("PUT", &tokens[0], mute_uri.clone(), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(request_as("GET", &tokens[1], chat_uri.clone(), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let history: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(history["messages"].as_array().unwrap().len(), 2);
+    assert_eq!(history["muted"][0], player_id.to_string());
+
+    // The host deletes the first message
+    let response = app
+        .clone()
+        .oneshot(request_as(
+            "DELETE",
+            &tokens[0],
+            format!("{}/{}", chat_uri, message_id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = app
+        .clone()
+        .oneshot(request_as(
+            "DELETE",
+            &tokens[0],
+            format!("{}/{}", chat_uri, message_id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .clone()
+        .oneshot(request_as("DELETE", &tokens[0], mute_uri, None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let mute: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(mute["muted"], false);
+
+    assert_eq!(
+        state
+            .audit_log
+            .recent(100)
+            .iter()
+            .filter(|record| record.action == "game.chat_mute")
+            .count(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn test_game_event_stream() {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post};
+    use blackjack_api::handlers;
+    use blackjack_api::middleware::auth_middleware;
+    use tower::ServiceExt;
+
+    let user_service = Arc::new(UserService::new());
+    let game_service = Arc::new(GameService::new(
+        ServiceConfig::default(),
+        user_service.clone(),
+    ));
+    let state = AppState {
+        game_service: game_service.clone(),
+        user_service: user_service.clone(),
+        invitation_service: Arc::new(InvitationService::new(InvitationConfig::default())),
+        matchmaking_service: Arc::new(MatchmakingService::default()),
+        notification_service: Arc::new(NotificationService::new()),
+        tournament_service: Arc::new(TournamentService::new()),
+        audit_log: Arc::new(AuditLog::new()),
+        mailer: Arc::new(OutboxMailer::new()),
+        config: Arc::new(blackjack_api::config::AppConfig::from_file().unwrap()),
+        rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+        chat_rate_limiter: blackjack_api::rate_limiter::RateLimiter::new(100),
+    };
+    let creator_id = user_service
+        .register("dealer@example.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let game_id = game_service.create_game(creator_id, None).unwrap();
+    game_service.close_enrollment(game_id, creator_id).unwrap();
+
+    let app = Router::new()
+        .route("/api/v1/auth/login", post(handlers::login))
+        .route(
+            "/api/v1/games/:game_id/events",
+            get(handlers::stream_game_events),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
+        .with_state(state);
+
+    let login = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/auth/login")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({
+                        "email": "dealer@example.com",
+                        "password": "TestP@ssw0rd"
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(login.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let token = serde_json::from_slice::<serde_json::Value>(&body).unwrap()["token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let events_request = |last_event_id: Option<&str>| {
+        let mut builder = Request::builder()
+            .uri(format!("/api/v1/games/{}/events", game_id))
+            .header("Authorization", format!("Bearer {}", token));
+        if let Some(id) = last_event_id {
+            builder = builder.header("Last-Event-ID", id);
+        }
+        builder.body(Body::empty()).unwrap()
+    };
+
+    // The stream stays open and delivers events as the game is played
+    let response = app.clone().oneshot(events_request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/event-stream"
+    );
+    game_service.draw_card(game_id, creator_id).ok();
+    if !game_service.get_game_state(game_id).unwrap().finished {
+        game_service.stand(game_id, creator_id).unwrap();
+    }
+    let body = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        axum::body::to_bytes(response.into_body(), usize::MAX),
+    )
+    .await
+    .expect("stream ends after the game finishes")
+    .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(body.contains("event: enrollment_closed\n"));
+    assert!(body.contains("id: 1\n"));
+    assert!(body.contains("event: card_drawn\n"));
+    assert!(body.contains("event: dealer_played\n"));
+    assert!(body.contains("event: game_finished\n"));
+
+    // Resuming skips the events already seen
+    let response = app
+        .clone()
+        .oneshot(events_request(Some("1")))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(!body.contains("event: enrollment_closed\n"));
+    assert!(body.contains("id: 2\n"));
+    assert!(body.contains("event: game_finished\n"));
+
+    let response = app
+        .clone()
+        .oneshot(events_request(Some("latest")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/games/{}/events", uuid::Uuid::new_v4()))
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,261 @@
+use crate::client::{ApiClient, OutputFormat, print_response, resolve_server, session_token};
+use crate::session::Session;
+use clap::{Args, Subcommand};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Options of the `admin` subcommand.
+///
+/// Uses the token saved by `client login`; the account needs the `moderator`
+/// or `admin` platform role. Some commands are for administrators only.
+#[derive(Debug, Args)]
+pub struct AdminArgs {
+    /// Base URL of the blackjack-api server [default: saved session or http://localhost:8080].
+    #[arg(long, env = "BLACKJACK_SERVER", global = true)]
+    server: Option<String>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    output: OutputFormat,
+
+    /// Session file holding the login token.
+    #[arg(long, env = crate::session::SESSION_FILE_ENV, global = true)]
+    session_file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: AdminCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum AdminCommand {
+    /// Manage user accounts.
+    #[command(subcommand)]
+    Users(UsersCommand),
+    /// Inspect and abort games.
+    #[command(subcommand)]
+    Games(GamesCommand),
+    /// Inspect and clean up invitations.
+    #[command(subcommand)]
+    Invitations(InvitationsCommand),
+    /// Show server statistics.
+    Stats,
+    /// Show the running server configuration (administrators only).
+    Config,
+    /// Query the security audit trail, newest first (administrators only).
+    Audit(AuditArgs),
+}
+
+/// Filters combine; all are optional.
+#[derive(Debug, Args)]
+struct AuditArgs {
+    /// Actor ID or email.
+    #[arg(long)]
+    actor: Option<String>,
+    /// Action prefix, e.g. `auth.` or `game.kick_player`.
+    #[arg(long)]
+    action: Option<String>,
+    /// Substring of the target.
+    #[arg(long)]
+    target: Option<String>,
+    /// success, denied or failed.
+    #[arg(long)]
+    outcome: Option<String>,
+    /// Request ID (`X-Request-Id`).
+    #[arg(long)]
+    request_id: Option<String>,
+    /// Records at or after this RFC 3339 time.
+    #[arg(long)]
+    since: Option<String>,
+    /// Records before this RFC 3339 time.
+    #[arg(long)]
+    until: Option<String>,
+    /// Maximum number of records.
+    #[arg(long, default_value_t = 100)]
+    limit: usize,
+    /// Print every matching record as JSON Lines, oldest first (ignores --limit).
+    #[arg(long)]
+    export: bool,
+}
+
+impl AuditArgs {
+    fn query(&self) -> Vec<(&'static str, String)> {
+        [
+            ("actor", &self.actor),
+            ("action", &self.action),
+            ("target", &self.target),
+            ("outcome", &self.outcome),
+            ("request_id", &self.request_id),
+            ("since", &self.since),
+            ("until", &self.until),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.clone().map(|value| (key, value)))
+        .collect()
+    }
+}
+
+/// Users are given by ID or email.
+#[derive(Debug, Subcommand)]
+enum UsersCommand {
+    /// List all accounts.
+    List,
+    /// Show one account.
+    Show { user: String },
+    /// Reactivate a suspended account.
+    Activate { user: String },
+    /// Suspend an account so it can't log in.
+    Deactivate { user: String },
+    /// Unlock an account locked by failed logins (administrators only).
+    Unlock { user: String },
+    /// Change an account's platform role (administrators only).
+    Role {
+        user: String,
+        /// user, moderator or admin.
+        role: String,
+    },
+    /// Set a new password, also unlocking the account (administrators only).
+    ResetPassword {
+        user: String,
+        #[arg(long, env = "BLACKJACK_NEW_PASSWORD", hide_env_values = true)]
+        password: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum GamesCommand {
+    /// List games in any phase.
+    List,
+    /// Show the full internal state of a game.
+    Inspect { game_id: String },
+    /// Force-finish a stuck game without counting results.
+    Abort { game_id: String },
+}
+
+#[derive(Debug, Subcommand)]
+enum InvitationsCommand {
+    /// List invitations.
+    List {
+        /// Only pending, accepted, declined, expired or revoked invitations.
+        #[arg(long)]
+        status: Option<String>,
+    },
+    /// Expire pending invitations past their deadline.
+    Cleanup,
+}
+
+/// Runs one admin command against the server and prints the response.
+///
+/// # Returns
+/// Returns an error message if the request fails or the server rejects it.
+pub fn run(args: AdminArgs) -> Result<(), String> {
+    let session_path = args.session_file.unwrap_or_else(Session::default_path);
+    let session = Session::load(&session_path);
+    let server = resolve_server(args.server, session.as_ref());
+    let client = ApiClient::new(&server, session_token(&server, session.as_ref()));
+    let api = client.authorized()?;
+
+    let response = match args.command {
+        AdminCommand::Users(command) => match command {
+            UsersCommand::List => api.get("/admin/users")?,
+            UsersCommand::Show { user } => {
+                api.get(&format!("/admin/users/{}", user_id(api, &user)?))?
+            }
+            UsersCommand::Activate { user } => user_action(api, &user, "activate", json!({}))?,
+            UsersCommand::Deactivate { user } => user_action(api, &user, "deactivate", json!({}))?,
+            UsersCommand::Unlock { user } => user_action(api, &user, "unlock", json!({}))?,
+            UsersCommand::Role { user, role } => api.put(
+                &format!("/admin/users/{}/role", user_id(api, &user)?),
+                json!({ "role": role }),
+            )?,
+            UsersCommand::ResetPassword { user, password } => user_action(
+                api,
+                &user,
+                "reset-password",
+                json!({ "new_password": password }),
+            )?,
+        },
+        AdminCommand::Games(command) => match command {
+            GamesCommand::List => api.get("/admin/games")?,
+            GamesCommand::Inspect { game_id } => api.get(&format!("/admin/games/{}", game_id))?,
+            GamesCommand::Abort { game_id } => {
+                api.post(&format!("/admin/games/{}/abort", game_id), json!({}))?
+            }
+        },
+        AdminCommand::Invitations(command) => match command {
+            InvitationsCommand::List { status } => {
+                let path = match status {
+                    Some(status) => format!("/admin/invitations?status={}", status),
+                    None => "/admin/invitations".to_string(),
+                };
+                api.get(&path)?
+            }
+            InvitationsCommand::Cleanup => api.post("/admin/invitations/cleanup", json!({}))?,
+        },
+        AdminCommand::Stats => api.get("/admin/stats")?,
+        AdminCommand::Config => api.get("/admin/config")?,
+        AdminCommand::Audit(audit) if audit.export => {
+            print!("{}", api.get_text("/admin/audit/export", &audit.query())?);
+            return Ok(());
+        }
+        AdminCommand::Audit(audit) => {
+            let mut query = audit.query();
+            query.push(("limit", audit.limit.to_string()));
+            api.get_query("/admin/audit", &query)?
+        }
+    };
+
+    print_response(&response, args.output)
+}
+
+fn user_action(api: &ApiClient, user: &str, action: &str, body: Value) -> Result<Value, String> {
+    api.post(
+        &format!("/admin/users/{}/{}", user_id(api, user)?, action),
+        body,
+    )
+}
+
+/// Resolves a user given by ID or email to an ID.
+fn user_id(api: &ApiClient, user: &str) -> Result<String, String> {
+    if Uuid::parse_str(user).is_ok() {
+        return Ok(user.to_string());
+    }
+    find_user_id(&api.get("/admin/users")?, user)
+}
+
+/// Looks up the ID of the account with `email` in a `/admin/users` response.
+fn find_user_id(users: &Value, email: &str) -> Result<String, String> {
+    users["users"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|user| user["email"] == email)
+        .and_then(|user| user["id"].as_str())
+        .map(str::to_string)
+        .ok_or_else(|| format!("No user with email {}", email))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_user_id_by_email() {
+        let users = json!({
+            "users": [
+                { "id": "a1", "email": "alice@example.com" },
+                { "id": "b2", "email": "bob@example.com" },
+            ],
+            "count": 2,
+        });
+
+        assert_eq!(
+            find_user_id(&users, "bob@example.com"),
+            Ok("b2".to_string())
+        );
+        assert_eq!(
+            find_user_id(&users, "carol@example.com"),
+            Err("No user with email carol@example.com".to_string())
+        );
+    }
+}
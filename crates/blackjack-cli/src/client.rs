@@ -0,0 +1,556 @@
+use crate::session::Session;
+use clap::{Args, Subcommand, ValueEnum};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Server used when neither `--server` nor a saved session says otherwise.
+const DEFAULT_SERVER: &str = "http://localhost:8080";
+
+/// Options of the `client` subcommand.
+#[derive(Debug, Args)]
+pub struct ClientArgs {
+    /// Base URL of the blackjack-api server [default: saved session or http://localhost:8080].
+    #[arg(long, env = "BLACKJACK_SERVER", global = true)]
+    server: Option<String>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    output: OutputFormat,
+
+    /// Session file holding the login token.
+    #[arg(long, env = crate::session::SESSION_FILE_ENV, global = true)]
+    session_file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: ClientCommand,
+}
+
+/// How responses are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum ClientCommand {
+    /// Create an account.
+    Register {
+        #[command(flatten)]
+        credentials: Credentials,
+        /// Name shown to other players; the server picks one when omitted.
+        #[arg(long)]
+        display_name: Option<String>,
+    },
+    /// Log in and store the token for later commands.
+    Login(Credentials),
+    /// Forget the stored token.
+    Logout,
+    /// Email yourself a password reset token.
+    ForgotPassword {
+        #[arg(long)]
+        email: String,
+    },
+    /// Set a new password with a token from the reset email (signs out all sessions).
+    ResetPassword {
+        #[arg(long)]
+        token: String,
+        #[arg(long, env = "BLACKJACK_NEW_PASSWORD", hide_env_values = true)]
+        password: String,
+    },
+    /// Verify your email address with the token from the verification email.
+    VerifyEmail { token: String },
+    /// Mail a new verification link to the logged-in account.
+    ResendVerification,
+    /// Create a game (you are enrolled as its creator).
+    Create {
+        /// Enrollment window in seconds.
+        #[arg(long)]
+        timeout: Option<u64>,
+        /// Who can find the game: public, unlisted or private.
+        #[arg(long)]
+        visibility: Option<String>,
+        /// Password other players need to enroll.
+        #[arg(long)]
+        password: Option<String>,
+        /// Table size.
+        #[arg(long)]
+        table_size: Option<u8>,
+    },
+    /// List open games.
+    List {
+        /// Page size.
+        #[arg(long)]
+        limit: Option<u32>,
+    },
+    /// Enroll in a game.
+    Enroll {
+        game_id: String,
+        /// Game password, if the game has one.
+        #[arg(long)]
+        password: Option<String>,
+    },
+    /// Close enrollment and start play (creator only).
+    Close { game_id: String },
+    /// Show the game state.
+    State { game_id: String },
+    /// Draw a card on your turn.
+    Draw { game_id: String },
+    /// Stand on your turn.
+    Stand { game_id: String },
+    /// Count one of your Aces as 1 or 11.
+    Ace {
+        game_id: String,
+        card_id: String,
+        /// New value of the Ace.
+        #[arg(value_parser = ["1", "11"])]
+        value: String,
+    },
+    /// Ask for a basic strategy hint on your turn.
+    Hint { game_id: String },
+    /// Finish the game (creator only).
+    Finish { game_id: String },
+    /// Show the results of a finished game.
+    Results { game_id: String },
+    /// Invite a player to a game.
+    Invite { game_id: String, email: String },
+    /// List invitations you received (or sent, with --sent).
+    Invitations {
+        #[arg(long)]
+        sent: bool,
+    },
+    /// Accept an invitation.
+    Accept { invitation_id: String },
+    /// Decline an invitation.
+    Decline { invitation_id: String },
+    /// Revoke an invitation you sent.
+    Revoke { invitation_id: String },
+}
+
+#[derive(Debug, Args)]
+struct Credentials {
+    #[arg(long)]
+    email: String,
+    #[arg(long, env = "BLACKJACK_PASSWORD", hide_env_values = true)]
+    password: String,
+}
+
+/// Runs one client command against the server and prints the response.
+///
+/// # Returns
+/// Returns an error message if the request fails or the server rejects it.
+pub fn run(args: ClientArgs) -> Result<(), String> {
+    let session_path = args.session_file.unwrap_or_else(Session::default_path);
+    let session = Session::load(&session_path);
+    let server = resolve_server(args.server, session.as_ref());
+    let api = ApiClient::new(&server, session_token(&server, session.as_ref()));
+
+    let response = match args.command {
+        ClientCommand::Register {
+            credentials,
+            display_name,
+        } => api.post(
+            "/auth/register",
+            json!({
+                "email": credentials.email,
+                "password": credentials.password,
+                "display_name": display_name,
+            }),
+        )?,
+        ClientCommand::Login(credentials) => {
+            let response = api.post(
+                "/auth/login",
+                json!({ "email": credentials.email, "password": credentials.password }),
+            )?;
+            let token = response["token"]
+                .as_str()
+                .ok_or("Login response did not contain a token")?;
+            Session {
+                server: server.clone(),
+                email: credentials.email,
+                display_name: response["display_name"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                token: token.to_string(),
+            }
+            .save(&session_path)?;
+            json!({
+                "message": format!("Logged in to {}", server),
+                "expires_in": response["expires_in"],
+            })
+        }
+        ClientCommand::Logout => {
+            let removed = Session::clear(&session_path)?;
+            json!({ "message": if removed { "Logged out" } else { "Not logged in" } })
+        }
+        ClientCommand::ForgotPassword { email } => {
+            api.post("/auth/forgot-password", json!({ "email": email }))?
+        }
+        ClientCommand::ResetPassword { token, password } => api.post(
+            "/auth/reset-password",
+            json!({ "token": token, "new_password": password }),
+        )?,
+        ClientCommand::VerifyEmail { token } => api.get(&format!("/auth/verify/{}", token))?,
+        ClientCommand::ResendVerification => api
+            .authorized()?
+            .post("/auth/resend-verification", json!({}))?,
+        ClientCommand::Create {
+            timeout,
+            visibility,
+            password,
+            table_size,
+        } => {
+            let mut body = json!({
+                "enrollment_timeout_seconds": timeout,
+                "visibility": visibility,
+                "password": password,
+            });
+            if let Some(max_players) = table_size {
+                body["rules"] = json!({ "max_players": max_players });
+            }
+            api.authorized()?.post("/games", body)?
+        }
+        ClientCommand::List { limit } => {
+            let path = match limit {
+                Some(limit) => format!("/games/open?limit={}", limit),
+                None => "/games/open".to_string(),
+            };
+            api.authorized()?.get(&path)?
+        }
+        ClientCommand::Enroll { game_id, password } => {
+            let email = session.map(|s| s.email).unwrap_or_default();
+            api.authorized()?.post(
+                &format!("/games/{}/enroll", game_id),
+                json!({ "email": email, "password": password }),
+            )?
+        }
+        ClientCommand::Close { game_id } => api
+            .authorized()?
+            .post(&format!("/games/{}/close-enrollment", game_id), json!({}))?,
+        ClientCommand::State { game_id } => {
+            api.authorized()?.get(&format!("/games/{}", game_id))?
+        }
+        ClientCommand::Draw { game_id } => api
+            .authorized()?
+            .post(&format!("/games/{}/draw", game_id), json!({}))?,
+        ClientCommand::Stand { game_id } => api
+            .authorized()?
+            .post(&format!("/games/{}/stand", game_id), json!({}))?,
+        ClientCommand::Ace {
+            game_id,
+            card_id,
+            value,
+        } => api.authorized()?.put(
+            &format!("/games/{}/ace", game_id),
+            json!({ "card_id": card_id, "as_eleven": value == "11" }),
+        )?,
+        ClientCommand::Hint { game_id } => {
+            api.authorized()?.get(&format!("/games/{}/hint", game_id))?
+        }
+        ClientCommand::Finish { game_id } => api
+            .authorized()?
+            .post(&format!("/games/{}/finish", game_id), json!({}))?,
+        ClientCommand::Results { game_id } => api
+            .authorized()?
+            .get(&format!("/games/{}/results", game_id))?,
+        ClientCommand::Invite { game_id, email } => api.authorized()?.post(
+            &format!("/games/{}/invitations", game_id),
+            json!({ "invitee_email": email }),
+        )?,
+        ClientCommand::Invitations { sent } => {
+            let path = if sent {
+                "/invitations/sent"
+            } else {
+                "/invitations/pending"
+            };
+            api.authorized()?.get(path)?
+        }
+        ClientCommand::Accept { invitation_id } => api
+            .authorized()?
+            .post(&format!("/invitations/{}/accept", invitation_id), json!({}))?,
+        ClientCommand::Decline { invitation_id } => api.authorized()?.post(
+            &format!("/invitations/{}/decline", invitation_id),
+            json!({}),
+        )?,
+        ClientCommand::Revoke { invitation_id } => api
+            .authorized()?
+            .delete(&format!("/invitations/{}", invitation_id))?,
+    };
+
+    print_response(&response, args.output)
+}
+
+/// Prints a server response in the selected format.
+pub fn print_response(response: &Value, output: OutputFormat) -> Result<(), String> {
+    match output {
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(response).map_err(|e| e.to_string())?
+        ),
+        OutputFormat::Text => print!("{}", render_text(response)),
+    }
+
+    Ok(())
+}
+
+/// Picks the server: explicit option first, then the saved session, then the default.
+pub fn resolve_server(server: Option<String>, session: Option<&Session>) -> String {
+    server
+        .or_else(|| session.map(|s| s.server.clone()))
+        .unwrap_or_else(|| DEFAULT_SERVER.to_string())
+}
+
+/// The saved token, but only if it was issued by `server`.
+///
+/// Sending it anywhere else would hand the login to whichever server
+/// `--server` or `BLACKJACK_SERVER` happens to point at.
+pub fn session_token(server: &str, session: Option<&Session>) -> Option<String> {
+    session
+        .filter(|s| s.server.trim_end_matches('/') == server.trim_end_matches('/'))
+        .map(|s| s.token.clone())
+}
+
+/// Thin blocking wrapper around the `/api/v1` REST endpoints.
+pub struct ApiClient {
+    base_url: String,
+    token: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl ApiClient {
+    /// Creates a client for `server`, sending `token` with every request if present.
+    pub fn new(server: &str, token: Option<String>) -> Self {
+        Self {
+            base_url: format!("{}/api/v1", server.trim_end_matches('/')),
+            token,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Makes sure a token is available before calling a protected endpoint.
+    pub fn authorized(&self) -> Result<&Self, String> {
+        match self.token {
+            Some(_) => Ok(self),
+            None => Err(format!(
+                "Not logged in to {}, run `blackjack-cli client login` first",
+                self.base_url.trim_end_matches("/api/v1")
+            )),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<Value, String> {
+        self.send("GET", path, &[], None)
+    }
+
+    /// GET with query parameters, percent-encoded by the client.
+    pub fn get_query(&self, path: &str, query: &[(&str, String)]) -> Result<Value, String> {
+        self.send("GET", path, query, None)
+    }
+
+    /// GET returning the raw response body, for non-JSON endpoints.
+    pub fn get_text(&self, path: &str, query: &[(&str, String)]) -> Result<String, String> {
+        self.call("GET", path, query, None)?
+            .into_string()
+            .map_err(|e| format!("Could not read response: {}", e))
+    }
+
+    pub fn post(&self, path: &str, body: Value) -> Result<Value, String> {
+        self.send("POST", path, &[], Some(body))
+    }
+
+    pub fn put(&self, path: &str, body: Value) -> Result<Value, String> {
+        self.send("PUT", path, &[], Some(body))
+    }
+
+    pub fn delete(&self, path: &str) -> Result<Value, String> {
+        self.send("DELETE", path, &[], None)
+    }
+
+    /// Sends a request and decodes the JSON response.
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Value>,
+    ) -> Result<Value, String> {
+        decode_body(self.call(method, path, query, body)?)
+    }
+
+    /// Sends a request and returns the successful response.
+    ///
+    /// API errors are turned into `message (status CODE)`.
+    fn call(
+        &self,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Value>,
+    ) -> Result<ureq::Response, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = self.agent.request(method, &url);
+        for (key, value) in query {
+            request = request.query(key, value);
+        }
+        if let Some(token) = &self.token {
+            request = request.set("Authorization", &format!("Bearer {}", token));
+        }
+
+        let result = match body {
+            Some(body) => request.send_json(body),
+            None => request.call(),
+        };
+
+        match result {
+            Ok(response) => Ok(response),
+            Err(ureq::Error::Status(status, response)) => {
+                let error = decode_body(response).unwrap_or(Value::Null);
+                Err(describe_error(status, &error))
+            }
+            Err(ureq::Error::Transport(e)) => Err(format!("Could not reach {}: {}", url, e)),
+        }
+    }
+}
+
+/// Reads a JSON body; an empty body decodes to `null`.
+fn decode_body(response: ureq::Response) -> Result<Value, String> {
+    let text = response.into_string().map_err(|e| e.to_string())?;
+    if text.trim().is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_json::from_str(&text).map_err(|e| format!("Invalid JSON from server: {}", e))
+}
+
+/// Formats an `ApiError` body (`message`, `code`) for the terminal.
+fn describe_error(status: u16, error: &Value) -> String {
+    match (error["message"].as_str(), error["code"].as_str()) {
+        (Some(message), Some(code)) => format!("{} ({} {})", message, status, code),
+        _ => format!("Request failed with status {}", status),
+    }
+}
+
+/// Renders a JSON response as indented `key: value` lines.
+fn render_text(value: &Value) -> String {
+    let mut out = String::new();
+    render_value(value, 0, &mut out);
+    out
+}
+
+fn render_value(value: &Value, indent: usize, out: &mut String) {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                if is_scalar(value) {
+                    out.push_str(&format!("{}{}: {}\n", pad, key, scalar(value)));
+                } else {
+                    out.push_str(&format!("{}{}:\n", pad, key));
+                    render_value(value, indent + 1, out);
+                }
+            }
+        }
+        Value::Array(items) if items.is_empty() => out.push_str(&format!("{}(none)\n", pad)),
+        Value::Array(items) if items.iter().all(is_scalar) => {
+            let items: Vec<String> = items.iter().map(scalar).collect();
+            out.push_str(&format!("{}{}\n", pad, items.join(", ")));
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                out.push_str(&format!("{}- #{}\n", pad, index + 1));
+                render_value(item, indent + 1, out);
+            }
+        }
+        other => out.push_str(&format!("{}{}\n", pad, scalar(other))),
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_)) || value == &json!([])
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => "-".to_string(),
+        Value::Array(_) => "(none)".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_text_nests_objects_and_lists() {
+        let value = json!({
+            "game_id": "abc",
+            "finished": false,
+            "turn_order": ["a@test.com", "b@test.com"],
+            "bots": [],
+            "dealer_up_card": null,
+            "players": { "a@test.com": { "points": 12 } },
+        });
+
+        let text = render_text(&value);
+        assert!(text.contains("game_id: abc\n"));
+        assert!(text.contains("finished: false\n"));
+        assert!(text.contains("turn_order:\n  a@test.com, b@test.com\n"));
+        assert!(text.contains("bots: (none)\n"));
+        assert!(text.contains("dealer_up_card: -\n"));
+        assert!(text.contains("players:\n  a@test.com:\n    points: 12\n"));
+    }
+
+    #[test]
+    fn test_describe_error_uses_api_error_body() {
+        let body =
+            json!({ "message": "It's not your turn", "code": "NOT_YOUR_TURN", "status": 403 });
+        assert_eq!(
+            describe_error(403, &body),
+            "It's not your turn (403 NOT_YOUR_TURN)"
+        );
+        assert_eq!(
+            describe_error(502, &Value::Null),
+            "Request failed with status 502"
+        );
+    }
+
+    #[test]
+    fn test_protected_commands_need_a_token() {
+        let anonymous = ApiClient::new("http://localhost:8080/", None);
+        assert_eq!(anonymous.base_url, "http://localhost:8080/api/v1");
+        assert!(anonymous.authorized().is_err());
+        assert!(
+            ApiClient::new("http://localhost:8080", Some("t".to_string()))
+                .authorized()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_saved_token_only_goes_to_its_server() {
+        let session = Session {
+            server: "http://localhost:8080".to_string(),
+            email: "player@example.com".to_string(),
+            display_name: "player".to_string(),
+            token: "token".to_string(),
+        };
+
+        assert_eq!(
+            session_token("http://localhost:8080/", Some(&session)),
+            Some("token".to_string())
+        );
+        assert_eq!(session_token("http://evil.example", Some(&session)), None);
+        assert_eq!(session_token("http://localhost:8080", None), None);
+
+        let error = ApiClient::new("http://evil.example", None)
+            .authorized()
+            .err()
+            .unwrap();
+        assert!(error.contains("Not logged in to http://evil.example"));
+    }
+}
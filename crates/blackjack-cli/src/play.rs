@@ -0,0 +1,327 @@
+use blackjack_core::{Card, Game, GameError, GameResult, MAX_TABLE_SIZE, PlayerOutcome};
+use std::io::{self, BufRead, Write};
+use uuid::Uuid;
+
+/// Enrollment window of the local table; players are seated right away.
+const ENROLLMENT_TIMEOUT_SECONDS: u64 = 300;
+
+/// Runs a local game on the terminal.
+///
+/// Local play drives the same `Game` engine as the server:
+/// 1. Requests the number of players (1 to 10)
+/// 2. Deals the dealer's up-card
+/// 3. Players take turns in order, one card or a stand per turn
+/// 4. The dealer plays once every player stood or busted
+/// 5. Each player's result against the dealer is displayed
+pub fn run() -> io::Result<()> {
+    let stdin = io::stdin();
+    play_game(&mut stdin.lock(), &mut io::stdout())
+}
+
+/// Plays a complete game reading decisions from `input`.
+///
+/// # Errors
+/// Returns an error if reading or writing fails, or if input ends early.
+pub fn play_game(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    writeln!(output, "Welcome to Blackjack!")?;
+
+    let total_players = read_total_players(input, output)?;
+    let mut game = new_game(total_players).map_err(engine_error)?;
+
+    if let Some(card) = game.dealer_up_card() {
+        writeln!(output, "\nDealer shows: {}", format_card(card))?;
+    }
+
+    while !game.finished {
+        let player_id = game
+            .get_current_player()
+            .ok_or_else(|| engine_error(GameError::InvalidPlayerCount))?;
+        play_turn(&mut game, player_id, input, output)?;
+    }
+
+    show_results(&game, output)
+}
+
+/// Creates a table with `total_players` players named "Player 1", "Player 2", ...
+/// and closes enrollment so play can start.
+fn new_game(total_players: u8) -> Result<Game, GameError> {
+    let mut game = Game::new(Uuid::new_v4(), player_name(1), ENROLLMENT_TIMEOUT_SECONDS)?;
+    for number in 2..=total_players {
+        game.add_player(Uuid::new_v4(), player_name(number))?;
+    }
+    game.close_enrollment()?;
+    Ok(game)
+}
+
+fn player_name(number: u8) -> String {
+    format!("Player {}", number)
+}
+
+/// Plays one turn: the current player either draws a card or stands.
+fn play_turn(
+    game: &mut Game,
+    player_id: Uuid,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    let player = &game.players[&player_id];
+    let name = player.name.clone();
+    writeln!(
+        output,
+        "\n{}'s turn - hand: {} ({} points)",
+        name,
+        format_hand(&player.cards_history),
+        player.points
+    )?;
+
+    if !prompt_decision(input, output, "Do you want a card?")? {
+        game.stand(player_id).map_err(engine_error)?;
+        writeln!(
+            output,
+            "{} stands with {} points.",
+            name, game.players[&player_id].points
+        )?;
+        return Ok(());
+    }
+
+    let card = game.draw_card(player_id).map_err(engine_error)?;
+    writeln!(output, "You got the card: {}", format_card(&card))?;
+
+    // An Ace counts as 1 unless the player chooses 11 and it doesn't bust the hand
+    let points = game.players[&player_id].points;
+    if card.name == "A"
+        && !game.finished
+        && points + 10 <= 21
+        && prompt_decision(
+            input,
+            output,
+            "You have an Ace! Do you want it to count as 11 points instead of 1?",
+        )?
+    {
+        game.set_ace_value(player_id, card.id, true)
+            .map_err(engine_error)?;
+    }
+
+    let player = &game.players[&player_id];
+    if player.busted {
+        writeln!(output, "{} busts with {} points!", name, player.points)?;
+    } else {
+        writeln!(output, "Your current points: {}", player.points)?;
+    }
+
+    Ok(())
+}
+
+/// Displays the dealer's hand and every player's result.
+fn show_results(game: &Game, output: &mut impl Write) -> io::Result<()> {
+    let results: GameResult = game.calculate_results();
+
+    writeln!(
+        output,
+        "\n\n==========================\nGame Results:\n=========================="
+    )?;
+    writeln!(
+        output,
+        "Dealer: {} ({} points{})",
+        format_hand(&game.dealer.cards_history),
+        results.dealer_points,
+        if results.dealer_busted {
+            ", busted"
+        } else {
+            ""
+        }
+    )?;
+
+    for name in game
+        .turn_order
+        .iter()
+        .filter_map(|id| game.player_name(*id))
+    {
+        let result = &results.player_results[name];
+        writeln!(
+            output,
+            "{}: {} points - {}",
+            name,
+            result.points,
+            outcome_label(&result.outcome)
+        )?;
+    }
+
+    if results.tied_players.len() > 1 {
+        writeln!(
+            output,
+            "Best hand is a tie between: {}",
+            results.tied_players.join(", ")
+        )?;
+    } else if let Some(winner) = &results.winner {
+        writeln!(
+            output,
+            "{} has the best hand with {} points!",
+            winner, results.highest_score
+        )?;
+    }
+    writeln!(output, "Finished.")
+}
+
+fn outcome_label(outcome: &PlayerOutcome) -> &'static str {
+    match outcome {
+        PlayerOutcome::Won => "wins against the dealer",
+        PlayerOutcome::Lost => "loses to the dealer",
+        PlayerOutcome::Push => "pushes with the dealer",
+        PlayerOutcome::Busted => "busted",
+    }
+}
+
+fn format_card(card: &Card) -> String {
+    format!("{} of {}", card.name, card.suit)
+}
+
+fn format_hand(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        return "no cards".to_string();
+    }
+    cards.iter().map(format_card).collect::<Vec<_>>().join(", ")
+}
+
+/// Checks the number of players fits at a table.
+fn validate_total_players(total_players: u8) -> Result<u8, String> {
+    if total_players == 0 {
+        return Err("The number of players must be greater than zero.".to_string());
+    }
+    if total_players > MAX_TABLE_SIZE {
+        return Err(format!(
+            "The maximum number of players is {}.",
+            MAX_TABLE_SIZE
+        ));
+    }
+    Ok(total_players)
+}
+
+/// Reads the total number of players, asking again until the answer is valid.
+fn read_total_players(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<u8> {
+    loop {
+        let answer = read_line(input, output, "Enter the number of players: ")?;
+        match answer.parse::<u8>() {
+            Ok(total_players) => match validate_total_players(total_players) {
+                Ok(total_players) => return Ok(total_players),
+                Err(message) => writeln!(output, "{}", message)?,
+            },
+            Err(_) => writeln!(output, "Please enter a valid number.")?,
+        }
+    }
+}
+
+/// Prompts the player for a yes/no decision.
+///
+/// # Returns
+/// Returns `true` if the response is "Y" (uppercase or lowercase) or empty (Enter).
+/// Returns `false` if the response is "N" or any other input.
+fn prompt_decision(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    prompt: &str,
+) -> io::Result<bool> {
+    let response = read_line(input, output, &format!("{} [Y/n]: ", prompt))?.to_uppercase();
+    Ok(response == "Y" || response.is_empty())
+}
+
+/// Prints a prompt and reads one trimmed line of input.
+///
+/// # Errors
+/// Returns `UnexpectedEof` if the input is closed.
+fn read_line(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    prompt: &str,
+) -> io::Result<String> {
+    write!(output, "{}", prompt)?;
+    output.flush()?;
+
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input closed"));
+    }
+    Ok(line.trim().to_string())
+}
+
+fn engine_error(error: GameError) -> io::Error {
+    io::Error::other(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_script(script: &str) -> io::Result<String> {
+        let mut output = Vec::new();
+        play_game(&mut script.as_bytes(), &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_validate_total_players() {
+        assert_eq!(validate_total_players(1), Ok(1));
+        assert_eq!(validate_total_players(10), Ok(10));
+        assert_eq!(
+            validate_total_players(0),
+            Err("The number of players must be greater than zero.".to_string())
+        );
+        assert_eq!(
+            validate_total_players(11),
+            Err("The maximum number of players is 10.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_player_count_is_asked_again() {
+        let output = run_script("0\nabc\n11\n1\nn\n").unwrap();
+        assert!(output.contains("The number of players must be greater than zero."));
+        assert!(output.contains("Please enter a valid number."));
+        assert!(output.contains("The maximum number of players is 10."));
+        assert!(output.contains("Finished."));
+    }
+
+    #[test]
+    fn test_players_take_turns_and_dealer_plays() {
+        // Player 1 draws once (keeping any Ace at 1), then everyone stands
+        let output = run_script("2\ny\nn\nn\nn\nn\n").unwrap();
+
+        let first = output.find("Player 1's turn").unwrap();
+        let second = output.find("Player 2's turn").unwrap();
+        assert!(first < second);
+        assert!(output.contains("Dealer shows:"));
+        assert!(output.contains("Player 1: "));
+        assert!(output.contains("Player 2: "));
+        assert!(output.contains("Finished."));
+    }
+
+    #[test]
+    fn test_standing_on_nothing_loses_unless_dealer_busts() {
+        let output = run_script("1\nn\n").unwrap();
+        assert!(
+            output.contains("Player 1: 0 points - loses to the dealer")
+                || output.contains("Player 1: 0 points - wins against the dealer")
+        );
+    }
+
+    #[test]
+    fn test_closed_input_is_an_error() {
+        let error = run_script("1\n").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_format_hand() {
+        let card = Card {
+            id: Uuid::new_v4(),
+            name: "K".to_string(),
+            value: 10,
+            suit: "Spades".to_string(),
+        };
+        assert_eq!(format_hand(&[]), "no cards");
+        assert_eq!(
+            format_hand(&[card.clone(), card]),
+            "K of Spades, K of Spades"
+        );
+    }
+}
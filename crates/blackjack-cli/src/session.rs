@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding where the session is stored.
+pub const SESSION_FILE_ENV: &str = "BLACKJACK_CLI_SESSION";
+
+/// Credentials kept between client invocations after `login`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    /// Server the token was issued by
+    pub server: String,
+    /// Account the token belongs to
+    pub email: String,
+    /// Name the account appears under in games
+    #[serde(default)]
+    pub display_name: String,
+    /// JWT sent as `Authorization: Bearer <token>`
+    pub token: String,
+}
+
+impl Session {
+    /// Default session file: `$BLACKJACK_CLI_SESSION`, or `~/.blackjack/session.json`.
+    pub fn default_path() -> PathBuf {
+        if let Ok(path) = std::env::var(SESSION_FILE_ENV) {
+            return PathBuf::from(path);
+        }
+        let home = std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .unwrap_or_else(|_| ".".to_string());
+        Path::new(&home).join(".blackjack").join("session.json")
+    }
+
+    /// Loads the saved session, if any.
+    ///
+    /// # Returns
+    /// Returns `None` if no session was saved or the file can't be read.
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Saves the session, readable only by the current user on Unix.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Could not create {:?}: {}", parent, e))?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        // Create the file private so the token is never readable by others, not even briefly
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        options
+            .open(path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|e| format!("Could not write {:?}: {}", path, e))?;
+
+        Ok(())
+    }
+
+    /// Deletes the saved session.
+    ///
+    /// # Returns
+    /// Returns `true` if a session was removed.
+    pub fn clear(path: &Path) -> Result<bool, String> {
+        match fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(format!("Could not remove {:?}: {}", path, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip() {
+        let dir = std::env::temp_dir().join(format!("blackjack-cli-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("nested").join("session.json");
+        let session = Session {
+            server: "http://localhost:8080".to_string(),
+            email: "player@example.com".to_string(),
+            display_name: "player".to_string(),
+            token: "token".to_string(),
+        };
+
+        assert_eq!(Session::load(&path), None);
+        session.save(&path).unwrap();
+        assert_eq!(Session::load(&path), Some(session));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        assert!(Session::clear(&path).unwrap());
+        assert!(!Session::clear(&path).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+use blackjack_core::TableRules;
+use blackjack_core::simulation::{self, SimulationConfig, SimulationReport};
+use blackjack_core::strategy::BotStrategy;
+use clap::{Args, ValueEnum};
+
+/// Options of the `simulate` subcommand.
+#[derive(Debug, Args)]
+pub struct SimulateArgs {
+    /// Number of rounds to play.
+    #[arg(long, default_value_t = 1_000_000)]
+    rounds: u64,
+
+    /// Seats at the table, all played with the same strategy.
+    #[arg(long, default_value_t = 1)]
+    seats: u8,
+
+    /// Strategy used for every seat.
+    #[arg(long, value_enum, default_value_t = StrategyArg::BasicStrategy)]
+    strategy: StrategyArg,
+
+    /// Seed for reproducible runs (random if omitted).
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Worker threads (defaults to one per CPU).
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Table size.
+    #[arg(long, default_value_t = TableRules::default().max_players)]
+    table_size: u8,
+
+    /// Smallest bet accepted at the table.
+    #[arg(long, default_value_t = TableRules::default().min_bet)]
+    min_bet: u32,
+
+    /// Largest bet accepted at the table.
+    #[arg(long, default_value_t = TableRules::default().max_bet)]
+    max_bet: u32,
+
+    /// Output format of the report.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum StrategyArg {
+    BasicStrategy,
+    MimicDealer,
+    Random,
+}
+
+impl From<StrategyArg> for BotStrategy {
+    fn from(arg: StrategyArg) -> Self {
+        match arg {
+            StrategyArg::BasicStrategy => BotStrategy::BasicStrategy,
+            StrategyArg::MimicDealer => BotStrategy::MimicDealer,
+            StrategyArg::Random => BotStrategy::Random,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Csv,
+}
+
+/// Runs the simulation and prints the report to stdout.
+///
+/// # Returns
+/// Returns an error message if the options are invalid.
+pub fn run(args: SimulateArgs) -> Result<(), String> {
+    let defaults = SimulationConfig::default();
+    let config = SimulationConfig {
+        rounds: args.rounds,
+        seats: args.seats,
+        rules: TableRules {
+            max_players: args.table_size,
+            min_bet: args.min_bet,
+            max_bet: args.max_bet,
+            ..TableRules::default()
+        },
+        strategy: args.strategy.into(),
+        seed: args.seed.unwrap_or_else(rand::random),
+        threads: args.threads.unwrap_or(defaults.threads),
+    };
+
+    let report = simulation::run(&config)?;
+
+    match args.format {
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?
+        ),
+        OutputFormat::Csv => println!("{}\n{}", CSV_HEADER, csv_row(&report)),
+    }
+
+    Ok(())
+}
+
+/// Column names of the CSV report.
+const CSV_HEADER: &str = "strategy,seed,rounds,seats,hands,table_size,min_bet,max_bet,\
+win_rate,blackjack_rate,push_rate,loss_rate,bust_rate,dealer_bust_rate,ev,house_edge,variance,std_error";
+
+/// Formats a report as one CSV row matching `CSV_HEADER`.
+fn csv_row(report: &SimulationReport) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        report.strategy.strategy().name(),
+        report.seed,
+        report.rounds,
+        report.seats,
+        report.hands,
+        report.rules.max_players,
+        report.rules.min_bet,
+        report.rules.max_bet,
+        report.win_rate,
+        report.blackjack_rate,
+        report.push_rate,
+        report.loss_rate,
+        report.bust_rate,
+        report.dealer_bust_rate,
+        report.ev,
+        report.house_edge,
+        report.variance,
+        report.std_error,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_row_matches_header() {
+        let report = simulation::run(&SimulationConfig {
+            rounds: 100,
+            seed: 7,
+            threads: 1,
+            ..SimulationConfig::default()
+        })
+        .unwrap();
+
+        let row = csv_row(&report);
+        assert_eq!(row.split(',').count(), CSV_HEADER.split(',').count());
+        assert!(row.starts_with("basic_strategy,7,100,1,100,"));
+    }
+}
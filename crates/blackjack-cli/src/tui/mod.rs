@@ -0,0 +1,110 @@
+//! Full-screen table for playing with the keyboard.
+
+mod table;
+mod view;
+
+use crate::client::{ApiClient, resolve_server, session_token};
+use crate::session::{SESSION_FILE_ENV, Session};
+use clap::Args;
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use std::path::PathBuf;
+use std::time::Duration;
+use table::{LocalTable, RemoteTable, TableSource};
+
+/// How long to wait for a key before redrawing.
+const TICK: Duration = Duration::from_millis(250);
+
+/// Options of the `tui` subcommand.
+#[derive(Debug, Args)]
+pub struct TuiArgs {
+    /// Players sharing this keyboard in a local game.
+    #[arg(long, default_value_t = 2)]
+    players: u8,
+
+    /// Play this game on the server as the logged-in client user instead.
+    #[arg(long)]
+    game: Option<String>,
+
+    /// Seconds between state refreshes of a remote game.
+    #[arg(long, default_value_t = 10)]
+    refresh: u64,
+
+    /// Base URL of the blackjack-api server [default: saved session or http://localhost:8080].
+    #[arg(long, env = "BLACKJACK_SERVER")]
+    server: Option<String>,
+
+    /// Session file holding the login token.
+    #[arg(long, env = SESSION_FILE_ENV)]
+    session_file: Option<PathBuf>,
+}
+
+/// Opens the table screen until the user quits.
+///
+/// # Returns
+/// Returns an error message if the table can't be set up or the terminal fails.
+pub fn run(args: TuiArgs) -> Result<(), String> {
+    let mut table: Box<dyn TableSource> = match args.game {
+        Some(game_id) => {
+            let session_path = args.session_file.unwrap_or_else(Session::default_path);
+            let session = Session::load(&session_path)
+                .ok_or("Not logged in, run `blackjack-cli client login` first")?;
+            if session.display_name.is_empty() {
+                return Err(
+                    "Session predates display names, run `blackjack-cli client login` again"
+                        .to_string(),
+                );
+            }
+            let server = resolve_server(args.server, Some(&session));
+            let token = session_token(&server, Some(&session)).ok_or_else(|| {
+                format!(
+                    "Not logged in to {}, run `blackjack-cli client login` first",
+                    server
+                )
+            })?;
+            Box::new(RemoteTable::new(
+                ApiClient::new(&server, Some(token)),
+                game_id,
+                session.display_name,
+                Duration::from_secs(args.refresh.max(1)),
+            ))
+        }
+        None => Box::new(LocalTable::new(args.players)?),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, table.as_mut());
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, table: &mut dyn TableSource) -> Result<(), String> {
+    let mut status = "Good luck!".to_string();
+    let mut view = table.snapshot()?;
+
+    loop {
+        terminal
+            .draw(|frame| view::render(frame, &view, &status))
+            .map_err(|e| e.to_string())?;
+
+        if event::poll(TICK).map_err(|e| e.to_string())?
+            && let Event::Key(key) = event::read().map_err(|e| e.to_string())?
+            && key.kind == KeyEventKind::Press
+        {
+            let outcome = match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('h') => table.hit(),
+                KeyCode::Char('s') => table.stand(),
+                KeyCode::Char('a') => table.toggle_ace(),
+                KeyCode::Char('r') => Ok("Refreshed".to_string()),
+                _ => continue,
+            };
+            status = outcome.unwrap_or_else(|e| format!("Error: {}", e));
+        }
+
+        match table.snapshot() {
+            Ok(latest) => view = latest,
+            Err(e) => status = format!("Error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,457 @@
+use crate::client::ApiClient;
+use blackjack_core::strategy::HandValue;
+use blackjack_core::{Card, Game, GameError, PlayerOutcome};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Everything the table screen shows, independent of where the game runs.
+#[derive(Debug, Clone, Default)]
+pub struct TableView {
+    /// Dealer's visible cards (just the up-card while players act)
+    pub dealer_cards: Vec<Card>,
+    /// Whether a face-down hole card should be drawn next to the up-card
+    pub dealer_hidden: bool,
+    /// Dealer total, known once the game is finished
+    pub dealer_points: Option<u8>,
+    /// Players in turn order
+    pub seats: Vec<Seat>,
+    pub current_turn: Option<String>,
+    /// Player controlled from this terminal (`None` for hot-seat play)
+    pub me: Option<String>,
+    pub cards_in_deck: usize,
+    pub enrollment_closed: bool,
+    pub time_remaining_seconds: i64,
+    pub finished: bool,
+    /// Results against the dealer once the game is finished
+    pub outcomes: HashMap<String, PlayerOutcome>,
+}
+
+/// One player's place at the table.
+#[derive(Debug, Clone)]
+pub struct Seat {
+    pub name: String,
+    pub cards: Vec<Card>,
+    pub points: u8,
+    pub busted: bool,
+    pub is_bot: bool,
+}
+
+/// A game the TUI can show and play: a local hot-seat table or a remote one.
+///
+/// Actions return a status message for the footer, or an error message.
+pub trait TableSource {
+    /// Current state of the table.
+    fn snapshot(&mut self) -> Result<TableView, String>;
+
+    /// Draws a card for the player whose turn it is.
+    fn hit(&mut self) -> Result<String, String>;
+
+    /// Stands for the player whose turn it is.
+    fn stand(&mut self) -> Result<String, String>;
+
+    /// Switches the hand's Aces between counting as 1 and one Ace as 11.
+    fn toggle_ace(&mut self) -> Result<String, String>;
+}
+
+/// Ace values to set so the hand flips between hard and soft counting.
+///
+/// A hand counting an Ace as 11 goes back to all Aces at 1; otherwise the
+/// first Ace becomes 11 if that doesn't bust the hand.
+pub fn ace_toggle(cards: &[Card], points: u8) -> Result<Vec<(Uuid, bool)>, String> {
+    let aces: Vec<Uuid> = cards
+        .iter()
+        .filter(|card| card.name == "A")
+        .map(|card| card.id)
+        .collect();
+    if aces.is_empty() {
+        return Err("No Ace to toggle".to_string());
+    }
+
+    let hard = HandValue::of(cards).hard;
+    if points > hard {
+        return Ok(aces.into_iter().map(|id| (id, false)).collect());
+    }
+    if hard + 10 > 21 {
+        return Err("Counting an Ace as 11 would bust the hand".to_string());
+    }
+    Ok(vec![(aces[0], true)])
+}
+
+/// Hot-seat game on this machine, driving `blackjack_core::Game` directly.
+pub struct LocalTable {
+    game: Game,
+}
+
+impl LocalTable {
+    /// Seats `players` players named "Player 1", "Player 2", ... and starts play.
+    pub fn new(players: u8) -> Result<Self, String> {
+        let setup = || -> Result<Game, GameError> {
+            let mut game = Game::new(Uuid::new_v4(), "Player 1".to_string(), 300)?;
+            for number in 2..=players {
+                game.add_player(Uuid::new_v4(), format!("Player {}", number))?;
+            }
+            game.close_enrollment()?;
+            Ok(game)
+        };
+        let game = setup().map_err(|e| e.to_string())?;
+        Ok(Self { game })
+    }
+
+    /// Id and name of the player whose turn it is.
+    fn current_player(&self) -> Result<(Uuid, String), String> {
+        if self.game.finished {
+            return Err("The game is over".to_string());
+        }
+        let id = self
+            .game
+            .get_current_player()
+            .ok_or_else(|| "Nobody is playing".to_string())?;
+        Ok((id, self.game.players[&id].name.clone()))
+    }
+}
+
+impl TableSource for LocalTable {
+    fn snapshot(&mut self) -> Result<TableView, String> {
+        let game = &self.game;
+        let outcomes = if game.finished {
+            game.calculate_results()
+                .player_results
+                .into_iter()
+                .map(|(name, result)| (name, result.outcome))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(TableView {
+            dealer_cards: if game.finished {
+                game.dealer.cards_history.clone()
+            } else {
+                game.dealer_up_card().cloned().into_iter().collect()
+            },
+            dealer_hidden: !game.finished,
+            dealer_points: game.finished.then_some(game.dealer.points),
+            seats: game
+                .turn_order
+                .iter()
+                .map(|id| {
+                    let player = &game.players[id];
+                    Seat {
+                        name: player.name.clone(),
+                        cards: player.cards_history.clone(),
+                        points: player.points,
+                        busted: player.busted,
+                        is_bot: game.is_bot(*id),
+                    }
+                })
+                .collect(),
+            current_turn: (!game.finished)
+                .then(|| game.current_player_name().map(str::to_string))
+                .flatten(),
+            me: None,
+            cards_in_deck: game.available_cards.len(),
+            enrollment_closed: game.enrollment_closed,
+            time_remaining_seconds: game.get_enrollment_time_remaining(),
+            finished: game.finished,
+            outcomes,
+        })
+    }
+
+    fn hit(&mut self) -> Result<String, String> {
+        let (id, name) = self.current_player()?;
+        let card = self.game.draw_card(id).map_err(|e| e.to_string())?;
+        Ok(format!("{} drew {} of {}", name, card.name, card.suit))
+    }
+
+    fn stand(&mut self) -> Result<String, String> {
+        let (id, name) = self.current_player()?;
+        self.game.stand(id).map_err(|e| e.to_string())?;
+        Ok(format!("{} stands", name))
+    }
+
+    fn toggle_ace(&mut self) -> Result<String, String> {
+        let (id, name) = self.current_player()?;
+        let player = &self.game.players[&id];
+        for (card_id, as_eleven) in ace_toggle(&player.cards_history, player.points)? {
+            self.game
+                .set_ace_value(id, card_id, as_eleven)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(format!(
+            "{} now has {} points",
+            name, self.game.players[&id].points
+        ))
+    }
+}
+
+/// Subset of `GET /games/:game_id` the table needs.
+#[derive(Debug, Deserialize)]
+struct RemoteState {
+    players: HashMap<String, RemotePlayer>,
+    cards_in_deck: usize,
+    finished: bool,
+    current_turn_player: Option<String>,
+    turn_order: Vec<String>,
+    time_remaining_seconds: i64,
+    enrollment_closed: bool,
+    #[serde(default)]
+    dealer_up_card: Option<Card>,
+    #[serde(default)]
+    dealer_cards: Vec<Card>,
+    #[serde(default)]
+    bots: Vec<String>,
+}
+
+impl RemoteState {
+    /// Table as seen by `me`; outcomes and the dealer total come from the results
+    fn into_view(self, me: &str) -> TableView {
+        TableView {
+            dealer_cards: if self.finished {
+                self.dealer_cards
+            } else {
+                self.dealer_up_card.into_iter().collect()
+            },
+            dealer_hidden: !self.finished,
+            dealer_points: None,
+            seats: self
+                .turn_order
+                .iter()
+                .filter_map(|name| {
+                    let player = self.players.get(name)?;
+                    Some(Seat {
+                        name: name.clone(),
+                        cards: player.cards_history.clone(),
+                        points: player.points,
+                        busted: player.busted,
+                        is_bot: self.bots.contains(name),
+                    })
+                })
+                .collect(),
+            current_turn: self.current_turn_player.filter(|_| !self.finished),
+            me: Some(me.to_string()),
+            cards_in_deck: self.cards_in_deck,
+            enrollment_closed: self.enrollment_closed,
+            time_remaining_seconds: self.time_remaining_seconds,
+            finished: self.finished,
+            outcomes: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RemotePlayer {
+    points: u8,
+    cards_history: Vec<Card>,
+    busted: bool,
+}
+
+/// Subset of `GET /games/:game_id/results` the table needs.
+#[derive(Debug, Deserialize)]
+struct RemoteResults {
+    dealer_points: u8,
+    player_results: HashMap<String, RemoteResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteResult {
+    outcome: PlayerOutcome,
+}
+
+/// Game hosted by a `blackjack-api` server, played as the logged-in user.
+///
+/// The state is fetched at most once per `refresh` interval (and after each
+/// action) to stay well inside the server's rate limit; the enrollment
+/// countdown keeps ticking locally in between.
+pub struct RemoteTable {
+    api: ApiClient,
+    game_id: String,
+    me: String,
+    refresh: Duration,
+    cached: Option<(TableView, Instant)>,
+}
+
+impl RemoteTable {
+    pub fn new(api: ApiClient, game_id: String, me: String, refresh: Duration) -> Self {
+        Self {
+            api,
+            game_id,
+            me,
+            refresh,
+            cached: None,
+        }
+    }
+
+    fn fetch(&self) -> Result<TableView, String> {
+        let state: RemoteState =
+            serde_json::from_value(self.api.get(&format!("/games/{}", self.game_id))?)
+                .map_err(|e| format!("Unexpected game state: {}", e))?;
+
+        let finished = state.finished;
+        let mut view = state.into_view(&self.me);
+
+        if finished {
+            let results: RemoteResults =
+                serde_json::from_value(self.api.get(&format!("/games/{}/results", self.game_id))?)
+                    .map_err(|e| format!("Unexpected game results: {}", e))?;
+            view.dealer_points = Some(results.dealer_points);
+            view.outcomes = results
+                .player_results
+                .into_iter()
+                .map(|(name, result)| (name, result.outcome))
+                .collect();
+        }
+
+        Ok(view)
+    }
+
+    fn my_seat(&mut self) -> Result<Seat, String> {
+        let view = self.snapshot()?;
+        view.seats
+            .into_iter()
+            .find(|seat| seat.name == self.me)
+            .ok_or_else(|| "You are not playing at this table".to_string())
+    }
+}
+
+impl TableSource for RemoteTable {
+    fn snapshot(&mut self) -> Result<TableView, String> {
+        if let Some((view, fetched_at)) = &self.cached {
+            let elapsed = fetched_at.elapsed();
+            // A finished game can't change any more
+            if view.finished || elapsed < self.refresh {
+                let mut view = view.clone();
+                if !view.enrollment_closed {
+                    view.time_remaining_seconds =
+                        (view.time_remaining_seconds - elapsed.as_secs() as i64).max(0);
+                }
+                return Ok(view);
+            }
+        }
+
+        let view = self.fetch()?;
+        self.cached = Some((view.clone(), Instant::now()));
+        Ok(view)
+    }
+
+    fn hit(&mut self) -> Result<String, String> {
+        let response = self.api.post(
+            &format!("/games/{}/draw", self.game_id),
+            serde_json::json!({}),
+        )?;
+        self.cached = None;
+        let card: Card = serde_json::from_value(response["card"].clone())
+            .map_err(|e| format!("Unexpected draw response: {}", e))?;
+        Ok(format!("You drew {} of {}", card.name, card.suit))
+    }
+
+    fn stand(&mut self) -> Result<String, String> {
+        self.api.post(
+            &format!("/games/{}/stand", self.game_id),
+            serde_json::json!({}),
+        )?;
+        self.cached = None;
+        Ok("You stand".to_string())
+    }
+
+    fn toggle_ace(&mut self) -> Result<String, String> {
+        let seat = self.my_seat()?;
+        let mut points = seat.points;
+        for (card_id, as_eleven) in ace_toggle(&seat.cards, seat.points)? {
+            let response = self.api.put(
+                &format!("/games/{}/ace", self.game_id),
+                serde_json::json!({ "card_id": card_id, "as_eleven": as_eleven }),
+            )?;
+            points = response["points"].as_u64().unwrap_or(points as u64) as u8;
+        }
+        self.cached = None;
+        Ok(format!("You now have {} points", points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(name: &str, value: u8) -> Card {
+        Card {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            value,
+            suit: "Spades".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ace_toggle_flips_between_hard_and_soft() {
+        let hand = vec![card("A", 1), card("6", 6)];
+        assert_eq!(ace_toggle(&hand, 7).unwrap(), vec![(hand[0].id, true)]);
+        assert_eq!(ace_toggle(&hand, 17).unwrap(), vec![(hand[0].id, false)]);
+
+        let stiff = vec![card("A", 1), card("K", 10), card("5", 5)];
+        assert!(ace_toggle(&stiff, 16).is_err());
+        assert!(ace_toggle(&[card("K", 10)], 10).is_err());
+    }
+
+    #[test]
+    fn test_local_table_plays_in_turn_order() {
+        let mut table = LocalTable::new(2).unwrap();
+        let view = table.snapshot().unwrap();
+        assert_eq!(view.seats.len(), 2);
+        assert_eq!(view.current_turn.as_deref(), Some("Player 1"));
+        assert_eq!(view.dealer_cards.len(), 1);
+        assert!(view.dealer_hidden);
+        assert_eq!(view.cards_in_deck, 51);
+
+        assert!(table.hit().unwrap().starts_with("Player 1 drew"));
+        assert_eq!(
+            table.snapshot().unwrap().current_turn.as_deref(),
+            Some("Player 2")
+        );
+
+        table.stand().unwrap();
+        table.stand().unwrap();
+        let view = table.snapshot().unwrap();
+        assert!(view.finished);
+        assert!(!view.dealer_hidden);
+        assert!(view.dealer_points.is_some());
+        assert_eq!(view.outcomes.len(), 2);
+        assert!(table.hit().is_err());
+    }
+
+    #[test]
+    fn test_remote_view_shows_dealer_hand_once_finished() {
+        let state = |finished: bool| -> RemoteState {
+            serde_json::from_value(serde_json::json!({
+                "players": {},
+                "cards_in_deck": 47,
+                "finished": finished,
+                "current_turn_player": null,
+                "turn_order": [],
+                "time_remaining_seconds": 0,
+                "enrollment_closed": true,
+                "dealer_up_card": card("7", 7),
+                "dealer_cards": if finished {
+                    vec![card("7", 7), card("K", 10)]
+                } else {
+                    Vec::new()
+                },
+            }))
+            .unwrap()
+        };
+
+        let playing = state(false).into_view("alice");
+        assert_eq!(playing.dealer_cards.len(), 1);
+        assert!(playing.dealer_hidden);
+
+        let finished = state(true).into_view("alice");
+        let names: Vec<&str> = finished
+            .dealer_cards
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, ["7", "K"]);
+        assert!(!finished.dealer_hidden);
+    }
+}
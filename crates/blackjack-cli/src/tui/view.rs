@@ -0,0 +1,226 @@
+use super::table::{Seat, TableView};
+use blackjack_core::{Card, PlayerOutcome};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+
+/// Seats shown side by side before wrapping to a second row.
+const SEATS_PER_ROW: usize = 5;
+
+/// Draws the whole table screen.
+pub fn render(frame: &mut Frame, view: &TableView, status: &str) {
+    let [header, dealer, seats, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(6),
+        Constraint::Min(7),
+        Constraint::Length(4),
+    ])
+    .areas(frame.area());
+
+    frame.render_widget(header_bar(view), header);
+    frame.render_widget(dealer_area(view), dealer);
+    render_seats(frame, view, seats);
+    frame.render_widget(footer_bar(status), footer);
+}
+
+fn header_bar(view: &TableView) -> Paragraph<'static> {
+    let enrollment = if view.enrollment_closed {
+        "closed".to_string()
+    } else {
+        format!("closes in {}s", view.time_remaining_seconds)
+    };
+    let turn = if view.finished {
+        "game over".to_string()
+    } else {
+        view.current_turn.clone().unwrap_or_else(|| "-".to_string())
+    };
+
+    Paragraph::new(Line::from(vec![
+        Span::styled(" Deck: ", Style::new().bold()),
+        Span::raw(format!("{} cards", view.cards_in_deck)),
+        Span::styled("   Turn: ", Style::new().bold()),
+        Span::styled(turn, Style::new().fg(Color::Yellow)),
+        Span::styled("   Enrollment: ", Style::new().bold()),
+        Span::raw(enrollment),
+    ]))
+    .block(Block::bordered().title(" Blackjack "))
+}
+
+fn dealer_area(view: &TableView) -> Paragraph<'static> {
+    let title = match view.dealer_points {
+        Some(points) => format!(" Dealer - {} points ", points),
+        None => " Dealer ".to_string(),
+    };
+    Paragraph::new(card_lines(&view.dealer_cards, view.dealer_hidden))
+        .block(Block::bordered().title(title))
+}
+
+fn render_seats(frame: &mut Frame, view: &TableView, area: Rect) {
+    if view.seats.is_empty() {
+        return;
+    }
+
+    let rows: Vec<&[Seat]> = view.seats.chunks(SEATS_PER_ROW).collect();
+    let row_areas =
+        Layout::vertical(vec![Constraint::Ratio(1, rows.len() as u32); rows.len()]).split(area);
+
+    for (row, row_area) in rows.iter().zip(row_areas.iter()) {
+        let columns = Layout::horizontal(vec![
+            Constraint::Ratio(1, SEATS_PER_ROW as u32);
+            SEATS_PER_ROW
+        ])
+        .split(*row_area);
+        for (seat, column) in row.iter().zip(columns.iter()) {
+            frame.render_widget(seat_panel(view, seat), *column);
+        }
+    }
+}
+
+fn seat_panel(view: &TableView, seat: &Seat) -> Paragraph<'static> {
+    let is_turn = view.current_turn.as_deref() == Some(seat.name.as_str());
+    let mut title = format!(" {} ", seat.name);
+    if view.me.as_deref() == Some(seat.name.as_str()) {
+        title.push_str("(you) ");
+    }
+    if seat.is_bot {
+        title.push_str("(bot) ");
+    }
+
+    let border = if is_turn {
+        Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::new()
+    };
+
+    let status = match view.outcomes.get(&seat.name) {
+        Some(PlayerOutcome::Won) => Span::styled("won", Style::new().fg(Color::Green)),
+        Some(PlayerOutcome::Push) => Span::raw("push"),
+        Some(PlayerOutcome::Lost) => Span::styled("lost", Style::new().fg(Color::Red)),
+        Some(PlayerOutcome::Busted) | None if seat.busted => {
+            Span::styled("busted", Style::new().fg(Color::Red))
+        }
+        _ if is_turn => Span::styled("to act", Style::new().fg(Color::Yellow)),
+        _ => Span::raw(""),
+    };
+
+    let mut lines = card_lines(&seat.cards, false);
+    lines.push(Line::from(vec![
+        Span::styled(format!("{} points ", seat.points), Style::new().bold()),
+        status,
+    ]));
+
+    Paragraph::new(lines).block(Block::bordered().title(title).border_style(border))
+}
+
+/// Renders cards as three-line boxes, e.g. `│A ♠│`, with an optional face-down card.
+fn card_lines(cards: &[Card], face_down: bool) -> Vec<Line<'static>> {
+    let mut top = Vec::new();
+    let mut middle = Vec::new();
+    let mut bottom = Vec::new();
+
+    for card in cards {
+        let style = Style::new().fg(suit_color(&card.suit));
+        top.push(Span::styled("┌───┐ ", style));
+        middle.push(Span::styled(
+            format!("│{:<2}{}│ ", card.name, suit_symbol(&card.suit)),
+            style,
+        ));
+        bottom.push(Span::styled("└───┘ ", style));
+    }
+    if face_down {
+        let style = Style::new().fg(Color::Blue);
+        top.push(Span::styled("┌───┐ ", style));
+        middle.push(Span::styled("│░░░│ ", style));
+        bottom.push(Span::styled("└───┘ ", style));
+    }
+    if top.is_empty() {
+        return vec![Line::raw("no cards"), Line::raw(""), Line::raw("")];
+    }
+
+    vec![Line::from(top), Line::from(middle), Line::from(bottom)]
+}
+
+fn suit_symbol(suit: &str) -> char {
+    match suit {
+        "Hearts" => '♥',
+        "Diamonds" => '♦',
+        "Clubs" => '♣',
+        "Spades" => '♠',
+        _ => '?',
+    }
+}
+
+fn suit_color(suit: &str) -> Color {
+    match suit {
+        "Hearts" | "Diamonds" => Color::Red,
+        _ => Color::White,
+    }
+}
+
+fn footer_bar(status: &str) -> Paragraph<'static> {
+    let keys = Line::from(vec![
+        Span::styled("[h]", Style::new().bold()),
+        Span::raw(" hit  "),
+        Span::styled("[s]", Style::new().bold()),
+        Span::raw(" stand  "),
+        Span::styled("[a]", Style::new().bold()),
+        Span::raw(" toggle ace  "),
+        Span::styled("[r]", Style::new().bold()),
+        Span::raw(" refresh  "),
+        Span::styled("[q]", Style::new().bold()),
+        Span::raw(" quit"),
+    ]);
+    Paragraph::new(vec![keys, Line::raw(status.to_string())]).block(Block::bordered())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::table::{LocalTable, TableSource};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn screen(view: &TableView, status: &str) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| render(frame, view, status)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_render_shows_table_state() {
+        let mut table = LocalTable::new(3).unwrap();
+        table.hit().unwrap();
+        let view = table.snapshot().unwrap();
+
+        let text = screen(&view, "Player 1 drew a card");
+        assert!(text.contains("Deck: 50 cards"));
+        assert!(text.contains("Turn: Player 2"));
+        assert!(text.contains("Enrollment: closed"));
+        assert!(text.contains("Dealer"));
+        assert!(text.contains("│░░░│"));
+        for name in ["Player 1", "Player 2", "Player 3"] {
+            assert!(text.contains(name));
+        }
+        assert!(text.contains("Player 1 drew a card"));
+    }
+
+    #[test]
+    fn test_card_glyphs() {
+        let card = Card {
+            id: uuid::Uuid::new_v4(),
+            name: "10".to_string(),
+            value: 10,
+            suit: "Hearts".to_string(),
+        };
+        let lines = card_lines(&[card], true);
+        assert_eq!(lines[1].to_string(), "│10♥│ │░░░│ ");
+    }
+}
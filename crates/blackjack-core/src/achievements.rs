@@ -0,0 +1,141 @@
+/// Achievements unlocked by finished games
+///
+/// Every achievement is a row in [`ACHIEVEMENTS`]: an ID, a name shown to
+/// players and the [`Criterion`] that unlocks it. After a game's results are
+/// calculated, each player's hand is checked against the achievements they
+/// don't have yet, using their statistics and streaks including that game.
+/// Adding an achievement only takes a new row, as long as an existing
+/// criterion fits.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::achievements::{self, NATURAL_BLACKJACK};
+/// use blackjack_core::{PlayerOutcome, PlayerResult, UserStats};
+/// use uuid::Uuid;
+///
+/// let result = PlayerResult {
+///     player_id: Uuid::new_v4(),
+///     points: 21,
+///     cards_count: 2,
+///     busted: false,
+///     outcome: PlayerOutcome::Won,
+///     is_bot: false,
+///     hints_used: 0,
+/// };
+/// let mut stats = UserStats::new();
+/// stats.record_game(&result.outcome, result.points, false);
+///
+/// let unlocked = achievements::evaluate(&[], &result, false, &stats);
+/// assert!(unlocked.iter().any(|achievement| achievement.id == NATURAL_BLACKJACK));
+/// ```
+use crate::{PlayerOutcome, PlayerResult, UserStats};
+use serde::{Deserialize, Serialize};
+
+/// ID of the achievement for a first natural blackjack
+pub const NATURAL_BLACKJACK: &str = "natural_blackjack";
+
+/// What a finished game must show to unlock an achievement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criterion {
+    /// A blackjack: 21 with the first two cards
+    NaturalBlackjack,
+    /// This many wins in a row
+    WinStreak(u32),
+    /// A win holding at least this many cards
+    WinWithCards(usize),
+    /// This many games in a row where the dealer busted and the player didn't
+    DealerBustStreak(u32),
+    /// This many games played
+    GamesPlayed(u32),
+}
+
+impl Criterion {
+    /// Checks if a hand meets the criterion
+    ///
+    /// `stats` must already include the game the hand was played in.
+    pub fn is_met(&self, result: &PlayerResult, dealer_busted: bool, stats: &UserStats) -> bool {
+        let won = result.outcome == PlayerOutcome::Won;
+        match *self {
+            Self::NaturalBlackjack => result.is_blackjack(),
+            Self::WinStreak(wins) => stats.current_win_streak >= wins,
+            Self::WinWithCards(cards) => won && result.cards_count >= cards,
+            Self::DealerBustStreak(games) => dealer_busted && stats.dealer_bust_streak >= games,
+            Self::GamesPlayed(games) => stats.games_played >= games,
+        }
+    }
+}
+
+/// An achievement players can unlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Achievement {
+    /// Stable identifier stored with the players who unlocked it
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub criterion: Criterion,
+}
+
+/// Every achievement, in the order they are listed to players
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: NATURAL_BLACKJACK,
+        name: "Natural",
+        description: "Get a blackjack: 21 with your first two cards",
+        criterion: Criterion::NaturalBlackjack,
+    },
+    Achievement {
+        id: "hot_streak",
+        name: "Hot Streak",
+        description: "Win five games in a row",
+        criterion: Criterion::WinStreak(5),
+    },
+    Achievement {
+        id: "five_card_charlie",
+        name: "Five Card Charlie",
+        description: "Win holding five or more cards",
+        criterion: Criterion::WinWithCards(5),
+    },
+    Achievement {
+        id: "bust_survivor",
+        name: "Bust Survivor",
+        description: "Stay in while the dealer busts three games in a row",
+        criterion: Criterion::DealerBustStreak(3),
+    },
+    Achievement {
+        id: "centurion",
+        name: "Centurion",
+        description: "Play 100 games",
+        criterion: Criterion::GamesPlayed(100),
+    },
+];
+
+/// Looks up an achievement by ID
+pub fn find(id: &str) -> Option<&'static Achievement> {
+    ACHIEVEMENTS.iter().find(|achievement| achievement.id == id)
+}
+
+/// An achievement a player has unlocked
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnlockedAchievement {
+    /// See [`Achievement::id`]
+    pub id: String,
+    /// When it was unlocked (ISO 8601 format)
+    pub unlocked_at: String,
+}
+
+/// Achievements a hand unlocks that aren't in `unlocked` yet
+///
+/// `stats` must already include the game the hand was played in.
+pub fn evaluate(
+    unlocked: &[UnlockedAchievement],
+    result: &PlayerResult,
+    dealer_busted: bool,
+    stats: &UserStats,
+) -> Vec<&'static Achievement> {
+    ACHIEVEMENTS
+        .iter()
+        .filter(|achievement| !unlocked.iter().any(|done| done.id == achievement.id))
+        .filter(|achievement| achievement.criterion.is_met(result, dealer_busted, stats))
+        .collect()
+}
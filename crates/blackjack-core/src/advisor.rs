@@ -0,0 +1,150 @@
+/// Basic strategy advisor
+///
+/// Recommends the statistically best play for a hand against the dealer's
+/// up-card, following the standard multi-deck chart for a dealer standing on
+/// soft 17. Doubling, splitting and surrender are only suggested on the first
+/// two cards and only when the `TableRules` allow them; otherwise the chart's
+/// fallback (usually hit or stand) is returned.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::advisor::recommend;
+/// use blackjack_core::strategy::Action;
+/// use blackjack_core::{Card, TableRules};
+/// use uuid::Uuid;
+///
+/// let card = |name: &str, value: u8| Card {
+///     id: Uuid::new_v4(),
+///     name: name.to_string(),
+///     value,
+///     suit: "Hearts".to_string(),
+/// };
+///
+/// let hand = vec![card("6", 6), card("5", 5)];
+/// let dealer_up_card = card("6", 6);
+///
+/// let rules = TableRules {
+///     double_allowed: true,
+///     ..TableRules::default()
+/// };
+/// let advice = recommend(&hand, Some(&dealer_up_card), &rules);
+/// assert_eq!(advice.action, Action::Double);
+///
+/// // Without doubling the chart falls back to hitting
+/// let advice = recommend(&hand, Some(&dealer_up_card), &TableRules::default());
+/// assert_eq!(advice.action, Action::Hit);
+/// ```
+use crate::strategy::{Action, HandValue, up_card_value};
+use crate::{Card, TableRules};
+use serde::{Deserialize, Serialize};
+
+/// A recommendation and the facts it was based on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Advice {
+    /// Recommended play
+    pub action: Action,
+    /// Best hand total (one Ace as 11 when it fits)
+    pub hand_total: u8,
+    /// Whether the hand total counts an Ace as 11
+    pub soft: bool,
+    /// Whether the hand is a splittable pair
+    pub pair: bool,
+    /// Dealer up-card on the 2-11 scale (Ace = 11, 10 assumed if unknown)
+    pub dealer_value: u8,
+}
+
+/// Recommends the basic strategy play for a hand
+pub fn recommend(hand: &[Card], dealer_up_card: Option<&Card>, rules: &TableRules) -> Advice {
+    let value = HandValue::of(hand);
+    let dealer = up_card_value(dealer_up_card);
+    let first_two = hand.len() == 2;
+    let pair = first_two && same_rank(&hand[0], &hand[1]);
+
+    let action = if value.is_bust() || value.total >= 21 {
+        Action::Stand
+    } else if first_two && rules.surrender_allowed && should_surrender(&value, pair, dealer) {
+        Action::Surrender
+    } else if pair && rules.split_allowed && should_split(&hand[0], dealer) {
+        Action::Split
+    } else {
+        let (action, double) = if value.soft {
+            soft_total(value.total, dealer)
+        } else {
+            hard_total(value.total, dealer)
+        };
+        match double {
+            Some(fallback) if !(first_two && rules.double_allowed) => fallback,
+            _ => action,
+        }
+    };
+
+    Advice {
+        action,
+        hand_total: value.total,
+        soft: value.soft,
+        pair,
+        dealer_value: dealer,
+    }
+}
+
+/// Pairs are matched by value, so any two ten-value cards form a pair
+fn same_rank(a: &Card, b: &Card) -> bool {
+    (a.name == "A") == (b.name == "A") && a.value == b.value
+}
+
+/// Late surrender: hard 16 against 9, 10 or Ace, hard 15 against 10
+fn should_surrender(value: &HandValue, pair: bool, dealer: u8) -> bool {
+    if value.soft || pair {
+        return false;
+    }
+    match value.total {
+        16 => dealer >= 9,
+        15 => dealer == 10,
+        _ => false,
+    }
+}
+
+fn should_split(card: &Card, dealer: u8) -> bool {
+    if card.name == "A" {
+        return true;
+    }
+    match card.value {
+        8 => true,
+        9 => matches!(dealer, 2..=6 | 8 | 9),
+        7 | 3 | 2 => dealer <= 7,
+        6 => dealer <= 6,
+        4 => matches!(dealer, 5 | 6),
+        // Tens and fives are played as hard totals
+        _ => false,
+    }
+}
+
+/// Chart entry for soft totals: the action, and its fallback if it is a double
+fn soft_total(total: u8, dealer: u8) -> (Action, Option<Action>) {
+    match total {
+        19.. => (Action::Stand, None),
+        18 => match dealer {
+            2..=6 => (Action::Double, Some(Action::Stand)),
+            7 | 8 => (Action::Stand, None),
+            _ => (Action::Hit, None),
+        },
+        17 if (3..=6).contains(&dealer) => (Action::Double, Some(Action::Hit)),
+        15 | 16 if (4..=6).contains(&dealer) => (Action::Double, Some(Action::Hit)),
+        13 | 14 if (5..=6).contains(&dealer) => (Action::Double, Some(Action::Hit)),
+        _ => (Action::Hit, None),
+    }
+}
+
+/// Chart entry for hard totals: the action, and its fallback if it is a double
+fn hard_total(total: u8, dealer: u8) -> (Action, Option<Action>) {
+    match total {
+        17.. => (Action::Stand, None),
+        13..=16 if dealer <= 6 => (Action::Stand, None),
+        12 if (4..=6).contains(&dealer) => (Action::Stand, None),
+        11 if dealer <= 10 => (Action::Double, Some(Action::Hit)),
+        10 if dealer <= 9 => (Action::Double, Some(Action::Hit)),
+        9 if (3..=6).contains(&dealer) => (Action::Double, Some(Action::Hit)),
+        _ => (Action::Hit, None),
+    }
+}
@@ -0,0 +1,109 @@
+/// Events that happen at a table
+///
+/// A [`Game`](crate::Game) records an event whenever its public state moves
+/// along: a card is drawn, the turn passes, enrollment closes, the dealer
+/// plays or the game finishes. Events are numbered per game from 1, so a
+/// client that saw event `n` can ask for everything after it and miss
+/// nothing.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::Game;
+/// use blackjack_core::events::GameEventKind;
+/// use uuid::Uuid;
+///
+/// let creator_id = Uuid::new_v4();
+/// let mut game = Game::new(creator_id, "ace".to_string(), 300).unwrap();
+/// game.close_enrollment().unwrap();
+/// game.stand(creator_id).unwrap();
+///
+/// let kinds: Vec<&str> = game.events_after(0).iter().map(|e| e.kind.name()).collect();
+/// assert_eq!(kinds, ["enrollment_closed", "dealer_played", "game_finished"]);
+/// assert!(game.events_after(3).is_empty());
+/// ```
+use crate::Card;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What happened at the table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameEventKind {
+    /// A player (or bot) drew a card
+    CardDrawn {
+        player_id: Uuid,
+        player_name: String,
+        card: Card,
+        /// Hand total after the card
+        points: u8,
+        busted: bool,
+    },
+    /// The turn passed to another player
+    TurnChanged {
+        player_id: Uuid,
+        player_name: String,
+    },
+    /// Enrollment closed and play started
+    EnrollmentClosed {
+        /// Player IDs in the order they play
+        turn_order: Vec<Uuid>,
+        dealer_up_card: Option<Card>,
+    },
+    /// The dealer played out their hand
+    DealerPlayed {
+        cards: Vec<Card>,
+        points: u8,
+        busted: bool,
+    },
+    /// The game is over; results are available unless it was aborted
+    GameFinished { aborted: bool },
+}
+
+impl GameEventKind {
+    /// Snake case name of the event, as in its `type` field
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CardDrawn { .. } => "card_drawn",
+            Self::TurnChanged { .. } => "turn_changed",
+            Self::EnrollmentClosed { .. } => "enrollment_closed",
+            Self::DealerPlayed { .. } => "dealer_played",
+            Self::GameFinished { .. } => "game_finished",
+        }
+    }
+
+    /// Moves references to a deleted account over to its tombstone
+    pub fn anonymize_player(&mut self, user_id: Uuid, tombstone_id: Uuid, name: &str) {
+        match self {
+            Self::CardDrawn {
+                player_id,
+                player_name,
+                ..
+            }
+            | Self::TurnChanged {
+                player_id,
+                player_name,
+            } if *player_id == user_id => {
+                *player_id = tombstone_id;
+                *player_name = name.to_string();
+            }
+            Self::EnrollmentClosed { turn_order, .. } => {
+                for id in turn_order.iter_mut().filter(|id| **id == user_id) {
+                    *id = tombstone_id;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// An event recorded by a game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameEvent {
+    /// Position in the game's event sequence, starting at 1
+    pub seq: u64,
+    #[serde(flatten)]
+    pub kind: GameEventKind,
+    /// When it happened (ISO 8601 format)
+    pub created_at: String,
+}
@@ -1,4 +1,5 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -9,6 +10,28 @@ pub mod password;
 /// Email and password validation module
 pub mod validation;
 
+/// Playing strategies for bot players
+pub mod strategy;
+
+/// Basic strategy advisor for hints
+pub mod advisor;
+
+/// Monte Carlo simulation of strategies and rule sets
+pub mod simulation;
+
+/// Elo skill ratings updated after each settled game
+pub mod rating;
+
+/// Achievements unlocked by finished games
+pub mod achievements;
+
+/// Sequenced events recorded as a game is played
+pub mod events;
+
+use events::{GameEvent, GameEventKind};
+
+use strategy::{Action, BotStrategy, HandValue, Strategy};
+
 /// Suits available in the deck
 const SUITS: [&str; 4] = ["Hearts", "Diamonds", "Clubs", "Spades"];
 
@@ -57,6 +80,35 @@ pub struct User {
     /// Player statistics
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stats: Option<UserStats>,
+    /// Consecutive failed login attempts since the last successful login
+    #[serde(default)]
+    pub failed_login_attempts: u32,
+    /// Locked after too many failed logins, until an administrator unlocks it
+    #[serde(default)]
+    pub is_locked: bool,
+    /// Platform-wide role, independent of the per-game `GameRole`s
+    #[serde(default)]
+    pub role: PlatformRole,
+    /// Bumped to invalidate every token issued before, e.g. after a password reset
+    #[serde(default)]
+    pub session_version: u32,
+    /// Whether the owner proved they control `email`
+    #[serde(default)]
+    pub email_verified: bool,
+    /// Public name shown to other players, unique across accounts
+    #[serde(default)]
+    pub display_name: String,
+    /// Seed clients use to draw the same generated avatar everywhere
+    #[serde(default)]
+    pub avatar_seed: String,
+    /// When the owner asked to delete the account (ISO 8601 format)
+    ///
+    /// The account stays deactivated until it is anonymised after a grace period.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletion_requested_at: Option<String>,
+    /// Achievements unlocked so far, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub achievements: Vec<achievements::UnlockedAchievement>,
 }
 
 fn default_active() -> bool {
@@ -64,7 +116,7 @@ fn default_active() -> bool {
 }
 
 /// Player statistics
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserStats {
     pub games_played: u32,
     pub games_won: u32,
@@ -73,6 +125,39 @@ pub struct UserStats {
     pub total_points: u64,
     pub highest_score: u8,
     pub times_busted: u32,
+    /// Elo skill rating, see [`rating`]
+    #[serde(default = "initial_rating")]
+    pub rating: f64,
+    /// Wins in a row up to the last game
+    #[serde(default)]
+    pub current_win_streak: u32,
+    #[serde(default)]
+    pub longest_win_streak: u32,
+    /// Games in a row where the dealer busted and the player didn't
+    #[serde(default)]
+    pub dealer_bust_streak: u32,
+}
+
+fn initial_rating() -> f64 {
+    rating::INITIAL_RATING
+}
+
+impl Default for UserStats {
+    fn default() -> Self {
+        Self {
+            games_played: 0,
+            games_won: 0,
+            games_lost: 0,
+            games_tied: 0,
+            total_points: 0,
+            highest_score: 0,
+            times_busted: 0,
+            rating: rating::INITIAL_RATING,
+            current_win_streak: 0,
+            longest_win_streak: 0,
+            dealer_bust_streak: 0,
+        }
+    }
 }
 
 impl UserStats {
@@ -81,8 +166,13 @@ impl UserStats {
         Self::default()
     }
 
-    /// Records a game outcome
-    pub fn record_game(&mut self, outcome: &PlayerOutcome, points: u8) {
+    /// Rating rounded to whole points, as shown to players
+    pub fn rating_points(&self) -> u32 {
+        self.rating.round().max(0.0) as u32
+    }
+
+    /// Records a game outcome and updates the streaks
+    pub fn record_game(&mut self, outcome: &PlayerOutcome, points: u8, dealer_busted: bool) {
         self.games_played += 1;
         self.total_points += points as u64;
         if points > self.highest_score {
@@ -98,6 +188,18 @@ impl UserStats {
                 self.times_busted += 1;
             }
         }
+
+        if *outcome == PlayerOutcome::Won {
+            self.current_win_streak += 1;
+            self.longest_win_streak = self.longest_win_streak.max(self.current_win_streak);
+        } else {
+            self.current_win_streak = 0;
+        }
+        if dealer_busted && *outcome != PlayerOutcome::Busted {
+            self.dealer_bust_streak += 1;
+        } else {
+            self.dealer_bust_streak = 0;
+        }
     }
 
     /// Calculate win rate as percentage
@@ -131,15 +233,30 @@ impl User {
     ///
     /// This function does NOT validate the email or hash the password.
     /// Use `validation::validate_email()` and `password::hash_password()` first.
+    ///
+    /// The display name defaults to `player-` and the start of the user ID;
+    /// the email never leaks into it.
     pub fn new(email: String, password_hash: String) -> Self {
+        let id = Uuid::new_v4();
+        let mut seed = [0u8; 8];
+        rand::rng().fill_bytes(&mut seed);
         Self {
-            id: Uuid::new_v4(),
+            id,
             email,
             password_hash,
             is_active: true,
             last_login: None,
             created_at: Some(chrono::Utc::now().to_rfc3339()),
             stats: Some(UserStats::new()),
+            failed_login_attempts: 0,
+            is_locked: false,
+            role: PlatformRole::User,
+            session_version: 0,
+            email_verified: false,
+            display_name: format!("player-{}", &id.simple().to_string()[..8]),
+            avatar_seed: seed.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            deletion_requested_at: None,
+            achievements: Vec::new(),
         }
     }
 
@@ -158,9 +275,97 @@ impl User {
         self.is_active = false;
     }
 
-    /// Activates the user account
+    /// Activates the user account, cancelling a pending deletion
     pub fn activate(&mut self) {
         self.is_active = true;
+        self.deletion_requested_at = None;
+    }
+
+    /// Deactivates the account and signs it out ahead of its deletion
+    pub fn request_deletion(&mut self) {
+        self.deactivate();
+        self.revoke_sessions();
+        self.deletion_requested_at = Some(chrono::Utc::now().to_rfc3339());
+    }
+
+    /// Checks if the deletion grace period has run out at `now`
+    pub fn deletion_due(
+        &self,
+        grace: chrono::Duration,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        self.deletion_requested_at
+            .as_deref()
+            .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .is_some_and(|at| at.with_timezone(&chrono::Utc) + grace <= now)
+    }
+
+    /// Counts a failed login and locks the account once `max_attempts` is reached
+    ///
+    /// Returns `true` if this attempt locked the account.
+    pub fn record_failed_login(&mut self, max_attempts: u32) -> bool {
+        self.failed_login_attempts += 1;
+        if !self.is_locked && self.failed_login_attempts >= max_attempts {
+            self.is_locked = true;
+            return true;
+        }
+        false
+    }
+
+    /// Unlocks the account and forgets earlier failed logins
+    pub fn unlock(&mut self) {
+        self.is_locked = false;
+        self.failed_login_attempts = 0;
+    }
+
+    /// Signs the account out everywhere by invalidating all issued tokens
+    pub fn revoke_sessions(&mut self) {
+        self.session_version += 1;
+    }
+}
+
+/// Platform-level role of an account
+///
+/// Roles are ordered, so `role >= PlatformRole::Moderator` admits moderators
+/// and administrators alike.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::PlatformRole;
+///
+/// assert!(PlatformRole::Admin > PlatformRole::Moderator);
+/// assert!(PlatformRole::Moderator > PlatformRole::User);
+/// assert_eq!(PlatformRole::default(), PlatformRole::User);
+/// assert_eq!("moderator".parse(), Ok(PlatformRole::Moderator));
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum PlatformRole {
+    /// Regular player
+    #[default]
+    User,
+    /// Can inspect the platform and step in on games
+    Moderator,
+    /// Full control over accounts and configuration
+    Admin,
+}
+
+impl std::str::FromStr for PlatformRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(Self::User),
+            "moderator" => Ok(Self::Moderator),
+            "admin" => Ok(Self::Admin),
+            other => Err(format!(
+                "Unknown role '{}', expected user, moderator or admin",
+                other
+            )),
+        }
     }
 }
 
@@ -172,6 +377,8 @@ pub enum InvitationStatus {
     Accepted,
     Declined,
     Expired,
+    /// Withdrawn by the inviter before the invitee responded
+    Revoked,
 }
 
 /// Represents a game invitation
@@ -215,6 +422,93 @@ impl GameInvitation {
             Err(_) => false,
         }
     }
+
+    /// Checks if the invitation is still awaiting a response and has not expired
+    pub fn is_pending(&self) -> bool {
+        self.status == InvitationStatus::Pending && !self.is_expired()
+    }
+
+    /// Total lifetime of the invitation in seconds (from creation to expiration)
+    pub fn timeout_seconds(&self) -> u64 {
+        match (
+            chrono::DateTime::parse_from_rfc3339(&self.created_at),
+            chrono::DateTime::parse_from_rfc3339(&self.expires_at),
+        ) {
+            (Ok(created_at), Ok(expires_at)) => {
+                std::cmp::max(0, (expires_at - created_at).num_seconds()) as u64
+            }
+            _ => 0,
+        }
+    }
+
+    /// Seconds remaining until the invitation expires (0 once expired)
+    pub fn expires_in_seconds(&self) -> i64 {
+        match chrono::DateTime::parse_from_rfc3339(&self.expires_at) {
+            Ok(expires_at) => {
+                let remaining =
+                    (expires_at.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds();
+                std::cmp::max(0, remaining)
+            }
+            Err(_) => 0,
+        }
+    }
+}
+
+/// Shareable join link for a game
+///
+/// Unlike a `GameInvitation`, a link is not addressed to a specific email:
+/// anyone holding the signed token built from it can join the game until the
+/// link expires, is revoked, or runs out of uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteLink {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub creator_id: Uuid,
+    /// Maximum number of redemptions (`None` = unlimited)
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    pub revoked: bool,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+impl InviteLink {
+    /// Creates a new link expiring together with the game's enrollment window
+    pub fn new(
+        game_id: Uuid,
+        creator_id: Uuid,
+        max_uses: Option<u32>,
+        game_enrollment_expires_at: String,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            game_id,
+            creator_id,
+            max_uses,
+            uses: 0,
+            revoked: false,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            expires_at: game_enrollment_expires_at,
+        }
+    }
+
+    /// Checks if the link has expired
+    pub fn is_expired(&self) -> bool {
+        match chrono::DateTime::parse_from_rfc3339(&self.expires_at) {
+            Ok(expires_at) => chrono::Utc::now() > expires_at,
+            Err(_) => false,
+        }
+    }
+
+    /// Checks if every allowed use has been consumed
+    pub fn is_exhausted(&self) -> bool {
+        self.max_uses.is_some_and(|max| self.uses >= max)
+    }
+
+    /// Remaining redemptions (`None` = unlimited)
+    pub fn remaining_uses(&self) -> Option<u32> {
+        self.max_uses.map(|max| max.saturating_sub(self.uses))
+    }
 }
 
 /// State of a player in the game
@@ -229,7 +523,10 @@ pub enum PlayerState {
 /// Represents a player in the game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
-    pub email: String,
+    /// User ID of the seat, or a generated ID for bots and the dealer
+    pub id: Uuid,
+    /// Name shown to the table: the user's display name, `bot-N` or `dealer`
+    pub name: String,
     pub points: u8,
     pub cards_history: Vec<Card>,
     /// Maps card_id to is_eleven (true = 11 points, false = 1 point)
@@ -239,10 +536,11 @@ pub struct Player {
 }
 
 impl Player {
-    /// Creates a new player with the given email
-    pub fn new(email: String) -> Self {
+    /// Creates a new player with the given ID and display name
+    pub fn new(id: Uuid, name: String) -> Self {
         Self {
-            email,
+            id,
+            name,
             points: 0,
             cards_history: Vec::new(),
             ace_values: HashMap::new(),
@@ -299,19 +597,56 @@ pub enum PlayerOutcome {
     Busted,
 }
 
+impl PlayerOutcome {
+    /// Bets won (or lost, if negative) by a one-unit bet with this outcome
+    ///
+    /// A win pays even money and a blackjack (`natural`) win 3:2; a push pays
+    /// nothing and a loss or bust costs the bet.
+    pub fn payout(&self, natural: bool) -> f64 {
+        match self {
+            PlayerOutcome::Won if natural => 1.5,
+            PlayerOutcome::Won => 1.0,
+            PlayerOutcome::Push => 0.0,
+            PlayerOutcome::Lost | PlayerOutcome::Busted => -1.0,
+        }
+    }
+}
+
 /// Detailed result information for a player
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerResult {
+    /// ID of the seat, see [`Player::id`]
+    pub player_id: Uuid,
     pub points: u8,
     pub cards_count: usize,
     pub busted: bool,
     pub outcome: PlayerOutcome,
+    /// Whether this seat was played by a bot
+    #[serde(default)]
+    pub is_bot: bool,
+    /// Number of strategy hints the player asked for
+    #[serde(default)]
+    pub hints_used: u32,
+}
+
+impl PlayerResult {
+    /// Checks if the hand was a blackjack (21 with the first two cards)
+    pub fn is_blackjack(&self) -> bool {
+        self.points == 21 && self.cards_count == 2
+    }
+
+    /// Bets the hand won or lost, see [`PlayerOutcome::payout`]
+    pub fn payout(&self) -> f64 {
+        self.outcome.payout(self.is_blackjack())
+    }
 }
 
 /// Result of a finished game
+///
+/// Players are identified by their display names.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameResult {
-    /// Email of the single winner (if no ties)
+    /// Name of the single winner (if no ties)
     pub winner: Option<String>,
     /// List of players who tied for the win
     pub tied_players: Vec<String>,
@@ -334,7 +669,7 @@ pub enum GameError {
     PlayerNotInGame,
     PlayerAlreadyBusted,
     InvalidPlayerCount,
-    InvalidEmail,
+    InvalidPlayerName,
     DeckEmpty,
     GameAlreadyFinished,
     CardNotFound,
@@ -359,7 +694,7 @@ impl std::fmt::Display for GameError {
             GameError::PlayerNotInGame => write!(f, "Player not in this game"),
             GameError::PlayerAlreadyBusted => write!(f, "Player already busted"),
             GameError::InvalidPlayerCount => write!(f, "Invalid player count (must be 1-10)"),
-            GameError::InvalidEmail => write!(f, "Invalid email (cannot be empty)"),
+            GameError::InvalidPlayerName => write!(f, "Invalid player name (cannot be empty)"),
             GameError::DeckEmpty => write!(f, "No more cards in the deck"),
             GameError::GameAlreadyFinished => write!(f, "Game has already finished"),
             GameError::CardNotFound => write!(f, "Card not found in player's hand"),
@@ -407,6 +742,10 @@ pub enum GamePermission {
     FinishGame,
     /// Modify game settings
     ModifySettings,
+    /// Seat bot players at the table
+    AddBots,
+    /// Mute participants in the table chat and delete their messages
+    ModerateChat,
 }
 
 impl GameRole {
@@ -443,27 +782,115 @@ impl GameRole {
                 GamePermission::CloseEnrollment,
                 GamePermission::FinishGame,
                 GamePermission::ModifySettings,
+                GamePermission::AddBots,
+                GamePermission::ModerateChat,
             ],
             GameRole::Player | GameRole::Spectator => vec![],
         }
     }
 }
 
+/// Game visibility - controls who can discover and join a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameVisibility {
+    /// Listed in the open games lobby, anyone can enroll
+    #[default]
+    Public,
+    /// Not listed, but anyone who knows the game ID can enroll
+    Unlisted,
+    /// Not listed, players can only join through an invitation or invite link
+    Private,
+}
+
+/// Largest table the engine supports
+pub const MAX_TABLE_SIZE: u8 = 10;
+
+/// Table configuration chosen when a game is created
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableRules {
+    /// Number of seats at the table (1 to `MAX_TABLE_SIZE`)
+    pub max_players: u8,
+    /// Smallest bet accepted at the table, in chips
+    pub min_bet: u32,
+    /// Largest bet accepted at the table, in chips
+    pub max_bet: u32,
+    /// Whether players may double down on their first two cards
+    pub double_allowed: bool,
+    /// Whether players may split a pair
+    pub split_allowed: bool,
+    /// Whether players may surrender their first two cards (late surrender)
+    pub surrender_allowed: bool,
+}
+
+impl Default for TableRules {
+    fn default() -> Self {
+        Self {
+            max_players: MAX_TABLE_SIZE,
+            min_bet: 10,
+            max_bet: 500,
+            double_allowed: false,
+            split_allowed: false,
+            surrender_allowed: false,
+        }
+    }
+}
+
+impl TableRules {
+    /// Checks the rules are internally consistent
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_players == 0 || self.max_players > MAX_TABLE_SIZE {
+            return Err(format!(
+                "Table size must be between 1 and {}",
+                MAX_TABLE_SIZE
+            ));
+        }
+        if self.min_bet == 0 {
+            return Err("Minimum bet must be at least 1".to_string());
+        }
+        if self.max_bet < self.min_bet {
+            return Err("Maximum bet cannot be lower than minimum bet".to_string());
+        }
+        Ok(())
+    }
+
+    /// Checks the rules only use plays the game engine can deal
+    ///
+    /// Tables deal hit and stand only; doubling, splitting and surrender are
+    /// understood by the advisor but not playable yet.
+    pub fn check_engine_support(&self) -> Result<(), String> {
+        if self.double_allowed || self.split_allowed || self.surrender_allowed {
+            return Err(
+                "Doubling, splitting and surrender are not supported at game tables yet"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks if the table accepts the given bet
+    pub fn accepts_bet(&self, bet: u32) -> bool {
+        (self.min_bet..=self.max_bet).contains(&bet)
+    }
+}
+
 /// Represents a participant in a game with their role
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameParticipant {
     pub user_id: Uuid,
-    pub email: String,
+    /// Display name at the time the user joined
+    pub display_name: String,
     pub role: GameRole,
     pub joined_at: String,
 }
 
 impl GameParticipant {
     /// Creates a new game participant
-    pub fn new(user_id: Uuid, email: String, role: GameRole) -> Self {
+    pub fn new(user_id: Uuid, display_name: String, role: GameRole) -> Self {
         Self {
             user_id,
-            email,
+            display_name,
             role,
             joined_at: chrono::Utc::now().to_rfc3339(),
         }
@@ -477,16 +904,42 @@ pub struct Game {
     pub creator_id: Uuid,
     /// Participants with their roles (user_id -> GameParticipant)
     pub participants: HashMap<Uuid, GameParticipant>,
-    pub players: HashMap<String, Player>,
+    /// Seated players by ID (user ID for humans)
+    pub players: HashMap<Uuid, Player>,
     pub dealer: Player,
     pub available_cards: Vec<Card>,
     pub finished: bool,
-    pub turn_order: Vec<String>,
+    /// Player IDs in seating order
+    pub turn_order: Vec<Uuid>,
     pub current_turn_index: usize,
     pub enrollment_timeout_seconds: u64,
     pub enrollment_start_time: String,
     pub enrollment_closed: bool,
     pub active: bool,
+    /// Who can discover and join the game
+    #[serde(default)]
+    pub visibility: GameVisibility,
+    /// Argon2id hash of the optional join password - NEVER store plaintext
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join_password_hash: Option<String>,
+    /// Table size and stakes
+    #[serde(default)]
+    pub rules: TableRules,
+    /// Bot seats (player ID -> strategy); bots have no user account
+    #[serde(default)]
+    pub bots: HashMap<Uuid, BotStrategy>,
+    /// Whether the final results were already recorded to player statistics
+    #[serde(default)]
+    pub results_recorded: bool,
+    /// Strategy hints requested per player (player ID -> count)
+    #[serde(default)]
+    pub hints_used: HashMap<Uuid, u32>,
+    /// Everything that happened at the table, oldest first
+    #[serde(default)]
+    pub events: Vec<GameEvent>,
+    /// Seeded card source for reproducible games, see [`Game::seed_deck`]
+    #[serde(skip)]
+    rng: Option<StdRng>,
 }
 
 impl Game {
@@ -494,12 +947,12 @@ impl Game {
     #[tracing::instrument]
     pub fn new(
         creator_id: Uuid,
-        creator_email: String,
+        creator_name: String,
         enrollment_timeout_seconds: u64,
     ) -> Result<Self, GameError> {
-        // Validate email is not empty
-        if creator_email.trim().is_empty() {
-            return Err(GameError::InvalidEmail);
+        // Validate name is not empty
+        if creator_name.trim().is_empty() {
+            return Err(GameError::InvalidPlayerName);
         }
 
         // Initialize 52-card deck (4 of each card type across 4 suits)
@@ -517,18 +970,18 @@ impl Game {
 
         // Auto-enroll creator as first player
         let mut players = HashMap::new();
-        players.insert(creator_email.clone(), Player::new(creator_email.clone()));
+        players.insert(creator_id, Player::new(creator_id, creator_name.clone()));
 
         // Initialize participants with creator as Creator role
         let mut participants = HashMap::new();
         participants.insert(
             creator_id,
-            GameParticipant::new(creator_id, creator_email.clone(), GameRole::Creator),
+            GameParticipant::new(creator_id, creator_name, GameRole::Creator),
         );
 
-        let turn_order = vec![creator_email];
+        let turn_order = vec![creator_id];
 
-        let dealer = Player::new("dealer".to_string());
+        let dealer = Player::new(Uuid::nil(), "dealer".to_string());
 
         Ok(Self {
             id: Uuid::new_v4(),
@@ -544,12 +997,20 @@ impl Game {
             enrollment_start_time: chrono::Utc::now().to_rfc3339(),
             enrollment_closed: false,
             active: true,
+            visibility: GameVisibility::default(),
+            join_password_hash: None,
+            rules: TableRules::default(),
+            bots: HashMap::new(),
+            results_recorded: false,
+            hints_used: HashMap::new(),
+            events: Vec::new(),
+            rng: None,
         })
     }
 
     /// Draws a card for the specified player
     #[tracing::instrument(skip(self))]
-    pub fn draw_card(&mut self, email: &str) -> Result<Card, GameError> {
+    pub fn draw_card(&mut self, player_id: Uuid) -> Result<Card, GameError> {
         if self.finished {
             return Err(GameError::GameAlreadyFinished);
         }
@@ -564,13 +1025,13 @@ impl Game {
         }
 
         // Check if it's the player's turn
-        if !self.can_player_act(email) {
+        if !self.can_player_act(player_id) {
             return Err(GameError::NotPlayerTurn);
         }
 
         let player = self
             .players
-            .get_mut(email)
+            .get_mut(&player_id)
             .ok_or(GameError::PlayerNotInGame)?;
 
         if player.busted {
@@ -582,10 +1043,17 @@ impl Game {
         }
 
         // Draw a random card from the deck
-        let random_index = rand::rng().random_range(0..self.available_cards.len());
-        let card = self.available_cards.remove(random_index);
+        let card = Self::take_random_card(&mut self.available_cards, &mut self.rng);
 
         player.add_card(card.clone());
+        let event = GameEventKind::CardDrawn {
+            player_id,
+            player_name: player.name.clone(),
+            card: card.clone(),
+            points: player.points,
+            busted: player.busted,
+        };
+        self.record_event(event);
 
         // Advance turn after drawing
         self.advance_turn();
@@ -596,14 +1064,15 @@ impl Game {
             // All players finished, play dealer automatically
             self.play_dealer()?;
             self.finished = true;
+            self.record_event(GameEventKind::GameFinished { aborted: false });
             tracing::info!("Game automatically finished after dealer play");
         }
 
         Ok(card)
     }
 
-    /// Adds a player to the game (from invitation acceptance)
-    pub fn add_player(&mut self, email: String) -> Result<(), GameError> {
+    /// Seats a player under `name`, which must be unique at the table
+    pub fn add_player(&mut self, player_id: Uuid, name: String) -> Result<(), GameError> {
         if !self.active {
             return Err(GameError::GameNotActive);
         }
@@ -616,26 +1085,199 @@ impl Game {
             return Err(GameError::InvalidPlayerCount);
         }
 
-        // Validate email is not empty
-        if email.trim().is_empty() {
-            return Err(GameError::InvalidEmail);
+        // Validate name is not empty
+        if name.trim().is_empty() {
+            return Err(GameError::InvalidPlayerName);
         }
 
-        if self.players.contains_key(&email) {
+        if self.players.contains_key(&player_id) || self.player_id(&name).is_some() {
             return Err(GameError::PlayerAlreadyEnrolled);
         }
 
-        if self.players.len() >= 10 {
+        if self.players.len() >= self.rules.max_players as usize {
             return Err(GameError::InvalidPlayerCount);
         }
 
-        self.players
-            .insert(email.clone(), Player::new(email.clone()));
-        self.turn_order.push(email);
+        self.players.insert(player_id, Player::new(player_id, name));
+        self.turn_order.push(player_id);
 
         Ok(())
     }
 
+    /// Removes a player who hasn't played yet, e.g. when kicked during enrollment
+    pub fn remove_player(&mut self, player_id: Uuid) -> Option<Player> {
+        let player = self.players.remove(&player_id)?;
+        self.turn_order.retain(|id| *id != player_id);
+        self.participants.remove(&player_id);
+        self.bots.remove(&player_id);
+        self.hints_used.remove(&player_id);
+        Some(player)
+    }
+
+    /// ID of the player seated under `name`
+    pub fn player_id(&self, name: &str) -> Option<Uuid> {
+        self.players
+            .values()
+            .find(|player| player.name == name)
+            .map(|player| player.id)
+    }
+
+    /// Moves a deleted user's seat to `tombstone_id` under `name`
+    ///
+    /// The hand and result stay in the game so other players keep their
+    /// history. Returns whether the user had a seat or role here.
+    pub fn anonymize_player(&mut self, user_id: Uuid, tombstone_id: Uuid, name: &str) -> bool {
+        let mut found = false;
+
+        if let Some(mut player) = self.players.remove(&user_id) {
+            player.id = tombstone_id;
+            player.name = name.to_string();
+            self.players.insert(tombstone_id, player);
+            found = true;
+        }
+        for id in self.turn_order.iter_mut().filter(|id| **id == user_id) {
+            *id = tombstone_id;
+        }
+        if let Some(mut participant) = self.participants.remove(&user_id) {
+            participant.user_id = tombstone_id;
+            participant.display_name = name.to_string();
+            self.participants.insert(tombstone_id, participant);
+            found = true;
+        }
+        if let Some(hints) = self.hints_used.remove(&user_id) {
+            self.hints_used.insert(tombstone_id, hints);
+        }
+        for event in &mut self.events {
+            event.kind.anonymize_player(user_id, tombstone_id, name);
+        }
+        if self.creator_id == user_id {
+            self.creator_id = tombstone_id;
+            found = true;
+        }
+
+        found
+    }
+
+    /// Display name of the player with `player_id`
+    pub fn player_name(&self, player_id: Uuid) -> Option<&str> {
+        self.players.get(&player_id).map(|p| p.name.as_str())
+    }
+
+    /// Seats a bot player using the given strategy
+    ///
+    /// Bots are named `bot-1`, `bot-2`, ... and take a seat like any player
+    /// under a generated ID. Returns the bot's player ID.
+    pub fn add_bot(&mut self, strategy: BotStrategy) -> Result<Uuid, GameError> {
+        let name = (1..)
+            .map(|n| format!("bot-{}", n))
+            .find(|name| self.player_id(name).is_none())
+            .expect("unbounded range always yields a free name");
+
+        let bot_id = Uuid::new_v4();
+        self.add_player(bot_id, name)?;
+        self.bots.insert(bot_id, strategy);
+
+        Ok(bot_id)
+    }
+
+    /// Checks if the player is a bot
+    pub fn is_bot(&self, player_id: Uuid) -> bool {
+        self.bots.contains_key(&player_id)
+    }
+
+    /// The dealer's face-up card, dealt when enrollment closes
+    pub fn dealer_up_card(&self) -> Option<&Card> {
+        self.dealer.cards_history.first()
+    }
+
+    /// Recommends the basic strategy play for the player whose turn it is
+    ///
+    /// Every hint is counted and reported in the player's final result.
+    pub fn hint(&mut self, player_id: Uuid) -> Result<advisor::Advice, GameError> {
+        if self.finished {
+            return Err(GameError::GameAlreadyFinished);
+        }
+
+        if !self.enrollment_closed {
+            return Err(GameError::EnrollmentNotClosed);
+        }
+
+        if !self.can_player_act(player_id) {
+            return Err(GameError::NotPlayerTurn);
+        }
+
+        let player = self
+            .players
+            .get(&player_id)
+            .ok_or(GameError::PlayerNotInGame)?;
+        let advice = advisor::recommend(
+            &player.cards_history,
+            self.dealer.cards_history.first(),
+            &self.rules,
+        );
+
+        *self.hints_used.entry(player_id).or_insert(0) += 1;
+
+        Ok(advice)
+    }
+
+    /// Makes every card dealt from now on reproducible for the given seed
+    pub fn seed_deck(&mut self, seed: u64) {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+    }
+
+    /// Removes a random card from the deck, using the seeded source if any
+    ///
+    /// The deck must not be empty.
+    fn take_random_card(available_cards: &mut Vec<Card>, rng: &mut Option<StdRng>) -> Card {
+        let random_index = match rng {
+            Some(rng) => rng.random_range(0..available_cards.len()),
+            None => rand::rng().random_range(0..available_cards.len()),
+        };
+        available_cards.remove(random_index)
+    }
+
+    /// Lets a strategy make the next decision for the player
+    ///
+    /// The engine only deals hit and stand: a double is played as a hit and
+    /// split or surrender as a stand. Before standing a soft Ace is counted as
+    /// 11, and a player who can't draw because the deck ran out stands instead.
+    /// Returns the action actually taken.
+    pub fn play_strategy_turn(
+        &mut self,
+        player_id: Uuid,
+        strategy: &dyn Strategy,
+        rng: &mut dyn RngCore,
+    ) -> Result<Action, GameError> {
+        let hand = self
+            .players
+            .get(&player_id)
+            .ok_or(GameError::PlayerNotInGame)?
+            .cards_history
+            .clone();
+        let action = strategy.decide(&hand, self.dealer_up_card(), &self.rules, rng);
+
+        // Aces stay at 1 while hitting so a draw can never bust a soft hand
+        if matches!(action, Action::Hit | Action::Double) {
+            match self.draw_card(player_id) {
+                Ok(_) => return Ok(Action::Hit),
+                Err(GameError::DeckEmpty) => {
+                    tracing::warn!(player_id = %player_id, "Deck empty, standing instead of hitting");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if HandValue::of(&hand).soft
+            && let Some(ace) = hand.iter().find(|card| card.name == "A")
+        {
+            self.set_ace_value(player_id, ace.id, true)?;
+        }
+        self.stand(player_id)?;
+
+        Ok(Action::Stand)
+    }
+
     /// Checks if enrollment is still open (not closed and timeout not exceeded)
     pub fn is_enrollment_open(&self) -> bool {
         if self.enrollment_closed {
@@ -652,9 +1294,24 @@ impl Game {
         false
     }
 
+    /// Checks if the game is shown in the public lobby
+    pub fn is_listed(&self) -> bool {
+        self.visibility == GameVisibility::Public
+    }
+
+    /// Checks if joining requires a password
+    pub fn is_password_protected(&self) -> bool {
+        self.join_password_hash.is_some()
+    }
+
     /// Checks if can enroll (space available and enrollment is open)
     pub fn can_enroll(&self) -> bool {
-        self.is_enrollment_open() && self.players.len() < 10
+        self.is_enrollment_open() && self.free_seats() > 0
+    }
+
+    /// Number of seats still available at the table
+    pub fn free_seats(&self) -> usize {
+        (self.rules.max_players as usize).saturating_sub(self.players.len())
     }
 
     /// Closes enrollment and finalizes turn order
@@ -663,11 +1320,25 @@ impl Game {
             return Err(GameError::GameAlreadyFinished);
         }
 
+        let was_closed = self.enrollment_closed;
         self.enrollment_closed = true;
 
         // Reset turn index to start
         self.current_turn_index = 0;
 
+        // Deal the dealer's up-card so players can see what they play against
+        if self.dealer.cards_history.is_empty() && !self.available_cards.is_empty() {
+            let card = Self::take_random_card(&mut self.available_cards, &mut self.rng);
+            self.dealer.add_card(card);
+        }
+
+        if !was_closed {
+            self.record_event(GameEventKind::EnrollmentClosed {
+                turn_order: self.turn_order.clone(),
+                dealer_up_card: self.dealer_up_card().cloned(),
+            });
+        }
+
         Ok(())
     }
 
@@ -783,27 +1454,27 @@ impl Game {
     /// # Arguments
     ///
     /// * `user_id` - The user's ID
-    /// * `email` - The user's email
+    /// * `display_name` - The user's display name
     ///
     /// # Note
     ///
     /// Players are added with `GameRole::Player` role.
     /// Only the creator gets `GameRole::Creator`.
-    pub fn add_participant(&mut self, user_id: Uuid, email: String) {
+    pub fn add_participant(&mut self, user_id: Uuid, display_name: String) {
         self.participants.insert(
             user_id,
-            GameParticipant::new(user_id, email, GameRole::Player),
+            GameParticipant::new(user_id, display_name, GameRole::Player),
         );
     }
 
-    /// Gets the email of the player whose turn it is
-    pub fn get_current_player(&self) -> Option<&str> {
-        if self.turn_order.is_empty() {
-            return None;
-        }
-        self.turn_order
-            .get(self.current_turn_index)
-            .map(|s| s.as_str())
+    /// Gets the ID of the player whose turn it is
+    pub fn get_current_player(&self) -> Option<Uuid> {
+        self.turn_order.get(self.current_turn_index).copied()
+    }
+
+    /// Gets the display name of the player whose turn it is
+    pub fn current_player_name(&self) -> Option<&str> {
+        self.player_name(self.get_current_player()?)
     }
 
     /// Advances to the next active player's turn
@@ -813,6 +1484,7 @@ impl Game {
         }
 
         let initial_index = self.current_turn_index;
+        let initial_player = self.get_current_player();
         loop {
             self.current_turn_index = (self.current_turn_index + 1) % self.turn_order.len();
 
@@ -822,24 +1494,33 @@ impl Game {
             }
 
             // Check if current player is active
-            if let Some(email) = self.turn_order.get(self.current_turn_index)
-                && let Some(player) = self.players.get(email)
+            if let Some(player_id) = self.turn_order.get(self.current_turn_index)
+                && let Some(player) = self.players.get(player_id)
                 && player.state == PlayerState::Active
             {
                 break;
             }
         }
+
+        if let Some(player_id) = self.get_current_player()
+            && Some(player_id) != initial_player
+        {
+            let player_name = self.player_name(player_id).unwrap_or_default().to_string();
+            self.record_event(GameEventKind::TurnChanged {
+                player_id,
+                player_name,
+            });
+        }
     }
 
     /// Checks if the specified player can act (it's their turn and they're active and enrollment is closed)
-    pub fn can_player_act(&self, email: &str) -> bool {
+    pub fn can_player_act(&self, player_id: Uuid) -> bool {
         if !self.enrollment_closed {
             return false;
         }
 
-        if let Some(current_email) = self.get_current_player()
-            && current_email == email
-            && let Some(player) = self.players.get(email)
+        if self.get_current_player() == Some(player_id)
+            && let Some(player) = self.players.get(&player_id)
         {
             return player.state == PlayerState::Active;
         }
@@ -848,7 +1529,7 @@ impl Game {
 
     /// Marks a player as standing (done playing)
     #[tracing::instrument(skip(self))]
-    pub fn stand(&mut self, email: &str) -> Result<(), GameError> {
+    pub fn stand(&mut self, player_id: Uuid) -> Result<(), GameError> {
         if self.finished {
             return Err(GameError::GameAlreadyFinished);
         }
@@ -859,13 +1540,13 @@ impl Game {
         }
 
         // Check if it's the player's turn
-        if !self.can_player_act(email) {
+        if !self.can_player_act(player_id) {
             return Err(GameError::NotPlayerTurn);
         }
 
         let player = self
             .players
-            .get_mut(email)
+            .get_mut(&player_id)
             .ok_or(GameError::PlayerNotInGame)?;
 
         if player.state != PlayerState::Active {
@@ -883,6 +1564,7 @@ impl Game {
             // All players finished, play dealer automatically
             self.play_dealer()?;
             self.finished = true;
+            self.record_event(GameEventKind::GameFinished { aborted: false });
             tracing::info!("Game automatically finished after dealer play");
         }
 
@@ -917,8 +1599,8 @@ impl Game {
     ///
     /// # Example Flow
     /// ```text
-    /// Dealer starts with 0 points
-    /// Draws cards: 7, 8 (total: 15) - continues
+    /// Dealer starts with the up-card dealt at enrollment close: 7
+    /// Draws card: 8 (total: 15) - continues
     /// Draws card: 5 (total: 20) - stops (>= 17)
     /// Final state: Standing with 20 points
     /// ```
@@ -937,8 +1619,7 @@ impl Game {
                 return Err(GameError::DeckEmpty);
             }
 
-            let random_index = rand::rng().random_range(0..self.available_cards.len());
-            let card = self.available_cards.remove(random_index);
+            let card = Self::take_random_card(&mut self.available_cards, &mut self.rng);
 
             tracing::debug!(
                 "Dealer draws {} of {} (value: {})",
@@ -968,6 +1649,12 @@ impl Game {
             );
         }
 
+        self.record_event(GameEventKind::DealerPlayed {
+            cards: self.dealer.cards_history.clone(),
+            points: self.dealer.points,
+            busted: self.dealer.busted,
+        });
+
         Ok(())
     }
 
@@ -975,7 +1662,7 @@ impl Game {
     #[tracing::instrument(skip(self))]
     pub fn set_ace_value(
         &mut self,
-        email: &str,
+        player_id: Uuid,
         card_id: Uuid,
         as_eleven: bool,
     ) -> Result<(), GameError> {
@@ -985,7 +1672,7 @@ impl Game {
 
         let player = self
             .players
-            .get_mut(email)
+            .get_mut(&player_id)
             .ok_or(GameError::PlayerNotInGame)?;
 
         // Verify the card exists in player's hand
@@ -1009,7 +1696,40 @@ impl Game {
 
     /// Marks the game as finished
     pub fn finish_game(&mut self) {
+        if !self.finished {
+            self.finished = true;
+            self.record_event(GameEventKind::GameFinished { aborted: false });
+        }
+    }
+
+    /// Ends the game without playing the dealer or counting results
+    ///
+    /// Used by administrators to clear stuck games. An aborted game is
+    /// finished and no longer active, so it never reaches player statistics.
+    pub fn abort(&mut self) {
+        if !self.finished {
+            self.record_event(GameEventKind::GameFinished { aborted: true });
+        }
         self.finished = true;
+        self.active = false;
+    }
+
+    /// Events with a sequence number above `seq`, oldest first
+    pub fn events_after(&self, seq: u64) -> &[GameEvent] {
+        // Sequence numbers start at 1 and have no gaps
+        let start = usize::try_from(seq)
+            .unwrap_or(usize::MAX)
+            .min(self.events.len());
+        &self.events[start..]
+    }
+
+    /// Appends an event with the next sequence number
+    fn record_event(&mut self, kind: GameEventKind) {
+        self.events.push(GameEvent {
+            seq: self.events.len() as u64 + 1,
+            kind,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        });
     }
 
     /// Calculates the game results
@@ -1031,9 +1751,9 @@ impl Game {
         );
 
         // Build player summaries
-        for (email, player) in &self.players {
+        for player in self.players.values() {
             all_players.insert(
-                email.clone(),
+                player.name.clone(),
                 PlayerSummary {
                     points: player.points,
                     cards_count: player.cards_history.len(),
@@ -1050,7 +1770,8 @@ impl Game {
         };
 
         // Calculate individual player results and find winner(s)
-        for (email, player) in &self.players {
+        for (player_id, player) in &self.players {
+            let name = &player.name;
             let outcome = if player.busted {
                 PlayerOutcome::Busted
             } else if dealer_score == 0 {
@@ -1068,12 +1789,15 @@ impl Game {
             };
 
             player_results.insert(
-                email.clone(),
+                name.clone(),
                 PlayerResult {
+                    player_id: *player_id,
                     points: player.points,
                     cards_count: player.cards_history.len(),
                     busted: player.busted,
                     outcome,
+                    is_bot: self.is_bot(*player_id),
+                    hints_used: self.hints_used.get(player_id).copied().unwrap_or(0),
                 },
             );
 
@@ -1082,19 +1806,19 @@ impl Game {
                 if dealer_score == 0 {
                     // Dealer busted, all non-busted players win
                     if player.points == highest_score && highest_score > 0 {
-                        tied_players.push(email.clone());
+                        tied_players.push(name.clone());
                     } else if player.points > highest_score {
                         highest_score = player.points;
-                        winner = Some(email.clone());
+                        winner = Some(name.clone());
                         tied_players.clear();
                     }
                 } else if player.points > dealer_score {
                     // Player beat dealer
                     if player.points == highest_score && highest_score > 0 {
-                        tied_players.push(email.clone());
+                        tied_players.push(name.clone());
                     } else if player.points > highest_score {
                         highest_score = player.points;
-                        winner = Some(email.clone());
+                        winner = Some(name.clone());
                         tied_players.clear();
                     }
                 }
@@ -1103,8 +1827,8 @@ impl Game {
 
         // If there are tied players, add the original winner to the list
         if !tied_players.is_empty() {
-            if let Some(winner_email) = &winner {
-                tied_players.insert(0, winner_email.clone());
+            if let Some(winner_name) = &winner {
+                tied_players.insert(0, winner_name.clone());
             }
             winner = None; // Clear single winner if there's a tie
         }
@@ -0,0 +1,129 @@
+/// Elo skill ratings
+///
+/// After a game settles, every rated seat plays one match against the dealer
+/// and one against each other rated seat at the table. Against the dealer the
+/// hand's outcome is the score (win 1, push ½, loss or bust 0); between two
+/// players the better outcome wins the match and equal outcomes draw. The
+/// rating moves by `K × (actual − expected)` averaged over the matches, so a
+/// game at a full table weighs as much as a heads-up game.
+///
+/// New players use a larger K factor until they have played
+/// [`PROVISIONAL_GAMES`] games, so their rating settles quickly.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::PlayerOutcome;
+/// use blackjack_core::rating::{INITIAL_RATING, RatedSeat, rating_changes};
+///
+/// let seats = [
+///     RatedSeat { rating: INITIAL_RATING, games_played: 30, outcome: PlayerOutcome::Won },
+///     RatedSeat { rating: INITIAL_RATING, games_played: 30, outcome: PlayerOutcome::Busted },
+/// ];
+/// let changes = rating_changes(&seats);
+///
+/// assert!(changes[0] > 0.0);
+/// assert!(changes[1] < 0.0);
+/// ```
+use crate::PlayerOutcome;
+use serde::{Deserialize, Serialize};
+
+/// Rating of a player who has not played yet
+pub const INITIAL_RATING: f64 = 1500.0;
+
+/// Fixed rating of the dealer
+pub const DEALER_RATING: f64 = 1500.0;
+
+/// Games played with the provisional K factor
+pub const PROVISIONAL_GAMES: u32 = 20;
+
+/// K factor while a rating is provisional
+const PROVISIONAL_K_FACTOR: f64 = 40.0;
+
+/// K factor once a rating is established
+const K_FACTOR: f64 = 20.0;
+
+/// A rated player's seat at a settled table
+#[derive(Debug, Clone)]
+pub struct RatedSeat {
+    /// Rating before the game
+    pub rating: f64,
+    /// Games played before this one
+    pub games_played: u32,
+    pub outcome: PlayerOutcome,
+}
+
+/// Chance of `rating` beating `opponent`, between 0 and 1
+pub fn expected_score(rating: f64, opponent: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent - rating) / 400.0))
+}
+
+/// Score of a hand against the dealer
+pub fn outcome_score(outcome: &PlayerOutcome) -> f64 {
+    match outcome {
+        PlayerOutcome::Won => 1.0,
+        PlayerOutcome::Push => 0.5,
+        PlayerOutcome::Lost | PlayerOutcome::Busted => 0.0,
+    }
+}
+
+/// K factor for a player with `games_played` games
+pub fn k_factor(games_played: u32) -> f64 {
+    if games_played < PROVISIONAL_GAMES {
+        PROVISIONAL_K_FACTOR
+    } else {
+        K_FACTOR
+    }
+}
+
+/// Rating change of every seat, in the same order as `seats`
+pub fn rating_changes(seats: &[RatedSeat]) -> Vec<f64> {
+    seats
+        .iter()
+        .enumerate()
+        .map(|(index, seat)| {
+            let score = outcome_score(&seat.outcome);
+            let mut actual = score;
+            let mut expected = expected_score(seat.rating, DEALER_RATING);
+
+            for (_, other) in seats.iter().enumerate().filter(|(i, _)| *i != index) {
+                let other_score = outcome_score(&other.outcome);
+                actual += match score.total_cmp(&other_score) {
+                    std::cmp::Ordering::Greater => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Less => 0.0,
+                };
+                expected += expected_score(seat.rating, other.rating);
+            }
+
+            k_factor(seat.games_played) * (actual - expected) / seats.len() as f64
+        })
+        .collect()
+}
+
+/// Inclusive range of ratings; an open end accepts any rating
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatingBand {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+}
+
+impl RatingBand {
+    /// Checks if `rating` lies within the band
+    pub fn contains(&self, rating: u32) -> bool {
+        self.min.is_none_or(|min| rating >= min) && self.max.is_none_or(|max| rating <= max)
+    }
+
+    /// Validates that the band is not empty
+    pub fn validate(&self) -> Result<(), String> {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if min > max => Err(format!(
+                "Rating band minimum {} is above its maximum {}",
+                min, max
+            )),
+            _ => Ok(()),
+        }
+    }
+}
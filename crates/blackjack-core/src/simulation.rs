@@ -0,0 +1,287 @@
+/// Monte Carlo simulation of a strategy against the dealer
+///
+/// Plays many rounds through the regular [`Game`] engine and reports the
+/// expected value and outcome rates per hand. Rounds are split into fixed
+/// chunks that are seeded from the configured seed, so the same seed always
+/// gives the same report no matter how many threads do the work.
+///
+/// Every hand is a flat one-unit bet paid out by [`PlayerOutcome::payout`]:
+/// a win pays +1, a blackjack +1.5, a push 0, a loss or bust -1.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::simulation::{SimulationConfig, run};
+/// use blackjack_core::strategy::BotStrategy;
+///
+/// let config = SimulationConfig {
+///     rounds: 2_000,
+///     strategy: BotStrategy::MimicDealer,
+///     seed: 42,
+///     threads: 2,
+///     ..SimulationConfig::default()
+/// };
+///
+/// let report = run(&config).unwrap();
+/// assert_eq!(report.hands, 2_000);
+/// assert_eq!(report, run(&SimulationConfig { threads: 1, ..config }).unwrap());
+/// ```
+use crate::strategy::{BotStrategy, Strategy};
+use crate::{Game, GameError, PlayerOutcome, TableRules};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Rounds played with one seeded random source
+const CHUNK_ROUNDS: u64 = 10_000;
+
+/// What to simulate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    /// Number of rounds (one shoe per round)
+    pub rounds: u64,
+    /// Seats at the table, all played with the same strategy
+    pub seats: u8,
+    /// Table rules; the engine only deals hit and stand
+    pub rules: TableRules,
+    /// Strategy used for every seat
+    pub strategy: BotStrategy,
+    /// Seed for the cards and any randomized strategy
+    pub seed: u64,
+    /// Worker threads
+    pub threads: usize,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            rounds: 100_000,
+            seats: 1,
+            rules: TableRules::default(),
+            strategy: BotStrategy::default(),
+            seed: 0,
+            threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Checks the configuration can be simulated
+    pub fn validate(&self) -> Result<(), String> {
+        self.rules.validate()?;
+        self.rules.check_engine_support()?;
+        if self.rounds == 0 {
+            return Err("At least one round must be simulated".to_string());
+        }
+        if self.seats == 0 || self.seats > self.rules.max_players {
+            return Err(format!(
+                "Seats must be between 1 and the table size ({})",
+                self.rules.max_players
+            ));
+        }
+        if self.threads == 0 {
+            return Err("At least one thread is required".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Outcome rates and expected value of a simulation
+///
+/// Rates are per hand and `win_rate + push_rate + loss_rate + bust_rate` is 1.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationReport {
+    pub strategy: BotStrategy,
+    pub rules: TableRules,
+    pub seed: u64,
+    pub rounds: u64,
+    pub seats: u8,
+    /// Hands played (`rounds * seats`)
+    pub hands: u64,
+    pub win_rate: f64,
+    /// Hands won with a blackjack, paid 3:2 (included in `win_rate`)
+    pub blackjack_rate: f64,
+    pub push_rate: f64,
+    /// Hands lost to a higher dealer total (busts are counted separately)
+    pub loss_rate: f64,
+    pub bust_rate: f64,
+    /// Share of rounds where the dealer busted
+    pub dealer_bust_rate: f64,
+    /// Expected value per hand, in bets
+    pub ev: f64,
+    /// Player disadvantage (`-ev`)
+    pub house_edge: f64,
+    /// Variance of a single hand's result
+    pub variance: f64,
+    /// Standard error of `ev`
+    pub std_error: f64,
+}
+
+/// Outcome counts, merged across chunks
+#[derive(Debug, Clone, Copy, Default)]
+struct Tally {
+    hands: u64,
+    wins: u64,
+    /// Wins with a blackjack, also counted in `wins`
+    blackjacks: u64,
+    pushes: u64,
+    losses: u64,
+    busts: u64,
+    dealer_busts: u64,
+}
+
+impl Tally {
+    fn merge(&mut self, other: Tally) {
+        self.hands += other.hands;
+        self.wins += other.wins;
+        self.blackjacks += other.blackjacks;
+        self.pushes += other.pushes;
+        self.losses += other.losses;
+        self.busts += other.busts;
+        self.dealer_busts += other.dealer_busts;
+    }
+}
+
+/// Runs the simulation across `config.threads` threads
+pub fn run(config: &SimulationConfig) -> Result<SimulationReport, String> {
+    config.validate()?;
+
+    let template = table(config).map_err(|e| e.to_string())?;
+    let chunks = config.rounds.div_ceil(CHUNK_ROUNDS);
+    let next_chunk = AtomicU64::new(0);
+
+    let tallies: Vec<Result<Tally, GameError>> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..config.threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let strategy = config.strategy.strategy();
+                    let mut tally = Tally::default();
+                    loop {
+                        let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                        if chunk >= chunks {
+                            return Ok(tally);
+                        }
+                        let rounds = CHUNK_ROUNDS.min(config.rounds - chunk * CHUNK_ROUNDS);
+                        let mut rng = StdRng::seed_from_u64(chunk_seed(config.seed, chunk));
+                        for _ in 0..rounds {
+                            play_round(&template, strategy.as_ref(), &mut rng, &mut tally)?;
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("simulation thread panicked"))
+            .collect()
+    });
+
+    let mut total = Tally::default();
+    for tally in tallies {
+        total.merge(tally.map_err(|e| e.to_string())?);
+    }
+
+    Ok(report(config, total))
+}
+
+/// Seed of one chunk, spread out so neighbouring chunks don't correlate
+fn chunk_seed(seed: u64, chunk: u64) -> u64 {
+    seed ^ chunk.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+/// Builds the table every round starts from
+fn table(config: &SimulationConfig) -> Result<Game, GameError> {
+    let mut game = Game::new(Uuid::from_u128(1), "seat-1".to_string(), 0)?;
+    game.rules = config.rules.clone();
+    for seat in 2..=config.seats {
+        game.add_player(Uuid::from_u128(seat as u128), format!("seat-{}", seat))?;
+    }
+    Ok(game)
+}
+
+fn play_round(
+    template: &Game,
+    strategy: &dyn Strategy,
+    rng: &mut StdRng,
+    tally: &mut Tally,
+) -> Result<(), GameError> {
+    let mut game = template.clone();
+    game.seed_deck(rng.random());
+    game.close_enrollment()?;
+
+    while !game.finished {
+        let player_id = game
+            .get_current_player()
+            .ok_or(GameError::InvalidPlayerCount)?;
+        game.play_strategy_turn(player_id, strategy, rng)?;
+    }
+
+    let results = game.calculate_results();
+    if results.dealer_busted {
+        tally.dealer_busts += 1;
+    }
+    for result in results.player_results.values() {
+        tally.hands += 1;
+        match result.outcome {
+            PlayerOutcome::Won => {
+                tally.wins += 1;
+                if result.is_blackjack() {
+                    tally.blackjacks += 1;
+                }
+            }
+            PlayerOutcome::Push => tally.pushes += 1,
+            PlayerOutcome::Lost => tally.losses += 1,
+            PlayerOutcome::Busted => tally.busts += 1,
+        }
+    }
+
+    Ok(())
+}
+
+fn report(config: &SimulationConfig, tally: Tally) -> SimulationReport {
+    let hands = tally.hands as f64;
+    let rate = |count: u64| count as f64 / hands;
+
+    // Share of hands and payout of each outcome
+    let payouts = [
+        (tally.blackjacks, PlayerOutcome::Won.payout(true)),
+        (
+            tally.wins - tally.blackjacks,
+            PlayerOutcome::Won.payout(false),
+        ),
+        (tally.pushes, PlayerOutcome::Push.payout(false)),
+        (tally.losses, PlayerOutcome::Lost.payout(false)),
+        (tally.busts, PlayerOutcome::Busted.payout(false)),
+    ];
+    let ev: f64 = payouts
+        .iter()
+        .map(|&(count, payout)| rate(count) * payout)
+        .sum();
+    let mean_square: f64 = payouts
+        .iter()
+        .map(|&(count, payout)| rate(count) * payout * payout)
+        .sum();
+    let variance = mean_square - ev * ev;
+
+    SimulationReport {
+        strategy: config.strategy,
+        rules: config.rules.clone(),
+        seed: config.seed,
+        rounds: config.rounds,
+        seats: config.seats,
+        hands: tally.hands,
+        win_rate: rate(tally.wins),
+        blackjack_rate: rate(tally.blackjacks),
+        push_rate: rate(tally.pushes),
+        loss_rate: rate(tally.losses),
+        bust_rate: rate(tally.busts),
+        dealer_bust_rate: tally.dealer_busts as f64 / config.rounds as f64,
+        ev,
+        house_edge: -ev,
+        variance,
+        std_error: (variance / hands).sqrt(),
+    }
+}
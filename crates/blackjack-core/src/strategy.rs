@@ -0,0 +1,199 @@
+/// Playing strategies for bot players
+///
+/// A [`Strategy`] looks at a hand, the dealer's up-card and the table rules
+/// and decides what to do next. Bots in the service layer use one strategy
+/// each; the same strategies can drive simulations.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_core::strategy::{Action, BotStrategy, HandValue};
+/// use blackjack_core::{Card, TableRules};
+/// use uuid::Uuid;
+///
+/// let card = |name: &str, value: u8| Card {
+///     id: Uuid::new_v4(),
+///     name: name.to_string(),
+///     value,
+///     suit: "Spades".to_string(),
+/// };
+///
+/// let hand = vec![card("10", 10), card("6", 6)];
+/// let dealer_up_card = card("7", 7);
+///
+/// let strategy = BotStrategy::BasicStrategy.strategy();
+/// let action = strategy.decide(
+///     &hand,
+///     Some(&dealer_up_card),
+///     &TableRules::default(),
+///     &mut rand::rng(),
+/// );
+/// assert_eq!(action, Action::Hit);
+/// assert_eq!(HandValue::of(&hand).total, 16);
+/// ```
+use crate::{Card, TableRules, advisor};
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Value of a hand, counting at most one Ace as 11 when that doesn't bust
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandValue {
+    /// Total with every Ace counted as 1
+    pub hard: u8,
+    /// Best total (one Ace as 11 if it fits under 21)
+    pub total: u8,
+    /// Whether `total` counts an Ace as 11
+    pub soft: bool,
+}
+
+impl HandValue {
+    /// Evaluates a set of cards
+    pub fn of(cards: &[Card]) -> Self {
+        let hard = cards
+            .iter()
+            .fold(0u8, |sum, card| sum.saturating_add(card.value));
+        let has_ace = cards.iter().any(|card| card.name == "A");
+        let soft = has_ace && hard + 10 <= 21;
+        Self {
+            hard,
+            total: if soft { hard + 10 } else { hard },
+            soft,
+        }
+    }
+
+    /// Checks if the hand is over 21
+    pub fn is_bust(&self) -> bool {
+        self.hard > 21
+    }
+}
+
+/// Dealer up-card value on the 2-11 scale used by strategy charts (Ace = 11)
+///
+/// Without an up-card the dealer is assumed to show a ten.
+pub fn up_card_value(dealer_up_card: Option<&Card>) -> u8 {
+    match dealer_up_card {
+        Some(card) if card.name == "A" => 11,
+        Some(card) => card.value,
+        None => 10,
+    }
+}
+
+/// A playing decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    /// Take another card
+    Hit,
+    /// Keep the current hand
+    Stand,
+    /// Double the bet and take exactly one more card
+    Double,
+    /// Split a pair into two hands
+    Split,
+    /// Give up the hand for half the bet back
+    Surrender,
+}
+
+/// Decides how to play a hand
+pub trait Strategy: Send + Sync {
+    /// Short identifier of the strategy
+    fn name(&self) -> &'static str;
+
+    /// Picks the next action for `hand` given the dealer's up-card
+    fn decide(
+        &self,
+        hand: &[Card],
+        dealer_up_card: Option<&Card>,
+        rules: &TableRules,
+        rng: &mut dyn RngCore,
+    ) -> Action;
+}
+
+/// Textbook basic strategy chart, see [`crate::advisor`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BasicStrategy;
+
+impl Strategy for BasicStrategy {
+    fn name(&self) -> &'static str {
+        "basic_strategy"
+    }
+
+    fn decide(
+        &self,
+        hand: &[Card],
+        dealer_up_card: Option<&Card>,
+        rules: &TableRules,
+        _rng: &mut dyn RngCore,
+    ) -> Action {
+        advisor::recommend(hand, dealer_up_card, rules).action
+    }
+}
+
+/// Plays like the dealer: hits until the hard total reaches 17
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MimicDealer;
+
+impl Strategy for MimicDealer {
+    fn name(&self) -> &'static str {
+        "mimic_dealer"
+    }
+
+    fn decide(
+        &self,
+        hand: &[Card],
+        _dealer_up_card: Option<&Card>,
+        _rules: &TableRules,
+        _rng: &mut dyn RngCore,
+    ) -> Action {
+        if HandValue::of(hand).hard < 17 {
+            Action::Hit
+        } else {
+            Action::Stand
+        }
+    }
+}
+
+/// Flips a coin on every decision, but never hits 21
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn decide(
+        &self,
+        hand: &[Card],
+        _dealer_up_card: Option<&Card>,
+        _rules: &TableRules,
+        rng: &mut dyn RngCore,
+    ) -> Action {
+        if HandValue::of(hand).total >= 21 || rng.random_bool(0.5) {
+            Action::Stand
+        } else {
+            Action::Hit
+        }
+    }
+}
+
+/// Built-in strategies that can be assigned to a bot seat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BotStrategy {
+    #[default]
+    BasicStrategy,
+    MimicDealer,
+    Random,
+}
+
+impl BotStrategy {
+    /// Returns the strategy implementation
+    pub fn strategy(&self) -> Box<dyn Strategy> {
+        match self {
+            BotStrategy::BasicStrategy => Box::new(BasicStrategy),
+            BotStrategy::MimicDealer => Box::new(MimicDealer),
+            BotStrategy::Random => Box::new(RandomStrategy),
+        }
+    }
+}
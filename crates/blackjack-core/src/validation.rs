@@ -1,4 +1,4 @@
-/// Email and password validation module
+/// Email, password and display name validation module
 ///
 /// Provides validation functions for user input according to security best practices.
 use regex::Regex;
@@ -7,6 +7,17 @@ use std::sync::OnceLock;
 /// Minimum password length
 pub const MIN_PASSWORD_LENGTH: usize = 8;
 
+/// Display name length limits
+pub const MIN_DISPLAY_NAME_LENGTH: usize = 3;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 24;
+
+/// Display name pattern: a letter, then letters, digits, `_` or `-`
+static DISPLAY_NAME_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_display_name_regex() -> &'static Regex {
+    DISPLAY_NAME_REGEX.get_or_init(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9_-]*$").unwrap())
+}
+
 /// Email validation regex pattern (simplified RFC 5322)
 static EMAIL_REGEX: OnceLock<Regex> = OnceLock::new();
 
@@ -27,6 +38,8 @@ pub enum ValidationError {
     },
     /// Password is missing required character types
     PasswordMissingRequirements(Vec<String>),
+    /// Display name is malformed or reserved
+    InvalidDisplayName(String),
 }
 
 impl std::fmt::Display for ValidationError {
@@ -46,6 +59,9 @@ impl std::fmt::Display for ValidationError {
             ValidationError::PasswordMissingRequirements(reqs) => {
                 write!(f, "Password missing requirements: {}", reqs.join(", "))
             }
+            ValidationError::InvalidDisplayName(reason) => {
+                write!(f, "Invalid display name: {}", reason)
+            }
         }
     }
 }
@@ -148,6 +164,55 @@ pub fn validate_password(password: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Validates a display name
+///
+/// Requirements:
+/// - 3 to 24 characters
+/// - Starts with a letter, followed by letters, digits, `_` or `-`
+/// - Not `dealer`, `bot-<number>` or `deleted-...`, which the game engine and
+///   account deletion use for their own seats
+///
+/// # Examples
+///
+/// ```
+/// use blackjack_core::validation::validate_display_name;
+///
+/// assert!(validate_display_name("ace_of_spades").is_ok());
+/// assert!(validate_display_name("ab").is_err());
+/// assert!(validate_display_name("user@example.com").is_err());
+/// assert!(validate_display_name("Dealer").is_err());
+/// assert!(validate_display_name("bot-2").is_err());
+/// assert!(validate_display_name("deleted-1a2b3c4d").is_err());
+/// ```
+pub fn validate_display_name(name: &str) -> Result<(), ValidationError> {
+    let length = name.chars().count();
+    if !(MIN_DISPLAY_NAME_LENGTH..=MAX_DISPLAY_NAME_LENGTH).contains(&length) {
+        return Err(ValidationError::InvalidDisplayName(format!(
+            "must be {} to {} characters",
+            MIN_DISPLAY_NAME_LENGTH, MAX_DISPLAY_NAME_LENGTH
+        )));
+    }
+
+    if !get_display_name_regex().is_match(name) {
+        return Err(ValidationError::InvalidDisplayName(
+            "must start with a letter and contain only letters, digits, '_' or '-'".to_string(),
+        ));
+    }
+
+    let lower = name.to_ascii_lowercase();
+    let is_bot_name = lower
+        .strip_prefix("bot-")
+        .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()));
+    if lower == "dealer" || is_bot_name || lower.starts_with("deleted-") {
+        return Err(ValidationError::InvalidDisplayName(format!(
+            "'{}' is reserved",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +234,20 @@ mod tests {
         assert!(validate_email("user name@example.com").is_err());
     }
 
+    #[test]
+    fn test_validate_display_name() {
+        assert!(validate_display_name("alice").is_ok());
+        assert!(validate_display_name("Bob-the_Builder2").is_ok());
+        assert!(validate_display_name("bot-builder").is_ok());
+        assert!(validate_display_name("al").is_err());
+        assert!(validate_display_name(&"a".repeat(25)).is_err());
+        assert!(validate_display_name("2fast").is_err());
+        assert!(validate_display_name("alice smith").is_err());
+        assert!(validate_display_name("DEALER").is_err());
+        assert!(validate_display_name("bot-7").is_err());
+        assert!(validate_display_name("Deleted-player").is_err());
+    }
+
     #[test]
     fn test_validate_password_valid() {
         assert!(validate_password("MyP@ssw0rd").is_ok());
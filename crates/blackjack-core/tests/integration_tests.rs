@@ -1,4 +1,13 @@
-use blackjack_core::{Game, GameError, PlayerState};
+use blackjack_core::achievements::{self, ACHIEVEMENTS, Criterion, UnlockedAchievement};
+use blackjack_core::advisor::recommend;
+use blackjack_core::rating::{
+    self, DEALER_RATING, INITIAL_RATING, PROVISIONAL_GAMES, RatedSeat, RatingBand,
+};
+use blackjack_core::simulation::{self, SimulationConfig};
+use blackjack_core::strategy::{Action, BotStrategy, HandValue};
+use blackjack_core::{
+    Card, Game, GameError, PlayerOutcome, PlayerResult, PlayerState, TableRules, UserStats,
+};
 use uuid::Uuid;
 
 // Helper function to create a test creator_id
@@ -11,6 +20,11 @@ fn test_creator_email() -> String {
     "creator@test.com".to_string()
 }
 
+// Helper returning the ID of the player seated under `name`
+fn id(game: &Game, name: &str) -> Uuid {
+    game.player_id(name).expect("player is seated")
+}
+
 // Helper function to create a game with default enrollment timeout
 fn test_game(emails: Vec<&str>) -> Result<Game, GameError> {
     let mut game = Game::new(test_creator_id(), test_creator_email(), 300)?;
@@ -18,7 +32,7 @@ fn test_game(emails: Vec<&str>) -> Result<Game, GameError> {
     // Enroll additional players (creator is already enrolled)
     for email in emails {
         if email != "creator@test.com" {
-            game.add_player(email.to_string())?;
+            game.add_player(Uuid::new_v4(), email.to_string())?;
         }
     }
 
@@ -27,14 +41,25 @@ fn test_game(emails: Vec<&str>) -> Result<Game, GameError> {
     Ok(game)
 }
 
+// Helper returning every card not yet dealt to a player: the deck plus the
+// dealer's up-card, which is dealt when enrollment closes
+fn full_shoe(game: &Game) -> Vec<&Card> {
+    game.available_cards
+        .iter()
+        .chain(game.dealer.cards_history.iter())
+        .collect()
+}
+
 #[test]
 fn test_deck_has_52_cards() {
     let game = test_game(vec!["player1@test.com"]).unwrap();
     assert_eq!(
-        game.available_cards.len(),
+        full_shoe(&game).len(),
         52,
         "Deck should have exactly 52 cards"
     );
+    assert_eq!(game.available_cards.len(), 51);
+    assert!(game.dealer_up_card().is_some());
 }
 
 #[test]
@@ -46,8 +71,7 @@ fn test_four_cards_of_each_type() {
         "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K",
     ];
     for card_type in card_types.iter() {
-        let count = game
-            .available_cards
+        let count = full_shoe(&game)
             .iter()
             .filter(|c| c.name == *card_type)
             .count();
@@ -65,11 +89,7 @@ fn test_cards_have_correct_suits() {
 
     let suits = ["Hearts", "Diamonds", "Clubs", "Spades"];
     for suit in suits.iter() {
-        let count = game
-            .available_cards
-            .iter()
-            .filter(|c| c.suit == *suit)
-            .count();
+        let count = full_shoe(&game).iter().filter(|c| c.suit == *suit).count();
         assert_eq!(count, 13, "Should have exactly 13 cards of suit {}", suit);
     }
 }
@@ -80,14 +100,9 @@ fn test_deck_exhaustion() {
 
     // Draw cards until player busts or game finishes
     let mut cards_drawn = 0;
-    loop {
-        // Try the current player
-        let current_player = game.get_current_player().unwrap_or("").to_string();
-        if current_player.is_empty() {
-            break;
-        }
-
-        let result = game.draw_card(&current_player);
+    // Try the current player
+    while let Some(current_player) = game.get_current_player() {
+        let result = game.draw_card(current_player);
         match result {
             Ok(_) => cards_drawn += 1,
             Err(GameError::PlayerAlreadyBusted) => {
@@ -117,7 +132,7 @@ fn test_deck_exhaustion() {
     game2.available_cards.clear();
 
     // Now try to draw from empty deck
-    let result = game2.draw_card("creator@test.com");
+    let result = game2.draw_card(id(&game2, "creator@test.com"));
     assert_eq!(
         result,
         Err(GameError::DeckEmpty),
@@ -139,17 +154,17 @@ fn test_ace_value_can_be_changed_multiple_times() {
     let ace_id = ace.id;
 
     game.players
-        .get_mut("player1@test.com")
+        .get_mut(&id(&game, "player1@test.com"))
         .unwrap()
         .add_card(ace);
 
-    let player = game.players.get("player1@test.com").unwrap();
+    let player = game.players.get(&id(&game, "player1@test.com")).unwrap();
     let initial_points = player.points;
 
     // Change Ace to 11
-    game.set_ace_value("player1@test.com", ace_id, true)
+    game.set_ace_value(id(&game, "player1@test.com"), ace_id, true)
         .unwrap();
-    let player = game.players.get("player1@test.com").unwrap();
+    let player = game.players.get(&id(&game, "player1@test.com")).unwrap();
     assert_eq!(
         player.points,
         initial_points + 10,
@@ -157,18 +172,18 @@ fn test_ace_value_can_be_changed_multiple_times() {
     );
 
     // Change back to 1
-    game.set_ace_value("player1@test.com", ace_id, false)
+    game.set_ace_value(id(&game, "player1@test.com"), ace_id, false)
         .unwrap();
-    let player = game.players.get("player1@test.com").unwrap();
+    let player = game.players.get(&id(&game, "player1@test.com")).unwrap();
     assert_eq!(
         player.points, initial_points,
         "Points should return to initial when Ace is 1"
     );
 
     // Change to 11 again
-    game.set_ace_value("player1@test.com", ace_id, true)
+    game.set_ace_value(id(&game, "player1@test.com"), ace_id, true)
         .unwrap();
-    let player = game.players.get("player1@test.com").unwrap();
+    let player = game.players.get(&id(&game, "player1@test.com")).unwrap();
     assert_eq!(
         player.points,
         initial_points + 10,
@@ -182,7 +197,7 @@ fn test_game_finished_prevents_draw() {
 
     game.finish_game();
 
-    let result = game.draw_card("player1@test.com");
+    let result = game.draw_card(id(&game, "player1@test.com"));
     assert_eq!(
         result,
         Err(GameError::GameAlreadyFinished),
@@ -204,13 +219,13 @@ fn test_game_finished_prevents_ace_change() {
     let ace_id = ace.id;
 
     game.players
-        .get_mut("player1@test.com")
+        .get_mut(&id(&game, "player1@test.com"))
         .unwrap()
         .add_card(ace);
 
     game.finish_game();
 
-    let result = game.set_ace_value("player1@test.com", ace_id, true);
+    let result = game.set_ace_value(id(&game, "player1@test.com"), ace_id, true);
     assert_eq!(
         result,
         Err(GameError::GameAlreadyFinished),
@@ -247,9 +262,18 @@ fn test_calculate_results_single_winner() {
     .unwrap();
 
     // Manually set points (simulating game play)
-    game.players.get_mut("player1@test.com").unwrap().points = 15;
-    game.players.get_mut("player2@test.com").unwrap().points = 20;
-    game.players.get_mut("player3@test.com").unwrap().points = 18;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 15;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 20;
+    game.players
+        .get_mut(&id(&game, "player3@test.com"))
+        .unwrap()
+        .points = 18;
 
     let result = game.calculate_results();
 
@@ -267,9 +291,18 @@ fn test_calculate_results_tie() {
     ])
     .unwrap();
 
-    game.players.get_mut("player1@test.com").unwrap().points = 19;
-    game.players.get_mut("player2@test.com").unwrap().points = 15;
-    game.players.get_mut("player3@test.com").unwrap().points = 19;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 19;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 15;
+    game.players
+        .get_mut(&id(&game, "player3@test.com"))
+        .unwrap()
+        .points = 19;
 
     let result = game.calculate_results();
 
@@ -292,10 +325,22 @@ fn test_calculate_results_tie() {
 fn test_calculate_results_all_bust() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    game.players.get_mut("player1@test.com").unwrap().points = 22;
-    game.players.get_mut("player1@test.com").unwrap().busted = true;
-    game.players.get_mut("player2@test.com").unwrap().points = 25;
-    game.players.get_mut("player2@test.com").unwrap().busted = true;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 22;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .busted = true;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 25;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .busted = true;
 
     let result = game.calculate_results();
 
@@ -313,9 +358,18 @@ fn test_calculate_results_perfect_21() {
     ])
     .unwrap();
 
-    game.players.get_mut("player1@test.com").unwrap().points = 20;
-    game.players.get_mut("player2@test.com").unwrap().points = 21;
-    game.players.get_mut("player3@test.com").unwrap().points = 19;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 20;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 21;
+    game.players
+        .get_mut(&id(&game, "player3@test.com"))
+        .unwrap()
+        .points = 19;
 
     let result = game.calculate_results();
 
@@ -346,12 +400,12 @@ fn test_invalid_player_count_too_many() {
         let email = format!("player{}@test.com", i);
         if i <= 9 {
             assert!(
-                game.add_player(email).is_ok(),
+                game.add_player(Uuid::new_v4(), email).is_ok(),
                 "Should allow up to 10 total players (9 + creator)"
             );
         } else {
             assert!(
-                game.add_player(email).is_err(),
+                game.add_player(Uuid::new_v4(), email).is_err(),
                 "Should reject more than 10 players"
             );
         }
@@ -359,27 +413,30 @@ fn test_invalid_player_count_too_many() {
 }
 
 #[test]
-fn test_invalid_email_empty() {
-    // Test creating game with empty creator email
+fn test_invalid_player_name_empty() {
+    // Test creating game with empty creator name
     let result = Game::new(test_creator_id(), "".to_string(), 300);
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), GameError::InvalidEmail));
+    assert!(matches!(result.unwrap_err(), GameError::InvalidPlayerName));
 }
 
 #[test]
-fn test_invalid_email_duplicate() {
+fn test_duplicate_player_rejected() {
     // Creator is auto-enrolled, so trying to add creator again should fail
-    let mut game = Game::new(test_creator_id(), test_creator_email(), 300).unwrap();
+    let creator_id = test_creator_id();
+    let mut game = Game::new(creator_id, test_creator_email(), 300).unwrap();
 
-    let result = game.add_player(test_creator_email());
-    assert!(result.is_err(), "Duplicate email (creator) should fail");
+    let result = game.add_player(creator_id, "other@test.com".to_string());
+    assert!(result.is_err(), "Duplicate ID (creator) should fail");
+    let result = game.add_player(Uuid::new_v4(), test_creator_email());
+    assert!(result.is_err(), "Duplicate name should fail");
 }
 
 #[test]
 fn test_player_not_in_game() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
-    let result = game.draw_card("nonexistent@test.com");
+    let result = game.draw_card(Uuid::new_v4());
     // With turn management, it will return NotPlayerTurn before checking if player exists
     assert_eq!(result, Err(GameError::NotPlayerTurn));
 }
@@ -389,9 +446,12 @@ fn test_busted_player_cannot_draw() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Manually set creator (current player) as busted
-    game.players.get_mut("creator@test.com").unwrap().busted = true;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .busted = true;
 
-    let result = game.draw_card("creator@test.com");
+    let result = game.draw_card(id(&game, "creator@test.com"));
     assert_eq!(result, Err(GameError::PlayerAlreadyBusted));
 }
 
@@ -425,14 +485,14 @@ fn test_player_state_initial() {
     let game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Check both creator and enrolled player
-    let creator = game.players.get("creator@test.com").unwrap();
+    let creator = game.players.get(&id(&game, "creator@test.com")).unwrap();
     assert_eq!(
         creator.state,
         PlayerState::Active,
         "Creator should be Active"
     );
 
-    let player = game.players.get("player1@test.com").unwrap();
+    let player = game.players.get(&id(&game, "player1@test.com")).unwrap();
     assert_eq!(
         player.state,
         PlayerState::Active,
@@ -450,7 +510,7 @@ fn test_get_current_player() {
     .unwrap();
 
     // Creator is enrolled first, so should have first turn
-    let current = game.get_current_player();
+    let current = game.current_player_name();
     assert_eq!(
         current,
         Some("creator@test.com"),
@@ -467,20 +527,20 @@ fn test_advance_turn() {
     ])
     .unwrap();
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
     game.advance_turn();
-    assert_eq!(game.get_current_player(), Some("player1@test.com"));
+    assert_eq!(game.current_player_name(), Some("player1@test.com"));
 
     game.advance_turn();
-    assert_eq!(game.get_current_player(), Some("player2@test.com"));
+    assert_eq!(game.current_player_name(), Some("player2@test.com"));
 
     game.advance_turn();
-    assert_eq!(game.get_current_player(), Some("player3@test.com"));
+    assert_eq!(game.current_player_name(), Some("player3@test.com"));
 
     game.advance_turn();
     assert_eq!(
-        game.get_current_player(),
+        game.current_player_name(),
         Some("creator@test.com"),
         "Should wrap around to creator"
     );
@@ -497,13 +557,16 @@ fn test_advance_turn_skips_standing_players() {
     .unwrap();
 
     // Mark player1 as standing (second in turn order after creator)
-    game.players.get_mut("player1@test.com").unwrap().state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
     game.advance_turn();
     // Should skip player1 and go to player2
-    assert_eq!(game.get_current_player(), Some("player2@test.com"));
+    assert_eq!(game.current_player_name(), Some("player2@test.com"));
 }
 
 #[test]
@@ -517,13 +580,16 @@ fn test_advance_turn_skips_busted_players() {
     .unwrap();
 
     // Mark player1 as busted (second in turn order after creator)
-    game.players.get_mut("player1@test.com").unwrap().state = PlayerState::Busted;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .state = PlayerState::Busted;
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
     game.advance_turn();
     // Should skip player1 and go to player2
-    assert_eq!(game.get_current_player(), Some("player2@test.com"));
+    assert_eq!(game.current_player_name(), Some("player2@test.com"));
 }
 
 #[test]
@@ -531,11 +597,11 @@ fn test_stand_marks_player_as_standing() {
     use blackjack_core::PlayerState;
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
-    game.stand("creator@test.com").unwrap();
+    game.stand(id(&game, "creator@test.com")).unwrap();
 
-    let player = game.players.get("creator@test.com").unwrap();
+    let player = game.players.get(&id(&game, "creator@test.com")).unwrap();
     assert_eq!(player.state, PlayerState::Standing);
 }
 
@@ -543,20 +609,20 @@ fn test_stand_marks_player_as_standing() {
 fn test_stand_advances_turn() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
-    game.stand("creator@test.com").unwrap();
+    game.stand(id(&game, "creator@test.com")).unwrap();
 
-    assert_eq!(game.get_current_player(), Some("player1@test.com"));
+    assert_eq!(game.current_player_name(), Some("player1@test.com"));
 }
 
 #[test]
 fn test_stand_not_your_turn() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
-    let result = game.stand("player1@test.com");
+    let result = game.stand(id(&game, "player1@test.com"));
     assert_eq!(result, Err(GameError::NotPlayerTurn));
 }
 
@@ -566,19 +632,19 @@ fn test_stand_auto_finishes_game() {
 
     assert!(!game.finished);
 
-    game.stand("creator@test.com").unwrap();
+    game.stand(id(&game, "creator@test.com")).unwrap();
     assert!(
         !game.finished,
         "Game should not finish with players remaining"
     );
 
-    game.stand("player1@test.com").unwrap();
+    game.stand(id(&game, "player1@test.com")).unwrap();
     assert!(
         !game.finished,
         "Game should not finish with one player remaining"
     );
 
-    game.stand("player2@test.com").unwrap();
+    game.stand(id(&game, "player2@test.com")).unwrap();
     assert!(
         game.finished,
         "Game should auto-finish when all players stand"
@@ -590,9 +656,18 @@ fn test_check_auto_finish_all_standing() {
     use blackjack_core::PlayerState;
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    game.players.get_mut("creator@test.com").unwrap().state = PlayerState::Standing;
-    game.players.get_mut("player1@test.com").unwrap().state = PlayerState::Standing;
-    game.players.get_mut("player2@test.com").unwrap().state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
 
     assert!(
         game.check_auto_finish(),
@@ -605,9 +680,18 @@ fn test_check_auto_finish_all_busted() {
     use blackjack_core::PlayerState;
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    game.players.get_mut("creator@test.com").unwrap().state = PlayerState::Busted;
-    game.players.get_mut("player1@test.com").unwrap().state = PlayerState::Busted;
-    game.players.get_mut("player2@test.com").unwrap().state = PlayerState::Busted;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .state = PlayerState::Busted;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .state = PlayerState::Busted;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .state = PlayerState::Busted;
 
     assert!(
         game.check_auto_finish(),
@@ -620,9 +704,18 @@ fn test_check_auto_finish_mixed() {
     use blackjack_core::PlayerState;
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    game.players.get_mut("creator@test.com").unwrap().state = PlayerState::Standing;
-    game.players.get_mut("player1@test.com").unwrap().state = PlayerState::Standing;
-    game.players.get_mut("player2@test.com").unwrap().state = PlayerState::Busted;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .state = PlayerState::Busted;
 
     assert!(
         game.check_auto_finish(),
@@ -635,8 +728,14 @@ fn test_check_auto_finish_has_active_player() {
     use blackjack_core::PlayerState;
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    game.players.get_mut("player1@test.com").unwrap().state = PlayerState::Standing;
-    game.players.get_mut("player2@test.com").unwrap().state = PlayerState::Active;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .state = PlayerState::Standing;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .state = PlayerState::Active;
 
     assert!(
         !game.check_auto_finish(),
@@ -649,11 +748,11 @@ fn test_can_player_act_current_turn() {
     let game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
     assert!(
-        game.can_player_act("creator@test.com"),
+        game.can_player_act(id(&game, "creator@test.com")),
         "Creator (current player) should be able to act"
     );
     assert!(
-        !game.can_player_act("player1@test.com"),
+        !game.can_player_act(id(&game, "player1@test.com")),
         "Non-current player should not be able to act"
     );
 }
@@ -664,7 +763,7 @@ fn test_can_player_act_enrollment_open() {
 
     // Enrollment still open
     assert!(
-        !game.can_player_act(&test_creator_email()),
+        !game.can_player_act(id(&game, &test_creator_email())),
         "Cannot act during enrollment phase"
     );
 }
@@ -673,12 +772,12 @@ fn test_can_player_act_enrollment_open() {
 fn test_draw_card_advances_turn() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
-    game.draw_card("creator@test.com").unwrap();
+    game.draw_card(id(&game, "creator@test.com")).unwrap();
 
     assert_eq!(
-        game.get_current_player(),
+        game.current_player_name(),
         Some("player1@test.com"),
         "Turn should advance after drawing"
     );
@@ -688,9 +787,9 @@ fn test_draw_card_advances_turn() {
 fn test_draw_card_not_your_turn() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
-    assert_eq!(game.get_current_player(), Some("creator@test.com"));
+    assert_eq!(game.current_player_name(), Some("creator@test.com"));
 
-    let result = game.draw_card("player1@test.com");
+    let result = game.draw_card(id(&game, "player1@test.com"));
     assert_eq!(result, Err(GameError::NotPlayerTurn));
 }
 
@@ -698,7 +797,7 @@ fn test_draw_card_not_your_turn() {
 fn test_draw_card_enrollment_open() {
     let mut game = Game::new(test_creator_id(), test_creator_email(), 300).unwrap();
 
-    let result = game.draw_card(&test_creator_email());
+    let result = game.draw_card(id(&game, &test_creator_email()));
     assert_eq!(
         result,
         Err(GameError::EnrollmentNotClosed),
@@ -712,7 +811,10 @@ fn test_busted_player_state_updates() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Find high-value cards to make player bust
-    let player = game.players.get_mut("player1@test.com").unwrap();
+    let player = game
+        .players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap();
 
     // Add cards manually to make player bust (e.g., 10 + 10 + 5 = 25)
     let ten_card_1 = game
@@ -759,8 +861,8 @@ fn test_dealer_plays_automatically_after_all_players_finish() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Both players stand
-    game.stand("creator@test.com").unwrap();
-    game.stand("player1@test.com").unwrap();
+    game.stand(id(&game, "creator@test.com")).unwrap();
+    game.stand(id(&game, "player1@test.com")).unwrap();
 
     // Game should be finished and dealer should have played
     assert!(game.finished, "Game should be finished");
@@ -855,8 +957,14 @@ fn test_dealer_included_in_game_results() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set some points for testing
-    game.players.get_mut("creator@test.com").unwrap().points = 18;
-    game.players.get_mut("player1@test.com").unwrap().points = 20;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .points = 18;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 20;
 
     game.play_dealer().unwrap();
     game.finished = true;
@@ -881,8 +989,14 @@ fn test_players_win_when_dealer_busts() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set player points
-    game.players.get_mut("creator@test.com").unwrap().points = 15;
-    game.players.get_mut("player1@test.com").unwrap().points = 18;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .points = 15;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 18;
 
     // Force dealer to bust by giving high-value cards
     for _ in 0..3 {
@@ -917,8 +1031,14 @@ fn test_dealer_wins_when_players_have_lower_scores() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set player points lower than dealer
-    game.players.get_mut("creator@test.com").unwrap().points = 15;
-    game.players.get_mut("player1@test.com").unwrap().points = 16;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .points = 15;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 16;
 
     // Manually set dealer to 20
     while game.dealer.points < 20 {
@@ -951,7 +1071,10 @@ fn test_push_when_player_ties_dealer() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set both to 19
-    game.players.get_mut("creator@test.com").unwrap().points = 19;
+    game.players
+        .get_mut(&id(&game, "creator@test.com"))
+        .unwrap()
+        .points = 19;
 
     // Manually set dealer to 19
     while game.dealer.points < 19 {
@@ -965,8 +1088,14 @@ fn test_push_when_player_ties_dealer() {
     }
 
     // Set other player to bust
-    game.players.get_mut("player1@test.com").unwrap().points = 25;
-    game.players.get_mut("player1@test.com").unwrap().busted = true;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 25;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .busted = true;
 
     if game.dealer.points == 19 && !game.dealer.busted {
         game.finished = true;
@@ -1020,7 +1149,10 @@ fn test_result_player_beats_dealer() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set up scenario: player1 has 20, dealer has 18
-    game.players.get_mut("player1@test.com").unwrap().points = 20;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 20;
     game.dealer.points = 18;
     game.dealer.busted = false;
     game.finished = true;
@@ -1050,7 +1182,10 @@ fn test_result_dealer_beats_player() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set up scenario: dealer has 20, player1 has 18
-    game.players.get_mut("player1@test.com").unwrap().points = 18;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 18;
     game.dealer.points = 20;
     game.dealer.busted = false;
     game.finished = true;
@@ -1080,7 +1215,10 @@ fn test_result_push_tie_with_dealer() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set up scenario: both have 19 (push)
-    game.players.get_mut("player1@test.com").unwrap().points = 19;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 19;
     game.dealer.points = 19;
     game.dealer.busted = false;
     game.finished = true;
@@ -1107,8 +1245,14 @@ fn test_result_player_busted() {
     let mut game = test_game(vec!["player1@test.com"]).unwrap();
 
     // Set up scenario: player1 busted
-    game.players.get_mut("player1@test.com").unwrap().points = 25;
-    game.players.get_mut("player1@test.com").unwrap().busted = true;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 25;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .busted = true;
     game.dealer.points = 18;
     game.dealer.busted = false;
     game.finished = true;
@@ -1133,8 +1277,14 @@ fn test_result_dealer_busted_players_win() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
     // Set up scenario: dealer busted, both players have different scores
-    game.players.get_mut("player1@test.com").unwrap().points = 18;
-    game.players.get_mut("player2@test.com").unwrap().points = 16;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 18;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 16;
     game.dealer.points = 25;
     game.dealer.busted = true;
     game.finished = true;
@@ -1174,9 +1324,18 @@ fn test_result_mixed_outcomes() {
     // - player2: 18 (loses)
     // - player3: 19 (push)
     // - dealer: 19
-    game.players.get_mut("player1@test.com").unwrap().points = 20;
-    game.players.get_mut("player2@test.com").unwrap().points = 18;
-    game.players.get_mut("player3@test.com").unwrap().points = 19;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 20;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 18;
+    game.players
+        .get_mut(&id(&game, "player3@test.com"))
+        .unwrap()
+        .points = 19;
     game.dealer.points = 19;
     game.dealer.busted = false;
     game.finished = true;
@@ -1212,10 +1371,22 @@ fn test_result_all_players_bust() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
     // All players busted
-    game.players.get_mut("player1@test.com").unwrap().points = 25;
-    game.players.get_mut("player1@test.com").unwrap().busted = true;
-    game.players.get_mut("player2@test.com").unwrap().points = 23;
-    game.players.get_mut("player2@test.com").unwrap().busted = true;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 25;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .busted = true;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 23;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .busted = true;
     game.dealer.points = 18;
     game.dealer.busted = false;
     game.finished = true;
@@ -1245,8 +1416,14 @@ fn test_result_tied_winners() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
     // Both players tie with same winning score
-    game.players.get_mut("player1@test.com").unwrap().points = 20;
-    game.players.get_mut("player2@test.com").unwrap().points = 20;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 20;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 20;
     game.dealer.points = 18;
     game.dealer.busted = false;
     game.finished = true;
@@ -1287,8 +1464,14 @@ fn test_result_multiple_players_tie_and_lose() {
     let mut game = test_game(vec!["player1@test.com", "player2@test.com"]).unwrap();
 
     // Both players tie at 18, dealer has 20 (both lose)
-    game.players.get_mut("player1@test.com").unwrap().points = 18;
-    game.players.get_mut("player2@test.com").unwrap().points = 18;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 18;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 18;
     game.dealer.points = 20;
     game.dealer.busted = false;
     game.finished = true;
@@ -1329,9 +1512,18 @@ fn test_result_multiple_players_tie_and_push() {
     .unwrap();
 
     // All three players tie at 19, dealer also has 19 (all push)
-    game.players.get_mut("player1@test.com").unwrap().points = 19;
-    game.players.get_mut("player2@test.com").unwrap().points = 19;
-    game.players.get_mut("player3@test.com").unwrap().points = 19;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 19;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 19;
+    game.players
+        .get_mut(&id(&game, "player3@test.com"))
+        .unwrap()
+        .points = 19;
     game.dealer.points = 19;
     game.dealer.busted = false;
     game.finished = true;
@@ -1379,9 +1571,18 @@ fn test_result_three_players_tie_and_win() {
     .unwrap();
 
     // All three players tie at 20, dealer has 18 (all win)
-    game.players.get_mut("player1@test.com").unwrap().points = 20;
-    game.players.get_mut("player2@test.com").unwrap().points = 20;
-    game.players.get_mut("player3@test.com").unwrap().points = 20;
+    game.players
+        .get_mut(&id(&game, "player1@test.com"))
+        .unwrap()
+        .points = 20;
+    game.players
+        .get_mut(&id(&game, "player2@test.com"))
+        .unwrap()
+        .points = 20;
+    game.players
+        .get_mut(&id(&game, "player3@test.com"))
+        .unwrap()
+        .points = 20;
     game.dealer.points = 18;
     game.dealer.busted = false;
     game.finished = true;
@@ -1418,3 +1619,328 @@ fn test_result_three_players_tie_and_win() {
         ));
     }
 }
+
+// Helper to build a card for strategy tests
+fn card(name: &str, value: u8) -> Card {
+    Card {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        value,
+        suit: "Hearts".to_string(),
+    }
+}
+
+#[test]
+fn test_hand_value_counts_one_soft_ace() {
+    let soft = HandValue::of(&[card("A", 1), card("6", 6)]);
+    assert_eq!((soft.hard, soft.total, soft.soft), (7, 17, true));
+
+    let two_aces = HandValue::of(&[card("A", 1), card("A", 1), card("9", 9)]);
+    assert_eq!((two_aces.total, two_aces.soft), (21, true));
+
+    let hard = HandValue::of(&[card("A", 1), card("K", 10), card("5", 5)]);
+    assert_eq!((hard.total, hard.soft), (16, false));
+}
+
+#[test]
+fn test_bot_strategies_decisions() {
+    let rules = TableRules::default();
+    let mut rng = rand::rng();
+    let basic = BotStrategy::BasicStrategy.strategy();
+    let mimic = BotStrategy::MimicDealer.strategy();
+    let random = BotStrategy::Random.strategy();
+
+    let hard_16 = [card("10", 10), card("6", 6)];
+    let weak_dealer = card("5", 5);
+    let strong_dealer = card("K", 10);
+    assert_eq!(
+        basic.decide(&hard_16, Some(&weak_dealer), &rules, &mut rng),
+        Action::Stand
+    );
+    assert_eq!(
+        basic.decide(&hard_16, Some(&strong_dealer), &rules, &mut rng),
+        Action::Hit
+    );
+    assert_eq!(
+        mimic.decide(&hard_16, Some(&weak_dealer), &rules, &mut rng),
+        Action::Hit
+    );
+
+    // Random never hits a 21
+    let blackjack = [card("A", 1), card("K", 10)];
+    for _ in 0..20 {
+        assert_eq!(
+            random.decide(&blackjack, None, &rules, &mut rng),
+            Action::Stand
+        );
+    }
+}
+
+#[test]
+fn test_bots_take_seats_and_show_in_results() {
+    let mut game = Game::new(test_creator_id(), test_creator_email(), 300).unwrap();
+    let first = game.add_bot(BotStrategy::MimicDealer).unwrap();
+    let second = game.add_bot(BotStrategy::Random).unwrap();
+    assert_eq!(game.player_name(first), Some("bot-1"));
+    assert_eq!(game.player_name(second), Some("bot-2"));
+    assert_eq!(game.free_seats(), 7);
+    assert!(game.is_bot(id(&game, "bot-1")));
+    assert!(!game.is_bot(id(&game, &test_creator_email())));
+
+    game.close_enrollment().unwrap();
+    let results = game.calculate_results();
+    assert!(results.player_results["bot-1"].is_bot);
+    assert!(!results.player_results[&test_creator_email()].is_bot);
+}
+
+#[test]
+fn test_advisor_follows_chart_and_rules() {
+    let all_plays = TableRules {
+        double_allowed: true,
+        split_allowed: true,
+        surrender_allowed: true,
+        ..TableRules::default()
+    };
+    let hit_stand = TableRules::default();
+    let advise =
+        |hand: &[Card], up: Card, rules: &TableRules| recommend(hand, Some(&up), rules).action;
+
+    // Aces and eights are always split, tens never
+    let aces = [card("A", 1), card("A", 1)];
+    assert_eq!(advise(&aces, card("K", 10), &all_plays), Action::Split);
+    assert_eq!(advise(&aces, card("K", 10), &hit_stand), Action::Hit);
+    let tens = [card("K", 10), card("Q", 10)];
+    assert_eq!(advise(&tens, card("6", 6), &all_plays), Action::Stand);
+
+    // Hard 16 against a ten: surrender, otherwise hit
+    let hard_16 = [card("10", 10), card("6", 6)];
+    assert_eq!(
+        advise(&hard_16, card("J", 10), &all_plays),
+        Action::Surrender
+    );
+    assert_eq!(advise(&hard_16, card("J", 10), &hit_stand), Action::Hit);
+
+    // Soft 18 against a 4: double, otherwise stand
+    let soft_18 = [card("A", 1), card("7", 7)];
+    assert_eq!(advise(&soft_18, card("4", 4), &all_plays), Action::Double);
+    assert_eq!(advise(&soft_18, card("4", 4), &hit_stand), Action::Stand);
+    assert_eq!(advise(&soft_18, card("9", 9), &all_plays), Action::Hit);
+
+    // Doubling is only offered on the first two cards
+    let three_card_11 = [card("2", 2), card("4", 4), card("5", 5)];
+    assert_eq!(
+        advise(&three_card_11, card("6", 6), &all_plays),
+        Action::Hit
+    );
+
+    let advice = recommend(&soft_18, Some(&card("A", 1)), &hit_stand);
+    assert_eq!((advice.hand_total, advice.soft), (18, true));
+    assert_eq!(advice.dealer_value, 11);
+}
+
+#[test]
+fn test_hint_requires_turn_and_is_counted() {
+    let mut game = Game::new(test_creator_id(), test_creator_email(), 300).unwrap();
+    game.add_player(Uuid::new_v4(), "player2@test.com".to_string())
+        .unwrap();
+    assert_eq!(
+        game.hint(id(&game, &test_creator_email())).unwrap_err(),
+        GameError::EnrollmentNotClosed
+    );
+
+    game.close_enrollment().unwrap();
+    assert_eq!(
+        game.hint(id(&game, "player2@test.com")).unwrap_err(),
+        GameError::NotPlayerTurn
+    );
+    game.hint(id(&game, &test_creator_email())).unwrap();
+    game.hint(id(&game, &test_creator_email())).unwrap();
+
+    game.stand(id(&game, &test_creator_email())).unwrap();
+    game.stand(id(&game, "player2@test.com")).unwrap();
+    let results = game.calculate_results();
+    assert_eq!(results.player_results[&test_creator_email()].hints_used, 2);
+    assert_eq!(results.player_results["player2@test.com"].hints_used, 0);
+}
+
+#[test]
+fn test_hint_follows_dealer_up_card() {
+    let mut game = Game::new(test_creator_id(), test_creator_email(), 300).unwrap();
+    game.close_enrollment().unwrap();
+    assert!(game.dealer_up_card().is_some());
+
+    // A hard 12 stands against a dealer 4 and hits against a dealer ten
+    let player_id = id(&game, &test_creator_email());
+    game.players.get_mut(&player_id).unwrap().cards_history = vec![card("10", 10), card("2", 2)];
+
+    game.dealer.cards_history = vec![card("4", 4)];
+    let advice = game.hint(player_id).unwrap();
+    assert_eq!((advice.dealer_value, advice.action), (4, Action::Stand));
+
+    game.dealer.cards_history = vec![card("K", 10)];
+    let advice = game.hint(player_id).unwrap();
+    assert_eq!((advice.dealer_value, advice.action), (10, Action::Hit));
+}
+
+#[test]
+fn test_simulation_is_reproducible_across_threads() {
+    let config = SimulationConfig {
+        rounds: 25_000,
+        seats: 3,
+        strategy: BotStrategy::Random,
+        seed: 2024,
+        threads: 4,
+        ..SimulationConfig::default()
+    };
+    let report = simulation::run(&config).unwrap();
+    let single = simulation::run(&SimulationConfig {
+        threads: 1,
+        ..config.clone()
+    })
+    .unwrap();
+    assert_eq!(report, single);
+
+    assert_eq!(report.hands, 75_000);
+    let total = report.win_rate + report.push_rate + report.loss_rate + report.bust_rate;
+    assert!((total - 1.0).abs() < 1e-9);
+    assert_eq!(report.house_edge, -report.ev);
+    assert!(report.variance > 0.0 && report.std_error > 0.0);
+
+    // Blackjacks are wins that pay an extra half bet
+    assert!(report.blackjack_rate > 0.0 && report.blackjack_rate < report.win_rate);
+    let ev = report.win_rate + 0.5 * report.blackjack_rate - report.loss_rate - report.bust_rate;
+    assert!((report.ev - ev).abs() < 1e-9);
+
+    let other_seed = simulation::run(&SimulationConfig { seed: 7, ..config }).unwrap();
+    assert_ne!(report.ev, other_seed.ev);
+}
+
+#[test]
+fn test_simulation_rejects_invalid_config() {
+    let too_many_seats = SimulationConfig {
+        seats: 3,
+        rules: TableRules {
+            max_players: 2,
+            ..TableRules::default()
+        },
+        ..SimulationConfig::default()
+    };
+    assert!(simulation::run(&too_many_seats).is_err());
+
+    let doubling = SimulationConfig {
+        rules: TableRules {
+            double_allowed: true,
+            ..TableRules::default()
+        },
+        ..SimulationConfig::default()
+    };
+    assert!(simulation::run(&doubling).is_err());
+
+    let no_rounds = SimulationConfig {
+        rounds: 0,
+        ..SimulationConfig::default()
+    };
+    assert!(simulation::run(&no_rounds).is_err());
+}
+
+#[test]
+fn test_rating_changes_favour_the_underdog() {
+    assert_eq!(rating::expected_score(1500.0, 1500.0), 0.5);
+    let strong = rating::expected_score(1800.0, 1500.0);
+    assert!((strong + rating::expected_score(1500.0, 1800.0) - 1.0).abs() < 1e-9);
+
+    let seat = |rating, outcome| RatedSeat {
+        rating,
+        games_played: PROVISIONAL_GAMES,
+        outcome,
+    };
+
+    // Heads-up against the dealer: beating the odds earns more
+    let underdog_win = rating::rating_changes(&[seat(1300.0, PlayerOutcome::Won)])[0];
+    let favourite_win = rating::rating_changes(&[seat(1700.0, PlayerOutcome::Won)])[0];
+    assert!(underdog_win > favourite_win);
+    assert!(favourite_win > 0.0);
+
+    // A push against an equally rated dealer changes nothing
+    let push = rating::rating_changes(&[seat(DEALER_RATING, PlayerOutcome::Push)])[0];
+    assert_eq!(push, 0.0);
+
+    // New players move faster
+    let provisional = RatedSeat {
+        games_played: 0,
+        ..seat(INITIAL_RATING, PlayerOutcome::Lost)
+    };
+    let established = seat(INITIAL_RATING, PlayerOutcome::Lost);
+    let changes = rating::rating_changes(&[provisional, established]);
+    assert!(changes[0] < changes[1]);
+    assert!(changes[1] < 0.0);
+
+    let band = RatingBand {
+        min: Some(1400),
+        max: Some(1600),
+    };
+    assert!(band.contains(1400) && band.contains(1600));
+    assert!(!band.contains(1399) && !band.contains(1601));
+    assert!(RatingBand::default().contains(0));
+    assert!(
+        RatingBand {
+            min: Some(1600),
+            max: Some(1400)
+        }
+        .validate()
+        .is_err()
+    );
+}
+
+#[test]
+fn test_streaks_and_achievement_criteria() {
+    let hand = |outcome: PlayerOutcome, points, cards_count| PlayerResult {
+        player_id: Uuid::new_v4(),
+        points,
+        cards_count,
+        busted: outcome == PlayerOutcome::Busted,
+        outcome,
+        is_bot: false,
+        hints_used: 0,
+    };
+    let mut stats = UserStats::new();
+
+    // The dealer busts three times, the player stays in and wins each time
+    for _ in 0..3 {
+        stats.record_game(&PlayerOutcome::Won, 18, true);
+    }
+    assert_eq!(stats.current_win_streak, 3);
+    assert_eq!(stats.dealer_bust_streak, 3);
+    let win = hand(PlayerOutcome::Won, 18, 3);
+    assert!(Criterion::DealerBustStreak(3).is_met(&win, true, &stats));
+    assert!(!Criterion::WinStreak(5).is_met(&win, true, &stats));
+
+    // Busting breaks both streaks but keeps the longest win streak
+    stats.record_game(&PlayerOutcome::Busted, 24, true);
+    assert_eq!(stats.current_win_streak, 0);
+    assert_eq!(stats.longest_win_streak, 3);
+    assert_eq!(stats.dealer_bust_streak, 0);
+
+    let charlie = hand(PlayerOutcome::Won, 20, 5);
+    assert!(Criterion::WinWithCards(5).is_met(&charlie, false, &stats));
+    let lost_charlie = hand(PlayerOutcome::Lost, 20, 5);
+    assert!(!Criterion::WinWithCards(5).is_met(&lost_charlie, false, &stats));
+    assert!(!Criterion::GamesPlayed(100).is_met(&charlie, false, &stats));
+
+    // Achievements already unlocked are not unlocked again
+    let unlocked = achievements::evaluate(&[], &charlie, false, &stats);
+    assert_eq!(
+        unlocked.iter().map(|a| a.id).collect::<Vec<_>>(),
+        vec!["five_card_charlie"]
+    );
+    let done = [UnlockedAchievement {
+        id: "five_card_charlie".to_string(),
+        unlocked_at: "2026-10-18T12:00:00+00:00".to_string(),
+    }];
+    assert!(achievements::evaluate(&done, &charlie, false, &stats).is_empty());
+
+    // IDs are unique and can be looked up
+    for achievement in ACHIEVEMENTS {
+        assert_eq!(achievements::find(achievement.id), Some(achievement));
+    }
+}
@@ -27,5 +27,19 @@
 --   drawn_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 -- );
 
+-- CREATE TABLE audit_log (
+--   id TEXT PRIMARY KEY,
+--   timestamp TIMESTAMP NOT NULL,
+--   request_id TEXT,
+--   actor_id TEXT,
+--   actor_email TEXT NOT NULL,
+--   actor_role TEXT NOT NULL,
+--   action TEXT NOT NULL,
+--   target TEXT NOT NULL,
+--   outcome TEXT NOT NULL,
+--   status INTEGER NOT NULL
+-- );
+
 -- CREATE INDEX idx_players_game ON players(game_id);
 -- CREATE INDEX idx_cards_player ON cards_history(player_id);
+-- CREATE INDEX idx_audit_log_timestamp ON audit_log(timestamp);
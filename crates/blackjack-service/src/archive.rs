@@ -0,0 +1,425 @@
+//! Archive of finished games
+//!
+//! When a game's results are recorded the whole table is archived: its rules,
+//! the dealer's hand and every seat's hand, outcome and chips. Players' game
+//! history is served from the archive, so it outlives the game itself.
+//!
+//! Like the audit log, archived games are kept in memory and, when the archive
+//! is opened with [`GameArchive::open`], also appended to a JSON Lines file
+//! that is replayed on startup. The file stands in for the SQLite `games` and
+//! `players` tables prepared in `migrations/` until the database layer lands.
+
+use crate::GameError;
+use crate::leaderboard::net_chips;
+use blackjack_core::{Card, Game, GameResult, PlayerOutcome, TableRules};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Page size of a player's game history when the query sets no limit
+pub const DEFAULT_PLAYER_GAMES_PAGE_SIZE: usize = 20;
+
+/// Largest page of a player's game history
+pub const MAX_PLAYER_GAMES_PAGE_SIZE: usize = 100;
+
+/// Cards of one hand and their final value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedHand {
+    pub cards: Vec<Card>,
+    pub points: u8,
+    pub busted: bool,
+}
+
+/// A seat at an archived table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedSeat {
+    /// User ID, or a generated ID for bots
+    pub player_id: Uuid,
+    pub display_name: String,
+    pub is_bot: bool,
+    /// One hand per seat until splitting is supported at tables
+    pub hands: Vec<ArchivedHand>,
+    pub outcome: PlayerOutcome,
+    /// Chips won or lost betting the table minimum, see [`net_chips`]
+    pub net_chips: i64,
+}
+
+/// A finished game as archived
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedGame {
+    pub game_id: Uuid,
+    pub finished_at: DateTime<Utc>,
+    pub rules: TableRules,
+    pub dealer: ArchivedHand,
+    /// Seats in turn order
+    pub seats: Vec<ArchivedSeat>,
+}
+
+impl ArchivedGame {
+    /// Archives a finished game from its calculated results
+    pub fn new(game: &Game, results: &GameResult, finished_at: DateTime<Utc>) -> Self {
+        let seats = game
+            .turn_order
+            .iter()
+            .filter_map(|player_id| game.players.get(player_id))
+            .filter_map(|player| {
+                let result = results.player_results.get(&player.name)?;
+                Some(ArchivedSeat {
+                    player_id: player.id,
+                    display_name: player.name.clone(),
+                    is_bot: result.is_bot,
+                    hands: vec![ArchivedHand {
+                        cards: player.cards_history.clone(),
+                        points: player.points,
+                        busted: player.busted,
+                    }],
+                    outcome: result.outcome.clone(),
+                    net_chips: net_chips(result, game.rules.min_bet),
+                })
+            })
+            .collect();
+
+        Self {
+            game_id: game.id,
+            finished_at,
+            rules: game.rules.clone(),
+            dealer: ArchivedHand {
+                cards: game.dealer.cards_history.clone(),
+                points: game.dealer.points,
+                busted: game.dealer.busted,
+            },
+            seats,
+        }
+    }
+
+    fn seat(&self, player_id: Uuid) -> Option<&ArchivedSeat> {
+        self.seats.iter().find(|seat| seat.player_id == player_id)
+    }
+}
+
+/// Another seat at the table, as seen in a player's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Opponent {
+    /// `None` for bots
+    pub user_id: Option<Uuid>,
+    pub display_name: String,
+    pub outcome: PlayerOutcome,
+}
+
+/// One game in a player's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerGameEntry {
+    pub game_id: Uuid,
+    pub played_at: DateTime<Utc>,
+    pub rules: TableRules,
+    pub hands: Vec<ArchivedHand>,
+    pub dealer_points: u8,
+    pub dealer_busted: bool,
+    pub outcome: PlayerOutcome,
+    pub net_chips: i64,
+    pub opponents: Vec<Opponent>,
+}
+
+impl PlayerGameEntry {
+    fn new(game: &ArchivedGame, seat: &ArchivedSeat) -> Self {
+        Self {
+            game_id: game.game_id,
+            played_at: game.finished_at,
+            rules: game.rules.clone(),
+            hands: seat.hands.clone(),
+            dealer_points: game.dealer.points,
+            dealer_busted: game.dealer.busted,
+            outcome: seat.outcome.clone(),
+            net_chips: seat.net_chips,
+            opponents: game
+                .seats
+                .iter()
+                .filter(|other| other.player_id != seat.player_id)
+                .map(|other| Opponent {
+                    user_id: (!other.is_bot).then_some(other.player_id),
+                    display_name: other.display_name.clone(),
+                    outcome: other.outcome.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Filters and page of a player's game history; unset filters match everything
+#[derive(Debug, Clone, Default)]
+pub struct PlayerGamesQuery {
+    pub outcome: Option<PlayerOutcome>,
+    /// Games finished at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Games finished before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Opaque cursor returned as `next_cursor` by the previous page
+    pub cursor: Option<String>,
+    /// Page size (default `DEFAULT_PLAYER_GAMES_PAGE_SIZE`, capped at `MAX_PLAYER_GAMES_PAGE_SIZE`)
+    pub limit: Option<usize>,
+}
+
+impl PlayerGamesQuery {
+    fn matches(&self, game: &ArchivedGame, seat: &ArchivedSeat) -> bool {
+        self.outcome
+            .as_ref()
+            .is_none_or(|outcome| seat.outcome == *outcome)
+            && self.since.is_none_or(|since| game.finished_at >= since)
+            && self.until.is_none_or(|until| game.finished_at < until)
+    }
+
+    fn parse_cursor(&self) -> Result<Option<(i64, Uuid)>, GameError> {
+        let Some(cursor) = self.cursor.as_deref() else {
+            return Ok(None);
+        };
+        cursor
+            .split_once('_')
+            .and_then(|(key, id)| Some((key.parse().ok()?, Uuid::parse_str(id).ok()?)))
+            .map(Some)
+            .ok_or_else(|| GameError::ValidationError("Invalid cursor".to_string()))
+    }
+}
+
+/// One page of a player's game history, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerGamesPage {
+    pub games: Vec<PlayerGameEntry>,
+    /// Number of games matching the filters, across all pages
+    pub total: usize,
+    /// Cursor for the next page, `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Sort key of an archived game: newest first, ties broken by game ID
+fn sort_key(game: &ArchivedGame) -> (i64, Uuid) {
+    (game.finished_at.timestamp_millis(), game.game_id)
+}
+
+#[derive(Default)]
+struct Archive {
+    games: Vec<ArchivedGame>,
+    /// Indexes into `games` of the games each player sat at
+    by_player: HashMap<Uuid, Vec<usize>>,
+}
+
+impl Archive {
+    fn insert(&mut self, game: ArchivedGame) {
+        let index = self.games.len();
+        for seat in game.seats.iter().filter(|seat| !seat.is_bot) {
+            self.by_player
+                .entry(seat.player_id)
+                .or_default()
+                .push(index);
+        }
+        self.games.push(game);
+    }
+
+    /// Games a player sat at with their seat, newest first
+    fn player_seats(&self, user_id: Uuid) -> Vec<(&ArchivedGame, &ArchivedSeat)> {
+        let mut games: Vec<(&ArchivedGame, &ArchivedSeat)> = self
+            .by_player
+            .get(&user_id)
+            .into_iter()
+            .flatten()
+            .map(|index| &self.games[*index])
+            .filter_map(|game| Some((game, game.seat(user_id)?)))
+            .collect();
+        games.sort_by_key(|(game, _)| std::cmp::Reverse(sort_key(game)));
+        games
+    }
+}
+
+/// Finished games, optionally backed by a JSON Lines file
+pub struct GameArchive {
+    archive: Mutex<Archive>,
+    file: Option<(PathBuf, Mutex<File>)>,
+}
+
+impl GameArchive {
+    /// Creates an empty, memory-only archive
+    pub fn new() -> Self {
+        Self {
+            archive: Mutex::new(Archive::default()),
+            file: None,
+        }
+    }
+
+    /// Opens a file-backed archive
+    ///
+    /// Games already in the file are loaded and new games are appended. Lines
+    /// that don't parse are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or opened for appending.
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let mut archive = Archive::default();
+
+        if path.exists() {
+            for (number, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<ArchivedGame>(&line) {
+                    Ok(game) => archive.insert(game),
+                    Err(err) => tracing::warn!(
+                        path = %path.display(),
+                        line = number + 1,
+                        error = %err,
+                        "Skipping unreadable archived game"
+                    ),
+                }
+            }
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        tracing::info!(path = %path.display(), games = archive.games.len(), "Game archive opened");
+
+        Ok(Self {
+            archive: Mutex::new(archive),
+            file: Some((path.to_path_buf(), Mutex::new(file))),
+        })
+    }
+
+    /// Adds a finished game
+    pub fn record(&self, game: ArchivedGame) {
+        if let Some((_, file)) = &self.file {
+            let line = serde_json::to_string(&game).expect("archived games serialize");
+            if let Err(err) = writeln!(file.lock().unwrap(), "{}", line) {
+                // The in-memory copy still serves history until the next restart
+                tracing::error!(game_id = %game.game_id, error = %err, "Failed to archive game");
+            }
+        }
+
+        tracing::debug!(game_id = %game.game_id, seats = game.seats.len(), "Game archived");
+        self.archive.lock().unwrap().insert(game);
+    }
+
+    /// Games a player sat at, newest first and paginated
+    ///
+    /// Pagination is keyset based like the open games lobby, so games
+    /// finishing between requests don't shift later pages.
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the cursor is malformed
+    pub fn player_games(
+        &self,
+        user_id: Uuid,
+        query: &PlayerGamesQuery,
+    ) -> Result<PlayerGamesPage, GameError> {
+        let cursor = query.parse_cursor()?;
+        let archive = self.archive.lock().unwrap();
+
+        let mut games = archive.player_seats(user_id);
+        games.retain(|(game, seat)| query.matches(game, seat));
+
+        let total = games.len();
+        let start = cursor.map_or(0, |cursor| {
+            games.partition_point(|(game, _)| sort_key(game) >= cursor)
+        });
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PLAYER_GAMES_PAGE_SIZE)
+            .clamp(1, MAX_PLAYER_GAMES_PAGE_SIZE);
+        let end = start.saturating_add(limit).min(total);
+
+        let next_cursor = (end < total).then(|| {
+            let (key, game_id) = sort_key(games[end - 1].0);
+            format!("{}_{}", key, game_id)
+        });
+
+        Ok(PlayerGamesPage {
+            games: games[start..end]
+                .iter()
+                .map(|(game, seat)| PlayerGameEntry::new(game, seat))
+                .collect(),
+            total,
+            next_cursor,
+        })
+    }
+
+    /// Every game a player sat at, newest first
+    ///
+    /// Unlike [`player_games`](Self::player_games) this is not paginated; it
+    /// backs the account export.
+    pub fn all_player_games(&self, user_id: Uuid) -> Vec<PlayerGameEntry> {
+        let archive = self.archive.lock().unwrap();
+        archive
+            .player_seats(user_id)
+            .into_iter()
+            .map(|(game, seat)| PlayerGameEntry::new(game, seat))
+            .collect()
+    }
+
+    /// Moves a deleted account's seats to its tombstone
+    ///
+    /// A file-backed archive is rewritten so the account's ID and name don't
+    /// survive on disk. Returns how many games were updated.
+    pub fn anonymize_player(&self, user_id: Uuid, tombstone_id: Uuid, name: &str) -> usize {
+        let mut archive = self.archive.lock().unwrap();
+        let Some(indexes) = archive.by_player.remove(&user_id) else {
+            return 0;
+        };
+        for index in &indexes {
+            for seat in archive.games[*index]
+                .seats
+                .iter_mut()
+                .filter(|seat| seat.player_id == user_id)
+            {
+                seat.player_id = tombstone_id;
+                seat.display_name = name.to_string();
+            }
+        }
+        let count = indexes.len();
+        archive.by_player.insert(tombstone_id, indexes);
+
+        if let Some((path, file)) = &self.file
+            && let Err(err) = Self::rewrite(path, file, &archive.games)
+        {
+            tracing::error!(path = %path.display(), error = %err, "Failed to rewrite game archive");
+        }
+
+        count
+    }
+
+    /// Replaces the file's contents with `games`
+    fn rewrite(path: &Path, file: &Mutex<File>, games: &[ArchivedGame]) -> std::io::Result<()> {
+        let mut file = file.lock().unwrap();
+        let temp = path.with_extension("jsonl.tmp");
+        {
+            let mut out = File::create(&temp)?;
+            for game in games {
+                let line = serde_json::to_string(game).expect("archived games serialize");
+                writeln!(out, "{}", line)?;
+            }
+            out.sync_all()?;
+        }
+        std::fs::rename(&temp, path)?;
+        *file = OpenOptions::new().append(true).open(path)?;
+        Ok(())
+    }
+
+    /// Number of archived games
+    pub fn len(&self) -> usize {
+        self.archive.lock().unwrap().games.len()
+    }
+
+    /// Whether no game has been archived yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for GameArchive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,384 @@
+//! Security audit log
+//!
+//! Security-relevant events (logins, password changes, kicks, permission
+//! denials and every request to the administration API) are appended here as
+//! structured records, whether they succeeded, failed or were denied, so
+//! operators can see who did what.
+//!
+//! The newest [`MAX_AUDIT_RECORDS`] records are kept in memory for queries.
+//! When the log is opened with [`AuditLog::open`] every record is also
+//! appended to a JSON Lines file that is replayed on startup, and exports read
+//! the whole file. A log created with [`AuditLog::new`] persists nothing and
+//! loses older records once the window is full. The file stands in for the
+//! SQLite `audit_log` table prepared in `migrations/` until the database layer
+//! lands.
+
+use blackjack_core::{PlatformRole, User};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Records kept in memory for queries; the oldest are dropped first
+///
+/// Exports of a file-backed log are not limited by this window.
+pub const MAX_AUDIT_RECORDS: usize = 10_000;
+
+/// Action names of audited security events
+///
+/// Requests to the administration API use the route instead, e.g.
+/// `POST /api/v1/admin/users/:user_id/unlock`.
+pub mod actions {
+    pub const LOGIN: &str = "auth.login";
+    pub const PASSWORD_CHANGE: &str = "auth.password_change";
+    pub const PASSWORD_RESET_REQUEST: &str = "auth.password_reset_request";
+    pub const PASSWORD_RESET: &str = "auth.password_reset";
+    pub const EMAIL_VERIFICATION: &str = "auth.email_verification";
+    pub const ACCOUNT_UPDATE: &str = "account.update";
+    pub const ACCOUNT_DELETION: &str = "account.deletion_request";
+    pub const CLOSE_ENROLLMENT: &str = "game.close_enrollment";
+    pub const KICK_PLAYER: &str = "game.kick_player";
+    pub const CHAT_MUTE: &str = "game.chat_mute";
+    pub const CHAT_DELETE_MESSAGE: &str = "game.chat_delete_message";
+}
+
+/// How an audited action ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    Success,
+    /// The actor lacked the required role or permission
+    Denied,
+    /// The action was allowed but could not be carried out
+    Failed,
+}
+
+impl AuditOutcome {
+    /// Outcome of a request that returned HTTP `status`
+    ///
+    /// `403 Forbidden` counts as denied, other non-2xx statuses as failed.
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            200..=299 => Self::Success,
+            403 => Self::Denied,
+            _ => Self::Failed,
+        }
+    }
+}
+
+impl std::str::FromStr for AuditOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "success" => Ok(Self::Success),
+            "denied" => Ok(Self::Denied),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!("Unknown audit outcome: {}", other)),
+        }
+    }
+}
+
+/// One audited action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub id: Uuid,
+    pub timestamp: DateTime<Utc>,
+    /// Request that triggered the action (`X-Request-Id`)
+    pub request_id: Option<String>,
+    /// `None` when the actor has no account, e.g. a login with an unknown email
+    pub actor_id: Option<Uuid>,
+    pub actor_email: String,
+    /// Role the actor held when acting
+    pub actor_role: PlatformRole,
+    /// Event name such as `auth.login`, or the admin route template
+    pub action: String,
+    /// Resource acted on, e.g. a user or game ID or an admin request path
+    pub target: String,
+    pub outcome: AuditOutcome,
+    /// HTTP status returned to the actor
+    pub status: u16,
+}
+
+/// Who performed an audited action
+#[derive(Debug, Clone)]
+pub struct AuditActor {
+    pub user_id: Option<Uuid>,
+    pub email: String,
+    pub role: PlatformRole,
+}
+
+impl AuditActor {
+    /// An actor without an account, identified only by the email they gave
+    pub fn anonymous(email: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            email: email.into(),
+            role: PlatformRole::User,
+        }
+    }
+}
+
+impl From<&User> for AuditActor {
+    fn from(user: &User) -> Self {
+        Self {
+            user_id: Some(user.id),
+            email: user.email.clone(),
+            role: user.role,
+        }
+    }
+}
+
+/// An event to append to the audit log
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    pub actor: AuditActor,
+    pub action: String,
+    pub target: String,
+    pub outcome: AuditOutcome,
+    pub status: u16,
+    pub request_id: Option<String>,
+}
+
+/// Filters for querying the audit log; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    /// Actor ID or email
+    pub actor: Option<String>,
+    /// Action prefix, e.g. `auth.` or `POST /api/v1/admin/users`
+    pub action: Option<String>,
+    /// Substring of the target
+    pub target: Option<String>,
+    pub outcome: Option<AuditOutcome>,
+    pub request_id: Option<String>,
+    /// Records at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Records before this time
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl AuditFilter {
+    /// Whether `record` passes every set filter
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        self.actor.as_ref().is_none_or(|actor| {
+            record.actor_email == *actor
+                || record.actor_id.is_some_and(|id| id.to_string() == *actor)
+        }) && self
+            .action
+            .as_ref()
+            .is_none_or(|action| record.action.starts_with(action.as_str()))
+            && self
+                .target
+                .as_ref()
+                .is_none_or(|target| record.target.contains(target.as_str()))
+            && self.outcome.is_none_or(|outcome| record.outcome == outcome)
+            && self
+                .request_id
+                .as_ref()
+                .is_none_or(|id| record.request_id.as_ref() == Some(id))
+            && self.since.is_none_or(|since| record.timestamp >= since)
+            && self.until.is_none_or(|until| record.timestamp < until)
+    }
+}
+
+/// Append-only audit log, optionally backed by a JSON Lines file
+pub struct AuditLog {
+    records: Arc<Mutex<VecDeque<AuditRecord>>>,
+    file: Option<(PathBuf, Mutex<File>)>,
+}
+
+impl AuditLog {
+    /// Creates an empty, memory-only audit log
+    pub fn new() -> Self {
+        Self {
+            records: Arc::new(Mutex::new(VecDeque::new())),
+            file: None,
+        }
+    }
+
+    /// Opens a file-backed audit log
+    ///
+    /// Records already in the file are loaded (the newest
+    /// [`MAX_AUDIT_RECORDS`] stay queryable) and new records are appended.
+    /// Lines that don't parse are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or opened for appending.
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let mut records = VecDeque::new();
+
+        if path.exists() {
+            for (number, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<AuditRecord>(&line) {
+                    Ok(record) => {
+                        records.push_back(record);
+                        if records.len() > MAX_AUDIT_RECORDS {
+                            records.pop_front();
+                        }
+                    }
+                    Err(err) => tracing::warn!(
+                        path = %path.display(),
+                        line = number + 1,
+                        error = %err,
+                        "Skipping unreadable audit record"
+                    ),
+                }
+            }
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        tracing::info!(path = %path.display(), records = records.len(), "Audit log opened");
+
+        Ok(Self {
+            records: Arc::new(Mutex::new(records)),
+            file: Some((path.to_path_buf(), Mutex::new(file))),
+        })
+    }
+
+    /// Appends a record
+    pub fn record(&self, event: AuditEvent) -> AuditRecord {
+        let record = AuditRecord {
+            id: Uuid::new_v4(),
+            timestamp: Utc::now(),
+            request_id: event.request_id,
+            actor_id: event.actor.user_id,
+            actor_email: event.actor.email,
+            actor_role: event.actor.role,
+            action: event.action,
+            target: event.target,
+            outcome: event.outcome,
+            status: event.status,
+        };
+
+        tracing::info!(
+            actor = %record.actor_email,
+            action = %record.action,
+            target = %record.target,
+            outcome = ?record.outcome,
+            request_id = ?record.request_id,
+            "Audit record"
+        );
+
+        if let Some((_, file)) = &self.file {
+            let line = serde_json::to_string(&record).expect("audit records serialize");
+            if let Err(err) = writeln!(file.lock().unwrap(), "{}", line) {
+                // The in-memory copy is still kept; losing the file must not
+                // block the action being audited
+                tracing::error!(error = %err, "Failed to write audit record");
+            }
+        }
+
+        let mut records = self.records.lock().unwrap();
+        records.push_back(record.clone());
+        if records.len() > MAX_AUDIT_RECORDS {
+            records.pop_front();
+        }
+
+        record
+    }
+
+    /// Most recent records, newest first
+    pub fn recent(&self, limit: usize) -> Vec<AuditRecord> {
+        self.query(&AuditFilter::default(), limit)
+    }
+
+    /// Records matching `filter`, newest first
+    pub fn query(&self, filter: &AuditFilter, limit: usize) -> Vec<AuditRecord> {
+        let records = self.records.lock().unwrap();
+        records
+            .iter()
+            .rev()
+            .filter(|record| filter.matches(record))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Records matching `filter` as JSON Lines, oldest first, one per item
+    ///
+    /// A file-backed log is read from its file as the lines are consumed, so
+    /// the export covers every record ever written, not just the in-memory
+    /// window. Unreadable lines are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be opened for reading.
+    pub fn export_lines(
+        &self,
+        filter: AuditFilter,
+    ) -> std::io::Result<Box<dyn Iterator<Item = String> + Send>> {
+        let Some((path, _)) = &self.file else {
+            let records = self.records.lock().unwrap();
+            let lines: Vec<String> = records
+                .iter()
+                .filter(|record| filter.matches(record))
+                .map(to_json_line)
+                .collect();
+            return Ok(Box::new(lines.into_iter()));
+        };
+
+        let path = path.clone();
+        let lines = BufReader::new(File::open(&path)?)
+            .lines()
+            .enumerate()
+            .map_while(move |(number, line)| match line {
+                Ok(line) => Some((number, line)),
+                Err(err) => {
+                    tracing::error!(path = %path.display(), error = %err, "Failed to read audit log");
+                    None
+                }
+            })
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(number, line)| match serde_json::from_str::<AuditRecord>(&line) {
+                Ok(record) => Some(record),
+                Err(err) => {
+                    tracing::warn!(line = number + 1, error = %err, "Skipping unreadable audit record");
+                    None
+                }
+            })
+            .filter(move |record| filter.matches(record))
+            .map(|record| to_json_line(&record));
+        Ok(Box::new(lines))
+    }
+
+    /// Records matching `filter` as JSON Lines, oldest first
+    ///
+    /// Collects [`export_lines`](Self::export_lines) into one string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be opened for reading.
+    pub fn export_json_lines(&self, filter: &AuditFilter) -> std::io::Result<String> {
+        Ok(self.export_lines(filter.clone())?.collect())
+    }
+
+    /// Number of records currently kept
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap().len()
+    }
+
+    /// Whether nothing has been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn to_json_line(record: &AuditRecord) -> String {
+    serde_json::to_string(record).expect("audit records serialize") + "\n"
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,189 @@
+//! Per-game table chat
+//!
+//! Participants of a game can post short messages to their table; every
+//! other participant receives a `chat_message` notification pointing at the
+//! message and the latest messages are kept as the table's history. Holders of the
+//! `ModerateChat` game permission (the creator) can mute participants, who
+//! then can't post, and delete messages from the history.
+//!
+//! Posting rates are limited by the API layer; this module only checks the
+//! message itself and mutes. Participation and permissions are checked by
+//! [`GameService`](crate::GameService), which owns the chat.
+
+use crate::GameError;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Longest chat message, in characters
+pub const MAX_CHAT_MESSAGE_LENGTH: usize = 500;
+
+/// Messages kept per game; the oldest are dropped first
+pub const MAX_CHAT_HISTORY: usize = 200;
+
+/// Messages returned when the history request sets no limit
+pub const DEFAULT_CHAT_HISTORY_PAGE_SIZE: usize = 50;
+
+/// A message posted to a table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub author_id: Uuid,
+    /// Author's display name in the game
+    pub author_name: String,
+    pub text: String,
+    pub sent_at: String,
+}
+
+/// Latest messages of a table and who is muted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatHistory {
+    /// Oldest first
+    pub messages: Vec<ChatMessage>,
+    pub muted: Vec<Uuid>,
+}
+
+#[derive(Default)]
+struct ChatChannel {
+    messages: VecDeque<ChatMessage>,
+    muted: HashSet<Uuid>,
+}
+
+/// Chat channels of every game, created on first use
+pub struct TableChat {
+    channels: Mutex<HashMap<Uuid, ChatChannel>>,
+}
+
+impl TableChat {
+    /// Creates an empty chat store
+    pub fn new() -> Self {
+        Self {
+            channels: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Adds a message to a game's channel
+    ///
+    /// Surrounding whitespace is trimmed.
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the message is empty or longer than `MAX_CHAT_MESSAGE_LENGTH`
+    /// - `ChatMuted` if the author is muted in this game
+    pub fn post(
+        &self,
+        game_id: Uuid,
+        author_id: Uuid,
+        author_name: &str,
+        text: &str,
+    ) -> Result<ChatMessage, GameError> {
+        let text = text.trim();
+        if text.is_empty() || text.chars().count() > MAX_CHAT_MESSAGE_LENGTH {
+            return Err(GameError::ValidationError(format!(
+                "Chat messages must be between 1 and {} characters",
+                MAX_CHAT_MESSAGE_LENGTH
+            )));
+        }
+
+        let mut channels = self.channels.lock().unwrap();
+        let channel = channels.entry(game_id).or_default();
+        if channel.muted.contains(&author_id) {
+            return Err(GameError::ChatMuted);
+        }
+
+        let message = ChatMessage {
+            id: Uuid::new_v4(),
+            game_id,
+            author_id,
+            author_name: author_name.to_string(),
+            text: text.to_string(),
+            sent_at: chrono::Utc::now().to_rfc3339(),
+        };
+        channel.messages.push_back(message.clone());
+        if channel.messages.len() > MAX_CHAT_HISTORY {
+            channel.messages.pop_front();
+        }
+
+        Ok(message)
+    }
+
+    /// Latest `limit` messages of a game, oldest first
+    pub fn history(&self, game_id: Uuid, limit: usize) -> ChatHistory {
+        let channels = self.channels.lock().unwrap();
+        let Some(channel) = channels.get(&game_id) else {
+            return ChatHistory {
+                messages: Vec::new(),
+                muted: Vec::new(),
+            };
+        };
+
+        let skip = channel.messages.len().saturating_sub(limit);
+        let mut muted: Vec<Uuid> = channel.muted.iter().copied().collect();
+        muted.sort();
+        ChatHistory {
+            messages: channel.messages.iter().skip(skip).cloned().collect(),
+            muted,
+        }
+    }
+
+    /// Removes a message from a game's history
+    ///
+    /// # Errors
+    ///
+    /// - `ChatMessageNotFound` if the game has no such message
+    pub fn delete(&self, game_id: Uuid, message_id: Uuid) -> Result<ChatMessage, GameError> {
+        let mut channels = self.channels.lock().unwrap();
+        let messages = &mut channels
+            .get_mut(&game_id)
+            .ok_or(GameError::ChatMessageNotFound)?
+            .messages;
+        let index = messages
+            .iter()
+            .position(|message| message.id == message_id)
+            .ok_or(GameError::ChatMessageNotFound)?;
+
+        Ok(messages.remove(index).expect("index is in bounds"))
+    }
+
+    /// Mutes or unmutes a user in a game; returns whether anything changed
+    pub fn set_muted(&self, game_id: Uuid, user_id: Uuid, muted: bool) -> bool {
+        let mut channels = self.channels.lock().unwrap();
+        let channel = channels.entry(game_id).or_default();
+        if muted {
+            channel.muted.insert(user_id)
+        } else {
+            channel.muted.remove(&user_id)
+        }
+    }
+
+    /// Moves a deleted account's messages and mutes to its tombstone
+    ///
+    /// Returns how many messages were updated.
+    pub fn anonymize_author(&self, user_id: Uuid, tombstone_id: Uuid, name: &str) -> usize {
+        let mut channels = self.channels.lock().unwrap();
+        let mut count = 0;
+        for channel in channels.values_mut() {
+            for message in channel
+                .messages
+                .iter_mut()
+                .filter(|message| message.author_id == user_id)
+            {
+                message.author_id = tombstone_id;
+                message.author_name = name.to_string();
+                count += 1;
+            }
+            if channel.muted.remove(&user_id) {
+                channel.muted.insert(tombstone_id);
+            }
+        }
+        count
+    }
+}
+
+impl Default for TableChat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,243 @@
+//! Leaderboards ranking players by win rate, chips, streaks and blackjacks
+//!
+//! Every recorded result updates one tally per time window (today, this ISO
+//! week and all time), so a query only sorts the players of one period and
+//! never scans the user store. Tallies of a past day or week are dropped once
+//! the next one starts.
+//!
+//! Chips are counted as a flat bet of the table's minimum per hand, see
+//! [`net_chips`].
+
+use blackjack_core::{PlayerOutcome, PlayerResult};
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Games a player needs before appearing on the win rate board, by default
+pub const DEFAULT_MIN_GAMES: u32 = 5;
+
+/// Entries returned when the query sets no limit
+pub const DEFAULT_LEADERBOARD_SIZE: usize = 10;
+
+/// Largest number of entries a query can ask for
+pub const MAX_LEADERBOARD_SIZE: usize = 100;
+
+/// Chips a hand won or lost when played for `stake`
+///
+/// Pays out as [`PlayerResult::payout`], so a blackjack win pays 3:2,
+/// rounded down to whole chips.
+pub fn net_chips(result: &PlayerResult, stake: u32) -> i64 {
+    (f64::from(stake) * result.payout()).floor() as i64
+}
+
+/// What a leaderboard ranks players by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Board {
+    /// Share of games won, among players with enough games
+    #[default]
+    WinRate,
+    /// Chips won minus chips lost
+    NetChips,
+    /// Longest run of consecutive wins
+    WinStreak,
+    /// Hands of 21 with the first two cards
+    Blackjacks,
+}
+
+/// Period a leaderboard covers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardWindow {
+    /// Current UTC day
+    Daily,
+    /// Current ISO week (UTC)
+    Weekly,
+    #[default]
+    AllTime,
+}
+
+impl LeaderboardWindow {
+    const ALL: [Self; 3] = [Self::Daily, Self::Weekly, Self::AllTime];
+
+    /// Label of the period containing `at`, e.g. `2026-10-18` or `2026-W42`
+    pub fn period(self, at: DateTime<Utc>) -> String {
+        match self {
+            Self::Daily => at.format("%Y-%m-%d").to_string(),
+            Self::Weekly => {
+                let week = at.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            Self::AllTime => "all-time".to_string(),
+        }
+    }
+}
+
+/// A player's results within one period
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub games_played: u32,
+    pub games_won: u32,
+    pub net_chips: i64,
+    pub blackjacks: u32,
+    pub current_win_streak: u32,
+    pub longest_win_streak: u32,
+}
+
+impl Tally {
+    fn record(&mut self, result: &PlayerResult, stake: u32) {
+        self.games_played += 1;
+        self.net_chips += net_chips(result, stake);
+        if result.is_blackjack() {
+            self.blackjacks += 1;
+        }
+
+        if result.outcome == PlayerOutcome::Won {
+            self.games_won += 1;
+            self.current_win_streak += 1;
+            self.longest_win_streak = self.longest_win_streak.max(self.current_win_streak);
+        } else {
+            self.current_win_streak = 0;
+        }
+    }
+
+    /// Percentage of games won
+    pub fn win_rate(&self) -> f32 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            (self.games_won as f32 / self.games_played as f32) * 100.0
+        }
+    }
+}
+
+/// Which leaderboard to show
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LeaderboardQuery {
+    #[serde(default)]
+    pub board: Board,
+    #[serde(default)]
+    pub window: LeaderboardWindow,
+    /// Games needed for the win rate board (default `DEFAULT_MIN_GAMES`)
+    #[serde(default)]
+    pub min_games: Option<u32>,
+    /// Entries to return (default 10, at most `MAX_LEADERBOARD_SIZE`)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl LeaderboardQuery {
+    /// Games a player needs to be ranked on this board
+    pub fn min_games(&self) -> u32 {
+        match self.board {
+            Board::WinRate => self.min_games.unwrap_or(DEFAULT_MIN_GAMES).max(1),
+            _ => 1,
+        }
+    }
+
+    /// Entries to return
+    pub fn limit(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_LEADERBOARD_SIZE)
+            .clamp(1, MAX_LEADERBOARD_SIZE)
+    }
+}
+
+/// One ranked player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub avatar_seed: String,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub win_rate: f32,
+    pub net_chips: i64,
+    pub longest_win_streak: u32,
+    pub blackjacks: u32,
+}
+
+/// A ranked leaderboard for one period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub board: Board,
+    pub window: LeaderboardWindow,
+    /// Label of the period shown, see [`LeaderboardWindow::period`]
+    pub period: String,
+    pub min_games: u32,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// Tallies of one window's current period, keyed by user
+type PeriodTallies = (String, HashMap<Uuid, Tally>);
+
+/// Tallies of the current period of every window
+#[derive(Debug, Default)]
+pub struct Leaderboards {
+    periods: Mutex<HashMap<LeaderboardWindow, PeriodTallies>>,
+}
+
+impl Leaderboards {
+    /// Adds a result to the user's tally in every window
+    ///
+    /// `stake` is the chips the hand was played for.
+    pub fn record(&self, user_id: Uuid, result: &PlayerResult, stake: u32, at: DateTime<Utc>) {
+        let mut periods = self.periods.lock().unwrap();
+        for window in LeaderboardWindow::ALL {
+            let period = window.period(at);
+            let (current, tallies) = periods.entry(window).or_default();
+            if *current != period {
+                *current = period;
+                tallies.clear();
+            }
+            tallies.entry(user_id).or_default().record(result, stake);
+        }
+    }
+
+    /// Best `query.limit()` players of the window's current period, best first
+    ///
+    /// Ties go to the player with more games.
+    pub fn top(&self, query: &LeaderboardQuery, now: DateTime<Utc>) -> Vec<(Uuid, Tally)> {
+        let period = query.window.period(now);
+        let periods = self.periods.lock().unwrap();
+        let Some(tallies) = periods
+            .get(&query.window)
+            .filter(|(current, _)| *current == period)
+            .map(|(_, tallies)| tallies)
+        else {
+            return Vec::new();
+        };
+
+        let min_games = query.min_games();
+        let mut ranked: Vec<(Uuid, Tally)> = tallies
+            .iter()
+            .filter(|(_, tally)| tally.games_played >= min_games)
+            .map(|(user_id, tally)| (*user_id, tally.clone()))
+            .collect();
+
+        ranked.sort_by(|(a_id, a), (b_id, b)| {
+            let by_board = match query.board {
+                Board::WinRate => b.win_rate().total_cmp(&a.win_rate()),
+                Board::NetChips => b.net_chips.cmp(&a.net_chips),
+                Board::WinStreak => b.longest_win_streak.cmp(&a.longest_win_streak),
+                Board::Blackjacks => b.blackjacks.cmp(&a.blackjacks),
+            };
+            by_board
+                .then(b.games_played.cmp(&a.games_played))
+                .then(a_id.cmp(b_id))
+        });
+        ranked.truncate(query.limit());
+        ranked
+    }
+
+    /// Drops a deleted account from every board
+    pub fn forget_user(&self, user_id: Uuid) {
+        let mut periods = self.periods.lock().unwrap();
+        for (_, tallies) in periods.values_mut() {
+            tallies.remove(&user_id);
+        }
+    }
+}
@@ -1,6 +1,14 @@
 use blackjack_core::{
-    Card, Game, GameError as CoreGameError, GameInvitation, GameResult, InvitationStatus, User,
-    password, validation,
+    Card, Game, GameError as CoreGameError, GameInvitation, GameResult, GameRole, GameVisibility,
+    InvitationStatus, InviteLink, PlatformRole, PlayerOutcome, PlayerResult, PlayerState,
+    TableRules, User, UserStats,
+    achievements::{self, ACHIEVEMENTS, UnlockedAchievement},
+    advisor::Advice,
+    events::GameEvent,
+    password,
+    rating::{self, RatedSeat, RatingBand},
+    strategy::BotStrategy,
+    validation,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -8,6 +16,51 @@ use std::sync::{Arc, Mutex};
 use thiserror::Error;
 use uuid::Uuid;
 
+/// Archive of finished games backing players' game history
+pub mod archive;
+
+/// Audit trail of privileged actions
+pub mod audit;
+
+/// Per-game table chat with moderation
+pub mod chat;
+
+/// Leaderboards by win rate, chips, streaks and blackjacks
+pub mod leaderboard;
+
+/// Matchmaking queue that auto-forms tables
+pub mod matchmaking;
+
+/// Outgoing email (SMTP and local outbox)
+pub mod mail;
+
+/// Per-user notification inbox
+pub mod notifications;
+
+/// Elimination tournaments across several tables
+pub mod tournament;
+
+pub use archive::{
+    ArchivedGame, ArchivedHand, ArchivedSeat, GameArchive, Opponent, PlayerGameEntry,
+    PlayerGamesPage, PlayerGamesQuery,
+};
+pub use audit::{
+    AuditActor, AuditEvent, AuditFilter, AuditLog, AuditOutcome, AuditRecord, MAX_AUDIT_RECORDS,
+};
+pub use chat::{ChatHistory, ChatMessage, TableChat};
+pub use leaderboard::{
+    Board, Leaderboard, LeaderboardEntry, LeaderboardQuery, LeaderboardWindow, Leaderboards,
+};
+pub use mail::{EmailMessage, MailError, Mailer, OutboxMailer, SmtpMailer, SmtpSettings};
+pub use matchmaking::{
+    MatchmakingConfig, MatchmakingService, QueueStatus, QueueTicket, TicketStatus,
+};
+pub use notifications::{Notification, NotificationKind, NotificationService};
+pub use tournament::{
+    StandingStatus, TableSeat, Tournament, TournamentInfo, TournamentPlayer, TournamentRound,
+    TournamentService, TournamentSettings, TournamentStanding, TournamentStatus, TournamentTable,
+};
+
 /// Service-level errors with thiserror derives
 #[derive(Error, Debug)]
 pub enum GameError {
@@ -33,12 +86,42 @@ pub enum GameError {
     UserNotFound,
     #[error("User already exists")]
     UserAlreadyExists,
+    #[error("Display name is already taken")]
+    DisplayNameTaken,
     #[error("Invalid credentials")]
     InvalidCredentials,
     #[error("Invitation not found")]
     InvitationNotFound,
     #[error("Invitation expired")]
     InvitationExpired,
+    #[error("A pending invitation already exists for this user and game")]
+    InvitationAlreadyExists,
+    #[error("Invitation is no longer pending")]
+    InvitationNotPending,
+    #[error("Invite link not found")]
+    InviteLinkNotFound,
+    #[error("Invite link has been revoked")]
+    InviteLinkRevoked,
+    #[error("Invite link has no uses left")]
+    InviteLinkExhausted,
+    #[error("Game is private and can only be joined by invitation")]
+    GameIsPrivate,
+    #[error("Invalid or missing game password")]
+    InvalidGamePassword,
+    #[error("Already waiting in the matchmaking queue")]
+    AlreadyQueued,
+    #[error("Not waiting in the matchmaking queue")]
+    NotQueued,
+    #[error("Muted in this game's chat")]
+    ChatMuted,
+    #[error("Chat message not found")]
+    ChatMessageNotFound,
+    #[error("Tournament not found")]
+    TournamentNotFound,
+    #[error("Tournament has already started")]
+    TournamentAlreadyStarted,
+    #[error("Already registered for this tournament")]
+    AlreadyRegistered,
     #[error("Invalid timeout (must be between 1 and {max} seconds)")]
     InvalidTimeout { max: u64 },
     #[error("Not player's turn")]
@@ -61,6 +144,12 @@ pub enum GameError {
     InsufficientPermissions,
     #[error("Account is locked due to too many failed login attempts")]
     AccountLocked,
+    #[error("Password reset token is invalid or expired")]
+    InvalidResetToken,
+    #[error("Email verification token is invalid or expired")]
+    InvalidVerificationToken,
+    #[error("Email address has not been verified")]
+    EmailNotVerified,
     #[error("Validation error: {0}")]
     ValidationError(String),
     #[error("Password hashing failed: {0}")]
@@ -70,7 +159,7 @@ pub enum GameError {
 }
 
 /// Configuration for the game service
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ServiceConfig {
     pub max_players: u8,
     pub min_players: u8,
@@ -152,6 +241,16 @@ pub struct DrawCardResponse {
     pub cards_history: Vec<Card>,
 }
 
+/// Basic strategy hint for the player whose turn it is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HintResponse {
+    #[serde(flatten)]
+    pub advice: Advice,
+    pub dealer_up_card: Option<Card>,
+    /// Hints this player has asked for in the game so far, this one included
+    pub hints_used: u32,
+}
+
 /// Response for player state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStateResponse {
@@ -159,6 +258,15 @@ pub struct PlayerStateResponse {
     pub busted: bool,
 }
 
+/// Events of a game after a given sequence number
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEventsResponse {
+    /// Oldest first
+    pub events: Vec<GameEvent>,
+    /// Whether the game is over, so no further events will follow
+    pub finished: bool,
+}
+
 /// Information about a player in the game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerInfo {
@@ -168,6 +276,8 @@ pub struct PlayerInfo {
 }
 
 /// Response for game state
+///
+/// Players are identified by their display names.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameStateResponse {
     pub players: HashMap<String, PlayerInfo>,
@@ -179,6 +289,71 @@ pub struct GameStateResponse {
     pub enrollment_closes_at: String,
     pub time_remaining_seconds: i64,
     pub enrollment_closed: bool,
+    /// Dealer's face-up card, once enrollment has closed
+    pub dealer_up_card: Option<Card>,
+    /// Dealer's full hand, once the game is finished
+    pub dealer_cards: Vec<Card>,
+    /// Player names of bot seats
+    pub bots: Vec<String>,
+}
+
+impl GameStateResponse {
+    fn from_game(game: &Game) -> Self {
+        let players = game
+            .players
+            .values()
+            .map(|player| {
+                (
+                    player.name.clone(),
+                    PlayerInfo {
+                        points: player.points,
+                        cards_history: player.cards_history.clone(),
+                        busted: player.busted,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            players,
+            cards_in_deck: game.available_cards.len(),
+            finished: game.finished,
+            current_turn_player: game.current_player_name().map(str::to_string),
+            turn_order: turn_order_names(game),
+            enrollment_timeout_seconds: game.enrollment_timeout_seconds,
+            enrollment_closes_at: game.get_enrollment_expires_at(),
+            time_remaining_seconds: game.get_enrollment_time_remaining(),
+            enrollment_closed: game.enrollment_closed,
+            dealer_up_card: game.dealer_up_card().cloned(),
+            dealer_cards: if game.finished {
+                game.dealer.cards_history.clone()
+            } else {
+                Vec::new()
+            },
+            bots: bot_names(game),
+        }
+    }
+}
+
+/// Display names in seating order
+fn turn_order_names(game: &Game) -> Vec<String> {
+    game.turn_order
+        .iter()
+        .filter_map(|id| game.player_name(*id))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Display names of the bot seats, sorted
+fn bot_names(game: &Game) -> Vec<String> {
+    let mut names: Vec<String> = game
+        .bots
+        .keys()
+        .filter_map(|id| game.player_name(*id))
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names
 }
 
 /// Information about a game in enrollment phase
@@ -188,9 +363,158 @@ pub struct GameInfo {
     pub creator_id: Uuid,
     pub enrolled_count: u64,
     pub max_players: u64,
+    pub free_seats: u64,
+    pub rules: TableRules,
     pub enrollment_timeout_seconds: u64,
     pub time_remaining_seconds: i64,
+    pub created_at: String,
     pub enrollment_closes_at: String,
+    pub visibility: GameVisibility,
+    pub password_protected: bool,
+    /// Average rating of the players enrolled so far
+    pub table_rating: u32,
+}
+
+impl GameInfo {
+    fn from_game(game: &Game, table_rating: u32) -> Self {
+        Self {
+            game_id: game.id,
+            creator_id: game.creator_id,
+            enrolled_count: game.players.len() as u64,
+            max_players: game.rules.max_players as u64,
+            free_seats: game.free_seats() as u64,
+            rules: game.rules.clone(),
+            enrollment_timeout_seconds: game.enrollment_timeout_seconds,
+            time_remaining_seconds: game.get_enrollment_time_remaining(),
+            created_at: game.enrollment_start_time.clone(),
+            enrollment_closes_at: game.get_enrollment_expires_at(),
+            visibility: game.visibility,
+            password_protected: game.is_password_protected(),
+            table_rating,
+        }
+    }
+}
+
+/// Largest page the open games lobby will return
+pub const MAX_OPEN_GAMES_PAGE_SIZE: usize = 100;
+
+/// Field used to order the open games lobby
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenGamesSort {
+    /// Enrollment closing time (least time remaining first when ascending)
+    #[default]
+    TimeRemaining,
+    /// Creation time
+    CreatedAt,
+    /// Number of free seats
+    FreeSeats,
+    /// Table minimum bet
+    MinBet,
+}
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Filters, ordering and pagination for the open games lobby
+///
+/// Every filter is optional; an empty query lists every open public game.
+#[derive(Debug, Clone, Default)]
+pub struct OpenGamesQuery {
+    /// Only games with at least this many free seats
+    pub min_free_seats: Option<u64>,
+    /// Only tables with exactly this many seats
+    pub table_size: Option<u8>,
+    /// Only tables whose betting range accepts this bet
+    pub stake: Option<u32>,
+    /// Only games created by this user
+    pub creator_id: Option<Uuid>,
+    /// Only games with at least this many seconds of enrollment left
+    pub min_time_remaining: Option<i64>,
+    /// Only games with at most this many seconds of enrollment left
+    pub max_time_remaining: Option<i64>,
+    /// Only tables whose average player rating lies in this band
+    pub rating: RatingBand,
+    pub sort: OpenGamesSort,
+    pub order: SortOrder,
+    /// Opaque cursor returned as `next_cursor` by the previous page
+    pub cursor: Option<String>,
+    /// Page size, capped at `MAX_OPEN_GAMES_PAGE_SIZE` (unbounded if `None`)
+    pub limit: Option<usize>,
+}
+
+/// One page of the open games lobby
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenGamesPage {
+    pub games: Vec<GameInfo>,
+    /// Number of games matching the filters, across all pages
+    pub total: usize,
+    /// Cursor for the next page, `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl OpenGamesQuery {
+    fn matches(&self, game: &Game) -> bool {
+        let time_remaining = game.get_enrollment_time_remaining();
+        self.min_free_seats
+            .is_none_or(|seats| game.free_seats() as u64 >= seats)
+            && self
+                .table_size
+                .is_none_or(|size| game.rules.max_players == size)
+            && self.stake.is_none_or(|bet| game.rules.accepts_bet(bet))
+            && self.creator_id.is_none_or(|id| game.creator_id == id)
+            && self
+                .min_time_remaining
+                .is_none_or(|secs| time_remaining >= secs)
+            && self
+                .max_time_remaining
+                .is_none_or(|secs| time_remaining <= secs)
+    }
+
+    /// Sort key for a game; ties are broken by game ID so the order is total
+    fn sort_key(&self, game: &Game) -> i64 {
+        let timestamp = |rfc3339: &str| {
+            chrono::DateTime::parse_from_rfc3339(rfc3339)
+                .map(|dt| dt.timestamp_millis())
+                .unwrap_or(0)
+        };
+        match self.sort {
+            OpenGamesSort::TimeRemaining => timestamp(&game.get_enrollment_expires_at()),
+            OpenGamesSort::CreatedAt => timestamp(&game.enrollment_start_time),
+            OpenGamesSort::FreeSeats => game.free_seats() as i64,
+            OpenGamesSort::MinBet => game.rules.min_bet as i64,
+        }
+    }
+
+    fn parse_cursor(&self) -> Result<Option<(i64, Uuid)>, GameError> {
+        let Some(cursor) = self.cursor.as_deref() else {
+            return Ok(None);
+        };
+        cursor
+            .split_once('_')
+            .and_then(|(key, id)| Some((key.parse().ok()?, Uuid::parse_str(id).ok()?)))
+            .map(Some)
+            .ok_or_else(|| GameError::ValidationError("Invalid cursor".to_string()))
+    }
+}
+
+/// Options for creating a game
+#[derive(Debug, Clone, Default)]
+pub struct CreateGameOptions {
+    /// Enrollment window in seconds (defaults to 300)
+    pub enrollment_timeout_seconds: Option<u64>,
+    /// Who can discover and join the game (defaults to public)
+    pub visibility: GameVisibility,
+    /// Optional plaintext join password, hashed before it is stored
+    pub join_password: Option<String>,
+    /// Table size and stakes
+    pub rules: TableRules,
 }
 
 /// Information about an invitation
@@ -198,18 +522,356 @@ pub struct GameInfo {
 pub struct InvitationInfo {
     pub id: Uuid,
     pub game_id: Uuid,
-    pub inviter_email: String,
+    pub inviter_id: Uuid,
+    /// Display name of the inviter; their email stays private
+    pub inviter_name: String,
     pub invitee_email: String,
     pub status: String,
     pub timeout_seconds: u64,
+    pub created_at: String,
     pub expires_at: String,
     pub expires_in_seconds: i64,
 }
 
+impl InvitationInfo {
+    /// Builds the public view of an invitation, resolving the inviter's display name
+    ///
+    /// Falls back to an empty name if the inviter account no longer exists.
+    fn from_invitation(invitation: &GameInvitation, user_service: &UserService) -> Self {
+        let inviter_name = user_service
+            .get_user(invitation.inviter_id)
+            .map(|user| user.display_name)
+            .unwrap_or_default();
+
+        Self {
+            id: invitation.id,
+            game_id: invitation.game_id,
+            inviter_id: invitation.inviter_id,
+            inviter_name,
+            invitee_email: invitation.invitee_email.clone(),
+            status: format!("{:?}", invitation.status).to_lowercase(),
+            timeout_seconds: invitation.timeout_seconds(),
+            created_at: invitation.created_at.clone(),
+            expires_at: invitation.expires_at.clone(),
+            expires_in_seconds: invitation.expires_in_seconds(),
+        }
+    }
+}
+
+/// Account details shown to administrators (never includes the password hash)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserInfo {
+    pub id: Uuid,
+    pub email: String,
+    pub display_name: String,
+    pub avatar_seed: String,
+    pub email_verified: bool,
+    pub role: PlatformRole,
+    pub is_active: bool,
+    pub is_locked: bool,
+    pub failed_login_attempts: u32,
+    pub created_at: Option<String>,
+    pub last_login: Option<String>,
+    pub stats: Option<UserStats>,
+}
+
+impl From<&User> for UserInfo {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            email: user.email.clone(),
+            display_name: user.display_name.clone(),
+            avatar_seed: user.avatar_seed.clone(),
+            email_verified: user.email_verified,
+            role: user.role,
+            is_active: user.is_active,
+            is_locked: user.is_locked,
+            failed_login_attempts: user.failed_login_attempts,
+            created_at: user.created_at.clone(),
+            last_login: user.last_login.clone(),
+            stats: user.stats.clone(),
+        }
+    }
+}
+
+/// Public view of an account that any player may see (never includes the email)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub avatar_seed: String,
+    pub member_since: Option<String>,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub win_rate: f32,
+    /// Elo skill rating
+    pub rating: u32,
+}
+
+impl From<&User> for PlayerProfile {
+    fn from(user: &User) -> Self {
+        let stats = user.stats.clone().unwrap_or_default();
+        Self {
+            user_id: user.id,
+            display_name: user.display_name.clone(),
+            avatar_seed: user.avatar_seed.clone(),
+            member_since: user.created_at.clone(),
+            games_played: stats.games_played,
+            games_won: stats.games_won,
+            win_rate: stats.win_rate(),
+            rating: stats.rating_points(),
+        }
+    }
+}
+
+/// An achievement and whether the player has unlocked it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementInfo {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    /// When the player unlocked it, `None` while still locked
+    pub unlocked_at: Option<String>,
+}
+
+/// A player's achievements and streaks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerAchievements {
+    /// Number of achievements unlocked
+    pub unlocked: usize,
+    /// Number of achievements there are
+    pub total: usize,
+    pub current_win_streak: u32,
+    pub longest_win_streak: u32,
+    /// Games in a row where the dealer busted and the player didn't
+    pub dealer_bust_streak: u32,
+    /// Every achievement, unlocked or not
+    pub achievements: Vec<AchievementInfo>,
+}
+
+/// A game as it appears in its player's account history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameHistoryEntry {
+    pub game_id: Uuid,
+    pub role: GameRole,
+    pub joined_at: String,
+    /// Display names in seating order
+    pub players: Vec<String>,
+    pub finished: bool,
+    /// Final points, once the game is over
+    pub points: Option<u8>,
+    /// Outcome against the dealer, once the game is over
+    pub outcome: Option<PlayerOutcome>,
+}
+
+impl GameHistoryEntry {
+    /// History entry for `user_id`, if they took part in `game`
+    fn from_game(game: &Game, user_id: Uuid) -> Option<Self> {
+        let participant = game.participants.get(&user_id)?;
+        let result = (game.finished && game.enrollment_closed)
+            .then(|| game.calculate_results())
+            .and_then(|results| {
+                results
+                    .player_results
+                    .into_values()
+                    .find(|result| result.player_id == user_id)
+            });
+
+        Some(Self {
+            game_id: game.id,
+            role: participant.role,
+            joined_at: participant.joined_at.clone(),
+            players: turn_order_names(game),
+            finished: game.finished,
+            points: result.as_ref().map(|result| result.points),
+            outcome: result.map(|result| result.outcome),
+        })
+    }
+}
+
+/// Default grace period between a deletion request and anonymisation
+pub const ACCOUNT_DELETION_GRACE_DAYS: i64 = 30;
+
+/// An account removed by [`GameService::purge_deleted_accounts`]
+#[derive(Debug, Clone)]
+pub struct DeletedAccount {
+    pub user_id: Uuid,
+    pub email: String,
+    /// ID the account's seats and results were moved to
+    pub tombstone_id: Uuid,
+}
+
+impl DeletedAccount {
+    /// Name shown in place of the deleted account's display name
+    pub fn tombstone_name(&self) -> String {
+        format!("deleted-{}", &self.tombstone_id.simple().to_string()[..8])
+    }
+}
+
+/// Summary of any game, in any phase, for administrators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameOverview {
+    pub game_id: Uuid,
+    pub creator_id: Uuid,
+    pub players: Vec<String>,
+    pub bots: Vec<String>,
+    pub enrollment_closed: bool,
+    pub finished: bool,
+    /// `false` once the game was aborted
+    pub active: bool,
+    pub current_turn_player: Option<String>,
+    pub cards_in_deck: usize,
+    pub created_at: String,
+    pub visibility: GameVisibility,
+}
+
+impl GameOverview {
+    fn from_game(game: &Game) -> Self {
+        let mut players: Vec<String> = game.players.values().map(|p| p.name.clone()).collect();
+        players.sort();
+
+        Self {
+            game_id: game.id,
+            creator_id: game.creator_id,
+            players,
+            bots: bot_names(game),
+            enrollment_closed: game.enrollment_closed,
+            finished: game.finished,
+            active: game.active,
+            current_turn_player: game.current_player_name().map(str::to_string),
+            cards_in_deck: game.available_cards.len(),
+            created_at: game.enrollment_start_time.clone(),
+            visibility: game.visibility,
+        }
+    }
+}
+
+/// Information about a shareable invite link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteLinkInfo {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub creator_id: Uuid,
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    pub remaining_uses: Option<u32>,
+    pub revoked: bool,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+impl From<&InviteLink> for InviteLinkInfo {
+    fn from(link: &InviteLink) -> Self {
+        Self {
+            id: link.id,
+            game_id: link.game_id,
+            creator_id: link.creator_id,
+            max_uses: link.max_uses,
+            uses: link.uses,
+            remaining_uses: link.remaining_uses(),
+            revoked: link.revoked,
+            created_at: link.created_at.clone(),
+            expires_at: link.expires_at.clone(),
+        }
+    }
+}
+
+/// Consecutive failed logins after which an account is locked
+pub const MAX_FAILED_LOGIN_ATTEMPTS: u32 = 5;
+
 /// User management service
 pub struct UserService {
     users: Arc<Mutex<HashMap<Uuid, User>>>,
     email_index: Arc<Mutex<HashMap<String, Uuid>>>,
+    /// Lowercased display name -> user ID, so names are unique ignoring case
+    display_name_index: Arc<Mutex<HashMap<String, Uuid>>>,
+    /// Outstanding password reset tokens
+    reset_tokens: OneTimeTokens,
+    /// Outstanding email verification tokens
+    verification_tokens: OneTimeTokens,
+    /// Boards updated as game results are recorded
+    leaderboards: Leaderboards,
+}
+
+/// Default lifetime of a password reset token
+pub const PASSWORD_RESET_TTL_MINUTES: i64 = 30;
+
+/// Default lifetime of an email verification token
+pub const EMAIL_VERIFICATION_TTL_HOURS: i64 = 48;
+
+/// Single-use tokens mailed to users, one outstanding token per account
+///
+/// Only the SHA-256 hash of each token is kept.
+#[derive(Default)]
+struct OneTimeTokens {
+    pending: Mutex<HashMap<String, PendingToken>>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingToken {
+    user_id: Uuid,
+    expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OneTimeTokens {
+    /// Hex-encoded SHA-256 of a token
+    fn hash(token: &str) -> String {
+        use sha2::{Digest, Sha256};
+        Sha256::digest(token.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Issues a token for `user_id`, replacing any earlier one
+    fn issue(&self, user_id: Uuid, ttl: chrono::Duration) -> String {
+        let mut bytes = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rng(), &mut bytes);
+        let token: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+        let mut pending = self.pending.lock().unwrap();
+        let now = chrono::Utc::now();
+        pending.retain(|_, entry| entry.user_id != user_id && entry.expires_at > now);
+        pending.insert(
+            Self::hash(&token),
+            PendingToken {
+                user_id,
+                expires_at: now + ttl,
+            },
+        );
+        token
+    }
+
+    /// Account a live token belongs to, without using it up
+    ///
+    /// Expired tokens are dropped.
+    fn peek(&self, token: &str) -> Option<Uuid> {
+        let hash = Self::hash(token);
+        let mut pending = self.pending.lock().unwrap();
+        match pending.get(&hash) {
+            Some(entry) if entry.expires_at > chrono::Utc::now() => Some(entry.user_id),
+            Some(_) => {
+                pending.remove(&hash);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Uses up a live token, returning its account
+    fn consume(&self, token: &str) -> Option<Uuid> {
+        let user_id = self.peek(token)?;
+        self.pending.lock().unwrap().remove(&Self::hash(token))?;
+        Some(user_id)
+    }
+
+    /// Drops any outstanding token of `user_id`
+    fn revoke(&self, user_id: Uuid) {
+        self.pending
+            .lock()
+            .unwrap()
+            .retain(|_, entry| entry.user_id != user_id);
+    }
 }
 
 impl UserService {
@@ -218,6 +880,10 @@ impl UserService {
         Self {
             users: Arc::new(Mutex::new(HashMap::new())),
             email_index: Arc::new(Mutex::new(HashMap::new())),
+            display_name_index: Arc::new(Mutex::new(HashMap::new())),
+            reset_tokens: OneTimeTokens::default(),
+            verification_tokens: OneTimeTokens::default(),
+            leaderboards: Leaderboards::default(),
         }
     }
 
@@ -241,6 +907,27 @@ impl UserService {
     /// * `Err(GameError)` - Validation or registration error
     #[tracing::instrument(skip(self, password))]
     pub fn register(&self, email: String, password: String) -> Result<Uuid, GameError> {
+        self.register_with_display_name(email, password, None)
+    }
+
+    /// Registers a new user under a chosen display name
+    ///
+    /// Without a display name the account gets a generated `player-xxxxxxxx`
+    /// name that can be changed later with [`Self::set_display_name`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::register`], plus:
+    ///
+    /// * `GameError::ValidationError` - Malformed or reserved display name
+    /// * `GameError::DisplayNameTaken` - Another account uses the name
+    #[tracing::instrument(skip(self, password))]
+    pub fn register_with_display_name(
+        &self,
+        email: String,
+        password: String,
+        display_name: Option<String>,
+    ) -> Result<Uuid, GameError> {
         // Validate email format
         validation::validate_email(&email)
             .map_err(|e| GameError::ValidationError(e.to_string()))?;
@@ -249,6 +936,11 @@ impl UserService {
         validation::validate_password(&password)
             .map_err(|e| GameError::WeakPassword(e.to_string()))?;
 
+        if let Some(name) = &display_name {
+            validation::validate_display_name(name)
+                .map_err(|e| GameError::ValidationError(e.to_string()))?;
+        }
+
         let mut email_index = self.email_index.lock().unwrap();
 
         // Check if user already exists
@@ -257,13 +949,31 @@ impl UserService {
             return Err(GameError::UserAlreadyExists);
         }
 
+        let mut display_name_index = self.display_name_index.lock().unwrap();
+        if let Some(name) = &display_name
+            && display_name_index.contains_key(&name.to_lowercase())
+        {
+            return Err(GameError::DisplayNameTaken);
+        }
+
         // Hash password using Argon2id
         let password_hash = password::hash_password(&password)
             .map_err(|e| GameError::PasswordHashError(e.to_string()))?;
 
-        let user = User::new(email.clone(), password_hash);
+        let mut user = User::new(email.clone(), password_hash);
         let user_id = user.id;
+        match display_name {
+            Some(name) => user.display_name = name,
+            // Generated names come from the ID, so a clash is next to impossible
+            None => {
+                while display_name_index.contains_key(&user.display_name.to_lowercase()) {
+                    user.display_name =
+                        format!("player-{}", &Uuid::new_v4().simple().to_string()[..8]);
+                }
+            }
+        }
 
+        display_name_index.insert(user.display_name.to_lowercase(), user_id);
         let mut users = self.users.lock().unwrap();
         users.insert(user_id, user);
         email_index.insert(email.clone(), user_id);
@@ -273,70 +983,240 @@ impl UserService {
         Ok(user_id)
     }
 
-    /// Authenticates a user with secure password verification
+    /// Changes a user's display name
     ///
-    /// # Security
+    /// Games already joined keep showing the old name.
     ///
-    /// - Uses constant-time password comparison via Argon2id
-    /// - Updates last_login timestamp on successful login
-    /// - Checks account is_active status
-    /// - Logs authentication attempts (success and failure)
-    /// - Does not reveal whether email or password is incorrect
+    /// # Errors
     ///
-    /// # Arguments
+    /// * `GameError::ValidationError` - Malformed or reserved display name
+    /// * `GameError::DisplayNameTaken` - Another account uses the name
+    /// * `GameError::UserNotFound` - User doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn set_display_name(&self, user_id: Uuid, display_name: &str) -> Result<User, GameError> {
+        validation::validate_display_name(display_name)
+            .map_err(|e| GameError::ValidationError(e.to_string()))?;
+
+        let mut display_name_index = self.display_name_index.lock().unwrap();
+        let key = display_name.to_lowercase();
+        if display_name_index
+            .get(&key)
+            .is_some_and(|owner| *owner != user_id)
+        {
+            return Err(GameError::DisplayNameTaken);
+        }
+
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
+        display_name_index.remove(&user.display_name.to_lowercase());
+        display_name_index.insert(key, user_id);
+        user.display_name = display_name.to_string();
+
+        tracing::info!(user_id = %user_id, display_name = %display_name, "Display name changed");
+
+        Ok(user.clone())
+    }
+
+    /// Changes a user's email after confirming their password
     ///
-    /// * `email` - User's email address
-    /// * `password` - Plaintext password to verify
+    /// The new address is unverified until the owner follows a fresh
+    /// verification link, and tokens issued for the old address are revoked.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ok(User)` - Authenticated user with updated last_login
-    /// * `Err(GameError)` - Authentication failure (invalid credentials or inactive account)
+    /// * `GameError::UserNotFound` - User doesn't exist
+    /// * `GameError::InvalidCredentials` - Wrong password
+    /// * `GameError::ValidationError` - Malformed email
+    /// * `GameError::UserAlreadyExists` - Another account uses the email
     #[tracing::instrument(skip(self, password))]
-    pub fn login(&self, email: &str, password: &str) -> Result<User, GameError> {
-        let email_index = self.email_index.lock().unwrap();
-        let user_id = email_index.get(email).ok_or_else(|| {
-            tracing::warn!(email = %email, "Login failed: user not found");
-            GameError::InvalidCredentials
-        })?;
+    pub fn update_email(
+        &self,
+        user_id: Uuid,
+        new_email: &str,
+        password: &str,
+    ) -> Result<User, GameError> {
+        validation::validate_email(new_email)
+            .map_err(|e| GameError::ValidationError(e.to_string()))?;
 
+        let mut email_index = self.email_index.lock().unwrap();
         let mut users = self.users.lock().unwrap();
-        let user = users.get_mut(user_id).ok_or(GameError::UserNotFound)?;
+        let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
+        Self::confirm_password(user, password)?;
 
-        // Check if account is active
-        if !user.is_account_active() {
-            tracing::warn!(user_id = %user_id, email = %email, "Login failed: account inactive");
-            return Err(GameError::AccountInactive);
+        if user.email == new_email {
+            return Ok(user.clone());
         }
-
-        // Verify password using constant-time comparison
-        let password_valid =
-            password::verify_password(password, &user.password_hash).map_err(|e| {
-                tracing::error!(user_id = %user_id, "Password verification error: {}", e);
-                GameError::PasswordHashError(e.to_string())
-            })?;
-
-        if !password_valid {
-            tracing::warn!(user_id = %user_id, email = %email, "Login failed: incorrect password");
-            return Err(GameError::InvalidCredentials);
+        if email_index.contains_key(new_email) {
+            return Err(GameError::UserAlreadyExists);
         }
 
-        // Update last login timestamp
-        user.update_last_login();
+        email_index.remove(&user.email);
+        email_index.insert(new_email.to_string(), user_id);
+        user.email = new_email.to_string();
+        user.email_verified = false;
+        user.revoke_sessions();
+        self.verification_tokens.revoke(user_id);
+        self.reset_tokens.revoke(user_id);
 
-        tracing::info!(user_id = %user_id, email = %email, "User logged in successfully");
+        tracing::info!(user_id = %user_id, "Email changed, sessions revoked");
 
         Ok(user.clone())
     }
 
-    /// Gets a user by ID
-    pub fn get_user(&self, user_id: Uuid) -> Result<User, GameError> {
-        let users = self.users.lock().unwrap();
-        users.get(&user_id).cloned().ok_or(GameError::UserNotFound)
-    }
-
-    /// Gets a user by email
-    pub fn get_user_by_email(&self, email: &str) -> Result<User, GameError> {
+    /// Deactivates an account at its owner's request, after confirming their password
+    ///
+    /// The account is anonymised by [`GameService::purge_deleted_accounts`]
+    /// once the grace period is over; until then an administrator can
+    /// reactivate it.
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::UserNotFound` - User doesn't exist
+    /// * `GameError::InvalidCredentials` - Wrong password
+    #[tracing::instrument(skip(self, password))]
+    pub fn request_deletion(&self, user_id: Uuid, password: &str) -> Result<User, GameError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
+        Self::confirm_password(user, password)?;
+
+        user.request_deletion();
+
+        tracing::info!(user_id = %user_id, "Account deletion requested");
+
+        Ok(user.clone())
+    }
+
+    /// Removes every account whose deletion grace period is over
+    ///
+    /// Returns the removed accounts so callers can anonymise what refers to them.
+    pub fn take_due_deletions(&self, grace: chrono::Duration) -> Vec<User> {
+        let mut email_index = self.email_index.lock().unwrap();
+        let mut display_name_index = self.display_name_index.lock().unwrap();
+        let mut users = self.users.lock().unwrap();
+
+        let now = chrono::Utc::now();
+        let due: Vec<Uuid> = users
+            .values()
+            .filter(|user| user.deletion_due(grace, now))
+            .map(|user| user.id)
+            .collect();
+
+        due.into_iter()
+            .filter_map(|user_id| users.remove(&user_id))
+            .inspect(|user| {
+                email_index.remove(&user.email);
+                display_name_index.remove(&user.display_name.to_lowercase());
+                self.reset_tokens.revoke(user.id);
+                self.verification_tokens.revoke(user.id);
+                self.leaderboards.forget_user(user.id);
+            })
+            .collect()
+    }
+
+    /// Checks `password` against the user's hash
+    fn confirm_password(user: &User, password: &str) -> Result<(), GameError> {
+        let valid = password::verify_password(password, &user.password_hash).map_err(|e| {
+            tracing::error!(user_id = %user.id, "Password verification error: {}", e);
+            GameError::PasswordHashError(e.to_string())
+        })?;
+        if !valid {
+            tracing::warn!(user_id = %user.id, "Password confirmation failed");
+            return Err(GameError::InvalidCredentials);
+        }
+        Ok(())
+    }
+
+    /// Authenticates a user with secure password verification
+    ///
+    /// # Security
+    ///
+    /// - Uses constant-time password comparison via Argon2id
+    /// - Updates last_login timestamp on successful login
+    /// - Checks account is_active status
+    /// - Locks the account after `MAX_FAILED_LOGIN_ATTEMPTS` consecutive failures
+    /// - Logs authentication attempts (success and failure)
+    /// - Does not reveal whether email or password is incorrect
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - User's email address
+    /// * `password` - Plaintext password to verify
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(User)` - Authenticated user with updated last_login
+    /// * `Err(GameError)` - Authentication failure (invalid credentials or inactive account)
+    #[tracing::instrument(skip(self, password))]
+    pub fn login(&self, email: &str, password: &str) -> Result<User, GameError> {
+        let email_index = self.email_index.lock().unwrap();
+        let user_id = email_index.get(email).ok_or_else(|| {
+            tracing::warn!(email = %email, "Login failed: user not found");
+            GameError::InvalidCredentials
+        })?;
+
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(user_id).ok_or(GameError::UserNotFound)?;
+
+        // Check if account is active
+        if !user.is_account_active() {
+            tracing::warn!(user_id = %user_id, email = %email, "Login failed: account inactive");
+            return Err(GameError::AccountInactive);
+        }
+
+        // Locked accounts stay locked until an administrator unlocks them
+        if user.is_locked {
+            tracing::warn!(user_id = %user_id, email = %email, "Login failed: account locked");
+            return Err(GameError::AccountLocked);
+        }
+
+        // Verify password using constant-time comparison
+        let password_valid =
+            password::verify_password(password, &user.password_hash).map_err(|e| {
+                tracing::error!(user_id = %user_id, "Password verification error: {}", e);
+                GameError::PasswordHashError(e.to_string())
+            })?;
+
+        if !password_valid {
+            tracing::warn!(user_id = %user_id, email = %email, "Login failed: incorrect password");
+            if user.record_failed_login(MAX_FAILED_LOGIN_ATTEMPTS) {
+                tracing::warn!(
+                    user_id = %user_id,
+                    email = %email,
+                    attempts = user.failed_login_attempts,
+                    "Account locked after too many failed logins"
+                );
+            }
+            return Err(GameError::InvalidCredentials);
+        }
+
+        // Update last login timestamp
+        user.failed_login_attempts = 0;
+        user.update_last_login();
+
+        tracing::info!(user_id = %user_id, email = %email, "User logged in successfully");
+
+        Ok(user.clone())
+    }
+
+    /// Gets a user by ID
+    pub fn get_user(&self, user_id: Uuid) -> Result<User, GameError> {
+        let users = self.users.lock().unwrap();
+        users.get(&user_id).cloned().ok_or(GameError::UserNotFound)
+    }
+
+    /// Gets a user by display name, ignoring case
+    pub fn get_user_by_display_name(&self, display_name: &str) -> Result<User, GameError> {
+        let display_name_index = self.display_name_index.lock().unwrap();
+        let user_id = display_name_index
+            .get(&display_name.to_lowercase())
+            .ok_or(GameError::UserNotFound)?;
+
+        let users = self.users.lock().unwrap();
+        users.get(user_id).cloned().ok_or(GameError::UserNotFound)
+    }
+
+    /// Gets a user by email
+    pub fn get_user_by_email(&self, email: &str) -> Result<User, GameError> {
         let email_index = self.email_index.lock().unwrap();
         let user_id = email_index.get(email).ok_or(GameError::UserNotFound)?;
 
@@ -408,7 +1288,8 @@ impl UserService {
 
     /// Deactivates a user account (Milestone 8)
     ///
-    /// Sets the account status to inactive, preventing login.
+    /// Sets the account status to inactive, preventing login, and revokes
+    /// every token already issued to the account.
     /// Used for account suspension or administrative actions.
     ///
     /// # Arguments
@@ -432,8 +1313,9 @@ impl UserService {
         let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
 
         user.deactivate();
+        user.revoke_sessions();
 
-        tracing::info!(user_id = %user_id, "Account deactivated");
+        tracing::info!(user_id = %user_id, "Account deactivated, sessions revoked");
 
         Ok(())
     }
@@ -462,6 +1344,330 @@ impl UserService {
 
         Ok(())
     }
+
+    /// Lists every account, ordered by email (administration)
+    pub fn list_users(&self) -> Vec<UserInfo> {
+        let users = self.users.lock().unwrap();
+        let mut list: Vec<UserInfo> = users.values().map(UserInfo::from).collect();
+        list.sort_by(|a, b| a.email.cmp(&b.email));
+        list
+    }
+
+    /// Changes an account's platform role (administration)
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::UserNotFound` - User doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn set_role(&self, user_id: Uuid, role: PlatformRole) -> Result<(), GameError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
+
+        let previous = user.role;
+        user.role = role;
+
+        tracing::info!(user_id = %user_id, ?previous, ?role, "Platform role changed");
+
+        Ok(())
+    }
+
+    /// Unlocks an account locked by failed logins (administration)
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::UserNotFound` - User doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn unlock_account(&self, user_id: Uuid) -> Result<(), GameError> {
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
+
+        user.unlock();
+
+        tracing::info!(user_id = %user_id, "Account unlocked");
+
+        Ok(())
+    }
+
+    /// Sets a new password without the old one (administration)
+    ///
+    /// The new password must meet the usual complexity rules. Resetting also
+    /// unlocks the account so the user can log in with it right away.
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::UserNotFound` - User doesn't exist
+    /// * `GameError::WeakPassword` - New password doesn't meet requirements
+    #[tracing::instrument(skip(self, new_password))]
+    pub fn reset_password(&self, user_id: Uuid, new_password: &str) -> Result<(), GameError> {
+        validation::validate_password(new_password)
+            .map_err(|e| GameError::WeakPassword(e.to_string()))?;
+
+        let password_hash = password::hash_password(new_password)
+            .map_err(|e| GameError::PasswordHashError(e.to_string()))?;
+
+        let mut users = self.users.lock().unwrap();
+        let user = users.get_mut(&user_id).ok_or(GameError::UserNotFound)?;
+
+        user.password_hash = password_hash;
+        user.unlock();
+        user.revoke_sessions();
+
+        tracing::info!(user_id = %user_id, "Password reset by administrator");
+
+        Ok(())
+    }
+
+    /// Issues a password reset token for the account with `email`
+    ///
+    /// Returns the plaintext token to mail to the user, or `None` when there
+    /// is no active account with that email; callers must not reveal which.
+    /// Only a SHA-256 hash of the token is kept, and issuing a new token
+    /// replaces any earlier one for the same account.
+    #[tracing::instrument(skip(self))]
+    pub fn request_password_reset(&self, email: &str, ttl: chrono::Duration) -> Option<String> {
+        let user = self.get_user_by_email(email).ok()?;
+        if !user.is_account_active() {
+            tracing::warn!(user_id = %user.id, "Password reset requested for inactive account");
+            return None;
+        }
+
+        let token = self.reset_tokens.issue(user.id, ttl);
+        tracing::info!(user_id = %user.id, "Password reset token issued");
+        Some(token)
+    }
+
+    /// Sets a new password using a token from [`Self::request_password_reset`]
+    ///
+    /// The token is single-use. The account is unlocked and every existing
+    /// session is revoked, so old tokens stop working.
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::InvalidResetToken` - Unknown, used or expired token
+    /// * `GameError::WeakPassword` - New password doesn't meet requirements;
+    ///   the token stays valid so the user can try again
+    #[tracing::instrument(skip(self, token, new_password))]
+    pub fn reset_password_with_token(
+        &self,
+        token: &str,
+        new_password: &str,
+    ) -> Result<Uuid, GameError> {
+        if self.reset_tokens.peek(token).is_none() {
+            tracing::warn!("Password reset with unknown or expired token");
+            return Err(GameError::InvalidResetToken);
+        }
+
+        validation::validate_password(new_password)
+            .map_err(|e| GameError::WeakPassword(e.to_string()))?;
+        let password_hash = password::hash_password(new_password)
+            .map_err(|e| GameError::PasswordHashError(e.to_string()))?;
+
+        // A concurrent reset may have used the token meanwhile
+        let user_id = self
+            .reset_tokens
+            .consume(token)
+            .ok_or(GameError::InvalidResetToken)?;
+
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .get_mut(&user_id)
+            .ok_or(GameError::InvalidResetToken)?;
+        user.password_hash = password_hash;
+        user.unlock();
+        user.revoke_sessions();
+
+        tracing::info!(user_id = %user.id, "Password reset with token");
+
+        Ok(user.id)
+    }
+
+    /// Issues an email verification token for the account
+    ///
+    /// Returns the plaintext token to mail to the user, or `None` when the
+    /// address is already verified. A new token replaces any earlier one.
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::UserNotFound` - User doesn't exist
+    #[tracing::instrument(skip(self))]
+    pub fn request_email_verification(
+        &self,
+        user_id: Uuid,
+        ttl: chrono::Duration,
+    ) -> Result<Option<String>, GameError> {
+        let user = self.get_user(user_id)?;
+        if user.email_verified {
+            return Ok(None);
+        }
+
+        let token = self.verification_tokens.issue(user_id, ttl);
+        tracing::info!(user_id = %user_id, "Email verification token issued");
+        Ok(Some(token))
+    }
+
+    /// Marks an email address as verified using a token from
+    /// [`Self::request_email_verification`]
+    ///
+    /// # Errors
+    ///
+    /// * `GameError::InvalidVerificationToken` - Unknown, used or expired token
+    #[tracing::instrument(skip(self, token))]
+    pub fn verify_email(&self, token: &str) -> Result<User, GameError> {
+        let user_id = self.verification_tokens.consume(token).ok_or_else(|| {
+            tracing::warn!("Email verification with unknown or expired token");
+            GameError::InvalidVerificationToken
+        })?;
+
+        let mut users = self.users.lock().unwrap();
+        let user = users
+            .get_mut(&user_id)
+            .ok_or(GameError::InvalidVerificationToken)?;
+        user.email_verified = true;
+
+        tracing::info!(user_id = %user_id, email = %user.email, "Email verified");
+
+        Ok(user.clone())
+    }
+
+    /// Records the outcomes of a settled table in its players' statistics
+    ///
+    /// Every player's rating is updated from the ratings everyone held before
+    /// the game, see [`blackjack_core::rating`], and the achievements their
+    /// hand earns are unlocked. Results of unknown users are skipped.
+    pub fn record_game_results(&self, results: &[&PlayerResult], dealer_busted: bool, stake: u32) {
+        let mut users = self.users.lock().unwrap();
+        let results: Vec<&PlayerResult> = results
+            .iter()
+            .copied()
+            .filter(|result| {
+                let known = users.contains_key(&result.player_id);
+                if !known {
+                    tracing::warn!(user_id = %result.player_id, "Failed to record game statistics for unknown user");
+                }
+                known
+            })
+            .collect();
+
+        let seats: Vec<RatedSeat> = results
+            .iter()
+            .map(|result| {
+                let stats = users[&result.player_id].stats.clone().unwrap_or_default();
+                RatedSeat {
+                    rating: stats.rating,
+                    games_played: stats.games_played,
+                    outcome: result.outcome.clone(),
+                }
+            })
+            .collect();
+        let changes = rating::rating_changes(&seats);
+
+        let now = chrono::Utc::now();
+        for (result, change) in results.into_iter().zip(changes) {
+            let user = users.get_mut(&result.player_id).expect("known user");
+            let stats = user.stats.get_or_insert_with(Default::default);
+            stats.record_game(&result.outcome, result.points, dealer_busted);
+            stats.rating += change;
+            self.leaderboards
+                .record(result.player_id, result, stake, now);
+
+            for achievement in
+                achievements::evaluate(&user.achievements, result, dealer_busted, stats)
+            {
+                tracing::info!(user_id = %user.id, achievement = achievement.id, "Achievement unlocked");
+                user.achievements.push(UnlockedAchievement {
+                    id: achievement.id.to_string(),
+                    unlocked_at: now.to_rfc3339(),
+                });
+            }
+        }
+    }
+
+    /// Every achievement with the user's progress on it
+    ///
+    /// # Errors
+    ///
+    /// - `UserNotFound` if the user does not exist
+    pub fn achievements(&self, user_id: Uuid) -> Result<PlayerAchievements, GameError> {
+        let user = self.get_user(user_id)?;
+        let stats = user.stats.unwrap_or_default();
+        let achievements: Vec<AchievementInfo> = ACHIEVEMENTS
+            .iter()
+            .map(|achievement| AchievementInfo {
+                id: achievement.id.to_string(),
+                name: achievement.name.to_string(),
+                description: achievement.description.to_string(),
+                unlocked_at: user
+                    .achievements
+                    .iter()
+                    .find(|unlocked| unlocked.id == achievement.id)
+                    .map(|unlocked| unlocked.unlocked_at.clone()),
+            })
+            .collect();
+
+        Ok(PlayerAchievements {
+            unlocked: achievements
+                .iter()
+                .filter(|achievement| achievement.unlocked_at.is_some())
+                .count(),
+            total: achievements.len(),
+            current_win_streak: stats.current_win_streak,
+            longest_win_streak: stats.longest_win_streak,
+            dealer_bust_streak: stats.dealer_bust_streak,
+            achievements,
+        })
+    }
+
+    /// Current rating of a user, or the initial rating for unknown users
+    pub fn rating(&self, user_id: Uuid) -> u32 {
+        self.users
+            .lock()
+            .unwrap()
+            .get(&user_id)
+            .and_then(|user| user.stats.as_ref())
+            .map_or(
+                UserStats::default().rating_points(),
+                UserStats::rating_points,
+            )
+    }
+
+    /// Ranks players on a leaderboard for the current period of its window
+    ///
+    /// Only the players of that period are sorted; accounts deleted since
+    /// are left out.
+    pub fn leaderboard(&self, query: &LeaderboardQuery) -> Leaderboard {
+        let now = chrono::Utc::now();
+        let users = self.users.lock().unwrap();
+        let entries = self
+            .leaderboards
+            .top(query, now)
+            .into_iter()
+            .filter_map(|(user_id, tally)| {
+                let user = users.get(&user_id)?;
+                Some((user, tally))
+            })
+            .enumerate()
+            .map(|(index, (user, tally))| LeaderboardEntry {
+                rank: index + 1,
+                user_id: user.id,
+                display_name: user.display_name.clone(),
+                avatar_seed: user.avatar_seed.clone(),
+                games_played: tally.games_played,
+                games_won: tally.games_won,
+                win_rate: tally.win_rate(),
+                net_chips: tally.net_chips,
+                longest_win_streak: tally.longest_win_streak,
+                blackjacks: tally.blackjacks,
+            })
+            .collect();
+
+        Leaderboard {
+            board: query.board,
+            window: query.window,
+            period: query.window.period(now),
+            min_games: query.min_games(),
+            entries,
+        }
+    }
 }
 
 impl Default for UserService {
@@ -473,6 +1679,7 @@ impl Default for UserService {
 /// Invitation management service
 pub struct InvitationService {
     invitations: Arc<Mutex<HashMap<Uuid, GameInvitation>>>,
+    links: Arc<Mutex<HashMap<Uuid, InviteLink>>>,
     #[allow(dead_code)]
     config: InvitationConfig,
 }
@@ -482,6 +1689,7 @@ impl InvitationService {
     pub fn new(config: InvitationConfig) -> Self {
         Self {
             invitations: Arc::new(Mutex::new(HashMap::new())),
+            links: Arc::new(Mutex::new(HashMap::new())),
             config,
         }
     }
@@ -505,6 +1713,7 @@ impl InvitationService {
     ///
     /// - `InsufficientPermissions` if inviter is not a participant
     /// - `GameNotFound` if game doesn't exist
+    /// - `InvitationAlreadyExists` if the invitee already has a pending invitation to this game
     #[tracing::instrument(skip(self, games))]
     pub fn create(
         &self,
@@ -531,6 +1740,20 @@ impl InvitationService {
 
         drop(games_lock); // Release lock before creating invitation
 
+        let mut invitations = self.invitations.lock().unwrap();
+
+        // Only one pending invitation per invitee and game
+        if invitations.values().any(|inv| {
+            inv.game_id == game_id && inv.invitee_email == invitee_email && inv.is_pending()
+        }) {
+            tracing::warn!(
+                game_id = %game_id,
+                invitee_email = %invitee_email,
+                "Duplicate invitation rejected"
+            );
+            return Err(GameError::InvitationAlreadyExists);
+        }
+
         let invitation = GameInvitation::new(
             game_id,
             inviter_id,
@@ -539,7 +1762,6 @@ impl InvitationService {
         );
         let invitation_id = invitation.id;
 
-        let mut invitations = self.invitations.lock().unwrap();
         invitations.insert(invitation_id, invitation);
 
         tracing::info!(
@@ -553,6 +1775,13 @@ impl InvitationService {
     }
 
     /// Accepts an invitation
+    ///
+    /// Only pending, non-expired invitations can be accepted; an expired one is
+    /// marked as such. Callers accept *before* enrolling the invitee so no other
+    /// request can use or revoke the invitation meanwhile, and [`reopen`] it if
+    /// the game turns them away.
+    ///
+    /// [`reopen`]: InvitationService::reopen
     #[tracing::instrument(skip(self))]
     pub fn accept(&self, invitation_id: Uuid) -> Result<GameInvitation, GameError> {
         let mut invitations = self.invitations.lock().unwrap();
@@ -560,6 +1789,10 @@ impl InvitationService {
             .get_mut(&invitation_id)
             .ok_or(GameError::InvitationNotFound)?;
 
+        if invitation.status != InvitationStatus::Pending {
+            return Err(GameError::InvitationNotPending);
+        }
+
         // Check if expired
         if invitation.is_expired() {
             invitation.status = InvitationStatus::Expired;
@@ -573,27 +1806,103 @@ impl InvitationService {
         Ok(invitation.clone())
     }
 
-    /// Declines an invitation
+    /// Returns an accepted invitation to pending
+    ///
+    /// Used to roll back [`accept`] when the invitee could not be seated, so a
+    /// full game or closed enrollment doesn't use up the invitation.
+    ///
+    /// [`accept`]: InvitationService::accept
+    ///
+    /// # Errors
+    ///
+    /// - `InvitationNotFound` if the invitation doesn't exist
+    /// - `InvitationNotPending` if the invitation isn't accepted
     #[tracing::instrument(skip(self))]
-    pub fn decline(&self, invitation_id: Uuid) -> Result<(), GameError> {
+    pub fn reopen(&self, invitation_id: Uuid) -> Result<(), GameError> {
         let mut invitations = self.invitations.lock().unwrap();
         let invitation = invitations
             .get_mut(&invitation_id)
             .ok_or(GameError::InvitationNotFound)?;
 
-        invitation.status = InvitationStatus::Declined;
+        if invitation.status != InvitationStatus::Accepted {
+            return Err(GameError::InvitationNotPending);
+        }
 
-        tracing::info!(invitation_id = %invitation_id, "Invitation declined");
+        invitation.status = InvitationStatus::Pending;
+
+        tracing::info!(invitation_id = %invitation_id, "Invitation reopened");
 
         Ok(())
     }
 
-    /// Gets all pending invitations for a user
-    pub fn get_pending_for_user(&self, email: &str) -> Vec<InvitationInfo> {
-        let mut invitations = self.invitations.lock().unwrap();
-        let now = chrono::Utc::now();
+    /// Declines an invitation
+    #[tracing::instrument(skip(self))]
+    pub fn decline(&self, invitation_id: Uuid) -> Result<(), GameError> {
+        let mut invitations = self.invitations.lock().unwrap();
+        let invitation = invitations
+            .get_mut(&invitation_id)
+            .ok_or(GameError::InvitationNotFound)?;
 
-        invitations
+        if invitation.status != InvitationStatus::Pending {
+            return Err(GameError::InvitationNotPending);
+        }
+
+        invitation.status = InvitationStatus::Declined;
+
+        tracing::info!(invitation_id = %invitation_id, "Invitation declined");
+
+        Ok(())
+    }
+
+    /// Revokes a pending invitation (only the inviter can do this)
+    ///
+    /// # Errors
+    ///
+    /// - `InvitationNotFound` if the invitation doesn't exist
+    /// - `InsufficientPermissions` if `user_id` is not the inviter
+    /// - `InvitationNotPending` if the invitation was already answered, revoked or expired
+    #[tracing::instrument(skip(self))]
+    pub fn revoke(&self, invitation_id: Uuid, user_id: Uuid) -> Result<GameInvitation, GameError> {
+        let mut invitations = self.invitations.lock().unwrap();
+        let invitation = invitations
+            .get_mut(&invitation_id)
+            .ok_or(GameError::InvitationNotFound)?;
+
+        if invitation.inviter_id != user_id {
+            tracing::warn!(
+                invitation_id = %invitation_id,
+                user_id = %user_id,
+                "Permission denied: user attempted to revoke another user's invitation"
+            );
+            return Err(GameError::InsufficientPermissions);
+        }
+
+        if invitation.status == InvitationStatus::Pending && invitation.is_expired() {
+            invitation.status = InvitationStatus::Expired;
+        }
+
+        if invitation.status != InvitationStatus::Pending {
+            return Err(GameError::InvitationNotPending);
+        }
+
+        invitation.status = InvitationStatus::Revoked;
+
+        tracing::info!(invitation_id = %invitation_id, user_id = %user_id, "Invitation revoked");
+
+        Ok(invitation.clone())
+    }
+
+    /// Gets all pending invitations for a user
+    ///
+    /// Expired invitations are marked as such and left out of the result.
+    pub fn get_pending_for_user(
+        &self,
+        email: &str,
+        user_service: &UserService,
+    ) -> Vec<InvitationInfo> {
+        let mut invitations = self.invitations.lock().unwrap();
+
+        let mut pending: Vec<InvitationInfo> = invitations
             .values_mut()
             .filter_map(|inv| {
                 if inv.invitee_email == email && inv.status == InvitationStatus::Pending {
@@ -603,26 +1912,104 @@ impl InvitationService {
                         return None;
                     }
 
-                    // Calculate expires_in_seconds
-                    let expires_at = chrono::DateTime::parse_from_rfc3339(&inv.expires_at).ok()?;
-                    let expires_at_utc = expires_at.with_timezone(&chrono::Utc);
-                    let expires_in = (expires_at_utc - now).num_seconds();
-
-                    Some(InvitationInfo {
-                        id: inv.id,
-                        game_id: inv.game_id,
-                        inviter_email: inv.inviter_id.to_string(), // Use inviter_id but convert to string
-                        invitee_email: inv.invitee_email.clone(),
-                        status: format!("{:?}", inv.status).to_lowercase(),
-                        timeout_seconds: 0, // No longer stored; calculated from game enrollment
-                        expires_at: inv.expires_at.clone(),
-                        expires_in_seconds: expires_in,
-                    })
+                    Some(InvitationInfo::from_invitation(inv, user_service))
                 } else {
                     None
                 }
             })
-            .collect()
+            .collect();
+
+        pending.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        pending
+    }
+
+    /// Gets all invitations sent by a user, newest first, regardless of status
+    pub fn get_sent_by_user(
+        &self,
+        inviter_id: Uuid,
+        user_service: &UserService,
+    ) -> Vec<InvitationInfo> {
+        let mut invitations = self.invitations.lock().unwrap();
+
+        let mut sent: Vec<InvitationInfo> = invitations
+            .values_mut()
+            .filter(|inv| inv.inviter_id == inviter_id)
+            .map(|inv| {
+                if inv.status == InvitationStatus::Pending && inv.is_expired() {
+                    inv.status = InvitationStatus::Expired;
+                }
+                InvitationInfo::from_invitation(inv, user_service)
+            })
+            .collect();
+
+        sent.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        sent
+    }
+
+    /// Gets every invitation addressed to `email`, newest first, regardless of status
+    pub fn get_received_by_email(
+        &self,
+        email: &str,
+        user_service: &UserService,
+    ) -> Vec<InvitationInfo> {
+        let mut invitations = self.invitations.lock().unwrap();
+
+        let mut received: Vec<InvitationInfo> = invitations
+            .values_mut()
+            .filter(|inv| inv.invitee_email == email)
+            .map(|inv| {
+                if inv.status == InvitationStatus::Pending && inv.is_expired() {
+                    inv.status = InvitationStatus::Expired;
+                }
+                InvitationInfo::from_invitation(inv, user_service)
+            })
+            .collect();
+
+        received.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        received
+    }
+
+    /// Drops the invitations and invite links of a deleted account
+    ///
+    /// Returns how many invitations and links were removed.
+    pub fn forget_user(&self, user_id: Uuid, email: &str) -> usize {
+        let mut invitations = self.invitations.lock().unwrap();
+        let before = invitations.len();
+        invitations.retain(|_, inv| inv.inviter_id != user_id && inv.invitee_email != email);
+        let mut removed = before - invitations.len();
+
+        let mut links = self.links.lock().unwrap();
+        let before = links.len();
+        links.retain(|_, link| link.creator_id != user_id);
+        removed += before - links.len();
+
+        removed
+    }
+
+    /// Lists every invitation, newest first, optionally only those with `status`
+    /// (administration)
+    pub fn list_all(
+        &self,
+        status: Option<InvitationStatus>,
+        user_service: &UserService,
+    ) -> Vec<InvitationInfo> {
+        let mut invitations = self.invitations.lock().unwrap();
+
+        let mut list: Vec<InvitationInfo> = invitations
+            .values_mut()
+            .filter_map(|inv| {
+                if inv.status == InvitationStatus::Pending && inv.is_expired() {
+                    inv.status = InvitationStatus::Expired;
+                }
+                match &status {
+                    Some(status) if *status != inv.status => None,
+                    _ => Some(InvitationInfo::from_invitation(inv, user_service)),
+                }
+            })
+            .collect();
+
+        list.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        list
     }
 
     /// Cleans up expired invitations
@@ -644,6 +2031,183 @@ impl InvitationService {
         count
     }
 
+    /// Creates a shareable invite link for a game
+    ///
+    /// The link expires together with the game's enrollment window. Only users
+    /// with the `InvitePlayers` permission (the creator) can create links.
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `InsufficientPermissions` if the user cannot invite players
+    /// - `EnrollmentClosed` if the game no longer accepts players
+    /// - `ValidationError` if `max_uses` is zero
+    #[tracing::instrument(skip(self, games))]
+    pub fn create_link(
+        &self,
+        game_id: Uuid,
+        creator_id: Uuid,
+        max_uses: Option<u32>,
+        games: &Arc<Mutex<HashMap<Uuid, Game>>>,
+    ) -> Result<InviteLinkInfo, GameError> {
+        use blackjack_core::GamePermission;
+
+        if max_uses == Some(0) {
+            return Err(GameError::ValidationError(
+                "max_uses must be at least 1".to_string(),
+            ));
+        }
+
+        let games_lock = games.lock().unwrap();
+        let game = games_lock.get(&game_id).ok_or(GameError::GameNotFound)?;
+
+        if !game.can_user_perform(creator_id, GamePermission::InvitePlayers) {
+            tracing::warn!(
+                game_id = %game_id,
+                user_id = %creator_id,
+                "Permission denied: user attempted to create invite link"
+            );
+            return Err(GameError::InsufficientPermissions);
+        }
+
+        if !game.is_enrollment_open() {
+            return Err(GameError::EnrollmentClosed);
+        }
+
+        let link = InviteLink::new(
+            game_id,
+            creator_id,
+            max_uses,
+            game.get_enrollment_expires_at(),
+        );
+        drop(games_lock);
+
+        let info = InviteLinkInfo::from(&link);
+        self.links.lock().unwrap().insert(link.id, link);
+
+        tracing::info!(
+            link_id = %info.id,
+            game_id = %game_id,
+            max_uses = ?max_uses,
+            "Invite link created"
+        );
+
+        Ok(info)
+    }
+
+    /// Lists the invite links of a game (only for users who can invite players)
+    pub fn get_links_for_game(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        games: &Arc<Mutex<HashMap<Uuid, Game>>>,
+    ) -> Result<Vec<InviteLinkInfo>, GameError> {
+        use blackjack_core::GamePermission;
+
+        {
+            let games_lock = games.lock().unwrap();
+            let game = games_lock.get(&game_id).ok_or(GameError::GameNotFound)?;
+            if !game.can_user_perform(user_id, GamePermission::InvitePlayers) {
+                return Err(GameError::InsufficientPermissions);
+            }
+        }
+
+        let links = self.links.lock().unwrap();
+        let mut infos: Vec<InviteLinkInfo> = links
+            .values()
+            .filter(|link| link.game_id == game_id)
+            .map(InviteLinkInfo::from)
+            .collect();
+        infos.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(infos)
+    }
+
+    /// Revokes an invite link of a game (only its creator can do this)
+    ///
+    /// # Errors
+    ///
+    /// - `InviteLinkNotFound` if the link doesn't exist or belongs to another game
+    /// - `InsufficientPermissions` if the user didn't create the link
+    #[tracing::instrument(skip(self))]
+    pub fn revoke_link(
+        &self,
+        link_id: Uuid,
+        game_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<InviteLinkInfo, GameError> {
+        let mut links = self.links.lock().unwrap();
+        let link = links
+            .get_mut(&link_id)
+            .filter(|link| link.game_id == game_id)
+            .ok_or(GameError::InviteLinkNotFound)?;
+
+        if link.creator_id != user_id {
+            tracing::warn!(
+                link_id = %link_id,
+                user_id = %user_id,
+                "Permission denied: user attempted to revoke invite link"
+            );
+            return Err(GameError::InsufficientPermissions);
+        }
+
+        link.revoked = true;
+
+        tracing::info!(link_id = %link_id, game_id = %link.game_id, "Invite link revoked");
+
+        Ok(InviteLinkInfo::from(&*link))
+    }
+
+    /// Redeems an invite link, enrolling the user in the linked game
+    ///
+    /// The link lock is held while enrolling so that concurrent redemptions
+    /// cannot exceed `max_uses`. A use is only counted if enrollment succeeds.
+    ///
+    /// # Errors
+    ///
+    /// - `InviteLinkNotFound` if the link doesn't exist or belongs to another game
+    /// - `InviteLinkRevoked` if the link was revoked
+    /// - `InvitationExpired` if the link has expired
+    /// - `InviteLinkExhausted` if every use has been consumed
+    /// - Any enrollment error from `GameService::add_player_to_game`
+    #[tracing::instrument(skip(self, game_service))]
+    pub fn redeem_link(
+        &self,
+        link_id: Uuid,
+        game_id: Uuid,
+        user_id: Uuid,
+        game_service: &GameService,
+    ) -> Result<InviteLinkInfo, GameError> {
+        let mut links = self.links.lock().unwrap();
+        let link = links
+            .get_mut(&link_id)
+            .filter(|link| link.game_id == game_id)
+            .ok_or(GameError::InviteLinkNotFound)?;
+
+        if link.revoked {
+            return Err(GameError::InviteLinkRevoked);
+        }
+        if link.is_expired() {
+            return Err(GameError::InvitationExpired);
+        }
+        if link.is_exhausted() {
+            return Err(GameError::InviteLinkExhausted);
+        }
+
+        game_service.add_player_to_game(game_id, user_id)?;
+        link.uses += 1;
+
+        tracing::info!(
+            link_id = %link_id,
+            game_id = %game_id,
+            user_id = %user_id,
+            uses = link.uses,
+            "Invite link redeemed"
+        );
+
+        Ok(InviteLinkInfo::from(&*link))
+    }
+
     /// Gets an invitation by ID
     pub fn get_invitation(&self, invitation_id: Uuid) -> Result<GameInvitation, GameError> {
         let invitations = self.invitations.lock().unwrap();
@@ -658,15 +2222,30 @@ impl InvitationService {
 pub struct GameService {
     pub games: Arc<Mutex<HashMap<Uuid, Game>>>,
     user_service: Arc<UserService>,
+    /// Finished games, kept after the game itself is gone
+    archive: GameArchive,
+    /// Table chat of every game
+    chat: TableChat,
     config: ServiceConfig,
 }
 
 impl GameService {
     /// Creates a new game service with the given configuration
     pub fn new(config: ServiceConfig, user_service: Arc<UserService>) -> Self {
+        Self::with_archive(config, user_service, GameArchive::new())
+    }
+
+    /// Creates a new game service archiving finished games to `archive`
+    pub fn with_archive(
+        config: ServiceConfig,
+        user_service: Arc<UserService>,
+        archive: GameArchive,
+    ) -> Self {
         Self {
             games: Arc::new(Mutex::new(HashMap::new())),
             user_service,
+            archive,
+            chat: TableChat::new(),
             config,
         }
     }
@@ -677,87 +2256,285 @@ impl GameService {
         Self::new(ServiceConfig::default(), user_service)
     }
 
-    /// Creates a new game with the specified creator and enrollment timeout
+    /// Creates a new public game with the specified creator and enrollment timeout
     /// The creator is automatically enrolled in the game
-    /// Creator's email is retrieved from the user database
-    #[tracing::instrument(skip(self), fields(game_id))]
     pub fn create_game(
         &self,
         creator_id: Uuid,
         enrollment_timeout_seconds: Option<u64>,
     ) -> Result<Uuid, GameError> {
-        // Use provided timeout or default to 300 seconds
-        let timeout = enrollment_timeout_seconds.unwrap_or(300);
+        self.create_game_with_options(
+            creator_id,
+            CreateGameOptions {
+                enrollment_timeout_seconds,
+                ..Default::default()
+            },
+        )
+    }
 
-        // Get creator's email from user service
+    /// Creates a new game with the given visibility and optional join password
+    /// The creator is automatically enrolled in the game
+    /// Creator's display name is retrieved from the user database
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the join password is empty or the table rules are invalid
+    /// - `PasswordHashError` if the join password cannot be hashed
+    #[tracing::instrument(skip(self, options), fields(game_id))]
+    pub fn create_game_with_options(
+        &self,
+        creator_id: Uuid,
+        options: CreateGameOptions,
+    ) -> Result<Uuid, GameError> {
+        // Use provided timeout or default to 300 seconds
+        let timeout = options.enrollment_timeout_seconds.unwrap_or(300);
+
+        options
+            .rules
+            .validate()
+            .map_err(GameError::ValidationError)?;
+
+        options
+            .rules
+            .check_engine_support()
+            .map_err(GameError::ValidationError)?;
+
+        // Hash the join password before it touches the game state
+        let join_password_hash = match options.join_password.as_deref() {
+            Some("") => {
+                return Err(GameError::ValidationError(
+                    "Game password cannot be empty".to_string(),
+                ));
+            }
+            Some(plaintext) => Some(
+                password::hash_password(plaintext)
+                    .map_err(|e| GameError::PasswordHashError(e.to_string()))?,
+            ),
+            None => None,
+        };
+
+        // Get creator's display name from user service
         let creator = self.user_service.get_user(creator_id)?;
-        let creator_email = creator.email;
+        let creator_name = creator.display_name;
 
         // Create game with creator automatically enrolled
-        let game = Game::new(creator_id, creator_email.clone(), timeout)?;
+        let mut game = Game::new(creator_id, creator_name.clone(), timeout)?;
+        game.visibility = options.visibility;
+        game.join_password_hash = join_password_hash;
+        game.rules = options.rules;
         let game_id = game.id;
+        let visibility = game.visibility;
+        let password_protected = game.is_password_protected();
 
         // Store the game
         let mut games = self.games.lock().unwrap();
         games.insert(game_id, game);
 
-        tracing::info!(game_id = %game_id, creator_id = %creator_id, creator_email = %creator_email, enrollment_timeout_seconds = timeout, "Game created with creator auto-enrolled");
+        tracing::info!(game_id = %game_id, creator_id = %creator_id, creator_name = %creator_name, enrollment_timeout_seconds = timeout, ?visibility, password_protected, "Game created with creator auto-enrolled");
 
         Ok(game_id)
     }
 
-    /// Lists all open games (in enrollment phase)
+    /// Creates a private game for a fixed set of players and closes enrollment
+    ///
+    /// Used by matchmaking and tournaments to seat a table. The first player
+    /// becomes the game creator. If the table cannot be fully seated, the
+    /// half-built game is removed so no player is left enrolled in it.
+    ///
+    /// # Errors
+    ///
+    /// Any error from creating the game, seating a player or closing enrollment
+    #[tracing::instrument(skip(self, players, rules))]
+    pub fn create_private_table(
+        &self,
+        players: &[Uuid],
+        rules: &TableRules,
+    ) -> Result<Uuid, GameError> {
+        let creator_id = players[0];
+        let game_id = self.create_game_with_options(
+            creator_id,
+            CreateGameOptions {
+                visibility: GameVisibility::Private,
+                rules: rules.clone(),
+                ..Default::default()
+            },
+        )?;
+
+        let seated = players[1..]
+            .iter()
+            .try_for_each(|user_id| self.add_player_to_game(game_id, *user_id))
+            .and_then(|()| self.close_enrollment(game_id, creator_id).map(|_| ()));
+        if let Err(e) = seated {
+            self.games.lock().unwrap().remove(&game_id);
+            tracing::warn!(game_id = %game_id, error = %e, "Removed partially seated table");
+            return Err(e);
+        }
+
+        Ok(game_id)
+    }
+
+    /// Lists all open public games (in enrollment phase)
+    ///
+    /// Unlisted and private games are never listed. When `exclude_user_id`
+    /// is given, games that user already participates in are skipped.
     pub fn get_open_games(
         &self,
         exclude_user_id: Option<Uuid>,
     ) -> Result<Vec<GameInfo>, GameError> {
-        let games = self.games.lock().unwrap();
-        let now = chrono::Utc::now();
-
-        let _ = exclude_user_id; // Reserved for future use when user-game relationship exists
-
-        let open_games = games
-            .values()
-            .filter(|game| {
-                // Game must be in enrollment phase and not finished
-                if game.finished {
-                    return false;
-                }
-
-                // Check if enrollment is still open
-                if !game.is_enrollment_open() {
-                    return false;
-                }
+        Ok(self
+            .list_open_games(exclude_user_id, &OpenGamesQuery::default())?
+            .games)
+    }
 
-                true
-            })
-            .map(|game| {
-                let expires_at = game.get_enrollment_expires_at();
-                let expires_at_parsed = chrono::DateTime::parse_from_rfc3339(&expires_at).ok();
-                let _expires_in = expires_at_parsed
-                    .map(|dt| (dt.with_timezone(&chrono::Utc) - now).num_seconds())
-                    .unwrap_or(0);
-
-                GameInfo {
-                    game_id: game.id,
-                    creator_id: game.creator_id,
-                    enrolled_count: game.players.len() as u64,
-                    max_players: 10,
-                    enrollment_timeout_seconds: game.enrollment_timeout_seconds,
-                    time_remaining_seconds: game.get_enrollment_time_remaining(),
-                    enrollment_closes_at: expires_at.clone(),
-                }
+    /// Lists open public games matching the query, sorted and paginated
+    ///
+    /// Pagination is keyset based: `next_cursor` encodes the sort key and ID
+    /// of the last game on the page, so games joining or leaving the lobby
+    /// between requests don't shift later pages.
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the cursor is malformed or the rating band empty
+    pub fn list_open_games(
+        &self,
+        exclude_user_id: Option<Uuid>,
+        query: &OpenGamesQuery,
+    ) -> Result<OpenGamesPage, GameError> {
+        let cursor = query.parse_cursor()?;
+        query
+            .rating
+            .validate()
+            .map_err(GameError::ValidationError)?;
+
+        // Only hold the lock long enough to snapshot the matching games
+        let mut open_games: Vec<(i64, GameInfo)> = {
+            let games = self.games.lock().unwrap();
+            games
+                .values()
+                .filter(|game| {
+                    // Game must be in enrollment phase and not finished
+                    !game.finished
+                        && game.is_enrollment_open()
+                        // Only public games are discoverable
+                        && game.is_listed()
+                        // Skip games the caller has already joined
+                        && !exclude_user_id
+                            .is_some_and(|user_id| game.participants.contains_key(&user_id))
+                        && query.matches(game)
+                })
+                .filter_map(|game| {
+                    let table_rating = self.table_rating(game);
+                    query.rating.contains(table_rating).then(|| {
+                        (
+                            query.sort_key(game),
+                            GameInfo::from_game(game, table_rating),
+                        )
+                    })
+                })
+                .collect()
+        };
+
+        let compare = |a: &(i64, Uuid), b: &(i64, Uuid)| match query.order {
+            SortOrder::Asc => a.cmp(b),
+            SortOrder::Desc => b.cmp(a),
+        };
+        open_games.sort_by(|a, b| compare(&(a.0, a.1.game_id), &(b.0, b.1.game_id)));
+
+        let total = open_games.len();
+        let start = cursor.map_or(0, |cursor| {
+            open_games.partition_point(|(key, info)| {
+                compare(&(*key, info.game_id), &cursor) != std::cmp::Ordering::Greater
             })
-            .collect();
+        });
+        let limit = query
+            .limit
+            .map_or(usize::MAX, |limit| limit.clamp(1, MAX_OPEN_GAMES_PAGE_SIZE));
+        let end = start.saturating_add(limit).min(total);
+
+        let next_cursor = (end < total)
+            .then(|| open_games[end - 1].clone())
+            .map(|(key, info)| format!("{}_{}", key, info.game_id));
+        let games = open_games.drain(start..end).map(|(_, info)| info).collect();
+
+        Ok(OpenGamesPage {
+            games,
+            total,
+            next_cursor,
+        })
+    }
 
-        Ok(open_games)
+    /// Average rating of a game's enrolled players
+    fn table_rating(&self, game: &Game) -> u32 {
+        if game.participants.is_empty() {
+            return UserStats::default().rating_points();
+        }
+        let total: u64 = game
+            .participants
+            .keys()
+            .map(|user_id| u64::from(self.user_service.rating(*user_id)))
+            .sum();
+        (total / game.participants.len() as u64) as u32
     }
 
-    /// Enrolls a player in a game
-    #[tracing::instrument(skip(self), fields(game_id, user_id))]
+    /// Enrolls a player in a game without a password
     pub fn enroll_player(&self, game_id: Uuid, user_id: Uuid) -> Result<(), GameError> {
+        self.enroll_player_with_password(game_id, user_id, None)
+    }
+
+    /// Enrolls a player in a game directly (not through an invitation)
+    ///
+    /// # Errors
+    ///
+    /// - `GameIsPrivate` if the game only accepts invited players
+    /// - `InvalidGamePassword` if the game has a password and it doesn't match
+    /// - Any error from the underlying enrollment
+    #[tracing::instrument(skip(self, join_password), fields(game_id, user_id))]
+    pub fn enroll_player_with_password(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        join_password: Option<&str>,
+    ) -> Result<(), GameError> {
+        let hash = {
+            let mut games = self.games.lock().unwrap();
+            let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
+
+            if game.visibility == GameVisibility::Private {
+                tracing::warn!(game_id = %game_id, user_id = %user_id, "Direct enrollment in private game rejected");
+                return Err(GameError::GameIsPrivate);
+            }
+
+            match game.join_password_hash.clone() {
+                Some(hash) => hash,
+                None => return self.enroll_into(game, user_id),
+            }
+        };
+
+        // Password hashing is slow on purpose, so verify without holding the games lock
+        let password_valid = match join_password {
+            Some(candidate) => password::verify_password(candidate, &hash)
+                .map_err(|e| GameError::PasswordHashError(e.to_string()))?,
+            None => false,
+        };
+        if !password_valid {
+            tracing::warn!(game_id = %game_id, user_id = %user_id, "Enrollment rejected: invalid game password");
+            return Err(GameError::InvalidGamePassword);
+        }
+
+        // Visibility and password are fixed at creation, but enrollment may
+        // have closed or filled up while the lock was released, which
+        // `enroll_into` checks again
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
+        self.enroll_into(game, user_id)
+    }
+
+    /// Seats a user in a game, bypassing visibility and password checks
+    ///
+    /// Used by direct enrollment after its checks pass, and by invitation
+    /// and invite link flows where the creator already vouched for the user.
+    fn enroll_into(&self, game: &mut Game, user_id: Uuid) -> Result<(), GameError> {
+        let game_id = game.id;
 
         // Check if enrollment is open
         if !game.is_enrollment_open() {
@@ -769,24 +2546,25 @@ impl GameService {
             return Err(GameError::GameFull);
         }
 
-        // Get user email from user service
+        // Get user display name from user service
         let user = self.user_service.get_user(user_id)?;
-        let player_email = user.email.clone();
+        let player_name = user.display_name;
 
         // Add player - explicitly map core errors to service errors
-        game.add_player(player_email.clone()).map_err(|e| match e {
-            CoreGameError::GameNotActive => GameError::GameNotActive,
-            CoreGameError::PlayerAlreadyEnrolled => GameError::PlayerAlreadyEnrolled,
-            other => GameError::CoreError(other),
-        })?;
+        game.add_player(user_id, player_name.clone())
+            .map_err(|e| match e {
+                CoreGameError::GameNotActive => GameError::GameNotActive,
+                CoreGameError::PlayerAlreadyEnrolled => GameError::PlayerAlreadyEnrolled,
+                other => GameError::CoreError(other),
+            })?;
 
         // Add to participants with Player role (M8: RBAC)
-        game.add_participant(user_id, player_email.clone());
+        game.add_participant(user_id, player_name.clone());
 
         tracing::info!(
             game_id = %game_id,
             user_id = %user_id,
-            player_email = %player_email,
+            player_name = %player_name,
             enrolled_count = game.players.len(),
             "Player enrolled in game"
         );
@@ -832,21 +2610,20 @@ impl GameService {
             "Enrollment closed"
         );
 
-        Ok(game.turn_order.clone())
+        // Bots seated first in turn order play right away
+        self.settle_turns(game);
+
+        Ok(turn_order_names(game))
     }
 
     /// Draws a card for a player in a game
     #[tracing::instrument(skip(self), fields(game_id, user_id))]
     pub fn draw_card(&self, game_id: Uuid, user_id: Uuid) -> Result<DrawCardResponse, GameError> {
-        // Get user email from user service
-        let user = self.user_service.get_user(user_id)?;
-        let email = user.email;
-
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
 
         // Map core errors to service errors for proper HTTP status codes
-        let card = game.draw_card(&email).map_err(|e| match e {
+        let card = game.draw_card(user_id).map_err(|e| match e {
             CoreGameError::EnrollmentNotClosed => GameError::EnrollmentNotClosed,
             CoreGameError::NotPlayerTurn => GameError::NotPlayerTurn,
             CoreGameError::PlayerNotActive => GameError::PlayerNotActive,
@@ -857,11 +2634,16 @@ impl GameService {
             other => GameError::CoreError(other),
         })?;
 
-        let player = game.players.get(&email).ok_or(GameError::PlayerNotInGame)?;
+        self.settle_turns(game);
+
+        let player = game
+            .players
+            .get(&user_id)
+            .ok_or(GameError::PlayerNotInGame)?;
 
         tracing::debug!(
             game_id = %game_id,
-            player_email = %email,
+            user_id = %user_id,
             card = ?card,
             "Card drawn"
         );
@@ -876,7 +2658,7 @@ impl GameService {
     }
 
     /// Sets the value of an Ace card for a player
-    #[tracing::instrument(skip(self), fields(game_id, player_email))]
+    #[tracing::instrument(skip(self), fields(game_id, user_id))]
     pub fn set_ace_value(
         &self,
         game_id: Uuid,
@@ -884,19 +2666,51 @@ impl GameService {
         card_id: Uuid,
         as_eleven: bool,
     ) -> Result<PlayerStateResponse, GameError> {
-        // Get user email from user service
-        let user = self.user_service.get_user(user_id)?;
-        let email = user.email;
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
+
+        game.set_ace_value(user_id, card_id, as_eleven)?;
+        let player = game
+            .players
+            .get(&user_id)
+            .ok_or(GameError::PlayerNotInGame)?;
 
+        Ok(PlayerStateResponse {
+            points: player.points,
+            busted: player.busted,
+        })
+    }
+
+    /// Recommends the basic strategy play for the user's current hand
+    ///
+    /// Only the player whose turn it is can ask; every hint is counted and
+    /// shows up in the game results.
+    #[tracing::instrument(skip(self), fields(game_id, user_id))]
+    pub fn get_hint(&self, game_id: Uuid, user_id: Uuid) -> Result<HintResponse, GameError> {
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
 
-        game.set_ace_value(&email, card_id, as_eleven)?;
-        let player = game.players.get(&email).ok_or(GameError::PlayerNotInGame)?;
+        let advice = game.hint(user_id).map_err(|e| match e {
+            CoreGameError::EnrollmentNotClosed => GameError::EnrollmentNotClosed,
+            CoreGameError::NotPlayerTurn => GameError::NotPlayerTurn,
+            CoreGameError::GameAlreadyFinished => GameError::GameAlreadyFinished,
+            CoreGameError::PlayerNotInGame => GameError::PlayerNotInGame,
+            other => GameError::CoreError(other),
+        })?;
+        let hints_used = game.hints_used.get(&user_id).copied().unwrap_or(0);
+
+        tracing::info!(
+            game_id = %game_id,
+            user_id = %user_id,
+            action = ?advice.action,
+            hints_used = hints_used,
+            "Hint given"
+        );
 
-        Ok(PlayerStateResponse {
-            points: player.points,
-            busted: player.busted,
+        Ok(HintResponse {
+            advice,
+            dealer_up_card: game.dealer_up_card().cloned(),
+            hints_used,
         })
     }
 
@@ -906,92 +2720,65 @@ impl GameService {
         let games = self.games.lock().unwrap();
         let game = games.get(&game_id).ok_or(GameError::GameNotFound)?;
 
-        let mut players: HashMap<String, PlayerInfo> = HashMap::new();
-        for (email, player) in &game.players {
-            players.insert(
-                email.clone(),
-                PlayerInfo {
-                    points: player.points,
-                    cards_history: player.cards_history.clone(),
-                    busted: player.busted,
-                },
-            );
-        }
+        Ok(GameStateResponse::from_game(game))
+    }
 
-        Ok(GameStateResponse {
-            players,
-            cards_in_deck: game.available_cards.len(),
+    /// Events recorded by a game after sequence number `after`
+    ///
+    /// Pass the `seq` of the last event seen to resume without gaps, or 0
+    /// for every event since the game was created.
+    #[tracing::instrument(skip(self), fields(game_id))]
+    pub fn game_events(&self, game_id: Uuid, after: u64) -> Result<GameEventsResponse, GameError> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id).ok_or(GameError::GameNotFound)?;
+
+        Ok(GameEventsResponse {
+            events: game.events_after(after).to_vec(),
             finished: game.finished,
-            current_turn_player: game.get_current_player().map(|s| s.to_string()),
-            turn_order: game.turn_order.clone(),
-            enrollment_timeout_seconds: game.enrollment_timeout_seconds,
-            enrollment_closes_at: game.get_enrollment_expires_at(),
-            time_remaining_seconds: game.get_enrollment_time_remaining(),
-            enrollment_closed: game.enrollment_closed,
         })
     }
 
     /// Player stands (stops playing)
     #[tracing::instrument(skip(self), fields(game_id, user_id))]
     pub fn stand(&self, game_id: Uuid, user_id: Uuid) -> Result<GameStateResponse, GameError> {
-        // Get user email from user service
-        let user = self.user_service.get_user(user_id)?;
-        let email = user.email;
-
         let mut games = self.games.lock().unwrap();
         let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
 
-        game.stand(&email)?;
+        game.stand(user_id)?;
+        self.settle_turns(game);
 
         tracing::info!(
             game_id = %game_id,
-            player_email = email,
+            user_id = %user_id,
             is_finished = game.finished,
             "Player stood"
         );
 
-        // Build response
-        let mut players: HashMap<String, PlayerInfo> = HashMap::new();
-        for (email, player) in &game.players {
-            players.insert(
-                email.clone(),
-                PlayerInfo {
-                    points: player.points,
-                    cards_history: player.cards_history.clone(),
-                    busted: player.busted,
-                },
-            );
-        }
-
-        Ok(GameStateResponse {
-            players,
-            cards_in_deck: game.available_cards.len(),
-            finished: game.finished,
-            current_turn_player: game.get_current_player().map(|s| s.to_string()),
-            turn_order: game.turn_order.clone(),
-            enrollment_timeout_seconds: game.enrollment_timeout_seconds,
-            enrollment_closes_at: game.get_enrollment_expires_at(),
-            time_remaining_seconds: game.get_enrollment_time_remaining(),
-            enrollment_closed: game.enrollment_closed,
-        })
+        Ok(GameStateResponse::from_game(game))
     }
 
     /// Adds a player to a game (from invitation acceptance)
+    ///
+    /// Applies the same seating rules as `enroll_player`: enrollment must still
+    /// be open and the table must have a free seat. Visibility and join password
+    /// are not checked, since the invitation already grants access.
+    ///
+    /// # Errors
+    ///
+    /// - `EnrollmentClosed` if enrollment was closed or timed out
+    /// - `GameFull` if there are no free seats
+    /// - `PlayerAlreadyEnrolled` if the user is already in the game
     #[tracing::instrument(skip(self), fields(game_id, user_id))]
     pub fn add_player_to_game(&self, game_id: Uuid, user_id: Uuid) -> Result<(), GameError> {
-        // Get user email from user service
-        let user = self.user_service.get_user(user_id)?;
-        let email = user.email;
-
-        let mut games = self.games.lock().unwrap();
-        let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
-
-        game.add_player(email.clone())?;
+        {
+            let mut games = self.games.lock().unwrap();
+            let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
+            self.enroll_into(game, user_id)?;
+        }
 
         tracing::info!(
             game_id = %game_id,
             user_id = %user_id,
-            player_email = %email,
             "Player added to game"
         );
 
@@ -1014,7 +2801,7 @@ impl GameService {
     ///
     /// # Returns
     ///
-    /// The email of the kicked player
+    /// The display name of the kicked player
     ///
     /// # Errors
     ///
@@ -1063,31 +2850,25 @@ impl GameService {
             return Err(GameError::EnrollmentClosed);
         }
 
-        // Get player's email before removing
-        let player_email = game
-            .participants
-            .get(&player_id)
-            .map(|p| p.email.clone())
-            .ok_or(GameError::PlayerNotInGame)?;
-
-        // Remove from participants
-        game.participants.remove(&player_id);
-
-        // Remove from players HashMap
-        game.players.remove(&player_email);
+        if !game.participants.contains_key(&player_id) {
+            return Err(GameError::PlayerNotInGame);
+        }
 
-        // Remove from turn order
-        game.turn_order.retain(|email| email != &player_email);
+        // Remove from participants, players and turn order
+        let player_name = game
+            .remove_player(player_id)
+            .map(|player| player.name)
+            .ok_or(GameError::PlayerNotInGame)?;
 
         tracing::info!(
             game_id = %game_id,
             kicker_id = %kicker_id,
             player_id = %player_id,
-            player_email = %player_email,
+            player_name = %player_name,
             "Player kicked from game"
         );
 
-        Ok(player_email)
+        Ok(player_name)
     }
 
     /// Checks if a user is the creator of a game
@@ -1131,6 +2912,7 @@ impl GameService {
         }
 
         game.finish_game();
+        self.record_results(game);
         let results = game.calculate_results();
 
         tracing::info!(
@@ -1144,6 +2926,51 @@ impl GameService {
         Ok(results)
     }
 
+    /// Lists every game in any phase, newest first (administration)
+    pub fn list_games(&self) -> Vec<GameOverview> {
+        let games = self.games.lock().unwrap();
+        let mut list: Vec<GameOverview> = games.values().map(GameOverview::from_game).collect();
+        list.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        list
+    }
+
+    /// Returns the full internal state of a game, deck included (administration)
+    ///
+    /// The join password hash is left out.
+    pub fn inspect_game(&self, game_id: Uuid) -> Result<Game, GameError> {
+        let games = self.games.lock().unwrap();
+        let mut game = games
+            .get(&game_id)
+            .cloned()
+            .ok_or(GameError::GameNotFound)?;
+        game.join_password_hash = None;
+        Ok(game)
+    }
+
+    /// Force-finishes a game without playing the dealer (administration)
+    ///
+    /// Meant for games stuck waiting on a player who left. Aborted games are
+    /// not counted in player statistics.
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `GameAlreadyFinished` if the game is already over
+    #[tracing::instrument(skip(self), fields(game_id))]
+    pub fn abort_game(&self, game_id: Uuid) -> Result<GameOverview, GameError> {
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
+
+        if game.finished {
+            return Err(GameError::GameAlreadyFinished);
+        }
+        game.abort();
+
+        tracing::warn!(game_id = %game_id, "Game aborted by administrator");
+
+        Ok(GameOverview::from_game(game))
+    }
+
     /// Retrieves the results of a finished game
     ///
     /// Returns the game results including winner, tied players, and all player summaries.
@@ -1160,6 +2987,371 @@ impl GameService {
         Ok(game.calculate_results())
     }
 
+    /// Seats a bot player in a game (only the creator can do this)
+    ///
+    /// Bots take a seat like any player and act automatically on their turn.
+    /// They have no user account, so their games never reach `UserStats`.
+    ///
+    /// # Returns
+    ///
+    /// The bot's player name (`bot-1`, `bot-2`, ...)
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `InsufficientPermissions` if the user is not the creator
+    /// - `EnrollmentClosed` if enrollment has already closed
+    /// - `GameFull` if there are no free seats
+    #[tracing::instrument(skip(self), fields(game_id, user_id))]
+    pub fn add_bot(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        strategy: BotStrategy,
+    ) -> Result<String, GameError> {
+        use blackjack_core::GamePermission;
+
+        let mut games = self.games.lock().unwrap();
+        let game = games.get_mut(&game_id).ok_or(GameError::GameNotFound)?;
+
+        if !game.can_user_perform(user_id, GamePermission::AddBots) {
+            tracing::warn!(
+                game_id = %game_id,
+                user_id = %user_id,
+                "Permission denied: user attempted to add a bot"
+            );
+            return Err(GameError::InsufficientPermissions);
+        }
+
+        if !game.is_enrollment_open() {
+            return Err(GameError::EnrollmentClosed);
+        }
+
+        if !game.can_enroll() {
+            return Err(GameError::GameFull);
+        }
+
+        let bot_id = game.add_bot(strategy).map_err(|e| match e {
+            CoreGameError::GameNotActive => GameError::GameNotActive,
+            other => GameError::CoreError(other),
+        })?;
+        let bot_name = game.player_name(bot_id).unwrap_or_default().to_string();
+
+        tracing::info!(
+            game_id = %game_id,
+            bot_name = %bot_name,
+            ?strategy,
+            enrolled_count = game.players.len(),
+            "Bot seated"
+        );
+
+        Ok(bot_name)
+    }
+
+    /// Lets bots take their turns, then records results if the game finished
+    ///
+    /// Called after every action that can move the turn along.
+    fn settle_turns(&self, game: &mut Game) {
+        Self::play_bot_turns(game);
+        self.record_results(game);
+    }
+
+    /// Plays every consecutive bot turn until a human is up or the game ends
+    fn play_bot_turns(game: &mut Game) {
+        let mut rng = rand::rng();
+
+        while !game.finished && game.enrollment_closed {
+            let Some(player_id) = game.get_current_player() else {
+                return;
+            };
+            let Some(strategy) = game.bots.get(&player_id).map(BotStrategy::strategy) else {
+                return;
+            };
+            let Some(player) = game.players.get(&player_id) else {
+                return;
+            };
+            if player.state != PlayerState::Active {
+                return;
+            }
+
+            if let Err(e) = game.play_strategy_turn(player_id, strategy.as_ref(), &mut rng) {
+                tracing::error!(bot_id = %player_id, error = %e, "Bot could not play its turn");
+                return;
+            }
+        }
+    }
+
+    /// Records final results in the statistics of every human player, once
+    ///
+    /// Games finished before enrollment closed were never played and are skipped.
+    fn record_results(&self, game: &mut Game) {
+        if !game.finished || !game.enrollment_closed || !game.active || game.results_recorded {
+            return;
+        }
+
+        let results = game.calculate_results();
+        let rated: Vec<&PlayerResult> = results
+            .player_results
+            .values()
+            .filter(|result| game.participants.contains_key(&result.player_id))
+            .collect();
+        self.user_service
+            .record_game_results(&rated, results.dealer_busted, game.rules.min_bet);
+        self.archive
+            .record(ArchivedGame::new(game, &results, chrono::Utc::now()));
+        game.results_recorded = true;
+
+        tracing::info!(game_id = %game.id, "Game results recorded to player statistics");
+    }
+
+    /// Finished games the user played in, from the archive
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the cursor is malformed
+    pub fn player_games(
+        &self,
+        user_id: Uuid,
+        query: &PlayerGamesQuery,
+    ) -> Result<PlayerGamesPage, GameError> {
+        self.archive.player_games(user_id, query)
+    }
+
+    /// Every finished game the user played in, from the archive, newest first
+    pub fn all_player_games(&self, user_id: Uuid) -> Vec<PlayerGameEntry> {
+        self.archive.all_player_games(user_id)
+    }
+
+    /// Games the user took part in, newest first
+    pub fn game_history(&self, user_id: Uuid) -> Vec<GameHistoryEntry> {
+        let games = self.games.lock().unwrap();
+        let mut history: Vec<GameHistoryEntry> = games
+            .values()
+            .filter_map(|game| GameHistoryEntry::from_game(game, user_id))
+            .collect();
+        history.sort_by(|a, b| b.joined_at.cmp(&a.joined_at));
+        history
+    }
+
+    /// Posts a chat message to a game's table
+    ///
+    /// Every other participant receives a `chat_message` notification with the
+    /// message ID. The text and author stay out of the notification so that
+    /// deleting the message or anonymising its author also hides them there.
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `PlayerNotInGame` if the user is not a participant
+    /// - `ChatMuted` if the user is muted in this game
+    /// - `ValidationError` if the message is empty or too long
+    #[tracing::instrument(skip(self, text, notifications), fields(game_id, user_id))]
+    pub fn post_chat_message(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        text: &str,
+        notifications: &NotificationService,
+    ) -> Result<ChatMessage, GameError> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id).ok_or(GameError::GameNotFound)?;
+        let author = game
+            .participants
+            .get(&user_id)
+            .ok_or(GameError::PlayerNotInGame)?;
+
+        let message = self
+            .chat
+            .post(game_id, user_id, &author.display_name, text)?;
+
+        for recipient in game.participants.keys().filter(|id| **id != user_id) {
+            notifications.notify(
+                *recipient,
+                NotificationKind::ChatMessage {
+                    game_id,
+                    message_id: message.id,
+                },
+                "New message in your game's chat".to_string(),
+            );
+        }
+
+        tracing::debug!(game_id = %game_id, user_id = %user_id, message_id = %message.id, "Chat message posted");
+
+        Ok(message)
+    }
+
+    /// Latest chat messages of a game, oldest first (participants only)
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `PlayerNotInGame` if the user is not a participant
+    pub fn chat_history(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        limit: Option<usize>,
+    ) -> Result<ChatHistory, GameError> {
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id).ok_or(GameError::GameNotFound)?;
+        if !game.is_participant(user_id) {
+            return Err(GameError::PlayerNotInGame);
+        }
+
+        let limit = limit
+            .unwrap_or(chat::DEFAULT_CHAT_HISTORY_PAGE_SIZE)
+            .clamp(1, chat::MAX_CHAT_HISTORY);
+        Ok(self.chat.history(game_id, limit))
+    }
+
+    /// Deletes a chat message (chat moderators only)
+    ///
+    /// The other participants receive a `chat_message_deleted` notification
+    /// so clients can hide the message.
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `InsufficientPermissions` if the user can't moderate the chat
+    /// - `ChatMessageNotFound` if the game has no such message
+    #[tracing::instrument(skip(self, notifications), fields(game_id, user_id, message_id))]
+    pub fn delete_chat_message(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        message_id: Uuid,
+        notifications: &NotificationService,
+    ) -> Result<ChatMessage, GameError> {
+        use blackjack_core::GamePermission;
+
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id).ok_or(GameError::GameNotFound)?;
+        if !game.can_user_perform(user_id, GamePermission::ModerateChat) {
+            tracing::warn!(
+                game_id = %game_id,
+                user_id = %user_id,
+                "Permission denied: user attempted to delete a chat message"
+            );
+            return Err(GameError::InsufficientPermissions);
+        }
+
+        let message = self.chat.delete(game_id, message_id)?;
+
+        for recipient in game.participants.keys().filter(|id| **id != user_id) {
+            notifications.notify(
+                *recipient,
+                NotificationKind::ChatMessageDeleted {
+                    game_id,
+                    message_id,
+                },
+                "A chat message was removed by the game creator".to_string(),
+            );
+        }
+
+        tracing::info!(game_id = %game_id, user_id = %user_id, message_id = %message_id, author_id = %message.author_id, "Chat message deleted");
+
+        Ok(message)
+    }
+
+    /// Mutes or unmutes a participant in a game's chat (chat moderators only)
+    ///
+    /// The participant is notified when their state changes.
+    ///
+    /// # Errors
+    ///
+    /// - `GameNotFound` if game doesn't exist
+    /// - `InsufficientPermissions` if the user can't moderate the chat
+    /// - `ValidationError` if the target is the game creator
+    /// - `PlayerNotInGame` if the target is not a participant
+    #[tracing::instrument(skip(self, notifications), fields(game_id, user_id, target_id))]
+    pub fn set_chat_muted(
+        &self,
+        game_id: Uuid,
+        user_id: Uuid,
+        target_id: Uuid,
+        muted: bool,
+        notifications: &NotificationService,
+    ) -> Result<(), GameError> {
+        use blackjack_core::GamePermission;
+
+        let games = self.games.lock().unwrap();
+        let game = games.get(&game_id).ok_or(GameError::GameNotFound)?;
+        if !game.can_user_perform(user_id, GamePermission::ModerateChat) {
+            tracing::warn!(
+                game_id = %game_id,
+                user_id = %user_id,
+                "Permission denied: user attempted to change a chat mute"
+            );
+            return Err(GameError::InsufficientPermissions);
+        }
+        if game.is_creator(target_id) {
+            return Err(GameError::ValidationError(
+                "The game creator cannot be muted".to_string(),
+            ));
+        }
+        if !game.is_participant(target_id) {
+            return Err(GameError::PlayerNotInGame);
+        }
+
+        if self.chat.set_muted(game_id, target_id, muted) {
+            let message = if muted {
+                "You were muted in this game's chat"
+            } else {
+                "You can chat in this game again"
+            };
+            notifications.notify(
+                target_id,
+                NotificationKind::ChatMuted { game_id, muted },
+                message.to_string(),
+            );
+        }
+
+        tracing::info!(game_id = %game_id, user_id = %user_id, target_id = %target_id, muted, "Chat mute updated");
+
+        Ok(())
+    }
+
+    /// Anonymises every account whose deletion grace period is over
+    ///
+    /// Each account is removed from the user store and its seats move to a
+    /// fresh tombstone ID named `deleted-xxxxxxxx`, so the other players keep
+    /// their game history and results.
+    pub fn purge_deleted_accounts(&self, grace: chrono::Duration) -> Vec<DeletedAccount> {
+        let deleted: Vec<DeletedAccount> = self
+            .user_service
+            .take_due_deletions(grace)
+            .into_iter()
+            .map(|user| DeletedAccount {
+                user_id: user.id,
+                email: user.email,
+                tombstone_id: Uuid::new_v4(),
+            })
+            .collect();
+
+        let mut games = self.games.lock().unwrap();
+        for account in &deleted {
+            let name = account.tombstone_name();
+            let anonymised = games
+                .values_mut()
+                .map(|game| game.anonymize_player(account.user_id, account.tombstone_id, &name))
+                .filter(|found| *found)
+                .count();
+            self.archive
+                .anonymize_player(account.user_id, account.tombstone_id, &name);
+            self.chat
+                .anonymize_author(account.user_id, account.tombstone_id, &name);
+
+            tracing::info!(
+                user_id = %account.user_id,
+                tombstone_id = %account.tombstone_id,
+                games = anonymised,
+                "Deleted account anonymised"
+            );
+        }
+
+        deleted
+    }
+
     /// Returns a reference to the service configuration
     pub fn config(&self) -> &ServiceConfig {
         &self.config
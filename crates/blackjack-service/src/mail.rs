@@ -0,0 +1,176 @@
+//! Outgoing email
+//!
+//! Services send mail through the [`Mailer`] trait. [`SmtpMailer`] delivers
+//! through an SMTP relay; [`OutboxMailer`] keeps messages in memory and can
+//! also write them to a directory, so development servers and tests can read
+//! what would have been sent.
+
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{SmtpTransport, Transport};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+
+/// An email ready to be sent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    /// Plain-text body
+    pub body: String,
+}
+
+/// A message could not be delivered
+#[derive(Debug, Error)]
+#[error("Mail delivery failed: {0}")]
+pub struct MailError(pub String);
+
+/// Sends email
+pub trait Mailer: Send + Sync {
+    /// Delivers `message`, blocking until the transport accepted it
+    fn send(&self, message: &EmailMessage) -> Result<(), MailError>;
+}
+
+/// SMTP relay settings
+#[derive(Debug, Clone)]
+pub struct SmtpSettings {
+    pub host: String,
+    pub port: u16,
+    /// Leave empty to send without authentication
+    pub username: String,
+    pub password: String,
+    /// Upgrade the connection with STARTTLS (required when authenticating)
+    pub starttls: bool,
+}
+
+/// Delivers mail through an SMTP relay
+pub struct SmtpMailer {
+    transport: SmtpTransport,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    /// Creates a mailer sending as `from`, e.g. `Blackjack <noreply@example.com>`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `from` is not a valid mailbox or the TLS settings
+    /// can't be set up for `host`.
+    pub fn new(settings: &SmtpSettings, from: &str) -> Result<Self, MailError> {
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|e| MailError(format!("Invalid sender {}: {}", from, e)))?;
+
+        let mut builder = if settings.starttls {
+            SmtpTransport::starttls_relay(&settings.host).map_err(|e| MailError(e.to_string()))?
+        } else {
+            SmtpTransport::builder_dangerous(&settings.host)
+        }
+        .port(settings.port);
+        if !settings.username.is_empty() {
+            builder = builder.credentials(Credentials::new(
+                settings.username.clone(),
+                settings.password.clone(),
+            ));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+impl Mailer for SmtpMailer {
+    #[tracing::instrument(skip(self, message), fields(to = %message.to))]
+    fn send(&self, message: &EmailMessage) -> Result<(), MailError> {
+        let to = message
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| MailError(format!("Invalid recipient {}: {}", message.to, e)))?;
+        let email = lettre::Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(&message.subject)
+            .body(message.body.clone())
+            .map_err(|e| MailError(e.to_string()))?;
+
+        self.transport
+            .send(&email)
+            .map_err(|e| MailError(e.to_string()))?;
+        tracing::info!(subject = %message.subject, "Mail sent");
+        Ok(())
+    }
+}
+
+/// Local outbox for development and tests
+///
+/// Every message is kept in memory; with [`OutboxMailer::with_dir`] it is
+/// also written to `<dir>/<timestamp>-<recipient>.eml`.
+#[derive(Clone, Default)]
+pub struct OutboxMailer {
+    messages: Arc<Mutex<Vec<EmailMessage>>>,
+    dir: Option<PathBuf>,
+}
+
+impl OutboxMailer {
+    /// Creates an outbox that only keeps messages in memory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an outbox that also writes messages to `dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be created.
+    pub fn with_dir(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            messages: Arc::default(),
+            dir: Some(dir),
+        })
+    }
+
+    /// Every message sent so far, oldest first
+    pub fn messages(&self) -> Vec<EmailMessage> {
+        self.messages.lock().unwrap().clone()
+    }
+
+    /// The most recent message sent to `to`
+    pub fn last_to(&self, to: &str) -> Option<EmailMessage> {
+        let messages = self.messages.lock().unwrap();
+        messages.iter().rev().find(|m| m.to == to).cloned()
+    }
+}
+
+impl Mailer for OutboxMailer {
+    fn send(&self, message: &EmailMessage) -> Result<(), MailError> {
+        if let Some(dir) = &self.dir {
+            let now = chrono::Utc::now();
+            let file_name = format!(
+                "{}-{}.eml",
+                now.format("%Y%m%dT%H%M%S%.6fZ"),
+                message
+                    .to
+                    .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+            );
+            let contents = format!(
+                "To: {}\nSubject: {}\nDate: {}\n\n{}\n",
+                message.to,
+                message.subject,
+                now.to_rfc2822(),
+                message.body
+            );
+            std::fs::write(dir.join(&file_name), contents).map_err(|e| MailError(e.to_string()))?;
+            tracing::info!(to = %message.to, file = %file_name, "Mail written to outbox");
+        } else {
+            tracing::info!(to = %message.to, subject = %message.subject, "Mail kept in outbox");
+        }
+
+        self.messages.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,476 @@
+//! Matchmaking queue that groups compatible players into new games
+//!
+//! Players queue with the table rules they want and, optionally, a band of
+//! opponent ratings they accept. As soon as enough compatible players are
+//! waiting to fill a table, the service creates a private game, seats them,
+//! closes enrollment and notifies everyone. Once the longest-waiting of them
+//! has waited `fill_after_seconds`, a table forms with as few as
+//! `min_players`. Players are compatible when they want the same table size,
+//! their stakes ranges overlap and each one's rating lies in the others'
+//! bands.
+
+use crate::notifications::{NotificationKind, NotificationService};
+use crate::{GameError, GameService};
+use blackjack_core::TableRules;
+use blackjack_core::rating::RatingBand;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Configuration for the matchmaking queue
+#[derive(Debug, Clone)]
+pub struct MatchmakingConfig {
+    pub default_wait_seconds: u64,
+    pub max_wait_seconds: u64,
+    /// Fewest players a table is formed with before it is full
+    pub min_players: u8,
+    /// How long the longest-waiting player waits for a full table
+    pub fill_after_seconds: u64,
+}
+
+impl Default for MatchmakingConfig {
+    fn default() -> Self {
+        Self {
+            default_wait_seconds: 120, // 2 minutes
+            max_wait_seconds: 900,     // 15 minutes
+            min_players: 2,
+            fill_after_seconds: 30,
+        }
+    }
+}
+
+impl MatchmakingConfig {
+    /// Load configuration from environment variables with defaults
+    pub fn from_env() -> Self {
+        let default_wait_seconds = std::env::var("BLACKJACK_MATCHMAKING_DEFAULT_WAIT_SECONDS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(120);
+
+        let max_wait_seconds = std::env::var("BLACKJACK_MATCHMAKING_MAX_WAIT_SECONDS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(900);
+
+        let min_players = std::env::var("BLACKJACK_MATCHMAKING_MIN_PLAYERS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(2);
+
+        let fill_after_seconds = std::env::var("BLACKJACK_MATCHMAKING_FILL_AFTER_SECONDS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30);
+
+        Self {
+            default_wait_seconds,
+            max_wait_seconds,
+            min_players,
+            fill_after_seconds,
+        }
+    }
+}
+
+/// Lifecycle of a matchmaking ticket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketStatus {
+    /// Waiting for enough compatible players
+    Waiting,
+    /// Seated at a new game (see `game_id`)
+    Matched,
+    /// No table formed before the ticket expired
+    TimedOut,
+    /// Withdrawn by the player
+    Cancelled,
+}
+
+/// A player's place in the matchmaking queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueTicket {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub rules: TableRules,
+    /// Player's rating when queueing
+    pub rating: u32,
+    /// Ratings the player accepts at the table
+    #[serde(default)]
+    pub rating_band: RatingBand,
+    pub status: TicketStatus,
+    /// Game the player was seated at, once matched
+    pub game_id: Option<Uuid>,
+    pub enqueued_at: String,
+    pub expires_at: String,
+}
+
+impl QueueTicket {
+    /// Checks if the ticket's wait time has run out
+    pub fn is_expired(&self) -> bool {
+        chrono::DateTime::parse_from_rfc3339(&self.expires_at)
+            .map(|expires_at| chrono::Utc::now() > expires_at)
+            .unwrap_or(true)
+    }
+
+    fn is_waiting(&self) -> bool {
+        self.status == TicketStatus::Waiting && !self.is_expired()
+    }
+
+    /// Checks if two players can share a table
+    ///
+    /// They must want the same table size and have overlapping stakes; other
+    /// rule options are settled when the table forms.
+    fn is_compatible(&self, other: &QueueTicket) -> bool {
+        self.rules.max_players == other.rules.max_players
+            && self.rules.min_bet <= other.rules.max_bet
+            && other.rules.min_bet <= self.rules.max_bet
+            && self.rating_band.contains(other.rating)
+            && other.rating_band.contains(self.rating)
+    }
+
+    fn waited_since(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        chrono::DateTime::parse_from_rfc3339(&self.enqueued_at)
+            .unwrap_or_else(|_| chrono::Utc::now().fixed_offset())
+    }
+}
+
+/// Rules for a table of compatible tickets
+///
+/// Stakes are the range every player accepts; doubling, splitting and
+/// surrender are only offered if every player asked for them.
+fn table_rules(table: &[&QueueTicket]) -> TableRules {
+    let mut rules = table[0].rules.clone();
+    for ticket in &table[1..] {
+        rules.min_bet = rules.min_bet.max(ticket.rules.min_bet);
+        rules.max_bet = rules.max_bet.min(ticket.rules.max_bet);
+        rules.double_allowed &= ticket.rules.double_allowed;
+        rules.split_allowed &= ticket.rules.split_allowed;
+        rules.surrender_allowed &= ticket.rules.surrender_allowed;
+    }
+    rules
+}
+
+/// Queue status returned to the player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueStatus {
+    pub ticket: QueueTicket,
+    /// Compatible players (including this one) waiting
+    pub players_waiting: usize,
+}
+
+/// Matchmaking service keeping one ticket per user
+pub struct MatchmakingService {
+    config: MatchmakingConfig,
+    /// Latest ticket per user (user_id -> ticket)
+    tickets: Arc<Mutex<HashMap<Uuid, QueueTicket>>>,
+}
+
+impl MatchmakingService {
+    /// Creates a new matchmaking service with the given configuration
+    pub fn new(config: MatchmakingConfig) -> Self {
+        Self {
+            config,
+            tickets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Adds a user to the queue and forms a table if enough players are waiting
+    ///
+    /// The ticket returned already reflects a match made by this call.
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the table rules are invalid
+    /// - `InvalidTimeout` if `wait_seconds` is zero or above the configured maximum
+    /// - `AlreadyQueued` if the user already has a waiting ticket
+    pub fn enqueue(
+        &self,
+        user_id: Uuid,
+        rules: TableRules,
+        wait_seconds: Option<u64>,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> Result<QueueStatus, GameError> {
+        self.enqueue_with_rating_band(
+            user_id,
+            rules,
+            wait_seconds,
+            RatingBand::default(),
+            game_service,
+            notifications,
+        )
+    }
+
+    /// Adds a user to the queue, only to be seated with players rated within `rating_band`
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the table rules are invalid or the band is empty
+    /// - `InvalidTimeout` if `wait_seconds` is zero or above the configured maximum
+    /// - `AlreadyQueued` if the user already has a waiting ticket
+    #[tracing::instrument(skip(self, game_service, notifications))]
+    pub fn enqueue_with_rating_band(
+        &self,
+        user_id: Uuid,
+        rules: TableRules,
+        wait_seconds: Option<u64>,
+        rating_band: RatingBand,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> Result<QueueStatus, GameError> {
+        rules.validate().map_err(GameError::ValidationError)?;
+        rating_band.validate().map_err(GameError::ValidationError)?;
+
+        let wait = wait_seconds.unwrap_or(self.config.default_wait_seconds);
+        if wait == 0 || wait > self.config.max_wait_seconds {
+            return Err(GameError::InvalidTimeout {
+                max: self.config.max_wait_seconds,
+            });
+        }
+
+        let mut tickets = self.tickets.lock().unwrap();
+        Self::expire_locked(&mut tickets, notifications);
+
+        if tickets.get(&user_id).is_some_and(QueueTicket::is_waiting) {
+            return Err(GameError::AlreadyQueued);
+        }
+
+        let now = chrono::Utc::now();
+        let ticket = QueueTicket {
+            id: Uuid::new_v4(),
+            user_id,
+            rules: rules.clone(),
+            rating: game_service.user_service.rating(user_id),
+            rating_band,
+            status: TicketStatus::Waiting,
+            game_id: None,
+            enqueued_at: now.to_rfc3339(),
+            expires_at: (now + chrono::Duration::seconds(wait as i64)).to_rfc3339(),
+        };
+        tickets.insert(user_id, ticket);
+
+        tracing::info!(user_id = %user_id, ?rules, ?rating_band, wait_seconds = wait, "Player joined matchmaking queue");
+
+        self.form_tables_locked(&mut tickets, game_service, notifications);
+
+        Ok(Self::status_locked(&tickets, user_id))
+    }
+
+    /// Forms every table that can be formed now
+    ///
+    /// Run periodically so a short-handed table forms once its players have
+    /// waited long enough, even when nobody else joins the queue.
+    ///
+    /// # Returns
+    ///
+    /// The number of tables formed
+    pub fn form_waiting_tables(
+        &self,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> usize {
+        let mut tickets = self.tickets.lock().unwrap();
+        Self::expire_locked(&mut tickets, notifications);
+        self.form_tables_locked(&mut tickets, game_service, notifications)
+    }
+
+    /// Gets the user's latest ticket
+    ///
+    /// # Errors
+    ///
+    /// - `NotQueued` if the user never queued
+    pub fn status(
+        &self,
+        user_id: Uuid,
+        notifications: &NotificationService,
+    ) -> Result<QueueStatus, GameError> {
+        let mut tickets = self.tickets.lock().unwrap();
+        Self::expire_locked(&mut tickets, notifications);
+
+        if !tickets.contains_key(&user_id) {
+            return Err(GameError::NotQueued);
+        }
+        Ok(Self::status_locked(&tickets, user_id))
+    }
+
+    /// Removes the user from the queue
+    ///
+    /// # Errors
+    ///
+    /// - `NotQueued` if the user has no waiting ticket
+    #[tracing::instrument(skip(self, notifications))]
+    pub fn cancel(
+        &self,
+        user_id: Uuid,
+        notifications: &NotificationService,
+    ) -> Result<QueueTicket, GameError> {
+        let mut tickets = self.tickets.lock().unwrap();
+        Self::expire_locked(&mut tickets, notifications);
+
+        let ticket = tickets
+            .get_mut(&user_id)
+            .filter(|ticket| ticket.status == TicketStatus::Waiting)
+            .ok_or(GameError::NotQueued)?;
+        ticket.status = TicketStatus::Cancelled;
+
+        tracing::info!(user_id = %user_id, ticket_id = %ticket.id, "Player left matchmaking queue");
+
+        Ok(ticket.clone())
+    }
+
+    /// Times out expired tickets and notifies their owners
+    pub fn expire_stale(&self, notifications: &NotificationService) -> usize {
+        let mut tickets = self.tickets.lock().unwrap();
+        Self::expire_locked(&mut tickets, notifications)
+    }
+
+    fn expire_locked(
+        tickets: &mut HashMap<Uuid, QueueTicket>,
+        notifications: &NotificationService,
+    ) -> usize {
+        let mut count = 0;
+        for ticket in tickets.values_mut() {
+            if ticket.status == TicketStatus::Waiting && ticket.is_expired() {
+                ticket.status = TicketStatus::TimedOut;
+                notifications.notify(
+                    ticket.user_id,
+                    NotificationKind::QueueTimedOut {
+                        ticket_id: ticket.id,
+                    },
+                    "No table could be formed before your matchmaking ticket expired".to_string(),
+                );
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            tracing::debug!(count = count, "Matchmaking tickets timed out");
+        }
+
+        count
+    }
+
+    fn status_locked(tickets: &HashMap<Uuid, QueueTicket>, user_id: Uuid) -> QueueStatus {
+        let ticket = tickets[&user_id].clone();
+        let players_waiting = tickets
+            .values()
+            .filter(|other| other.is_waiting() && other.is_compatible(&ticket))
+            .count();
+        QueueStatus {
+            ticket,
+            players_waiting,
+        }
+    }
+
+    /// Offers a table to each waiting player, longest-waiting first
+    fn form_tables_locked(
+        &self,
+        tickets: &mut HashMap<Uuid, QueueTicket>,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> usize {
+        let mut queue: Vec<(String, Uuid)> = tickets
+            .values()
+            .filter(|ticket| ticket.is_waiting())
+            .map(|ticket| (ticket.enqueued_at.clone(), ticket.user_id))
+            .collect();
+        queue.sort();
+
+        queue
+            .into_iter()
+            .filter(|(_, user_id)| {
+                tickets[user_id].is_waiting()
+                    && self.try_form_table(tickets, *user_id, game_service, notifications)
+            })
+            .count()
+    }
+
+    /// Seats a waiting player with the longest-waiting compatible players
+    ///
+    /// A table forms once it is full, or once it has `min_players` and its
+    /// longest-waiting player has waited `fill_after_seconds`. If the game
+    /// cannot be set up the tickets stay in the queue.
+    ///
+    /// # Returns
+    ///
+    /// Whether a table was formed
+    fn try_form_table(
+        &self,
+        tickets: &mut HashMap<Uuid, QueueTicket>,
+        user_id: Uuid,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> bool {
+        let anchor = &tickets[&user_id];
+        let max_players = anchor.rules.max_players as usize;
+        let mut waiting: Vec<&QueueTicket> = tickets
+            .values()
+            .filter(|ticket| {
+                ticket.user_id != user_id && ticket.is_waiting() && ticket.is_compatible(anchor)
+            })
+            .collect();
+        waiting.sort_by(|a, b| a.enqueued_at.cmp(&b.enqueued_at));
+
+        // Everyone at the table must accept everyone else
+        let mut table = vec![anchor];
+        for ticket in waiting {
+            if table.len() == max_players {
+                break;
+            }
+            if table.iter().all(|seated| seated.is_compatible(ticket)) {
+                table.push(ticket);
+            }
+        }
+        if table.len() < max_players {
+            let min_players = (self.config.min_players as usize).clamp(1, max_players);
+            let fill_after = chrono::Duration::seconds(self.config.fill_after_seconds as i64);
+            let longest_wait = table
+                .iter()
+                .map(|ticket| chrono::Utc::now().fixed_offset() - ticket.waited_since())
+                .max()
+                .unwrap_or_default();
+            if table.len() < min_players || longest_wait < fill_after {
+                return false;
+            }
+        }
+        // The longest-waiting player creates the game
+        table.sort_by(|a, b| a.enqueued_at.cmp(&b.enqueued_at));
+        let players: Vec<Uuid> = table.iter().map(|ticket| ticket.user_id).collect();
+        let rules = table_rules(&table);
+
+        let game_id = match game_service.create_private_table(&players, &rules) {
+            Ok(game_id) => game_id,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to form matchmaking table");
+                return false;
+            }
+        };
+
+        for user_id in &players {
+            let ticket = tickets.get_mut(user_id).expect("matched ticket exists");
+            ticket.status = TicketStatus::Matched;
+            ticket.game_id = Some(game_id);
+            notifications.notify(
+                *user_id,
+                NotificationKind::MatchFound {
+                    ticket_id: ticket.id,
+                    game_id,
+                },
+                format!(
+                    "Matched with {} player(s), your game is ready",
+                    players.len() - 1
+                ),
+            );
+        }
+
+        tracing::info!(game_id = %game_id, players = players.len(), "Matchmaking table formed");
+
+        true
+    }
+}
+
+impl Default for MatchmakingService {
+    fn default() -> Self {
+        Self::new(MatchmakingConfig::default())
+    }
+}
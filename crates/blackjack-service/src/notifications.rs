@@ -0,0 +1,127 @@
+//! Per-user notification inbox
+//!
+//! Services push notifications here when something happens to a user outside
+//! of a request they made (a matchmaking table forming, a queue timing out,
+//! a tournament round being seated, a chat message at their table).
+//! Clients poll the inbox through the API.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Maximum notifications kept per user; the oldest are dropped first
+const MAX_NOTIFICATIONS_PER_USER: usize = 100;
+
+/// What a notification is about
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// The matchmaking queue seated the user at a new game
+    MatchFound { ticket_id: Uuid, game_id: Uuid },
+    /// The user's matchmaking ticket expired before a table formed
+    QueueTimedOut { ticket_id: Uuid },
+    /// A tournament round seated the user at a table
+    TournamentTableReady {
+        tournament_id: Uuid,
+        round: u8,
+        game_id: Uuid,
+    },
+    /// The user was knocked out of a tournament
+    TournamentEliminated { tournament_id: Uuid, round: u8 },
+    /// The user won a tournament
+    TournamentWon { tournament_id: Uuid },
+    /// Another participant posted to a game's chat; the text is in the chat history
+    ChatMessage { game_id: Uuid, message_id: Uuid },
+    /// The game creator deleted a chat message
+    ChatMessageDeleted { game_id: Uuid, message_id: Uuid },
+    /// The game creator muted or unmuted the user in a game's chat
+    ChatMuted { game_id: Uuid, muted: bool },
+}
+
+/// A single notification addressed to one user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[serde(flatten)]
+    pub kind: NotificationKind,
+    pub message: String,
+    pub created_at: String,
+    pub read: bool,
+}
+
+/// In-memory notification inbox keyed by user ID
+pub struct NotificationService {
+    inboxes: Arc<Mutex<HashMap<Uuid, Vec<Notification>>>>,
+}
+
+impl NotificationService {
+    /// Creates an empty notification service
+    pub fn new() -> Self {
+        Self {
+            inboxes: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Delivers a notification to a user's inbox
+    pub fn notify(&self, user_id: Uuid, kind: NotificationKind, message: String) -> Notification {
+        let notification = Notification {
+            id: Uuid::new_v4(),
+            user_id,
+            kind,
+            message,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            read: false,
+        };
+
+        let mut inboxes = self.inboxes.lock().unwrap();
+        let inbox = inboxes.entry(user_id).or_default();
+        inbox.push(notification.clone());
+        if inbox.len() > MAX_NOTIFICATIONS_PER_USER {
+            let overflow = inbox.len() - MAX_NOTIFICATIONS_PER_USER;
+            inbox.drain(..overflow);
+        }
+
+        tracing::debug!(user_id = %user_id, notification_id = %notification.id, "Notification delivered");
+
+        notification
+    }
+
+    /// Gets a user's notifications, newest first
+    pub fn get_for_user(&self, user_id: Uuid, unread_only: bool) -> Vec<Notification> {
+        let inboxes = self.inboxes.lock().unwrap();
+        inboxes
+            .get(&user_id)
+            .map(|inbox| {
+                inbox
+                    .iter()
+                    .rev()
+                    .filter(|notification| !unread_only || !notification.read)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Marks every notification of a user as read, returning how many changed
+    pub fn mark_all_read(&self, user_id: Uuid) -> usize {
+        let mut inboxes = self.inboxes.lock().unwrap();
+        let Some(inbox) = inboxes.get_mut(&user_id) else {
+            return 0;
+        };
+
+        let mut count = 0;
+        for notification in inbox.iter_mut().filter(|n| !n.read) {
+            notification.read = true;
+            count += 1;
+        }
+        count
+    }
+}
+
+impl Default for NotificationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
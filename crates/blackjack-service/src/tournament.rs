@@ -0,0 +1,746 @@
+//! Elimination tournaments played across several game tables
+//!
+//! A tournament is created with a number of rounds, a starting chip stack,
+//! a table size and how many players advance from each table. Players
+//! register until the organiser starts it; every round then seats the
+//! remaining players across private games of at most `table_size` seats,
+//! balancing chip stacks over the tables. Each hand is played for the
+//! tournament's bet (capped at the player's stack) and pays like the net
+//! chips leaderboard. Once every table of a round has finished, the top
+//! stacks at each table advance to the next round and the others are
+//! eliminated. The player with the most chips after the last round, or the
+//! last one standing, wins.
+//!
+//! Tables are settled by [`TournamentService::advance`], which the API runs
+//! in the background and before serving tournament state.
+
+use crate::leaderboard::net_chips;
+use crate::notifications::{NotificationKind, NotificationService};
+use crate::{GameError, GameService, UserService};
+use blackjack_core::{MAX_TABLE_SIZE, PlayerOutcome, TableRules};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Most rounds a tournament can be created with
+pub const MAX_TOURNAMENT_ROUNDS: u8 = 10;
+
+/// Longest tournament name, in characters
+pub const MAX_TOURNAMENT_NAME_LENGTH: usize = 64;
+
+/// Players needed to start a tournament
+pub const MIN_TOURNAMENT_PLAYERS: usize = 2;
+
+/// Format of a tournament, fixed when it is created
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TournamentSettings {
+    /// Rounds to play (1 to `MAX_TOURNAMENT_ROUNDS`)
+    pub rounds: u8,
+    /// Chips every player starts with
+    pub starting_chips: u32,
+    /// Seats per table (2 to `MAX_TABLE_SIZE`)
+    pub table_size: u8,
+    /// Players advancing from each table, fewer than `table_size`
+    pub advancing_per_table: u8,
+    /// Chips bet on every hand
+    pub bet: u32,
+}
+
+impl Default for TournamentSettings {
+    fn default() -> Self {
+        Self {
+            rounds: 3,
+            starting_chips: 1000,
+            table_size: 4,
+            advancing_per_table: 2,
+            bet: 100,
+        }
+    }
+}
+
+impl TournamentSettings {
+    /// Validates the format
+    pub fn validate(&self) -> Result<(), String> {
+        if self.rounds == 0 || self.rounds > MAX_TOURNAMENT_ROUNDS {
+            return Err(format!(
+                "Rounds must be between 1 and {}",
+                MAX_TOURNAMENT_ROUNDS
+            ));
+        }
+        if self.table_size < 2 || self.table_size > MAX_TABLE_SIZE {
+            return Err(format!(
+                "Table size must be between 2 and {}",
+                MAX_TABLE_SIZE
+            ));
+        }
+        if self.advancing_per_table == 0 || self.advancing_per_table >= self.table_size {
+            return Err(
+                "Players advancing per table must be at least 1 and below the table size"
+                    .to_string(),
+            );
+        }
+        if self.bet == 0 {
+            return Err("Bet must be at least 1".to_string());
+        }
+        if self.starting_chips < self.bet {
+            return Err("Starting chips cannot be lower than the bet".to_string());
+        }
+        Ok(())
+    }
+
+    /// Rules of the games the tournament's tables play
+    fn table_rules(&self) -> TableRules {
+        TableRules {
+            max_players: self.table_size,
+            min_bet: self.bet,
+            max_bet: self.bet,
+            ..Default::default()
+        }
+    }
+}
+
+/// Lifecycle of a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TournamentStatus {
+    /// Accepting registrations
+    Registering,
+    /// Rounds are being played
+    Running,
+    /// A champion has been decided
+    Finished,
+}
+
+/// A registered player and their chip stack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentPlayer {
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub chips: u32,
+    /// Round the player went out in, `None` while still playing and for the champion
+    pub eliminated_in_round: Option<u8>,
+}
+
+/// A player's seat at a tournament table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableSeat {
+    pub user_id: Uuid,
+    pub display_name: String,
+    /// Stack when the round started
+    pub starting_chips: u32,
+    /// Stack after the table settled
+    pub chips: Option<u32>,
+    /// Outcome of the hand, `None` until settled or if the game never finished
+    pub outcome: Option<PlayerOutcome>,
+    pub advanced: bool,
+}
+
+/// One table of a round
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentTable {
+    /// Game played at the table, `None` if it could not be created
+    pub game_id: Option<Uuid>,
+    /// Seats in turn order
+    pub seats: Vec<TableSeat>,
+    pub settled: bool,
+}
+
+/// One round of the bracket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentRound {
+    pub number: u8,
+    pub tables: Vec<TournamentTable>,
+}
+
+/// A tournament with its players and bracket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tournament {
+    pub id: Uuid,
+    pub name: String,
+    /// Organiser, who starts the tournament and need not play in it
+    pub creator_id: Uuid,
+    pub settings: TournamentSettings,
+    pub status: TournamentStatus,
+    /// Players in registration order
+    pub players: Vec<TournamentPlayer>,
+    /// Rounds played so far, the last one possibly in progress
+    pub rounds: Vec<TournamentRound>,
+    pub champion_id: Option<Uuid>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+/// Where a player stands in a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StandingStatus {
+    Playing,
+    Eliminated,
+    Champion,
+}
+
+/// A player's place in the standings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentStanding {
+    pub rank: usize,
+    pub user_id: Uuid,
+    pub display_name: String,
+    pub chips: u32,
+    pub status: StandingStatus,
+    pub eliminated_in_round: Option<u8>,
+}
+
+/// Summary of a tournament for listings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentInfo {
+    pub id: Uuid,
+    pub name: String,
+    pub creator_id: Uuid,
+    pub settings: TournamentSettings,
+    pub status: TournamentStatus,
+    pub registered_players: usize,
+    /// Round being played, 0 before the tournament starts
+    pub current_round: u8,
+    pub champion_id: Option<Uuid>,
+    pub created_at: String,
+}
+
+impl From<&Tournament> for TournamentInfo {
+    fn from(tournament: &Tournament) -> Self {
+        Self {
+            id: tournament.id,
+            name: tournament.name.clone(),
+            creator_id: tournament.creator_id,
+            settings: tournament.settings.clone(),
+            status: tournament.status,
+            registered_players: tournament.players.len(),
+            current_round: tournament.current_round(),
+            champion_id: tournament.champion_id,
+            created_at: tournament.created_at.clone(),
+        }
+    }
+}
+
+impl Tournament {
+    /// Round being played (or last played), 0 before the tournament starts
+    pub fn current_round(&self) -> u8 {
+        self.rounds.last().map_or(0, |round| round.number)
+    }
+
+    /// Players ranked: the champion, players still in by chips, then
+    /// eliminated players, latest rounds first
+    ///
+    /// Ties keep registration order.
+    pub fn standings(&self) -> Vec<TournamentStanding> {
+        let mut players: Vec<&TournamentPlayer> = self.players.iter().collect();
+        players.sort_by_key(|player| {
+            std::cmp::Reverse((
+                self.champion_id == Some(player.user_id),
+                player.eliminated_in_round.unwrap_or(u8::MAX),
+                player.chips,
+            ))
+        });
+
+        players
+            .into_iter()
+            .enumerate()
+            .map(|(index, player)| TournamentStanding {
+                rank: index + 1,
+                user_id: player.user_id,
+                display_name: player.display_name.clone(),
+                chips: player.chips,
+                status: if self.champion_id == Some(player.user_id) {
+                    StandingStatus::Champion
+                } else if player.eliminated_in_round.is_some() {
+                    StandingStatus::Eliminated
+                } else {
+                    StandingStatus::Playing
+                },
+                eliminated_in_round: player.eliminated_in_round,
+            })
+            .collect()
+    }
+
+    /// Moves a deleted account's registration and seats over to its tombstone
+    ///
+    /// Returns whether the account was part of the tournament.
+    pub fn anonymize_player(&mut self, user_id: Uuid, tombstone_id: Uuid, name: &str) -> bool {
+        let mut found = false;
+
+        for player in self
+            .players
+            .iter_mut()
+            .filter(|player| player.user_id == user_id)
+        {
+            player.user_id = tombstone_id;
+            player.display_name = name.to_string();
+            found = true;
+        }
+        for seat in self
+            .rounds
+            .iter_mut()
+            .flat_map(|round| round.tables.iter_mut())
+            .flat_map(|table| table.seats.iter_mut())
+            .filter(|seat| seat.user_id == user_id)
+        {
+            seat.user_id = tombstone_id;
+            seat.display_name = name.to_string();
+        }
+        if self.creator_id == user_id {
+            self.creator_id = tombstone_id;
+            found = true;
+        }
+        if self.champion_id == Some(user_id) {
+            self.champion_id = Some(tombstone_id);
+        }
+
+        found
+    }
+
+    fn player_mut(&mut self, user_id: Uuid) -> &mut TournamentPlayer {
+        self.players
+            .iter_mut()
+            .find(|player| player.user_id == user_id)
+            .expect("seated players are registered")
+    }
+
+    /// Seats `players` across the tables of a new round and starts their games
+    ///
+    /// Players are dealt to tables in order of chips so every table gets a
+    /// similar mix of stacks. A table whose game can't be created settles
+    /// without a hand being played.
+    fn seat_round(
+        &mut self,
+        mut players: Vec<Uuid>,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) {
+        let number = self.current_round() + 1;
+        let chips: HashMap<Uuid, u32> = self
+            .players
+            .iter()
+            .map(|player| (player.user_id, player.chips))
+            .collect();
+        players.sort_by_key(|user_id| std::cmp::Reverse(chips[user_id]));
+
+        let table_count = players.len().div_ceil(self.settings.table_size as usize);
+        let mut seating = vec![Vec::new(); table_count];
+        for (index, user_id) in players.into_iter().enumerate() {
+            seating[index % table_count].push(user_id);
+        }
+
+        let rules = self.settings.table_rules();
+        let mut tables = Vec::with_capacity(table_count);
+        for seated in seating {
+            let game_id = match game_service.create_private_table(&seated, &rules) {
+                Ok(game_id) => Some(game_id),
+                Err(e) => {
+                    tracing::warn!(tournament_id = %self.id, round = number, error = %e, "Failed to create tournament table");
+                    None
+                }
+            };
+            let seats = seated
+                .iter()
+                .map(|user_id| {
+                    let player = self.player_mut(*user_id);
+                    TableSeat {
+                        user_id: *user_id,
+                        display_name: player.display_name.clone(),
+                        starting_chips: player.chips,
+                        chips: None,
+                        outcome: None,
+                        advanced: false,
+                    }
+                })
+                .collect();
+
+            if let Some(game_id) = game_id {
+                for user_id in &seated {
+                    notifications.notify(
+                        *user_id,
+                        NotificationKind::TournamentTableReady {
+                            tournament_id: self.id,
+                            round: number,
+                            game_id,
+                        },
+                        format!("Round {} of {} is ready at your table", number, self.name),
+                    );
+                }
+            }
+            tables.push(TournamentTable {
+                game_id,
+                seats,
+                settled: false,
+            });
+        }
+
+        tracing::info!(tournament_id = %self.id, round = number, tables = tables.len(), "Tournament round seated");
+        self.rounds.push(TournamentRound { number, tables });
+    }
+
+    /// Settles the current round's finished tables and moves the tournament on
+    ///
+    /// Returns how many tables were settled.
+    fn advance(
+        &mut self,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> usize {
+        if self.status != TournamentStatus::Running {
+            return 0;
+        }
+
+        let bet = self.settings.bet;
+        let advancing = self.settings.advancing_per_table as usize;
+        let Some(mut round) = self.rounds.pop() else {
+            return 0;
+        };
+
+        let mut settled = 0;
+        for table in round.tables.iter_mut().filter(|table| !table.settled) {
+            let results = match table.game_id {
+                Some(game_id) => match game_service.inspect_game(game_id) {
+                    Ok(game) if !game.finished => continue,
+                    // Aborted games settle without the hand counting
+                    Ok(game) if game.active => Some(game.calculate_results()),
+                    _ => None,
+                },
+                None => None,
+            };
+
+            for seat in table.seats.iter_mut() {
+                let result = results.as_ref().and_then(|results| {
+                    results
+                        .player_results
+                        .values()
+                        .find(|result| result.player_id == seat.user_id)
+                });
+                let stake = bet.min(seat.starting_chips);
+                let change = result.map_or(0, |result| net_chips(result, stake));
+                let chips = (i64::from(seat.starting_chips) + change).max(0) as u32;
+                seat.chips = Some(chips);
+                seat.outcome = result.map(|result| result.outcome.clone());
+                self.player_mut(seat.user_id).chips = chips;
+            }
+
+            // Top stacks advance; ties go to the earlier seat, empty stacks never advance
+            let mut ranked: Vec<&mut TableSeat> = table.seats.iter_mut().collect();
+            ranked.sort_by_key(|seat| std::cmp::Reverse(seat.chips));
+            for seat in ranked.into_iter().take(advancing) {
+                seat.advanced = seat.chips.is_some_and(|chips| chips > 0);
+            }
+            table.settled = true;
+            settled += 1;
+        }
+
+        let round_over = round.tables.iter().all(|table| table.settled);
+        let number = round.number;
+        self.rounds.push(round);
+        if !round_over {
+            return settled;
+        }
+
+        let round = self.rounds.last().expect("round was just pushed");
+        let advancing: Vec<Uuid> = round
+            .tables
+            .iter()
+            .flat_map(|table| table.seats.iter())
+            .filter(|seat| seat.advanced)
+            .map(|seat| seat.user_id)
+            .collect();
+        let played: Vec<Uuid> = round
+            .tables
+            .iter()
+            .flat_map(|table| table.seats.iter())
+            .map(|seat| seat.user_id)
+            .collect();
+
+        if number >= self.settings.rounds || advancing.len() <= 1 {
+            self.finish(&played, notifications);
+        } else {
+            for user_id in played.iter().filter(|user_id| !advancing.contains(user_id)) {
+                self.player_mut(*user_id).eliminated_in_round = Some(number);
+                notifications.notify(
+                    *user_id,
+                    NotificationKind::TournamentEliminated {
+                        tournament_id: self.id,
+                        round: number,
+                    },
+                    format!("You were eliminated from {} in round {}", self.name, number),
+                );
+            }
+            self.seat_round(advancing, game_service, notifications);
+        }
+
+        settled
+    }
+
+    /// Crowns the biggest stack among the last round's players
+    fn finish(&mut self, played: &[Uuid], notifications: &NotificationService) {
+        let number = self.current_round();
+        let chips: HashMap<Uuid, u32> = self
+            .players
+            .iter()
+            .map(|player| (player.user_id, player.chips))
+            .collect();
+        // Ties go to the earlier seat
+        let champion_id = played.iter().copied().reduce(|best, user_id| {
+            if chips[&user_id] > chips[&best] {
+                user_id
+            } else {
+                best
+            }
+        });
+
+        for user_id in played {
+            if Some(*user_id) != champion_id {
+                self.player_mut(*user_id).eliminated_in_round = Some(number);
+            }
+        }
+        self.champion_id = champion_id;
+        self.status = TournamentStatus::Finished;
+        self.finished_at = Some(chrono::Utc::now().to_rfc3339());
+
+        for user_id in played {
+            let (kind, message) = if Some(*user_id) == champion_id {
+                (
+                    NotificationKind::TournamentWon {
+                        tournament_id: self.id,
+                    },
+                    format!("You won {}!", self.name),
+                )
+            } else {
+                (
+                    NotificationKind::TournamentEliminated {
+                        tournament_id: self.id,
+                        round: number,
+                    },
+                    format!("{} is over, you reached the final round", self.name),
+                )
+            };
+            notifications.notify(*user_id, kind, message);
+        }
+
+        tracing::info!(tournament_id = %self.id, champion_id = ?champion_id, rounds = number, "Tournament finished");
+    }
+}
+
+/// Tournament service keeping every tournament in memory
+pub struct TournamentService {
+    tournaments: Arc<Mutex<HashMap<Uuid, Tournament>>>,
+}
+
+impl TournamentService {
+    /// Creates an empty tournament service
+    pub fn new() -> Self {
+        Self {
+            tournaments: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Creates a tournament open for registration
+    ///
+    /// # Errors
+    ///
+    /// - `ValidationError` if the name or settings are invalid
+    #[tracing::instrument(skip(self))]
+    pub fn create(
+        &self,
+        creator_id: Uuid,
+        name: &str,
+        settings: TournamentSettings,
+    ) -> Result<Tournament, GameError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_TOURNAMENT_NAME_LENGTH {
+            return Err(GameError::ValidationError(format!(
+                "Tournament name must be between 1 and {} characters",
+                MAX_TOURNAMENT_NAME_LENGTH
+            )));
+        }
+        settings.validate().map_err(GameError::ValidationError)?;
+
+        let tournament = Tournament {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            creator_id,
+            settings,
+            status: TournamentStatus::Registering,
+            players: Vec::new(),
+            rounds: Vec::new(),
+            champion_id: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            started_at: None,
+            finished_at: None,
+        };
+
+        tracing::info!(tournament_id = %tournament.id, creator_id = %creator_id, "Tournament created");
+
+        self.tournaments
+            .lock()
+            .unwrap()
+            .insert(tournament.id, tournament.clone());
+        Ok(tournament)
+    }
+
+    /// Registers a player with the starting chip stack
+    ///
+    /// # Errors
+    ///
+    /// - `TournamentNotFound` if the tournament doesn't exist
+    /// - `TournamentAlreadyStarted` if registration has closed
+    /// - `AlreadyRegistered` if the user is registered already
+    /// - `UserNotFound` if the user doesn't exist
+    #[tracing::instrument(skip(self, user_service))]
+    pub fn register(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+        user_service: &UserService,
+    ) -> Result<Tournament, GameError> {
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let tournament = tournaments
+            .get_mut(&tournament_id)
+            .ok_or(GameError::TournamentNotFound)?;
+
+        if tournament.status != TournamentStatus::Registering {
+            return Err(GameError::TournamentAlreadyStarted);
+        }
+        if tournament
+            .players
+            .iter()
+            .any(|player| player.user_id == user_id)
+        {
+            return Err(GameError::AlreadyRegistered);
+        }
+
+        let user = user_service.get_user(user_id)?;
+        tournament.players.push(TournamentPlayer {
+            user_id,
+            display_name: user.display_name,
+            chips: tournament.settings.starting_chips,
+            eliminated_in_round: None,
+        });
+
+        tracing::info!(tournament_id = %tournament_id, user_id = %user_id, players = tournament.players.len(), "Player registered for tournament");
+
+        Ok(tournament.clone())
+    }
+
+    /// Closes registration and seats the first round (organiser only)
+    ///
+    /// # Errors
+    ///
+    /// - `TournamentNotFound` if the tournament doesn't exist
+    /// - `InsufficientPermissions` if the user is not the organiser
+    /// - `TournamentAlreadyStarted` if it has started already
+    /// - `ValidationError` if fewer than `MIN_TOURNAMENT_PLAYERS` registered
+    #[tracing::instrument(skip(self, game_service, notifications))]
+    pub fn start(
+        &self,
+        tournament_id: Uuid,
+        user_id: Uuid,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> Result<Tournament, GameError> {
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let tournament = tournaments
+            .get_mut(&tournament_id)
+            .ok_or(GameError::TournamentNotFound)?;
+
+        if tournament.creator_id != user_id {
+            return Err(GameError::InsufficientPermissions);
+        }
+        if tournament.status != TournamentStatus::Registering {
+            return Err(GameError::TournamentAlreadyStarted);
+        }
+        if tournament.players.len() < MIN_TOURNAMENT_PLAYERS {
+            return Err(GameError::ValidationError(format!(
+                "A tournament needs at least {} players",
+                MIN_TOURNAMENT_PLAYERS
+            )));
+        }
+
+        tournament.status = TournamentStatus::Running;
+        tournament.started_at = Some(chrono::Utc::now().to_rfc3339());
+        let players = tournament
+            .players
+            .iter()
+            .map(|player| player.user_id)
+            .collect();
+        tournament.seat_round(players, game_service, notifications);
+
+        tracing::info!(tournament_id = %tournament_id, players = tournament.players.len(), "Tournament started");
+
+        Ok(tournament.clone())
+    }
+
+    /// Gets a tournament with its players and bracket
+    ///
+    /// # Errors
+    ///
+    /// - `TournamentNotFound` if the tournament doesn't exist
+    pub fn get(&self, tournament_id: Uuid) -> Result<Tournament, GameError> {
+        self.tournaments
+            .lock()
+            .unwrap()
+            .get(&tournament_id)
+            .cloned()
+            .ok_or(GameError::TournamentNotFound)
+    }
+
+    /// Lists tournaments, newest first, optionally only those with `status`
+    pub fn list(&self, status: Option<TournamentStatus>) -> Vec<TournamentInfo> {
+        let tournaments = self.tournaments.lock().unwrap();
+        let mut list: Vec<TournamentInfo> = tournaments
+            .values()
+            .filter(|tournament| status.is_none_or(|status| tournament.status == status))
+            .map(TournamentInfo::from)
+            .collect();
+        list.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        list
+    }
+
+    /// Moves a deleted account over to its tombstone in every tournament
+    ///
+    /// Returns how many tournaments the account was part of.
+    pub fn anonymize_player(&self, user_id: Uuid, tombstone_id: Uuid, name: &str) -> usize {
+        self.tournaments
+            .lock()
+            .unwrap()
+            .values_mut()
+            .map(|tournament| tournament.anonymize_player(user_id, tombstone_id, name))
+            .filter(|found| *found)
+            .count()
+    }
+
+    /// Settles finished tables of running tournaments, seating the next round
+    /// or crowning a champion once a round is complete
+    ///
+    /// Returns how many tables were settled.
+    pub fn advance(
+        &self,
+        game_service: &GameService,
+        notifications: &NotificationService,
+    ) -> usize {
+        let mut tournaments = self.tournaments.lock().unwrap();
+        let settled: usize = tournaments
+            .values_mut()
+            .map(|tournament| tournament.advance(game_service, notifications))
+            .sum();
+
+        if settled > 0 {
+            tracing::debug!(tables = settled, "Tournament tables settled");
+        }
+
+        settled
+    }
+}
+
+impl Default for TournamentService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
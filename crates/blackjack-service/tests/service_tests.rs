@@ -1,4 +1,17 @@
-use blackjack_service::{GameError, GameService, ServiceConfig, UserService};
+use blackjack_core::rating::RatingBand;
+use blackjack_core::strategy::BotStrategy;
+use blackjack_core::{
+    GameVisibility, InvitationStatus, PlatformRole, PlayerOutcome, PlayerResult, TableRules,
+};
+use blackjack_service::{
+    ArchivedGame, ArchivedHand, ArchivedSeat, AuditActor, AuditEvent, AuditFilter, AuditLog,
+    AuditOutcome, Board, CreateGameOptions, EmailMessage, GameArchive, GameError, GameService,
+    InvitationConfig, InvitationService, LeaderboardQuery, LeaderboardWindow, Leaderboards,
+    MAX_AUDIT_RECORDS, MAX_FAILED_LOGIN_ATTEMPTS, Mailer, MatchmakingConfig, MatchmakingService,
+    NotificationKind, NotificationService, OpenGamesQuery, OpenGamesSort, OutboxMailer,
+    PlayerGamesQuery, PlayerProfile, ServiceConfig, SortOrder, StandingStatus, TicketStatus,
+    TournamentService, TournamentSettings, TournamentStatus, UserService, audit,
+};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -132,7 +145,8 @@ fn test_draw_card() {
 
     assert!(result.is_ok());
     let response = result.unwrap();
-    assert_eq!(response.cards_remaining, 51);
+    // 52 minus the dealer's up-card and the drawn card
+    assert_eq!(response.cards_remaining, 50);
     assert_eq!(response.cards_history.len(), 1);
 }
 
@@ -414,6 +428,50 @@ fn test_invalid_email_rejected() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_display_names_are_unique_ignoring_case() {
+    let user_service = UserService::new();
+    let register = |email: &str, name: &str| {
+        user_service.register_with_display_name(
+            email.to_string(),
+            "TestP@ssw0rd".to_string(),
+            Some(name.to_string()),
+        )
+    };
+
+    let ace_id = register("ace@test.com", "Ace").unwrap();
+    assert!(matches!(
+        register("other@test.com", "aCE"),
+        Err(GameError::DisplayNameTaken)
+    ));
+    assert!(matches!(
+        register("dealer@test.com", "Dealer"),
+        Err(GameError::ValidationError(_))
+    ));
+
+    // Generated names are unique too and can be changed later
+    let generated_id = user_service
+        .register("anon@test.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let generated = user_service.get_user(generated_id).unwrap();
+    assert!(generated.display_name.starts_with("player-"));
+    assert!(!generated.avatar_seed.is_empty());
+    assert!(matches!(
+        user_service.set_display_name(generated_id, "ACE"),
+        Err(GameError::DisplayNameTaken)
+    ));
+
+    // Renaming frees the old name
+    user_service
+        .set_display_name(ace_id, "high_roller")
+        .unwrap();
+    user_service.set_display_name(generated_id, "ace").unwrap();
+    assert_eq!(
+        user_service.get_user_by_display_name("ACE").unwrap().id,
+        generated_id
+    );
+}
+
 #[test]
 fn test_failed_login_with_wrong_password() {
     let user_service = UserService::new();
@@ -547,7 +605,10 @@ fn test_rbac_only_creator_can_kick_players() {
     // Creator should be able to kick player1
     let result = service.kick_player(game_id, creator_id, player1_id);
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), "player1@test.com");
+    assert_eq!(
+        result.unwrap(),
+        user_service.get_user(player1_id).unwrap().display_name
+    );
 }
 
 #[test]
@@ -587,6 +648,21 @@ fn test_account_status_inactive_cannot_login() {
     assert!(matches!(result, Err(GameError::AccountInactive)));
 }
 
+#[test]
+fn test_deactivation_revokes_sessions() {
+    let user_service = UserService::new();
+    let user_id = user_service
+        .register("user@test.com".to_string(), "TestP@ssw0rd".to_string())
+        .unwrap();
+    let session_version = user_service.get_user(user_id).unwrap().session_version;
+
+    user_service.deactivate_account(user_id).unwrap();
+    assert_ne!(
+        user_service.get_user(user_id).unwrap().session_version,
+        session_version
+    );
+}
+
 #[test]
 fn test_last_login_updated_on_successful_login() {
     let user_service = UserService::new();
@@ -602,3 +678,2335 @@ fn test_last_login_updated_on_successful_login() {
     // last_login should be set
     assert!(user.last_login.is_some());
 }
+
+// Helper to create an invitation to the given game using its enrollment window
+fn invite(
+    invitations: &InvitationService,
+    service: &GameService,
+    game_id: Uuid,
+    inviter_id: Uuid,
+    invitee_email: &str,
+) -> Result<Uuid, GameError> {
+    let expires_at = service.get_game_state(game_id)?.enrollment_closes_at;
+    invitations.create(
+        game_id,
+        inviter_id,
+        invitee_email.to_string(),
+        expires_at,
+        &service.games,
+    )
+}
+
+#[test]
+fn test_pending_invitations_resolve_inviter_name() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap();
+    let creator_id = creator.id;
+    let game_id = service.create_game(creator_id, Some(120)).unwrap();
+
+    invite(
+        &invitations,
+        &service,
+        game_id,
+        creator_id,
+        "guest@test.com",
+    )
+    .unwrap();
+
+    let pending = invitations.get_pending_for_user("guest@test.com", &user_service);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].inviter_id, creator_id);
+    assert_eq!(pending[0].inviter_name, creator.display_name);
+    assert!(pending[0].timeout_seconds > 0 && pending[0].timeout_seconds <= 120);
+    assert!(pending[0].expires_in_seconds > 0);
+}
+
+#[test]
+fn test_duplicate_pending_invitation_rejected() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+
+    let first = invite(
+        &invitations,
+        &service,
+        game_id,
+        creator_id,
+        "guest@test.com",
+    )
+    .unwrap();
+    let second = invite(
+        &invitations,
+        &service,
+        game_id,
+        creator_id,
+        "guest@test.com",
+    );
+    assert!(matches!(second, Err(GameError::InvitationAlreadyExists)));
+
+    // Once the first invitation is answered, a new one can be sent
+    invitations.decline(first).unwrap();
+    assert!(
+        invite(
+            &invitations,
+            &service,
+            game_id,
+            creator_id,
+            "guest@test.com"
+        )
+        .is_ok()
+    );
+}
+
+#[test]
+fn test_only_inviter_can_revoke_invitation() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    let invitation_id = invite(
+        &invitations,
+        &service,
+        game_id,
+        creator_id,
+        "guest@test.com",
+    )
+    .unwrap();
+
+    let result = invitations.revoke(invitation_id, Uuid::new_v4());
+    assert!(matches!(result, Err(GameError::InsufficientPermissions)));
+
+    invitations.revoke(invitation_id, creator_id).unwrap();
+    assert!(
+        invitations
+            .get_pending_for_user("guest@test.com", &user_service)
+            .is_empty()
+    );
+    assert!(matches!(
+        invitations.accept(invitation_id),
+        Err(GameError::InvitationNotPending)
+    ));
+
+    let sent = invitations.get_sent_by_user(creator_id, &user_service);
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].status, "revoked");
+}
+
+#[test]
+fn test_reopened_invitation_can_be_accepted_again() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    let invitation_id = invite(
+        &invitations,
+        &service,
+        game_id,
+        creator_id,
+        "guest@test.com",
+    )
+    .unwrap();
+
+    // Only accepted invitations can be reopened
+    assert!(matches!(
+        invitations.reopen(invitation_id),
+        Err(GameError::InvitationNotPending)
+    ));
+
+    invitations.accept(invitation_id).unwrap();
+    assert!(matches!(
+        invitations.revoke(invitation_id, creator_id),
+        Err(GameError::InvitationNotPending)
+    ));
+
+    invitations.reopen(invitation_id).unwrap();
+    assert_eq!(
+        invitations
+            .get_pending_for_user("guest@test.com", &user_service)
+            .len(),
+        1
+    );
+    invitations.accept(invitation_id).unwrap();
+}
+
+#[test]
+fn test_add_player_to_game_rejects_closed_enrollment() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+
+    service.close_enrollment(game_id, creator_id).unwrap();
+
+    let result = service.add_player_to_game(game_id, guest_id);
+    assert!(matches!(result, Err(GameError::EnrollmentClosed)));
+}
+
+#[test]
+fn test_revoked_invite_link_cannot_be_redeemed() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+
+    // Only the creator can create links
+    let denied = invitations.create_link(game_id, guest_id, None, &service.games);
+    assert!(matches!(denied, Err(GameError::InsufficientPermissions)));
+
+    let link = invitations
+        .create_link(game_id, creator_id, None, &service.games)
+        .unwrap();
+    assert_eq!(link.remaining_uses, None);
+
+    // Revoking through another game leaves the link untouched
+    let other_game_id = service.create_game(creator_id, None).unwrap();
+    let result = invitations.revoke_link(link.id, other_game_id, creator_id);
+    assert!(matches!(result, Err(GameError::InviteLinkNotFound)));
+    let links = invitations
+        .get_links_for_game(game_id, creator_id, &service.games)
+        .unwrap();
+    assert!(!links[0].revoked);
+
+    invitations
+        .revoke_link(link.id, game_id, creator_id)
+        .unwrap();
+
+    let result = invitations.redeem_link(link.id, game_id, guest_id, &service);
+    assert!(matches!(result, Err(GameError::InviteLinkRevoked)));
+    assert_eq!(service.get_game_state(game_id).unwrap().players.len(), 1);
+}
+
+#[test]
+fn test_private_game_only_joinable_by_invitation() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service
+        .create_game_with_options(
+            creator_id,
+            CreateGameOptions {
+                visibility: GameVisibility::Private,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+
+    // Not listed and cannot be joined directly
+    assert!(service.get_open_games(Some(guest_id)).unwrap().is_empty());
+    let result = service.enroll_player(game_id, guest_id);
+    assert!(matches!(result, Err(GameError::GameIsPrivate)));
+
+    // An accepted invitation still seats the player
+    let invitation_id = invite(
+        &invitations,
+        &service,
+        game_id,
+        creator_id,
+        "guest@test.com",
+    )
+    .unwrap();
+    service.add_player_to_game(game_id, guest_id).unwrap();
+    invitations.accept(invitation_id).unwrap();
+    assert_eq!(service.get_game_state(game_id).unwrap().players.len(), 2);
+}
+
+#[test]
+fn test_password_protected_game_requires_password() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service
+        .create_game_with_options(
+            creator_id,
+            CreateGameOptions {
+                join_password: Some("table-secret".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+
+    let open_games = service.get_open_games(Some(guest_id)).unwrap();
+    assert_eq!(open_games.len(), 1);
+    assert!(open_games[0].password_protected);
+
+    assert!(matches!(
+        service.enroll_player(game_id, guest_id),
+        Err(GameError::InvalidGamePassword)
+    ));
+    assert!(matches!(
+        service.enroll_player_with_password(game_id, guest_id, Some("wrong")),
+        Err(GameError::InvalidGamePassword)
+    ));
+    service
+        .enroll_player_with_password(game_id, guest_id, Some("table-secret"))
+        .unwrap();
+
+    // Empty passwords are rejected at creation
+    let empty = service.create_game_with_options(
+        creator_id,
+        CreateGameOptions {
+            join_password: Some(String::new()),
+            ..Default::default()
+        },
+    );
+    assert!(matches!(empty, Err(GameError::ValidationError(_))));
+}
+
+#[test]
+fn test_open_games_exclude_unlisted_and_joined_games() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let public_id = service.create_game(creator_id, None).unwrap();
+    let unlisted_id = service
+        .create_game_with_options(
+            creator_id,
+            CreateGameOptions {
+                visibility: GameVisibility::Unlisted,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+
+    let open_games = service.get_open_games(Some(guest_id)).unwrap();
+    assert_eq!(open_games.len(), 1);
+    assert_eq!(open_games[0].game_id, public_id);
+
+    // The creator already sits at the public game
+    assert!(service.get_open_games(Some(creator_id)).unwrap().is_empty());
+
+    // Unlisted games can still be joined by ID
+    service.enroll_player(unlisted_id, guest_id).unwrap();
+}
+
+#[test]
+fn test_open_games_filter_sort_and_paginate() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    for (max_players, min_bet) in [(2, 10), (6, 25), (6, 100)] {
+        service
+            .create_game_with_options(
+                creator_id,
+                CreateGameOptions {
+                    rules: TableRules {
+                        max_players,
+                        min_bet,
+                        max_bet: 500,
+                        ..TableRules::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+    }
+
+    // Real table capacity is reported
+    let six_seat = OpenGamesQuery {
+        table_size: Some(6),
+        sort: OpenGamesSort::MinBet,
+        order: SortOrder::Desc,
+        ..Default::default()
+    };
+    let page = service.list_open_games(None, &six_seat).unwrap();
+    assert_eq!(page.total, 2);
+    assert_eq!(page.games[0].rules.min_bet, 100);
+    assert!(page.games.iter().all(|game| game.max_players == 6));
+    assert!(page.games.iter().all(|game| game.free_seats == 5));
+
+    let stake_filter = OpenGamesQuery {
+        stake: Some(20),
+        ..Default::default()
+    };
+    assert_eq!(
+        service.list_open_games(None, &stake_filter).unwrap().total,
+        1
+    );
+
+    // Walk the lobby two games at a time
+    let mut query = OpenGamesQuery {
+        sort: OpenGamesSort::MinBet,
+        limit: Some(2),
+        ..Default::default()
+    };
+    let first = service.list_open_games(None, &query).unwrap();
+    assert_eq!(first.games.len(), 2);
+    assert_eq!(first.total, 3);
+    query.cursor = first.next_cursor.clone();
+    let second = service.list_open_games(None, &query).unwrap();
+    assert_eq!(second.games.len(), 1);
+    assert_eq!(second.games[0].rules.min_bet, 100);
+    assert!(second.next_cursor.is_none());
+
+    query.cursor = Some("not-a-cursor".to_string());
+    assert!(matches!(
+        service.list_open_games(None, &query),
+        Err(GameError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn test_table_size_limits_enrollment() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service
+        .create_game_with_options(
+            creator_id,
+            CreateGameOptions {
+                rules: TableRules {
+                    max_players: 2,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+    let late_id = user_service
+        .register("late@test.com".to_string(), test_password())
+        .unwrap();
+    service.enroll_player(game_id, guest_id).unwrap();
+    assert!(matches!(
+        service.enroll_player(game_id, late_id),
+        Err(GameError::GameFull)
+    ));
+
+    let oversized = service.create_game_with_options(
+        creator_id,
+        CreateGameOptions {
+            rules: TableRules {
+                max_players: 11,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    assert!(matches!(oversized, Err(GameError::ValidationError(_))));
+}
+
+#[test]
+fn test_matchmaking_forms_table_when_full() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let matchmaking = MatchmakingService::default();
+    let notifications = NotificationService::new();
+    let rules = TableRules {
+        max_players: 2,
+        ..Default::default()
+    };
+    let first_id = user_service
+        .register("first@test.com".to_string(), test_password())
+        .unwrap();
+    let second_id = user_service
+        .register("second@test.com".to_string(), test_password())
+        .unwrap();
+    let other_id = user_service
+        .register("other@test.com".to_string(), test_password())
+        .unwrap();
+
+    let waiting = matchmaking
+        .enqueue(first_id, rules.clone(), None, &service, &notifications)
+        .unwrap();
+    assert_eq!(waiting.ticket.status, TicketStatus::Waiting);
+    assert_eq!(waiting.players_waiting, 1);
+    assert!(matches!(
+        matchmaking.enqueue(first_id, rules.clone(), None, &service, &notifications),
+        Err(GameError::AlreadyQueued)
+    ));
+
+    // Stakes that don't overlap are not compatible
+    let high_stakes = TableRules {
+        min_bet: 1000,
+        max_bet: 5000,
+        ..rules.clone()
+    };
+    matchmaking
+        .enqueue(other_id, high_stakes, None, &service, &notifications)
+        .unwrap();
+
+    let matched = matchmaking
+        .enqueue(second_id, rules, None, &service, &notifications)
+        .unwrap();
+    assert_eq!(matched.ticket.status, TicketStatus::Matched);
+    let game_id = matched.ticket.game_id.unwrap();
+
+    // Both players are seated, enrollment is closed and the game is unlisted
+    let state = service.get_game_state(game_id).unwrap();
+    assert_eq!(state.players.len(), 2);
+    assert!(state.enrollment_closed);
+    assert!(service.is_game_creator(game_id, first_id).unwrap());
+    assert!(service.get_open_games(None).unwrap().is_empty());
+
+    let first_status = matchmaking.status(first_id, &notifications).unwrap();
+    assert_eq!(first_status.ticket.game_id, Some(game_id));
+    let inbox = notifications.get_for_user(first_id, true);
+    assert_eq!(inbox.len(), 1);
+    assert!(matches!(
+        inbox[0].kind,
+        NotificationKind::MatchFound { game_id: id, .. } if id == game_id
+    ));
+    assert_eq!(
+        matchmaking
+            .status(other_id, &notifications)
+            .unwrap()
+            .ticket
+            .status,
+        TicketStatus::Waiting
+    );
+}
+
+#[test]
+fn test_matchmaking_forms_short_table_after_waiting() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let matchmaking = MatchmakingService::new(MatchmakingConfig {
+        fill_after_seconds: 1,
+        ..MatchmakingConfig::default()
+    });
+    let notifications = NotificationService::new();
+    let first_id = user_service
+        .register("first@test.com".to_string(), test_password())
+        .unwrap();
+    let second_id = user_service
+        .register("second@test.com".to_string(), test_password())
+        .unwrap();
+    let other_id = user_service
+        .register("other@test.com".to_string(), test_password())
+        .unwrap();
+    let rules = TableRules {
+        max_players: 4,
+        double_allowed: true,
+        ..Default::default()
+    };
+
+    matchmaking
+        .enqueue(first_id, rules.clone(), None, &service, &notifications)
+        .unwrap();
+    // Overlapping stakes are enough, the rest of the rules may differ
+    let waiting = matchmaking
+        .enqueue(
+            second_id,
+            TableRules {
+                min_bet: 100,
+                max_bet: 1000,
+                double_allowed: false,
+                ..rules.clone()
+            },
+            None,
+            &service,
+            &notifications,
+        )
+        .unwrap();
+    assert_eq!(waiting.ticket.status, TicketStatus::Waiting);
+    assert_eq!(waiting.players_waiting, 2);
+    // A different table size is not compatible
+    matchmaking
+        .enqueue(
+            other_id,
+            TableRules {
+                max_players: 6,
+                ..rules
+            },
+            None,
+            &service,
+            &notifications,
+        )
+        .unwrap();
+    assert_eq!(matchmaking.form_waiting_tables(&service, &notifications), 0);
+
+    // Once the longest-waiting player has waited long enough, two players will do
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    assert_eq!(matchmaking.form_waiting_tables(&service, &notifications), 1);
+    let matched = matchmaking.status(second_id, &notifications).unwrap();
+    assert_eq!(matched.ticket.status, TicketStatus::Matched);
+    let game_id = matched.ticket.game_id.unwrap();
+    assert!(service.is_game_creator(game_id, first_id).unwrap());
+
+    // The table plays at the stakes and options both players accept
+    let game = service.inspect_game(game_id).unwrap();
+    assert_eq!(game.players.len(), 2);
+    assert_eq!(game.rules.max_players, 4);
+    assert_eq!((game.rules.min_bet, game.rules.max_bet), (100, 500));
+    assert!(!game.rules.double_allowed);
+
+    // Nobody is left to play with the third player
+    assert_eq!(
+        matchmaking
+            .status(other_id, &notifications)
+            .unwrap()
+            .ticket
+            .status,
+        TicketStatus::Waiting
+    );
+}
+
+#[test]
+fn test_private_table_is_removed_when_seating_fails() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let first_id = user_service
+        .register("first@test.com".to_string(), test_password())
+        .unwrap();
+    let second_id = user_service
+        .register("second@test.com".to_string(), test_password())
+        .unwrap();
+
+    // The second seat fails after the game was created with the first player
+    assert!(matches!(
+        service.create_private_table(&[first_id, second_id, first_id], &TableRules::default()),
+        Err(GameError::PlayerAlreadyEnrolled)
+    ));
+    assert!(service.games.lock().unwrap().is_empty());
+
+    let game_id = service
+        .create_private_table(&[first_id, second_id], &TableRules::default())
+        .unwrap();
+    let state = service.get_game_state(game_id).unwrap();
+    assert_eq!(state.players.len(), 2);
+    assert!(state.enrollment_closed);
+}
+
+#[test]
+fn test_matchmaking_cancel_and_timeout() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let matchmaking = MatchmakingService::default();
+    let notifications = NotificationService::new();
+    let user_id = user_service
+        .register("queued@test.com".to_string(), test_password())
+        .unwrap();
+
+    assert!(matches!(
+        matchmaking.cancel(user_id, &notifications),
+        Err(GameError::NotQueued)
+    ));
+    assert!(matches!(
+        matchmaking.enqueue(
+            user_id,
+            TableRules::default(),
+            Some(0),
+            &service,
+            &notifications
+        ),
+        Err(GameError::InvalidTimeout { .. })
+    ));
+
+    matchmaking
+        .enqueue(
+            user_id,
+            TableRules::default(),
+            None,
+            &service,
+            &notifications,
+        )
+        .unwrap();
+    let cancelled = matchmaking.cancel(user_id, &notifications).unwrap();
+    assert_eq!(cancelled.status, TicketStatus::Cancelled);
+
+    // A fresh ticket times out and the player is told about it
+    matchmaking
+        .enqueue(
+            user_id,
+            TableRules::default(),
+            Some(1),
+            &service,
+            &notifications,
+        )
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    assert_eq!(matchmaking.expire_stale(&notifications), 1);
+    assert_eq!(
+        matchmaking
+            .status(user_id, &notifications)
+            .unwrap()
+            .ticket
+            .status,
+        TicketStatus::TimedOut
+    );
+    assert!(matches!(
+        notifications.get_for_user(user_id, false)[0].kind,
+        NotificationKind::QueueTimedOut { .. }
+    ));
+}
+
+#[test]
+fn test_bots_play_automatically_and_skip_stats() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+
+    // Only the creator can seat bots
+    assert!(matches!(
+        service.add_bot(game_id, guest_id, BotStrategy::Random),
+        Err(GameError::InsufficientPermissions)
+    ));
+    let bot_name = service
+        .add_bot(game_id, creator_id, BotStrategy::MimicDealer)
+        .unwrap();
+
+    service.close_enrollment(game_id, creator_id).unwrap();
+    assert!(matches!(
+        service.add_bot(game_id, creator_id, BotStrategy::Random),
+        Err(GameError::EnrollmentClosed)
+    ));
+    let state = service.get_game_state(game_id).unwrap();
+    assert_eq!(state.bots, vec![bot_name.clone()]);
+
+    // Once the creator stands, the bot plays out its hand and the game ends
+    let state = service.stand(game_id, creator_id).unwrap();
+    assert!(state.finished);
+    let bot_points = state.players[&bot_name].points;
+    assert!(
+        bot_points >= 17,
+        "mimic dealer bot stopped at {}",
+        bot_points
+    );
+
+    let results = service.get_game_results(game_id).unwrap();
+    assert!(results.player_results[&bot_name].is_bot);
+
+    // The human's game is recorded exactly once, the bot has no account
+    service.finish_game(game_id, creator_id).unwrap();
+    let stats = user_service.get_user(creator_id).unwrap().stats.unwrap();
+    assert_eq!(stats.games_played, 1);
+    assert!(user_service.get_user_by_email(&bot_name).is_err());
+}
+
+#[test]
+fn test_hint_for_current_player_is_recorded() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    let guest_id = user_service
+        .register("guest@test.com".to_string(), test_password())
+        .unwrap();
+    service.enroll_player(game_id, guest_id).unwrap();
+
+    assert!(matches!(
+        service.get_hint(game_id, creator_id),
+        Err(GameError::EnrollmentNotClosed)
+    ));
+    service.close_enrollment(game_id, creator_id).unwrap();
+    assert!(matches!(
+        service.get_hint(game_id, guest_id),
+        Err(GameError::NotPlayerTurn)
+    ));
+
+    let hint = service.get_hint(game_id, creator_id).unwrap();
+    assert!(hint.dealer_up_card.is_some());
+    assert_eq!(hint.advice.hand_total, 0);
+    assert_eq!(hint.hints_used, 1);
+
+    service.stand(game_id, creator_id).unwrap();
+    service.stand(game_id, guest_id).unwrap();
+    let results = service.get_game_results(game_id).unwrap();
+    let name = |id| user_service.get_user(id).unwrap().display_name;
+    assert_eq!(results.player_results[&name(creator_id)].hints_used, 1);
+    assert_eq!(results.player_results[&name(guest_id)].hints_used, 0);
+}
+
+#[test]
+fn test_create_game_rejects_unsupported_plays() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+
+    let result = service.create_game_with_options(
+        creator_id,
+        CreateGameOptions {
+            rules: TableRules {
+                double_allowed: true,
+                ..TableRules::default()
+            },
+            ..Default::default()
+        },
+    );
+    assert!(matches!(result, Err(GameError::ValidationError(_))));
+}
+
+#[test]
+fn test_account_locks_after_failed_logins_until_unlocked() {
+    let user_service = UserService::new();
+    let user_id = user_service
+        .register("user@test.com".to_string(), test_password())
+        .unwrap();
+
+    for _ in 0..MAX_FAILED_LOGIN_ATTEMPTS {
+        assert!(matches!(
+            user_service.login("user@test.com", "WrongP@ssw0rd"),
+            Err(GameError::InvalidCredentials)
+        ));
+    }
+    assert!(matches!(
+        user_service.login("user@test.com", &test_password()),
+        Err(GameError::AccountLocked)
+    ));
+
+    user_service.unlock_account(user_id).unwrap();
+    assert!(
+        user_service
+            .login("user@test.com", &test_password())
+            .is_ok()
+    );
+    assert_eq!(
+        user_service
+            .get_user(user_id)
+            .unwrap()
+            .failed_login_attempts,
+        0
+    );
+}
+
+#[test]
+fn test_successful_login_resets_failed_attempts() {
+    let user_service = UserService::new();
+    let user_id = user_service
+        .register("user@test.com".to_string(), test_password())
+        .unwrap();
+
+    for _ in 1..MAX_FAILED_LOGIN_ATTEMPTS {
+        let _ = user_service.login("user@test.com", "WrongP@ssw0rd");
+    }
+    user_service
+        .login("user@test.com", &test_password())
+        .unwrap();
+    let _ = user_service.login("user@test.com", "WrongP@ssw0rd");
+
+    let user = user_service.get_user(user_id).unwrap();
+    assert_eq!(user.failed_login_attempts, 1);
+    assert!(!user.is_locked);
+}
+
+#[test]
+fn test_admin_reset_password_unlocks_account() {
+    let user_service = UserService::new();
+    let user_id = user_service
+        .register("user@test.com".to_string(), test_password())
+        .unwrap();
+    for _ in 0..MAX_FAILED_LOGIN_ATTEMPTS {
+        let _ = user_service.login("user@test.com", "WrongP@ssw0rd");
+    }
+
+    assert!(matches!(
+        user_service.reset_password(user_id, "weak"),
+        Err(GameError::WeakPassword(_))
+    ));
+    user_service
+        .reset_password(user_id, "N3w!Passw0rd")
+        .unwrap();
+
+    assert!(user_service.login("user@test.com", "N3w!Passw0rd").is_ok());
+    assert!(matches!(
+        user_service.login("user@test.com", &test_password()),
+        Err(GameError::InvalidCredentials)
+    ));
+}
+
+#[test]
+fn test_list_users_sorted_by_email() {
+    let user_service = create_test_user_service();
+    user_service
+        .register("alice@test.com".to_string(), test_password())
+        .unwrap();
+
+    let users = user_service.list_users();
+    let emails: Vec<&str> = users.iter().map(|u| u.email.as_str()).collect();
+    assert_eq!(emails, ["alice@test.com", "creator@test.com"]);
+    assert!(users.iter().all(|u| u.is_active && !u.is_locked));
+}
+
+#[test]
+fn test_abort_game_skips_statistics() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.close_enrollment(game_id, creator_id).unwrap();
+
+    let overview = service.abort_game(game_id).unwrap();
+    assert!(overview.finished);
+    assert!(!overview.active);
+    assert!(matches!(
+        service.abort_game(game_id),
+        Err(GameError::GameAlreadyFinished)
+    ));
+    assert!(matches!(
+        service.draw_card(game_id, creator_id),
+        Err(GameError::GameAlreadyFinished)
+    ));
+
+    service.finish_game(game_id, creator_id).unwrap();
+    let stats = user_service.get_user(creator_id).unwrap().stats.unwrap();
+    assert_eq!(stats.games_played, 0);
+    assert_eq!(service.list_games()[0].game_id, game_id);
+    assert!(service.inspect_game(game_id).unwrap().finished);
+}
+
+#[test]
+fn test_list_all_invitations_filters_by_status() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let invitations = InvitationService::new(InvitationConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+
+    let declined = invite(&invitations, &service, game_id, creator_id, "a@test.com").unwrap();
+    invite(&invitations, &service, game_id, creator_id, "b@test.com").unwrap();
+    invitations.decline(declined).unwrap();
+
+    assert_eq!(invitations.list_all(None, &user_service).len(), 2);
+    let pending = invitations.list_all(Some(InvitationStatus::Pending), &user_service);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].invitee_email, "b@test.com");
+}
+
+#[test]
+fn test_set_platform_role() {
+    let user_service = create_test_user_service();
+    let user = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap();
+    assert_eq!(user.role, PlatformRole::User);
+
+    user_service
+        .set_role(user.id, PlatformRole::Moderator)
+        .unwrap();
+    assert_eq!(
+        user_service.get_user(user.id).unwrap().role,
+        PlatformRole::Moderator
+    );
+    assert_eq!(user_service.list_users()[0].role, PlatformRole::Moderator);
+    assert!(matches!(
+        user_service.set_role(Uuid::new_v4(), PlatformRole::Admin),
+        Err(GameError::UserNotFound)
+    ));
+}
+
+#[test]
+fn test_audit_log_keeps_newest_first() {
+    let audit_log = AuditLog::new();
+    let actor = AuditActor {
+        user_id: Some(Uuid::new_v4()),
+        email: "admin@test.com".to_string(),
+        role: PlatformRole::Admin,
+    };
+
+    for action in [
+        "GET /api/v1/admin/users",
+        "POST /api/v1/admin/games/:game_id/abort",
+    ] {
+        audit_log.record(AuditEvent {
+            actor: actor.clone(),
+            action: action.to_string(),
+            target: action.replace("GET ", "").replace("POST ", ""),
+            outcome: AuditOutcome::Success,
+            status: 200,
+            request_id: None,
+        });
+    }
+
+    let records = audit_log.recent(10);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].action, "POST /api/v1/admin/games/:game_id/abort");
+    assert_eq!(records[1].actor_email, "admin@test.com");
+    assert_eq!(audit_log.recent(1).len(), 1);
+}
+
+#[test]
+fn test_audit_log_filters_and_persists() {
+    let path = std::env::temp_dir().join(format!("audit-{}.jsonl", Uuid::new_v4()));
+    let audit_log = AuditLog::open(&path).unwrap();
+    let user_id = Uuid::new_v4();
+    let event = |actor: AuditActor, action: &str, outcome: AuditOutcome, status: u16| AuditEvent {
+        actor,
+        action: action.to_string(),
+        target: "user:target".to_string(),
+        outcome,
+        status,
+        request_id: Some(format!("req-{}", status)),
+    };
+
+    audit_log.record(event(
+        AuditActor::anonymous("nobody@test.com"),
+        audit::actions::LOGIN,
+        AuditOutcome::Failed,
+        401,
+    ));
+    audit_log.record(event(
+        AuditActor {
+            user_id: Some(user_id),
+            email: "player@test.com".to_string(),
+            role: PlatformRole::User,
+        },
+        audit::actions::KICK_PLAYER,
+        AuditOutcome::Denied,
+        403,
+    ));
+
+    let filter = AuditFilter {
+        action: Some("auth.".to_string()),
+        ..Default::default()
+    };
+    let logins = audit_log.query(&filter, 10);
+    assert_eq!(logins.len(), 1);
+    assert_eq!(logins[0].actor_id, None);
+
+    let filter = AuditFilter {
+        actor: Some(user_id.to_string()),
+        outcome: Some(AuditOutcome::Denied),
+        ..Default::default()
+    };
+    assert_eq!(audit_log.query(&filter, 10).len(), 1);
+
+    let filter = AuditFilter {
+        request_id: Some("req-401".to_string()),
+        since: Some(chrono::Utc::now()),
+        ..Default::default()
+    };
+    assert!(audit_log.query(&filter, 10).is_empty());
+
+    let export = audit_log
+        .export_json_lines(&AuditFilter::default())
+        .unwrap();
+    let lines: Vec<&str> = export.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("\"auth.login\""));
+
+    // Reopening replays the file
+    drop(audit_log);
+    let reopened = AuditLog::open(&path).unwrap();
+    assert_eq!(reopened.len(), 2);
+    assert_eq!(reopened.recent(1)[0].action, audit::actions::KICK_PLAYER);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_audit_export_reads_past_the_memory_window() {
+    let path = std::env::temp_dir().join(format!("audit-{}.jsonl", Uuid::new_v4()));
+    let audit_log = AuditLog::open(&path).unwrap();
+    for status in 0..=MAX_AUDIT_RECORDS {
+        audit_log.record(AuditEvent {
+            actor: AuditActor::anonymous("nobody@test.com"),
+            action: audit::actions::LOGIN.to_string(),
+            target: format!("attempt:{}", status),
+            outcome: AuditOutcome::Failed,
+            status: 401,
+            request_id: None,
+        });
+    }
+
+    // Queries see the newest records, the export the whole file
+    assert_eq!(audit_log.len(), MAX_AUDIT_RECORDS);
+    let mut lines = audit_log.export_lines(AuditFilter::default()).unwrap();
+    assert!(lines.next().unwrap().contains("\"attempt:0\""));
+    assert_eq!(lines.count(), MAX_AUDIT_RECORDS);
+
+    // A memory-only log exports what it still holds
+    assert!(
+        AuditLog::new()
+            .export_json_lines(&AuditFilter::default())
+            .unwrap()
+            .is_empty()
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_password_reset_token_is_single_use_and_revokes_sessions() {
+    let service = UserService::new();
+    let user_id = service
+        .register("user@test.com".to_string(), "OldP@ssw0rd".to_string())
+        .unwrap();
+    assert!(
+        service
+            .request_password_reset("nobody@test.com", chrono::Duration::minutes(30))
+            .is_none()
+    );
+
+    let token = service
+        .request_password_reset("user@test.com", chrono::Duration::minutes(30))
+        .unwrap();
+    let sessions_before = service.get_user(user_id).unwrap().session_version;
+
+    // A weak password is rejected without burning the token
+    assert!(matches!(
+        service.reset_password_with_token(&token, "weak"),
+        Err(GameError::WeakPassword(_))
+    ));
+    assert_eq!(
+        service
+            .reset_password_with_token(&token, "NewP@ssw0rd")
+            .unwrap(),
+        user_id
+    );
+    assert!(matches!(
+        service.reset_password_with_token(&token, "OtherP@ssw0rd"),
+        Err(GameError::InvalidResetToken)
+    ));
+
+    assert!(service.login("user@test.com", "NewP@ssw0rd").is_ok());
+    assert_eq!(
+        service.get_user(user_id).unwrap().session_version,
+        sessions_before + 1
+    );
+}
+
+#[test]
+fn test_password_reset_token_expires_and_is_replaced() {
+    let service = UserService::new();
+    service
+        .register("user@test.com".to_string(), "OldP@ssw0rd".to_string())
+        .unwrap();
+
+    let expired = service
+        .request_password_reset("user@test.com", chrono::Duration::seconds(-1))
+        .unwrap();
+    assert!(matches!(
+        service.reset_password_with_token(&expired, "NewP@ssw0rd"),
+        Err(GameError::InvalidResetToken)
+    ));
+
+    let first = service
+        .request_password_reset("user@test.com", chrono::Duration::minutes(30))
+        .unwrap();
+    let second = service
+        .request_password_reset("user@test.com", chrono::Duration::minutes(30))
+        .unwrap();
+    assert!(matches!(
+        service.reset_password_with_token(&first, "NewP@ssw0rd"),
+        Err(GameError::InvalidResetToken)
+    ));
+    assert!(
+        service
+            .reset_password_with_token(&second, "NewP@ssw0rd")
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_email_verification_token_is_single_use() {
+    let service = UserService::new();
+    let user_id = service
+        .register("user@test.com".to_string(), "P@ssw0rd1".to_string())
+        .unwrap();
+    assert!(!service.get_user(user_id).unwrap().email_verified);
+
+    let expired = service
+        .request_email_verification(user_id, chrono::Duration::seconds(-1))
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        service.verify_email(&expired),
+        Err(GameError::InvalidVerificationToken)
+    ));
+
+    let token = service
+        .request_email_verification(user_id, chrono::Duration::hours(48))
+        .unwrap()
+        .unwrap();
+    assert!(service.verify_email(&token).unwrap().email_verified);
+    assert!(service.get_user(user_id).unwrap().email_verified);
+    assert!(matches!(
+        service.verify_email(&token),
+        Err(GameError::InvalidVerificationToken)
+    ));
+
+    // Verified accounts get no new tokens
+    assert!(
+        service
+            .request_email_verification(user_id, chrono::Duration::hours(48))
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_outbox_mailer_keeps_and_writes_messages() {
+    let dir = std::env::temp_dir().join(format!("outbox-{}", Uuid::new_v4()));
+    let outbox = OutboxMailer::with_dir(&dir).unwrap();
+    let message = EmailMessage {
+        to: "user@test.com".to_string(),
+        subject: "Hello".to_string(),
+        body: "Body".to_string(),
+    };
+
+    outbox.send(&message).unwrap();
+
+    assert_eq!(outbox.last_to("user@test.com"), Some(message));
+    assert!(outbox.last_to("other@test.com").is_none());
+    let files: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+    assert_eq!(files.len(), 1);
+    let contents = std::fs::read_to_string(files[0].as_ref().unwrap().path()).unwrap();
+    assert!(contents.starts_with("To: user@test.com\nSubject: Hello\n"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_update_email_requires_password_and_verification() {
+    let service = UserService::new();
+    let user_id = service
+        .register("old@test.com".to_string(), test_password())
+        .unwrap();
+    service
+        .register("taken@test.com".to_string(), test_password())
+        .unwrap();
+    let token = service
+        .request_email_verification(user_id, chrono::Duration::hours(48))
+        .unwrap()
+        .unwrap();
+
+    assert!(matches!(
+        service.update_email(user_id, "new@test.com", "WrongP@ss1"),
+        Err(GameError::InvalidCredentials)
+    ));
+    assert!(matches!(
+        service.update_email(user_id, "taken@test.com", &test_password()),
+        Err(GameError::UserAlreadyExists)
+    ));
+
+    let user = service
+        .update_email(user_id, "new@test.com", &test_password())
+        .unwrap();
+    assert_eq!(user.email, "new@test.com");
+    assert!(!user.email_verified);
+    assert_eq!(user.session_version, 1);
+    assert_eq!(
+        service.get_user_by_email("new@test.com").unwrap().id,
+        user_id
+    );
+    assert!(service.get_user_by_email("old@test.com").is_err());
+    // A link mailed to the old address no longer verifies the account
+    assert!(matches!(
+        service.verify_email(&token),
+        Err(GameError::InvalidVerificationToken)
+    ));
+}
+
+#[test]
+fn test_deleted_account_is_anonymised_after_grace_period() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player_id = user_service
+        .register("leaving@test.com".to_string(), test_password())
+        .unwrap();
+
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.enroll_player(game_id, player_id).unwrap();
+    service.close_enrollment(game_id, creator_id).unwrap();
+    service.stand(game_id, creator_id).unwrap();
+    service.draw_card(game_id, player_id).unwrap();
+    service.stand(game_id, player_id).unwrap();
+    let player_name = user_service.get_user(player_id).unwrap().display_name;
+
+    assert!(matches!(
+        user_service.request_deletion(player_id, "WrongP@ss1"),
+        Err(GameError::InvalidCredentials)
+    ));
+    let user = user_service
+        .request_deletion(player_id, &test_password())
+        .unwrap();
+    assert!(!user.is_active);
+    assert!(user.deletion_requested_at.is_some());
+
+    // Nothing happens during the grace period
+    assert!(
+        service
+            .purge_deleted_accounts(chrono::Duration::days(30))
+            .is_empty()
+    );
+    assert!(user_service.get_user(player_id).is_ok());
+
+    let deleted = service.purge_deleted_accounts(chrono::Duration::zero());
+    assert_eq!(deleted.len(), 1);
+    let tombstone_id = deleted[0].tombstone_id;
+    assert!(user_service.get_user(player_id).is_err());
+    // The email can be registered again
+    assert!(
+        user_service
+            .register("leaving@test.com".to_string(), test_password())
+            .is_ok()
+    );
+
+    // The other player still has the game, with the seat under the tombstone
+    let results = service.get_game_results(game_id).unwrap();
+    let tombstone = &results.player_results[&deleted[0].tombstone_name()];
+    assert_eq!(tombstone.player_id, tombstone_id);
+    assert_eq!(service.game_history(creator_id).len(), 1);
+    assert!(service.game_history(player_id).is_empty());
+
+    // The game's event log no longer names the account either
+    let events = serde_json::to_string(&service.game_events(game_id, 0).unwrap().events).unwrap();
+    assert!(!events.contains(&player_id.to_string()));
+    assert!(!events.contains(&player_name));
+    assert!(events.contains(&tombstone_id.to_string()));
+}
+
+// Helper to build a hand result for leaderboard tests
+fn hand(outcome: PlayerOutcome, points: u8, cards_count: usize) -> PlayerResult {
+    PlayerResult {
+        player_id: Uuid::new_v4(),
+        points,
+        cards_count,
+        busted: outcome == PlayerOutcome::Busted,
+        outcome,
+        is_bot: false,
+        hints_used: 0,
+    }
+}
+
+#[test]
+fn test_leaderboard_tallies_chips_streaks_and_blackjacks() {
+    let boards = Leaderboards::default();
+    let now = chrono::Utc::now();
+    let player = Uuid::new_v4();
+
+    for result in [
+        hand(PlayerOutcome::Won, 21, 2),
+        hand(PlayerOutcome::Won, 19, 3),
+        hand(PlayerOutcome::Push, 18, 2),
+        hand(PlayerOutcome::Won, 20, 2),
+        hand(PlayerOutcome::Busted, 24, 3),
+    ] {
+        boards.record(player, &result, 10, now);
+    }
+
+    let query = LeaderboardQuery {
+        board: Board::NetChips,
+        ..Default::default()
+    };
+    let top = boards.top(&query, now);
+    assert_eq!(top.len(), 1);
+    let tally = &top[0].1;
+    assert_eq!(tally.games_played, 5);
+    assert_eq!(tally.games_won, 3);
+    // 15 for the blackjack, 10 + 10 for the wins, -10 for the bust
+    assert_eq!(tally.net_chips, 25);
+    assert_eq!(tally.longest_win_streak, 2);
+    assert_eq!(tally.current_win_streak, 0);
+    assert_eq!(tally.blackjacks, 1);
+
+    // The win rate board only counts players with enough games
+    assert_eq!(boards.top(&LeaderboardQuery::default(), now).len(), 1);
+    let stricter = LeaderboardQuery {
+        min_games: Some(6),
+        ..Default::default()
+    };
+    assert!(boards.top(&stricter, now).is_empty());
+}
+
+#[test]
+fn test_leaderboard_windows_reset_with_their_period() {
+    let boards = Leaderboards::default();
+    let player = Uuid::new_v4();
+    let monday = chrono::DateTime::parse_from_rfc3339("2026-10-12T10:00:00Z")
+        .unwrap()
+        .to_utc();
+    let tuesday = monday + chrono::Duration::days(1);
+    let next_monday = monday + chrono::Duration::days(7);
+
+    boards.record(player, &hand(PlayerOutcome::Won, 20, 2), 10, monday);
+    boards.record(player, &hand(PlayerOutcome::Won, 20, 2), 10, tuesday);
+
+    let games = |window, at| {
+        let query = LeaderboardQuery {
+            board: Board::WinStreak,
+            window,
+            ..Default::default()
+        };
+        boards
+            .top(&query, at)
+            .first()
+            .map_or(0, |(_, tally)| tally.games_played)
+    };
+    assert_eq!(games(LeaderboardWindow::Daily, tuesday), 1);
+    assert_eq!(games(LeaderboardWindow::Weekly, tuesday), 2);
+    assert_eq!(games(LeaderboardWindow::AllTime, tuesday), 2);
+    // A board for a period with no results yet is empty
+    assert_eq!(games(LeaderboardWindow::Weekly, next_monday), 0);
+
+    boards.record(player, &hand(PlayerOutcome::Lost, 17, 2), 10, next_monday);
+    assert_eq!(games(LeaderboardWindow::Weekly, next_monday), 1);
+    assert_eq!(games(LeaderboardWindow::AllTime, next_monday), 3);
+}
+
+#[test]
+fn test_leaderboard_ranks_players_from_recorded_results() {
+    let user_service = create_test_user_service();
+    let ace = user_service
+        .register_with_display_name(
+            "ace@test.com".to_string(),
+            test_password(),
+            Some("ace".to_string()),
+        )
+        .unwrap();
+    let rookie = user_service
+        .register_with_display_name(
+            "rookie@test.com".to_string(),
+            test_password(),
+            Some("rookie".to_string()),
+        )
+        .unwrap();
+
+    for _ in 0..3 {
+        let ace_hand = PlayerResult {
+            player_id: ace,
+            ..hand(PlayerOutcome::Won, 20, 2)
+        };
+        let rookie_hand = PlayerResult {
+            player_id: rookie,
+            ..hand(PlayerOutcome::Lost, 18, 3)
+        };
+        user_service.record_game_results(&[&ace_hand, &rookie_hand], false, 10);
+    }
+
+    let query = LeaderboardQuery {
+        board: Board::WinRate,
+        window: LeaderboardWindow::Daily,
+        min_games: Some(3),
+        limit: None,
+    };
+    let board = user_service.leaderboard(&query);
+    assert_eq!(board.min_games, 3);
+    let names: Vec<_> = board
+        .entries
+        .iter()
+        .map(|entry| (entry.rank, entry.display_name.as_str()))
+        .collect();
+    assert_eq!(names, vec![(1, "ace"), (2, "rookie")]);
+    assert_eq!(board.entries[0].win_rate, 100.0);
+    assert_eq!(board.entries[1].net_chips, -30);
+
+    let top_one = user_service.leaderboard(&LeaderboardQuery {
+        limit: Some(1),
+        ..query
+    });
+    assert_eq!(top_one.entries.len(), 1);
+    // Profile statistics still count the games
+    assert_eq!(
+        user_service
+            .get_user(ace)
+            .unwrap()
+            .stats
+            .unwrap()
+            .games_played,
+        3
+    );
+}
+
+#[test]
+fn test_ratings_move_with_results_against_dealer_and_table() {
+    let user_service = create_test_user_service();
+    let winner = user_service
+        .register("winner@test.com".to_string(), test_password())
+        .unwrap();
+    let loser = user_service
+        .register("loser@test.com".to_string(), test_password())
+        .unwrap();
+    let pusher = user_service
+        .register("pusher@test.com".to_string(), test_password())
+        .unwrap();
+    assert_eq!(user_service.rating(winner), 1500);
+
+    let seat = |player_id, outcome, points| PlayerResult {
+        player_id,
+        ..hand(outcome, points, 3)
+    };
+    user_service.record_game_results(
+        &[
+            &seat(winner, PlayerOutcome::Won, 20),
+            &seat(loser, PlayerOutcome::Busted, 25),
+            &seat(pusher, PlayerOutcome::Push, 19),
+            // Unknown seats are skipped
+            &seat(Uuid::new_v4(), PlayerOutcome::Won, 21),
+        ],
+        false,
+        10,
+    );
+
+    let (winner_rating, loser_rating, pusher_rating) = (
+        user_service.rating(winner),
+        user_service.rating(loser),
+        user_service.rating(pusher),
+    );
+    assert!(winner_rating > 1500);
+    assert!(loser_rating < 1500);
+    // Beat the loser, lost to the winner and drew with the dealer
+    assert_eq!(pusher_rating, 1500);
+    // Equal ratings before the game, so the changes cancel out
+    assert_eq!(winner_rating + loser_rating, 3000);
+
+    let profile = PlayerProfile::from(&user_service.get_user(winner).unwrap());
+    assert_eq!(profile.rating, winner_rating);
+    assert_eq!(profile.games_played, 1);
+}
+
+#[test]
+fn test_open_games_and_matchmaking_filter_by_rating() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let matchmaking = MatchmakingService::default();
+    let notifications = NotificationService::new();
+    let veteran = user_service
+        .register("veteran@test.com".to_string(), test_password())
+        .unwrap();
+    let newcomer = user_service
+        .register("newcomer@test.com".to_string(), test_password())
+        .unwrap();
+    let other_newcomer = user_service
+        .register("other@test.com".to_string(), test_password())
+        .unwrap();
+    for _ in 0..10 {
+        let win = PlayerResult {
+            player_id: veteran,
+            ..hand(PlayerOutcome::Won, 20, 3)
+        };
+        user_service.record_game_results(&[&win], false, 10);
+    }
+    let veteran_rating = user_service.rating(veteran);
+    assert!(veteran_rating > 1600);
+
+    // Tables are rated by their enrolled players
+    let game_id = service.create_game(veteran, None).unwrap();
+    let open = |min, max| {
+        let query = OpenGamesQuery {
+            rating: RatingBand { min, max },
+            ..Default::default()
+        };
+        service.list_open_games(None, &query)
+    };
+    let page = open(Some(1600), None).unwrap();
+    assert_eq!(page.games.len(), 1);
+    assert_eq!(page.games[0].game_id, game_id);
+    assert_eq!(page.games[0].table_rating, veteran_rating);
+    assert!(open(None, Some(1550)).unwrap().games.is_empty());
+    assert!(matches!(
+        open(Some(1700), Some(1600)),
+        Err(GameError::ValidationError(_))
+    ));
+
+    // Players only meet opponents inside their rating band
+    let rules = TableRules {
+        max_players: 2,
+        ..TableRules::default()
+    };
+    let queue = |user_id, band| {
+        matchmaking.enqueue_with_rating_band(
+            user_id,
+            rules.clone(),
+            None,
+            band,
+            &service,
+            &notifications,
+        )
+    };
+    let waiting = queue(
+        veteran,
+        RatingBand {
+            min: Some(1600),
+            max: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(waiting.ticket.rating, veteran_rating);
+    let waiting = queue(newcomer, RatingBand::default()).unwrap();
+    assert_eq!(waiting.ticket.status, TicketStatus::Waiting);
+    assert_eq!(waiting.players_waiting, 1);
+
+    let matched = queue(other_newcomer, RatingBand::default()).unwrap();
+    assert_eq!(matched.ticket.status, TicketStatus::Matched);
+    let state = service
+        .get_game_state(matched.ticket.game_id.unwrap())
+        .unwrap();
+    assert_eq!(state.players.len(), 2);
+    assert_eq!(
+        matchmaking
+            .status(veteran, &notifications)
+            .unwrap()
+            .ticket
+            .status,
+        TicketStatus::Waiting
+    );
+}
+
+#[test]
+fn test_achievements_unlock_once_from_recorded_results() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let player = user_service
+        .register("achiever@test.com".to_string(), test_password())
+        .unwrap();
+    let seat = |outcome, points, cards_count| PlayerResult {
+        player_id: player,
+        ..hand(outcome, points, cards_count)
+    };
+
+    let progress = user_service.achievements(player).unwrap();
+    assert_eq!(progress.unlocked, 0);
+    assert!(progress.total >= 5);
+    assert!(
+        progress
+            .achievements
+            .iter()
+            .all(|achievement| achievement.unlocked_at.is_none())
+    );
+
+    user_service.record_game_results(&[&seat(PlayerOutcome::Won, 21, 2)], false, 10);
+    for _ in 0..4 {
+        user_service.record_game_results(&[&seat(PlayerOutcome::Won, 19, 3)], true, 10);
+    }
+    // A second blackjack doesn't unlock the achievement again
+    user_service.record_game_results(&[&seat(PlayerOutcome::Won, 21, 2)], false, 10);
+
+    let progress = user_service.achievements(player).unwrap();
+    let unlocked: Vec<&str> = progress
+        .achievements
+        .iter()
+        .filter(|achievement| achievement.unlocked_at.is_some())
+        .map(|achievement| achievement.id.as_str())
+        .collect();
+    assert_eq!(
+        unlocked,
+        vec!["natural_blackjack", "hot_streak", "bust_survivor"]
+    );
+    assert_eq!(progress.unlocked, 3);
+    assert_eq!(progress.current_win_streak, 6);
+    assert_eq!(progress.longest_win_streak, 6);
+    assert_eq!(progress.dealer_bust_streak, 0);
+    assert_eq!(user_service.get_user(player).unwrap().achievements.len(), 3);
+
+    assert!(matches!(
+        user_service.achievements(Uuid::new_v4()),
+        Err(GameError::UserNotFound)
+    ));
+
+    // Results of a played game are evaluated too
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.close_enrollment(game_id, creator_id).unwrap();
+    service.stand(game_id, creator_id).unwrap();
+    let results = service.get_game_results(game_id).unwrap();
+    let creator_stats = user_service.get_user(creator_id).unwrap().stats.unwrap();
+    assert_eq!(creator_stats.games_played, 1);
+    assert_eq!(
+        creator_stats.dealer_bust_streak,
+        u32::from(results.dealer_busted)
+    );
+}
+
+#[test]
+fn test_player_games_list_played_games_with_opponents() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player_id = user_service
+        .register("opponent@test.com".to_string(), test_password())
+        .unwrap();
+
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.enroll_player(game_id, player_id).unwrap();
+    let bot_name = service
+        .add_bot(game_id, creator_id, BotStrategy::MimicDealer)
+        .unwrap();
+    service.close_enrollment(game_id, creator_id).unwrap();
+    service.stand(game_id, creator_id).unwrap();
+    service.stand(game_id, player_id).unwrap();
+    let results = service.get_game_results(game_id).unwrap();
+    let creator_hand = results
+        .player_results
+        .values()
+        .find(|result| result.player_id == creator_id)
+        .unwrap();
+
+    let page = service
+        .player_games(creator_id, &PlayerGamesQuery::default())
+        .unwrap();
+    assert_eq!(page.total, 1);
+    assert!(page.next_cursor.is_none());
+    let entry = &page.games[0];
+    assert_eq!(entry.game_id, game_id);
+    assert_eq!(entry.dealer_points, results.dealer_points);
+    assert_eq!(entry.hands.len(), 1);
+    assert_eq!(entry.hands[0].points, creator_hand.points);
+    assert_eq!(entry.hands[0].cards.len(), creator_hand.cards_count);
+    let outcome = entry.outcome.clone();
+    assert_eq!(entry.opponents.len(), 2);
+    let bot = entry
+        .opponents
+        .iter()
+        .find(|opponent| opponent.display_name == bot_name)
+        .unwrap();
+    assert_eq!(bot.user_id, None);
+    assert!(
+        entry
+            .opponents
+            .iter()
+            .any(|opponent| opponent.user_id == Some(player_id))
+    );
+
+    // Filters by outcome and finish time
+    let other_outcome = if outcome == PlayerOutcome::Push {
+        PlayerOutcome::Won
+    } else {
+        PlayerOutcome::Push
+    };
+    let query = |outcome| PlayerGamesQuery {
+        outcome: Some(outcome),
+        ..Default::default()
+    };
+    assert_eq!(
+        service
+            .player_games(creator_id, &query(outcome))
+            .unwrap()
+            .total,
+        1
+    );
+    assert_eq!(
+        service
+            .player_games(creator_id, &query(other_outcome))
+            .unwrap()
+            .total,
+        0
+    );
+    let before = PlayerGamesQuery {
+        until: Some(entry.played_at),
+        ..Default::default()
+    };
+    assert_eq!(service.player_games(creator_id, &before).unwrap().total, 0);
+
+    // The game stays listed once the deleted account is anonymised
+    user_service
+        .request_deletion(player_id, &test_password())
+        .unwrap();
+    let deleted = service.purge_deleted_accounts(chrono::Duration::zero());
+    let page = service
+        .player_games(creator_id, &PlayerGamesQuery::default())
+        .unwrap();
+    assert!(
+        page.games[0]
+            .opponents
+            .iter()
+            .any(|opponent| opponent.user_id == Some(deleted[0].tombstone_id))
+    );
+    assert_eq!(
+        service
+            .player_games(player_id, &PlayerGamesQuery::default())
+            .unwrap()
+            .total,
+        0
+    );
+}
+
+// Helper to build an archived heads-up game against the dealer
+fn archived_game(player_id: Uuid, outcome: PlayerOutcome, minutes_ago: i64) -> ArchivedGame {
+    let hand = |points| ArchivedHand {
+        cards: Vec::new(),
+        points,
+        busted: points > 21,
+    };
+    ArchivedGame {
+        game_id: Uuid::new_v4(),
+        finished_at: chrono::Utc::now() - chrono::Duration::minutes(minutes_ago),
+        rules: TableRules::default(),
+        dealer: hand(18),
+        seats: vec![ArchivedSeat {
+            player_id,
+            display_name: "archived".to_string(),
+            is_bot: false,
+            hands: vec![hand(20)],
+            outcome,
+            net_chips: 10,
+        }],
+    }
+}
+
+#[test]
+fn test_game_archive_paginates_and_persists() {
+    let path = std::env::temp_dir().join(format!("games-{}.jsonl", Uuid::new_v4()));
+    let archive = GameArchive::open(&path).unwrap();
+    let player_id = Uuid::new_v4();
+    for minutes_ago in [30, 10, 20, 40, 50] {
+        archive.record(archived_game(player_id, PlayerOutcome::Won, minutes_ago));
+    }
+    archive.record(archived_game(player_id, PlayerOutcome::Lost, 5));
+
+    let mut query = PlayerGamesQuery {
+        outcome: Some(PlayerOutcome::Won),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let mut seen = Vec::new();
+    loop {
+        let page = archive.player_games(player_id, &query).unwrap();
+        assert_eq!(page.total, 5);
+        seen.extend(page.games.iter().map(|game| game.played_at));
+        match page.next_cursor {
+            Some(cursor) => query.cursor = Some(cursor),
+            None => break,
+        }
+    }
+    assert_eq!(seen.len(), 5);
+    assert!(seen.windows(2).all(|pair| pair[0] > pair[1]));
+
+    let since = PlayerGamesQuery {
+        since: Some(chrono::Utc::now() - chrono::Duration::minutes(25)),
+        ..Default::default()
+    };
+    assert_eq!(archive.player_games(player_id, &since).unwrap().total, 3);
+
+    let bad_cursor = PlayerGamesQuery {
+        cursor: Some("not-a-cursor".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        archive.player_games(player_id, &bad_cursor),
+        Err(GameError::ValidationError(_))
+    ));
+
+    // Anonymising rewrites the file and reopening replays it
+    let tombstone_id = Uuid::new_v4();
+    assert_eq!(
+        archive.anonymize_player(player_id, tombstone_id, "deleted"),
+        6
+    );
+    drop(archive);
+    let reopened = GameArchive::open(&path).unwrap();
+    assert_eq!(reopened.len(), 6);
+    let query = PlayerGamesQuery::default();
+    assert_eq!(reopened.player_games(player_id, &query).unwrap().total, 0);
+    let page = reopened.player_games(tombstone_id, &query).unwrap();
+    assert_eq!(page.total, 6);
+    assert!(
+        !std::fs::read_to_string(&path)
+            .unwrap()
+            .contains(&player_id.to_string())
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_tournament_registration_and_start() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let notifications = NotificationService::new();
+    let tournaments = TournamentService::new();
+    let organiser = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player = user_service
+        .register("entrant@test.com".to_string(), test_password())
+        .unwrap();
+
+    let invalid = TournamentSettings {
+        advancing_per_table: 4,
+        ..Default::default()
+    };
+    assert!(matches!(
+        tournaments.create(organiser, "Friday", invalid),
+        Err(GameError::ValidationError(_))
+    ));
+    assert!(matches!(
+        tournaments.create(organiser, "  ", TournamentSettings::default()),
+        Err(GameError::ValidationError(_))
+    ));
+
+    let id = tournaments
+        .create(organiser, "Friday", TournamentSettings::default())
+        .unwrap()
+        .id;
+    tournaments.register(id, player, &user_service).unwrap();
+    assert!(matches!(
+        tournaments.register(id, player, &user_service),
+        Err(GameError::AlreadyRegistered)
+    ));
+    // One player isn't a tournament
+    assert!(matches!(
+        tournaments.start(id, organiser, &service, &notifications),
+        Err(GameError::ValidationError(_))
+    ));
+
+    tournaments.register(id, organiser, &user_service).unwrap();
+    assert!(matches!(
+        tournaments.start(id, player, &service, &notifications),
+        Err(GameError::InsufficientPermissions)
+    ));
+    let tournament = tournaments
+        .start(id, organiser, &service, &notifications)
+        .unwrap();
+    assert_eq!(tournament.status, TournamentStatus::Running);
+    assert_eq!(tournament.current_round(), 1);
+    assert_eq!(tournament.rounds[0].tables.len(), 1);
+    assert!(matches!(
+        tournaments.start(id, organiser, &service, &notifications),
+        Err(GameError::TournamentAlreadyStarted)
+    ));
+    let late = user_service
+        .register("late@test.com".to_string(), test_password())
+        .unwrap();
+    assert!(matches!(
+        tournaments.register(id, late, &user_service),
+        Err(GameError::TournamentAlreadyStarted)
+    ));
+    assert!(matches!(
+        tournaments.get(Uuid::new_v4()),
+        Err(GameError::TournamentNotFound)
+    ));
+    assert_eq!(
+        tournaments.list(Some(TournamentStatus::Running))[0].registered_players,
+        2
+    );
+    assert!(
+        tournaments
+            .list(Some(TournamentStatus::Finished))
+            .is_empty()
+    );
+
+    // The seated game is private, closed and uses the tournament's bet
+    let table = &tournament.rounds[0].tables[0];
+    let game = service.inspect_game(table.game_id.unwrap()).unwrap();
+    assert!(game.enrollment_closed);
+    assert_eq!(game.visibility, GameVisibility::Private);
+    assert_eq!(game.rules.min_bet, 100);
+    assert!(
+        notifications
+            .get_for_user(player, false)
+            .iter()
+            .any(|notification| matches!(
+                notification.kind,
+                NotificationKind::TournamentTableReady { round: 1, .. }
+            ))
+    );
+}
+
+#[test]
+fn test_tournament_eliminates_until_a_champion() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let notifications = NotificationService::new();
+    let tournaments = TournamentService::new();
+    let organiser = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let settings = TournamentSettings {
+        rounds: 3,
+        starting_chips: 500,
+        table_size: 3,
+        advancing_per_table: 1,
+        bet: 100,
+    };
+    let id = tournaments
+        .create(organiser, "Knockout", settings)
+        .unwrap()
+        .id;
+    let players: Vec<Uuid> = (0..5)
+        .map(|index| {
+            user_service
+                .register(format!("knockout{}@test.com", index), test_password())
+                .unwrap()
+        })
+        .collect();
+    for player in &players {
+        tournaments.register(id, *player, &user_service).unwrap();
+    }
+
+    let tournament = tournaments
+        .start(id, organiser, &service, &notifications)
+        .unwrap();
+    let tables = &tournament.rounds[0].tables;
+    let mut sizes: Vec<usize> = tables.iter().map(|table| table.seats.len()).collect();
+    sizes.sort();
+    assert_eq!(sizes, vec![2, 3]);
+
+    // Nothing settles while games are in progress
+    assert_eq!(tournaments.advance(&service, &notifications), 0);
+
+    // The first table plays its hand, the second is aborted and doesn't count
+    for seat in &tables[0].seats {
+        service
+            .stand(tables[0].game_id.unwrap(), seat.user_id)
+            .unwrap();
+    }
+    service.abort_game(tables[1].game_id.unwrap()).unwrap();
+    assert_eq!(tournaments.advance(&service, &notifications), 2);
+
+    let tournament = tournaments.get(id).unwrap();
+    let round = &tournament.rounds[0];
+    for table in &round.tables {
+        assert!(table.settled);
+        assert!(table.seats.iter().filter(|seat| seat.advanced).count() <= 1);
+    }
+    for seat in &round.tables[1].seats {
+        assert_eq!(seat.chips, Some(500));
+        assert!(seat.outcome.is_none());
+    }
+    let first_table = &round.tables[0];
+    for seat in &first_table.seats {
+        let chips = seat.chips.unwrap();
+        match seat.outcome.as_ref().unwrap() {
+            PlayerOutcome::Won => assert!(chips == 600 || chips == 650),
+            PlayerOutcome::Push => assert_eq!(chips, 500),
+            PlayerOutcome::Lost | PlayerOutcome::Busted => assert_eq!(chips, 400),
+        }
+    }
+
+    let advanced = round
+        .tables
+        .iter()
+        .flat_map(|table| table.seats.iter())
+        .filter(|seat| seat.advanced)
+        .count();
+    if advanced > 1 {
+        // The final table plays out and crowns the biggest stack
+        assert_eq!(tournament.status, TournamentStatus::Running);
+        assert_eq!(tournament.current_round(), 2);
+        let table = &tournament.rounds[1].tables[0];
+        for seat in &table.seats {
+            service.stand(table.game_id.unwrap(), seat.user_id).unwrap();
+        }
+        assert_eq!(tournaments.advance(&service, &notifications), 1);
+    }
+
+    let tournament = tournaments.get(id).unwrap();
+    assert_eq!(tournament.status, TournamentStatus::Finished);
+    let champion = tournament.champion_id.unwrap();
+    let standings = tournament.standings();
+    assert_eq!(standings.len(), 5);
+    assert_eq!(standings[0].user_id, champion);
+    assert_eq!(standings[0].status, StandingStatus::Champion);
+    assert!(
+        standings[1..]
+            .iter()
+            .all(|standing| standing.status == StandingStatus::Eliminated)
+    );
+    assert!(
+        notifications
+            .get_for_user(champion, false)
+            .iter()
+            .any(|notification| matches!(
+                notification.kind,
+                NotificationKind::TournamentWon { .. }
+            ))
+    );
+    // A finished tournament is left alone
+    assert_eq!(tournaments.advance(&service, &notifications), 0);
+}
+
+#[test]
+fn test_table_chat_delivers_messages_to_other_participants() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let notifications = NotificationService::new();
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player_id = user_service
+        .register("player1@test.com".to_string(), test_password())
+        .unwrap();
+    let outsider_id = user_service
+        .register("outsider@test.com".to_string(), test_password())
+        .unwrap();
+
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.enroll_player(game_id, player_id).unwrap();
+
+    let message = service
+        .post_chat_message(game_id, player_id, "  good luck  ", &notifications)
+        .unwrap();
+    assert_eq!(message.text, "good luck");
+    assert_eq!(
+        message.author_name,
+        user_service.get_user(player_id).unwrap().display_name
+    );
+
+    // Only the other participants are notified
+    let inbox = notifications.get_for_user(creator_id, false);
+    assert_eq!(inbox.len(), 1);
+    assert!(matches!(
+        inbox[0].kind,
+        NotificationKind::ChatMessage { message_id, .. } if message_id == message.id
+    ));
+    // The text stays in the chat history, where deletion removes it
+    assert!(!inbox[0].message.contains("good luck"));
+    assert!(notifications.get_for_user(player_id, false).is_empty());
+
+    // Empty and overlong messages are rejected
+    assert!(matches!(
+        service.post_chat_message(game_id, player_id, "   ", &notifications),
+        Err(GameError::ValidationError(_))
+    ));
+    let long = "a".repeat(blackjack_service::chat::MAX_CHAT_MESSAGE_LENGTH + 1);
+    assert!(matches!(
+        service.post_chat_message(game_id, player_id, &long, &notifications),
+        Err(GameError::ValidationError(_))
+    ));
+
+    // Outsiders can neither post nor read
+    assert!(matches!(
+        service.post_chat_message(game_id, outsider_id, "hi", &notifications),
+        Err(GameError::PlayerNotInGame)
+    ));
+    assert!(matches!(
+        service.chat_history(game_id, outsider_id, None),
+        Err(GameError::PlayerNotInGame)
+    ));
+
+    service
+        .post_chat_message(game_id, creator_id, "you too", &notifications)
+        .unwrap();
+    let history = service.chat_history(game_id, player_id, None).unwrap();
+    let texts: Vec<&str> = history.messages.iter().map(|m| m.text.as_str()).collect();
+    assert_eq!(texts, vec!["good luck", "you too"]);
+    let latest = service.chat_history(game_id, player_id, Some(1)).unwrap();
+    assert_eq!(latest.messages.len(), 1);
+    assert_eq!(latest.messages[0].text, "you too");
+}
+
+#[test]
+fn test_table_chat_moderation() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let notifications = NotificationService::new();
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player1_id = user_service
+        .register("player1@test.com".to_string(), test_password())
+        .unwrap();
+    let player2_id = user_service
+        .register("player2@test.com".to_string(), test_password())
+        .unwrap();
+
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.enroll_player(game_id, player1_id).unwrap();
+    service.enroll_player(game_id, player2_id).unwrap();
+
+    let message = service
+        .post_chat_message(game_id, player1_id, "spam", &notifications)
+        .unwrap();
+
+    // Only the creator moderates the chat, and can't be muted
+    assert!(matches!(
+        service.set_chat_muted(game_id, player2_id, player1_id, true, &notifications),
+        Err(GameError::InsufficientPermissions)
+    ));
+    assert!(matches!(
+        service.delete_chat_message(game_id, player2_id, message.id, &notifications),
+        Err(GameError::InsufficientPermissions)
+    ));
+    assert!(matches!(
+        service.set_chat_muted(game_id, creator_id, creator_id, true, &notifications),
+        Err(GameError::ValidationError(_))
+    ));
+
+    service
+        .set_chat_muted(game_id, creator_id, player1_id, true, &notifications)
+        .unwrap();
+    assert!(matches!(
+        service.post_chat_message(game_id, player1_id, "more spam", &notifications),
+        Err(GameError::ChatMuted)
+    ));
+    assert!(
+        notifications
+            .get_for_user(player1_id, false)
+            .iter()
+            .any(|n| matches!(n.kind, NotificationKind::ChatMuted { muted: true, .. }))
+    );
+    let history = service.chat_history(game_id, player2_id, None).unwrap();
+    assert_eq!(history.muted, vec![player1_id]);
+
+    service
+        .delete_chat_message(game_id, creator_id, message.id, &notifications)
+        .unwrap();
+    assert!(
+        service
+            .chat_history(game_id, player2_id, None)
+            .unwrap()
+            .messages
+            .is_empty()
+    );
+    assert!(matches!(
+        service.delete_chat_message(game_id, creator_id, message.id, &notifications),
+        Err(GameError::ChatMessageNotFound)
+    ));
+
+    service
+        .set_chat_muted(game_id, creator_id, player1_id, false, &notifications)
+        .unwrap();
+    assert!(
+        service
+            .post_chat_message(game_id, player1_id, "sorry", &notifications)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_game_events_are_sequenced_and_resumable() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let creator_id = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player_id = user_service
+        .register("player1@test.com".to_string(), test_password())
+        .unwrap();
+
+    let game_id = service.create_game(creator_id, None).unwrap();
+    service.enroll_player(game_id, player_id).unwrap();
+    assert!(service.game_events(game_id, 0).unwrap().events.is_empty());
+
+    service.close_enrollment(game_id, creator_id).unwrap();
+    service.draw_card(game_id, creator_id).unwrap();
+    let so_far = service.game_events(game_id, 0).unwrap();
+    assert!(!so_far.finished);
+    let names: Vec<&str> = so_far.events.iter().map(|e| e.kind.name()).collect();
+    assert_eq!(names, ["enrollment_closed", "card_drawn", "turn_changed"]);
+    assert!(matches!(
+        &so_far.events[2].kind,
+        blackjack_core::events::GameEventKind::TurnChanged { player_id: id, .. } if *id == player_id
+    ));
+
+    // Drawing moved the turn on, so the creator stands after the player
+    service.stand(game_id, player_id).unwrap();
+    service.stand(game_id, creator_id).unwrap();
+
+    // Resuming after the last seen event returns only what came next
+    let rest = service.game_events(game_id, 3).unwrap();
+    assert!(rest.finished);
+    let names: Vec<&str> = rest.events.iter().map(|e| e.kind.name()).collect();
+    assert_eq!(names.last(), Some(&"game_finished"));
+    assert!(names.contains(&"dealer_played"));
+    let seqs: Vec<u64> = rest.events.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, (4..4 + seqs.len() as u64).collect::<Vec<_>>());
+    assert!(
+        service
+            .game_events(game_id, *seqs.last().unwrap())
+            .unwrap()
+            .events
+            .is_empty()
+    );
+
+    assert!(matches!(
+        service.game_events(Uuid::new_v4(), 0),
+        Err(GameError::GameNotFound)
+    ));
+}
+
+#[test]
+fn test_tournament_anonymises_deleted_accounts() {
+    let (service, user_service) = create_game_service(ServiceConfig::default());
+    let notifications = NotificationService::new();
+    let tournaments = TournamentService::new();
+    let organiser = user_service
+        .get_user_by_email(&test_creator_email())
+        .unwrap()
+        .id;
+    let player = user_service
+        .register("leaving@test.com".to_string(), test_password())
+        .unwrap();
+    let player_name = user_service.get_user(player).unwrap().display_name;
+
+    let id = tournaments
+        .create(organiser, "Friday", TournamentSettings::default())
+        .unwrap()
+        .id;
+    tournaments.register(id, organiser, &user_service).unwrap();
+    tournaments.register(id, player, &user_service).unwrap();
+    tournaments
+        .start(id, organiser, &service, &notifications)
+        .unwrap();
+
+    user_service
+        .request_deletion(player, &test_password())
+        .unwrap();
+    let deleted = service.purge_deleted_accounts(chrono::Duration::zero());
+    let account = &deleted[0];
+    assert_eq!(
+        tournaments.anonymize_player(
+            account.user_id,
+            account.tombstone_id,
+            &account.tombstone_name()
+        ),
+        1
+    );
+
+    let tournament = tournaments.get(id).unwrap();
+    let json = serde_json::to_string(&tournament).unwrap();
+    assert!(!json.contains(&player.to_string()));
+    assert!(!json.contains(&player_name));
+    let seats = &tournament.rounds[0].tables[0].seats;
+    assert!(
+        seats
+            .iter()
+            .any(|seat| seat.user_id == account.tombstone_id)
+    );
+    assert!(
+        tournament
+            .standings()
+            .iter()
+            .any(|standing| standing.display_name == account.tombstone_name())
+    );
+}